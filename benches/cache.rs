@@ -0,0 +1,62 @@
+//! Times matching a corpus of 10 000 snippets in which 90% are duplicates, with and
+//! without the cache of the matcher.
+//!
+//! Run with `cargo bench --features std --bench cache`. With the cache, only the
+//! distinct snippets are matched, so the speedup should be close to 10x.
+
+use license::text::Matcher;
+use std::time::Instant;
+
+const SNIPPETS: usize = 10_000;
+const DISTINCT: usize = SNIPPETS / 10;
+
+/// The first `len` bytes of a text, cut at a character boundary.
+fn head(text: &str, len: usize) -> &str {
+    let mut end = len.min(text.len());
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+fn main() {
+    let distinct: Vec<&str> = license::LICENSES
+        .iter()
+        .cycle()
+        .enumerate()
+        .map(|(i, license)| head(license.text(), 200 + i % 7 * 100))
+        .take(DISTINCT)
+        .collect();
+    // Every distinct snippet, then duplicates spread over them.
+    let snippets: Vec<&str> = (0..SNIPPETS)
+        .map(|i| distinct[i * 7919 % DISTINCT])
+        .collect();
+
+    let uncached = Matcher::new();
+    let start = Instant::now();
+    let plain: Vec<_> = snippets
+        .iter()
+        .map(|text| uncached.match_one(text))
+        .collect();
+    let without = start.elapsed();
+
+    let cached = Matcher::new().with_cache(DISTINCT);
+    let start = Instant::now();
+    let hits: Vec<_> = snippets.iter().map(|text| cached.match_one(text)).collect();
+    let with = start.elapsed();
+
+    assert!(plain
+        .iter()
+        .zip(&hits)
+        .all(|(a, b)| a.map(|m| m.license().id()) == b.map(|m| m.license().id())));
+    let stats = cached.cache_stats().unwrap();
+    println!("without cache: {} snippets in {:?}", SNIPPETS, without);
+    println!(
+        "with cache:    {} snippets in {:?}, {:.1}x, {} hits and {} misses",
+        SNIPPETS,
+        with,
+        without.as_secs_f64() / with.as_secs_f64(),
+        stats.hits(),
+        stats.misses()
+    );
+}
@@ -0,0 +1,56 @@
+//! Times matching a corpus of 10 000 snippets, one by one and with `match_many`.
+//!
+//! Run with `cargo bench --features alloc,rayon --bench matcher`. The speedup of
+//! `match_many` should be close to the number of cores.
+
+use license::text::Matcher;
+use std::time::Instant;
+
+const SNIPPETS: usize = 10_000;
+
+/// The first `len` bytes of a text, cut at a character boundary.
+fn head(text: &str, len: usize) -> &str {
+    let mut end = len.min(text.len());
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+fn main() {
+    let start = Instant::now();
+    let matcher = Matcher::new();
+    println!("built the matcher in {:?}", start.elapsed());
+
+    let snippets: Vec<&str> = license::LICENSES
+        .iter()
+        .cycle()
+        .enumerate()
+        .map(|(i, license)| head(license.text(), 200 + i % 7 * 100))
+        .take(SNIPPETS)
+        .collect();
+
+    let start = Instant::now();
+    let one: Vec<_> = snippets
+        .iter()
+        .map(|text| matcher.match_one(text))
+        .collect();
+    let sequential = start.elapsed();
+
+    let start = Instant::now();
+    let many = matcher.match_many(&snippets);
+    let parallel = start.elapsed();
+
+    assert!(one
+        .iter()
+        .zip(&many)
+        .all(|(a, b)| a.map(|m| m.license().id()) == b.map(|m| m.license().id())));
+    println!("match_one:  {} snippets in {:?}", SNIPPETS, sequential);
+    println!(
+        "match_many: {} snippets in {:?}, {:.1}x on {} threads",
+        SNIPPETS,
+        parallel,
+        sequential.as_secs_f64() / parallel.as_secs_f64(),
+        rayon::current_num_threads()
+    );
+}
@@ -0,0 +1,51 @@
+//! Builds the library on its minimum supported Rust version, see `rust-toolchain.toml`.
+//!
+//! The const APIs and the macros that expand to constants are used in constants here,
+//! so that they are checked to be const on that version too.
+
+use license::{
+    Conditions, Limitations, Metadata, PackageLicense, PackageLicenseError, Permissions,
+};
+
+pub const EXPRESSION: Result<PackageLicense, PackageLicenseError> =
+    PackageLicense::parse("(MIT OR Apache-2.0) AND GPL-2.0-only WITH Classpath-exception-2.0");
+
+pub const METADATA: Metadata = Metadata::new(
+    Permissions::from_bits_truncate(0b11111),
+    Conditions::from_bits_truncate(0b100),
+    Limitations::from_bits_truncate(0b101),
+);
+
+pub const PERMISSIONS: u32 = METADATA.permissions().bits();
+
+pub const DECLARED: &PackageLicense =
+    license::declared!("GPL-2.0-only WITH Classpath-exception-2.0");
+
+pub const FROM_ENV: &PackageLicense = license::from_env!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constants() {
+        assert_eq!(
+            EXPRESSION.unwrap().expression(),
+            "(MIT OR Apache-2.0) AND GPL-2.0-only WITH Classpath-exception-2.0"
+        );
+        assert!(METADATA.limitations().no_liability());
+        assert_eq!(PERMISSIONS, 0b11111);
+        assert_eq!(
+            DECLARED.expression(),
+            "GPL-2.0-only WITH Classpath-exception-2.0"
+        );
+        assert_eq!(FROM_ENV.expression(), "MIT OR Apache-2.0");
+    }
+
+    #[test]
+    fn lookups() {
+        let mit = license::from_id_ext("MIT").unwrap();
+        assert_eq!(mit.name(), "MIT License");
+        assert!(license::LICENSES.len() > 400);
+    }
+}
@@ -0,0 +1,265 @@
+//! Compares the extension data of the crate against choosealicense.com and SPDX.
+//!
+//! Run it from the `run` directory with `cargo run --bin upstream-check`.
+
+use license::{LicenseExt, LICENSES_EXT};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::Path;
+use std::process::{self, Command};
+
+const CHOOSEALICENSE: &str = "../target/choosealicense.com";
+
+type Flag = fn(&dyn LicenseExt) -> bool;
+
+/// Maps the rule tags of choosealicense.com to the flags of the crate.
+const RULES: &[(&str, &str, Flag)] = &[
+    ("permissions", "commercial-use", |l| {
+        l.permissions().commercial_use()
+    }),
+    ("permissions", "distribution", |l| {
+        l.permissions().distribution()
+    }),
+    ("permissions", "modifications", |l| {
+        l.permissions().modification()
+    }),
+    ("permissions", "patent-use", |l| {
+        l.permissions().patent_rights()
+    }),
+    ("permissions", "private-use", |l| {
+        l.permissions().private_use()
+    }),
+    ("conditions", "disclose-source", |l| {
+        l.conditions().disclose_sources()
+    }),
+    // choosealicense.com doesn't tell documenting changes from marking them.
+    ("conditions", "document-changes", |l| {
+        l.conditions().document_changes() || l.conditions().mark_modifications()
+    }),
+    ("conditions", "include-copyright", |l| {
+        l.conditions().license_and_copyright_notice()
+    }),
+    ("conditions", "network-use-disclose", |l| {
+        l.conditions().network_use_is_distribution()
+    }),
+    ("conditions", "same-license", |l| {
+        l.conditions().same_license()
+    }),
+    ("limitations", "liability", |l| {
+        l.limitations().no_liability()
+    }),
+    ("limitations", "trademark-use", |l| {
+        l.limitations().no_trademark_rights()
+    }),
+    ("limitations", "warranty", |l| l.limitations().no_warranty()),
+    ("limitations", "patent-use", |l| {
+        l.limitations().no_patent_rights()
+    }),
+];
+
+/// Tags that refine another tag and count as it.
+const ALIASES: &[(&str, &str)] = &[
+    ("include-copyright--source", "include-copyright"),
+    ("same-license--file", "same-license"),
+    ("same-license--library", "same-license"),
+];
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxList {
+    licenses: Vec<SpdxLicense>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxLicense {
+    license_id: String,
+    name: String,
+    is_osi_approved: bool,
+}
+
+struct Mismatch {
+    id: &'static str,
+    field: String,
+    crate_value: String,
+    upstream_value: String,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    update_choosealicense()?;
+    let mut mismatches = Vec::new();
+    check_choosealicense(&mut mismatches)?;
+    check_spdx(&mut mismatches)?;
+
+    if mismatches.is_empty() {
+        println!("No mismatches.");
+        return Ok(());
+    }
+    let width = |f: fn(&Mismatch) -> &str, title: &str| {
+        mismatches
+            .iter()
+            .map(|m| f(m).len())
+            .chain(Some(title.len()))
+            .max()
+            .unwrap()
+    };
+    let widths = [
+        width(|m| m.id, "License"),
+        width(|m| &m.field, "Field"),
+        width(|m| &m.crate_value, "Crate"),
+    ];
+    println!(
+        "{:w0$}  {:w1$}  {:w2$}  Upstream",
+        "License",
+        "Field",
+        "Crate",
+        w0 = widths[0],
+        w1 = widths[1],
+        w2 = widths[2],
+    );
+    for m in &mismatches {
+        println!(
+            "{:w0$}  {:w1$}  {:w2$}  {}",
+            m.id,
+            m.field,
+            m.crate_value,
+            m.upstream_value,
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+        );
+    }
+    process::exit(1);
+}
+
+fn update_choosealicense() -> Result<(), Box<dyn Error>> {
+    let status = if Path::new(CHOOSEALICENSE).exists() {
+        Command::new("git")
+            .arg("pull")
+            .arg("--ff-only")
+            .current_dir(CHOOSEALICENSE)
+            .status()?
+    } else {
+        Command::new("git")
+            .arg("clone")
+            .arg("--depth")
+            .arg("1")
+            .arg("https://github.com/github/choosealicense.com.git")
+            .arg(CHOOSEALICENSE)
+            .status()?
+    };
+    if status.success() {
+        Ok(())
+    } else {
+        Err("failed to update choosealicense.com".into())
+    }
+}
+
+fn check_choosealicense(mismatches: &mut Vec<Mismatch>) -> Result<(), Box<dyn Error>> {
+    let mut upstream = HashMap::new();
+    for entry in fs::read_dir(Path::new(CHOOSEALICENSE).join("_licenses"))? {
+        let text = fs::read_to_string(entry?.path())?;
+        let front_matter = parse_front_matter(&text);
+        if let Some(id) = front_matter
+            .get("spdx-id")
+            .and_then(|values| values.first())
+        {
+            upstream.insert(id.clone(), front_matter);
+        }
+    }
+
+    for &license in LICENSES_EXT {
+        let front_matter = match upstream.get(license.id()) {
+            Some(front_matter) => front_matter,
+            None => continue,
+        };
+        for &(key, tag, flag) in RULES {
+            let expected = front_matter.get(key).is_some_and(|tags| {
+                tags.iter()
+                    .any(|t| t == tag || ALIASES.iter().any(|&(alias, of)| t == alias && of == tag))
+            });
+            let actual = flag(license);
+            if expected != actual {
+                mismatches.push(Mismatch {
+                    id: license.id(),
+                    field: format!("{}: {}", key, tag),
+                    crate_value: actual.to_string(),
+                    upstream_value: format!("{} (choosealicense.com)", expected),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_spdx(mismatches: &mut Vec<Mismatch>) -> Result<(), Box<dyn Error>> {
+    let rdr = File::open("../license-list-data/json/licenses.json")?;
+    let list: SpdxList = serde_json::from_reader(BufReader::new(rdr))?;
+    let upstream: HashMap<_, _> = list
+        .licenses
+        .iter()
+        .map(|l| (l.license_id.as_str(), l))
+        .collect();
+
+    for &license in LICENSES_EXT {
+        if license.exception().is_some() {
+            continue;
+        }
+        let spdx = match upstream.get(license.id()) {
+            Some(spdx) => spdx,
+            None => {
+                mismatches.push(Mismatch {
+                    id: license.id(),
+                    field: "id".to_string(),
+                    crate_value: license.id().to_string(),
+                    upstream_value: "missing (SPDX)".to_string(),
+                });
+                continue;
+            }
+        };
+        if spdx.name != license.name() {
+            mismatches.push(Mismatch {
+                id: license.id(),
+                field: "name".to_string(),
+                crate_value: license.name().to_string(),
+                upstream_value: format!("{} (SPDX)", spdx.name),
+            });
+        }
+        if spdx.is_osi_approved != license.is_osi_approved() {
+            mismatches.push(Mismatch {
+                id: license.id(),
+                field: "is_osi_approved".to_string(),
+                crate_value: license.is_osi_approved().to_string(),
+                upstream_value: format!("{} (SPDX)", spdx.is_osi_approved),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Parses the scalar values and lists of the YAML front matter of a choosealicense.com license.
+fn parse_front_matter(text: &str) -> HashMap<String, Vec<String>> {
+    let mut map = HashMap::new();
+    let mut key = None;
+    for line in text.lines().skip(1).take_while(|line| *line != "---") {
+        if let Some(item) = line.trim_start().strip_prefix("- ") {
+            if let Some(key) = &key {
+                map.entry(String::clone(key))
+                    .or_insert_with(Vec::new)
+                    .push(item.trim().to_string());
+            }
+        } else if !line.starts_with(' ') {
+            if let Some((k, v)) = line.split_once(':') {
+                let v = v.trim();
+                if !v.is_empty() {
+                    map.insert(k.to_string(), vec![v.to_string()]);
+                }
+                key = Some(k.to_string());
+            }
+        }
+    }
+    map
+}
@@ -4,8 +4,15 @@ use std::fs::{self, File};
 use std::io::{BufReader, BufWriter, Write};
 use std::process::Command;
 
-#[serde(rename_all = "camelCase")]
+#[path = "../../src/family/named.rs"]
+mod named;
+mod provenance;
+mod sections;
+
+use license::Family;
+
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct License {
     name: String,
     license_id: String,
@@ -28,15 +35,15 @@ impl License {
             .replace('.', "_")
             .replace('+', "_plus");
         if ident == "0BSD" {
-            "BSD_0".to_string()
+            "ZERO_BSD".to_string()
         } else {
             ident
         }
     }
 }
 
-#[serde(rename_all = "camelCase")]
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct Exception {
     name: String,
     license_exception_id: String,
@@ -61,6 +68,38 @@ impl Exception {
     }
 }
 
+/// The licenses that are newer than the retrieved license list, with the pages that
+/// their texts were taken from. Each is read from `extra/<id>.json`, in the format of
+/// the list, and is removed from here once the list has it.
+const EXTRA_LICENSES: &[(&str, &str)] = &[
+    (
+        "Elastic-2.0",
+        "https://www.elastic.co/licensing/elastic-license",
+    ),
+    ("DL-DE-BY-2.0", "https://www.govdata.de/dl-de/by-2-0"),
+];
+
+/// The families whose licenses are also re-exported from a module of their own, with
+/// the name of the module and the first line of its docs.
+const FAMILY_MODULES: &[(Family, &str, &str)] = &[
+    (Family::Gnu, "gnu", "The licenses of the GNU project"),
+    (Family::Bsd, "bsd", "The BSD licenses and their variants"),
+];
+
+/// The former names of the licenses whose name isn't their id with `_` for the
+/// separators, and their names, written as deprecated aliases.
+const RENAMED: &[(&str, &str)] = &[("BSD_0", "ZERO_BSD")];
+
+/// The `#[doc(alias)]` that finds an item by its SPDX id in rustdoc, unless the id is
+/// the name of the item.
+fn doc_alias(id: &str, ident: &str) -> String {
+    if id == ident {
+        String::new()
+    } else {
+        format!("#[doc(alias = {:?})]\n", id)
+    }
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
     let output = Command::new("git")
         .arg("pull")
@@ -76,8 +115,9 @@ fn main() -> Result<(), Box<dyn Error>> {
             return Ok(());
         }
 
-        build_licenses_from_json()?;
-        build_exceptions_from_json()?;
+        let licenses = build_licenses_from_json()?;
+        let exceptions = build_exceptions_from_json()?;
+        build_provenance(&licenses, &exceptions)?;
 
         Command::new("cargo")
             .arg("fmt")
@@ -89,10 +129,17 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn build_licenses_from_json() -> Result<(), Box<dyn Error>> {
+fn build_licenses_from_json() -> Result<Vec<License>, Box<dyn Error>> {
+    let descriptions = fs::read_to_string("DESCRIPTIONS")?;
+    let descriptions: Vec<_> = descriptions
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once('\t'))
+        .collect();
     let mut f = BufWriter::with_capacity(4_194_304, File::create("../src/licenses.rs")?);
     let mut licenses = Vec::with_capacity(512);
     f.write_all(b"// This file is autogenerated, do not edit.\n\n")?;
+    f.write_all(b"pub mod creative_commons;\n\n")?;
     f.write_all(b"/// Returns a license based on the provided id.\n")?;
     f.write_all(b"///\n")?;
     f.write_all(b"/// # Examples\n")?;
@@ -114,16 +161,90 @@ fn build_licenses_from_json() -> Result<(), Box<dyn Error>> {
         )?;
         licenses.push(license);
     }
-    f.write_all(b"        _ => None,\n")?;
+    for (id, _) in EXTRA_LICENSES {
+        if licenses.iter().any(|license| license.license_id == *id) {
+            return Err(format!(
+                "{} is on the license list, remove it from EXTRA_LICENSES",
+                id
+            )
+            .into());
+        }
+        let rdr = File::open(format!("extra/{}.json", id))?;
+        let license: License = serde_json::from_reader(BufReader::new(rdr))?;
+        writeln!(
+            f,
+            "        {:?} => Some(&{}),",
+            license.license_id,
+            license.ident()
+        )?;
+        licenses.push(license);
+    }
+    f.write_all(b"        _ => crate::sentinel::by_id(id),\n")?;
     f.write_all(b"    }\n")?;
     f.write_all(b"}\n\n")?;
-    for license in licenses {
+    let mut sorted: Vec<_> = licenses.iter().collect();
+    sorted.sort_by(|a, b| a.license_id.cmp(&b.license_id));
+    f.write_all(
+        b"/// All licenses, sorted by id, which is the order of `dyn License`, see [`License`](crate::License).\n",
+    )?;
+    f.write_all(b"///\n")?;
+    f.write_all(b"/// # Examples\n")?;
+    f.write_all(b"/// ```\n")?;
+    f.write_all(b"/// let ids: Vec<_> = license::LICENSES.iter().map(|l| l.id()).collect();\n")?;
+    f.write_all(b"/// assert_eq!(ids[..2], [\"0BSD\", \"AAL\"]);\n")?;
+    f.write_all(b"/// ```\n")?;
+    f.write_all(b"pub const LICENSES: &[&dyn crate::License] = &[\n")?;
+    for license in &sorted {
+        writeln!(f, "    &{},", license.ident())?;
+    }
+    f.write_all(b"];\n\n")?;
+    f.write_all(b"/// The ids of [`LICENSES`], for lookups in constant expressions.\n")?;
+    f.write_all(b"pub(crate) const LICENSE_IDS: &[&str] = &[\n")?;
+    for license in &sorted {
+        writeln!(f, "    {:?},", license.license_id)?;
+    }
+    f.write_all(b"];\n\n")?;
+    f.write_all(b"/// The version of the generated fingerprints, see\n")?;
+    f.write_all(b"/// [`FINGERPRINT_VERSION`](crate::text::FINGERPRINT_VERSION).\n")?;
+    writeln!(
+        f,
+        "pub(crate) const GENERATED_FINGERPRINT_VERSION: u32 = {};\n",
+        license::text::FINGERPRINT_VERSION
+    )?;
+    for (family, module, docs) in FAMILY_MODULES {
+        writeln!(
+            f,
+            "/// {}, see [`Family::{:?}`](crate::Family::{:?}).",
+            docs, family, family
+        )?;
+        f.write_all(b"///\n")?;
+        f.write_all(b"/// Like every license, they are also available from the parent module.\n")?;
+        writeln!(f, "pub mod {} {{", module)?;
+        f.write_all(b"    pub use super::{")?;
+        for license in sorted
+            .iter()
+            .filter(|license| named::named_family(&license.license_id) == Some(*family))
+        {
+            write!(f, "{}, ", license.ident())?;
+        }
+        f.write_all(b"};\n}\n\n")?;
+    }
+    for (id, _) in &descriptions {
+        if !licenses.iter().any(|license| license.license_id == *id) {
+            return Err(format!("{} isn't a license, remove it from DESCRIPTIONS", id).into());
+        }
+    }
+    for license in &licenses {
         writeln!(
             f,
             include_str!("../LICENSE-TEMPLATE"),
             ident = license.ident(),
+            alias = doc_alias(&license.license_id, &license.ident()),
             name = license.name,
+            description = description(license, &descriptions),
             id = license.license_id,
+            sections = sections::render(&license.license_text),
+            fingerprint = license::text::fingerprint(&license.license_text).hashes(),
             text = license.license_text,
             header = license.standard_license_header,
             osi = license.is_osi_approved,
@@ -132,10 +253,33 @@ fn build_licenses_from_json() -> Result<(), Box<dyn Error>> {
             see_also = license.see_also,
         )?;
     }
-    Ok(())
+    for (old, new) in RENAMED {
+        writeln!(f, include_str!("../RENAMED-TEMPLATE"), old = old, new = new)?;
+    }
+    Ok(licenses)
+}
+
+/// The one line description of a license, from `DESCRIPTIONS`, or else of its family.
+fn description(license: &License, descriptions: &[(&str, &str)]) -> String {
+    if let Some((_, description)) = descriptions
+        .iter()
+        .find(|(id, _)| *id == license.license_id)
+    {
+        return description.to_string();
+    }
+    match named::named_family(&license.license_id) {
+        Some(Family::Gnu) => "A license of the GNU project.",
+        Some(Family::Bsd) => "A variant of the BSD license.",
+        Some(Family::CreativeCommons) => "A Creative Commons license.",
+        Some(Family::Mozilla) => "A Mozilla or Netscape Public License.",
+        Some(Family::Apache) => "A version of the Apache License.",
+        Some(Family::PublicDomain) => "A public domain dedication or notice.",
+        Some(Family::Other(_)) | None => "A license on the SPDX License List.",
+    }
+    .to_string()
 }
 
-fn build_exceptions_from_json() -> Result<(), Box<dyn Error>> {
+fn build_exceptions_from_json() -> Result<Vec<Exception>, Box<dyn Error>> {
     let mut f = BufWriter::with_capacity(524_288, File::create("../src/exceptions.rs")?);
     let mut exceptions = Vec::with_capacity(64);
     f.write_all(b"// This file is autogenerated, do not edit.\n\n")?;
@@ -165,11 +309,20 @@ fn build_exceptions_from_json() -> Result<(), Box<dyn Error>> {
     f.write_all(b"        _ => None,\n")?;
     f.write_all(b"    }\n")?;
     f.write_all(b"}\n\n")?;
-    for exception in exceptions {
+    let mut sorted: Vec<_> = exceptions.iter().collect();
+    sorted.sort_by(|a, b| a.license_exception_id.cmp(&b.license_exception_id));
+    f.write_all(b"/// The ids of all exceptions, sorted, for lookups in constant expressions.\n")?;
+    f.write_all(b"pub(crate) const EXCEPTION_IDS: &[&str] = &[\n")?;
+    for exception in sorted {
+        writeln!(f, "    {:?},", exception.license_exception_id)?;
+    }
+    f.write_all(b"];\n\n")?;
+    for exception in &exceptions {
         writeln!(
             f,
             include_str!("../EXCEPTION-TEMPLATE"),
             ident = exception.ident(),
+            alias = doc_alias(&exception.license_exception_id, &exception.ident()),
             name = exception.name,
             id = exception.license_exception_id,
             text = exception.license_exception_text,
@@ -178,5 +331,37 @@ fn build_exceptions_from_json() -> Result<(), Box<dyn Error>> {
             see_also = exception.see_also,
         )?;
     }
+    Ok(exceptions)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LicenseList {
+    license_list_version: String,
+}
+
+fn build_provenance(licenses: &[License], exceptions: &[Exception]) -> Result<(), Box<dyn Error>> {
+    let rdr = File::open("../license-list-data/json/licenses.json")?;
+    let list: LicenseList = serde_json::from_reader(BufReader::new(rdr))?;
+    let date = Command::new("date").arg("-u").arg("+%Y-%m-%d").output()?;
+    let retrieved = String::from_utf8(date.stdout)?;
+    let mut texts: Vec<_> = licenses
+        .iter()
+        .map(|l| (&*l.license_id, "license", &*l.license_text))
+        .chain(exceptions.iter().map(|e| {
+            (
+                &*e.license_exception_id,
+                "exception",
+                &*e.license_exception_text,
+            )
+        }))
+        .collect();
+    let manifest = provenance::render(
+        &list.license_list_version,
+        retrieved.trim(),
+        &mut texts,
+        EXTRA_LICENSES,
+    );
+    fs::write("../src/provenance.tsv", manifest)?;
     Ok(())
 }
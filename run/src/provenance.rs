@@ -0,0 +1,69 @@
+use std::fmt::Write;
+
+/// The policy for the texts, stated in the manifest.
+const POLICY: &str = "the texts are the SPDX `licenseText` and `licenseExceptionText` verbatim, \
+                      with the placeholders as SPDX writes them, such as `<year> <copyright holders>` \
+                      in MIT or `[yyyy] [name of copyright owner]` in Apache-2.0, never the \
+                      choosealicense placeholders such as `[fullname]`";
+
+/// Where a text comes from, as `(id, kind, text)` with `kind` either `license` or `exception`.
+pub type Text<'a> = (&'a str, &'a str, &'a str);
+
+/// The 64-bit FNV-1a hash of the UTF-8 bytes of a text.
+pub fn checksum(text: &str) -> u64 {
+    text.bytes().fold(0xCBF2_9CE4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01B3)
+    })
+}
+
+/// Renders the provenance manifest of the texts, as tab separated `id`, `kind`, `source`
+/// and `checksum` columns after `#` comments, sorted by kind and id.
+///
+/// The `extras` are the ids of the licenses that are newer than the list, with the
+/// pages that their texts were taken from, which are named as deviations.
+pub fn render(
+    version: &str,
+    retrieved: &str,
+    texts: &mut [Text],
+    extras: &[(&str, &str)],
+) -> String {
+    // The licenses come before the exceptions.
+    texts.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    let mut manifest = String::new();
+    manifest.push_str("# The provenance of the texts in src/licenses.rs and src/exceptions.rs.\n");
+    manifest.push_str("# This file is autogenerated, do not edit.\n");
+    manifest.push_str("# repository: https://github.com/spdx/license-list-data\n");
+    writeln!(manifest, "# license-list-version: {}", version).unwrap();
+    writeln!(manifest, "# retrieved: {}", retrieved).unwrap();
+    manifest.push_str("# checksum: 64-bit FNV-1a of the UTF-8 bytes of the text, in hex\n");
+    writeln!(manifest, "# policy: {}", POLICY).unwrap();
+    if extras.is_empty() {
+        manifest.push_str("# deviations: none\n");
+    }
+    for (id, source) in extras {
+        writeln!(
+            manifest,
+            "# deviations: {} is newer than this retrieval, its text is read from \
+             run/extra/{}.json, taken from {}, until the next retrieval",
+            id, id, source
+        )
+        .unwrap();
+    }
+    manifest.push_str("id\tkind\tsource\tchecksum\n");
+    for (id, kind, text) in texts.iter() {
+        let source = match extras.iter().find(|(extra, _)| extra == id) {
+            Some((_, source)) => source.to_string(),
+            None => format!("https://spdx.org/licenses/{}.json", id),
+        };
+        writeln!(
+            manifest,
+            "{}\t{}\t{}\t{:016x}",
+            id,
+            kind,
+            source,
+            checksum(text)
+        )
+        .unwrap();
+    }
+    manifest
+}
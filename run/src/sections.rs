@@ -0,0 +1,114 @@
+use std::ops::Range;
+
+/// The longest title a heading can have, a longer first sentence is the text of the section.
+const MAX_TITLE_LEN: usize = 60;
+
+/// Sections need at least this many numbered headings, fewer are likely a list.
+const MIN_SECTIONS: usize = 3;
+
+const TRAILER: &str = "END OF TERMS AND CONDITIONS";
+
+/// A section of a text, as `(number, title, span)`.
+pub type Section = (Option<String>, String, Range<usize>);
+
+/// Splits a text into sections at lines such as `4. Redistribution.`, whose numbers
+/// count up from 0 or 1.
+///
+/// The text before the first heading, if it isn't blank, is an unnumbered section
+/// titled by its first line, and so is a trailer from `END OF TERMS AND CONDITIONS`.
+/// The spans cover the whole text, without gaps or overlaps. Texts with too few
+/// headings, such as MIT, have no sections.
+pub fn sections(text: &str) -> Vec<Section> {
+    let mut headings = Vec::new();
+    let mut trailer = None;
+    let mut expected = None;
+    let mut start = 0;
+    for line in text.split('\n') {
+        let trimmed = line.trim_matches(|c| c == ' ' || c == '\t' || c == '\r');
+        if trimmed == TRAILER && !headings.is_empty() {
+            trailer = Some(start);
+        } else if trailer.is_none() {
+            if let Some((number, title)) = heading(trimmed) {
+                let next = number.parse::<usize>().unwrap_or(usize::MAX);
+                let accepted = match expected {
+                    None => next <= 1,
+                    Some(expected) => next == expected,
+                };
+                if accepted {
+                    expected = Some(next + 1);
+                    headings.push((Some(number.to_string()), title.to_string(), start));
+                }
+            }
+        }
+        start += line.len() + 1;
+    }
+    if headings.len() < MIN_SECTIONS {
+        return Vec::new();
+    }
+
+    let first = headings[0].2;
+    if text[..first].trim().is_empty() {
+        headings[0].2 = 0;
+    } else {
+        let title = text[..first]
+            .lines()
+            .map(|line| line.trim())
+            .find(|line| !line.is_empty())
+            .unwrap_or_default();
+        headings.insert(0, (None, title.to_string(), 0));
+    }
+    if let Some(trailer) = trailer {
+        headings.push((None, TRAILER.to_string(), trailer));
+    }
+
+    let ends: Vec<_> = headings
+        .iter()
+        .skip(1)
+        .map(|(_, _, start)| *start)
+        .chain(Some(text.len()))
+        .collect();
+    headings
+        .into_iter()
+        .zip(ends)
+        .map(|((number, title, start), end)| (number, title, start..end))
+        .collect()
+}
+
+/// Parses a heading such as `4. Redistribution. You may ...` into `("4", "Redistribution")`,
+/// with an empty title if the section has none.
+fn heading(line: &str) -> Option<(&str, &str)> {
+    let digits = line.bytes().take_while(u8::is_ascii_digit).count();
+    if digits == 0 || digits > 3 {
+        return None;
+    }
+    let rest = line[digits..].strip_prefix(". ")?;
+    let title = match rest.find(". ") {
+        Some(end) => &rest[..end],
+        None => rest,
+    };
+    let title = title.trim_end_matches('.').trim();
+    if title.is_empty() {
+        return None;
+    }
+    if title.chars().count() > MAX_TITLE_LEN {
+        // The section starts with its text, as in the GPL-2.0.
+        return Some((&line[..digits], ""));
+    }
+    Some((&line[..digits], title))
+}
+
+/// Renders the sections of a text as the elements of a `crate::text::Section` array.
+pub fn render(text: &str) -> String {
+    sections(text)
+        .iter()
+        .map(|(number, title, span)| {
+            format!(
+                "crate::text::Section::new({:?}, {:?}, {}..{}), ",
+                number.as_deref(),
+                title,
+                span.start,
+                span.end
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,215 @@
+use crate::params::CopyrightYears;
+use crate::*;
+use core::fmt::{self, Display, Formatter};
+
+/// The instructions of a license on how to apply it, with the placeholders replaced,
+/// see [`LicenseExt::apply_instructions`].
+///
+/// Its [`Display`] impl writes the source header followed, if the license has one, by
+/// the notice of interactive programs.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ApplyInstructions<'a> {
+    header: &'static str,
+    interactive_notice: Option<&'static str>,
+    lesser: bool,
+    program: &'a str,
+    author: &'a str,
+    years: CopyrightYears,
+}
+
+impl<'a> ApplyInstructions<'a> {
+    /// The instructions of a GNU license, taken from its standard header and, for the
+    /// notice of interactive programs, from the appendix of its text.
+    ///
+    /// `lesser` turns the GPL into the LGPL, as the FSF recommends for LGPL libraries.
+    pub(crate) fn gnu(
+        license: &dyn License,
+        lesser: bool,
+        program: &'a str,
+        author: &'a str,
+        year: u16,
+    ) -> Option<Self> {
+        Some(ApplyInstructions {
+            header: license.header()?,
+            interactive_notice: if lesser {
+                None
+            } else {
+                interactive_notice(license.text())
+            },
+            lesser,
+            program,
+            author,
+            years: CopyrightYears::single(year),
+        })
+    }
+
+    /// The instructions with `years` in place of the year, such as a range of years.
+    pub const fn with_years(mut self, years: CopyrightYears) -> Self {
+        self.years = years;
+        self
+    }
+
+    /// The notice to attach to the start of each source file.
+    pub fn header(&self) -> impl Display + 'a {
+        Header(*self)
+    }
+
+    /// The notice that an interactive program should output when it starts.
+    pub fn interactive_notice(&self) -> Option<impl Display + 'a> {
+        let template = self.interactive_notice?;
+        Some(Substituted {
+            template,
+            instructions: *self,
+        })
+    }
+}
+
+impl Display for ApplyInstructions<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.header().fmt(f)?;
+        if let Some(notice) = self.interactive_notice() {
+            write!(f, "\n{}", notice)?;
+        }
+        Ok(())
+    }
+}
+
+/// The copyright and license notice of a license with the placeholders replaced, see
+/// [`LicenseExt::notice`].
+///
+/// Its [`Display`] impl writes the notice with every line unindented.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Notice<'a>(ApplyInstructions<'a>);
+
+impl<'a> Notice<'a> {
+    /// The copyright holder of [`LicenseExt::notice_example`].
+    pub const EXAMPLE_HOLDER: &'static str = "Example Corp";
+
+    /// The year of [`LicenseExt::notice_example`].
+    pub const EXAMPLE_YEAR: u16 = 2024;
+
+    /// The notice of a template, such as the whole text of MIT.
+    pub(crate) fn new(template: &'static str, holder: &'a str, year: u16) -> Self {
+        Notice(ApplyInstructions {
+            header: template,
+            interactive_notice: None,
+            lesser: false,
+            program: "",
+            author: holder,
+            years: CopyrightYears::single(year),
+        })
+    }
+
+    /// The notice with `years` in place of the year, such as a range of years.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::params::CopyrightYears;
+    ///
+    /// let bsd = license::from_id_ext("BSD-3-Clause").unwrap();
+    /// let years = CopyrightYears::range(2019, 2024).unwrap();
+    /// let notice = bsd.notice("Jane Doe", 2019).unwrap().with_years(years).to_string();
+    /// assert!(notice.starts_with("Copyright (c) 2019-2024 Jane Doe. All rights reserved.\n"));
+    /// ```
+    pub const fn with_years(self, years: CopyrightYears) -> Self {
+        Notice(self.0.with_years(years))
+    }
+
+    /// The template with the placeholders.
+    #[cfg(feature = "fs")]
+    pub(crate) fn template(&self) -> &'static str {
+        self.0.header
+    }
+}
+
+impl Display for Notice<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let notice = Substituted {
+            template: self.0.header.trim_end(),
+            instructions: self.0,
+        };
+        writeln!(f, "{}", notice)
+    }
+}
+
+/// Finds the indented notice of interactive programs in the appendix of a GNU license.
+fn interactive_notice(text: &'static str) -> Option<&'static str> {
+    let start = text.find("<program>")?;
+    let start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+    let len = text[start..].find("\n\n").unwrap_or(text.len() - start);
+    Some(&text[start..start + len])
+}
+
+struct Header<'a>(ApplyInstructions<'a>);
+
+impl Display for Header<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.0.program)?;
+        let header = Substituted {
+            template: self.0.header.trim_end(),
+            instructions: self.0,
+        };
+        writeln!(f, "{}", header)
+    }
+}
+
+/// Placeholders of the texts, and what replaces them.
+#[derive(Copy, Clone)]
+enum Placeholder {
+    Year,
+    Author,
+    Program,
+    Lesser,
+}
+
+const PLACEHOLDERS: &[(&str, Placeholder)] = &[
+    ("<year>", Placeholder::Year),
+    ("[year]", Placeholder::Year),
+    ("[yyyy]", Placeholder::Year),
+    ("<name of author>", Placeholder::Author),
+    ("[name of author]", Placeholder::Author),
+    ("<copyright holders>", Placeholder::Author),
+    ("<owner>", Placeholder::Author),
+    ("[name of copyright owner]", Placeholder::Author),
+    ("<program>", Placeholder::Program),
+    ("GNU General Public License", Placeholder::Lesser),
+];
+
+/// Writes a template with the placeholders replaced, and every line unindented.
+struct Substituted<'a> {
+    template: &'static str,
+    instructions: ApplyInstructions<'a>,
+}
+
+impl Display for Substituted<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let instructions = &self.instructions;
+        for (i, line) in self.template.lines().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            let mut rest = line.trim_start();
+            loop {
+                let next = PLACEHOLDERS
+                    .iter()
+                    .filter(|(_, p)| instructions.lesser || !matches!(p, Placeholder::Lesser))
+                    .filter_map(|&(pattern, p)| rest.find(pattern).map(|i| (i, pattern, p)))
+                    .min_by_key(|&(i, _, _)| i);
+                let (i, pattern, placeholder) = match next {
+                    Some(next) => next,
+                    None => break,
+                };
+                f.write_str(&rest[..i])?;
+                match placeholder {
+                    Placeholder::Year => instructions.years.fmt(f)?,
+                    Placeholder::Author => f.write_str(instructions.author)?,
+                    Placeholder::Program => f.write_str(instructions.program)?,
+                    Placeholder::Lesser => f.write_str("GNU Lesser General Public License")?,
+                }
+                rest = &rest[i + pattern.len()..];
+            }
+            f.write_str(rest)?;
+        }
+        Ok(())
+    }
+}
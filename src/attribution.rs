@@ -0,0 +1,156 @@
+//! Groups the license texts of a vendor tree for attribution, so that each license is
+//! printed once with all of its copyright holders.
+//!
+//! A tree commonly has many copies of the same license, such as MIT, that only differ
+//! in their copyright lines, and copies that differ in line wrapping or whitespace.
+//! [`dedupe`] identifies each text with a [`Matcher`], groups the texts of the same
+//! license, and merges the holders found by [`extract_copyrights`]. The [`Strictness`]
+//! decides what happens to texts that aren't the canonical text of their license.
+//!
+//! Requires the `alloc` feature.
+//!
+//! # Examples
+//! ```
+//! use license::attribution::{self, Strictness};
+//! use license::text::Matcher;
+//!
+//! let mit = license::from_id("MIT").unwrap().text();
+//! let a = mit.replace("<year> <copyright holders>", "2020 Alice");
+//! let b = mit.replace("<year> <copyright holders>", "2021 Bob");
+//! let groups = attribution::dedupe(&Matcher::new(), &[&b, &a, &b], Strictness::Canonical);
+//! assert_eq!(groups.len(), 1);
+//! assert_eq!(groups[0].license().unwrap().id(), "MIT");
+//! assert_eq!(groups[0].holders(), ["Alice", "Bob"]);
+//! assert_eq!(groups[0].entries(), [0, 1, 2]);
+//! ```
+
+use crate::text::{diff, extract_copyrights, tokenize, Matcher};
+use crate::*;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Formatter};
+
+/// The similarity from which [`Strictness::Similar`] merges a text into the group of
+/// the license it matches, see [`Match::similarity`](crate::text::Match::similarity).
+pub const NEAR_IDENTICAL: f32 = 0.8;
+
+/// Which texts [`dedupe`] merges into the group of their license.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Strictness {
+    /// Only the texts that are the canonical text of a license, apart from copyright
+    /// lines and placeholders, are grouped by license, see
+    /// [`TextDiff::is_trivial`](crate::text::TextDiff::is_trivial). Any other text is kept
+    /// verbatim, only grouped with the texts that have the same words.
+    Canonical,
+    /// The texts that match a license with a similarity of at least [`NEAR_IDENTICAL`]
+    /// are also grouped by license, so that a text with small changes is printed as the
+    /// canonical text of the group.
+    Similar,
+}
+
+/// Texts of the same license with their holders, see [`dedupe`].
+#[derive(Clone)]
+pub struct Group<'a> {
+    license: Option<&'static dyn License>,
+    by_license: bool,
+    canonical: bool,
+    text: &'a str,
+    holders: Vec<String>,
+    entries: Vec<usize>,
+}
+
+impl<'a> Group<'a> {
+    /// The license that the texts match best, or `None` if they match none.
+    ///
+    /// The texts of a group that isn't [canonical](Group::is_canonical) may differ from
+    /// the text of the license in more than their copyright lines.
+    pub fn license(&self) -> Option<&'static dyn License> {
+        self.license
+    }
+
+    /// Says if [`text`](Group::text) is the canonical text of the license, apart from
+    /// copyright lines and placeholders.
+    ///
+    /// With [`Strictness::Canonical`], the texts of a group that isn't canonical all have
+    /// the same words.
+    pub fn is_canonical(&self) -> bool {
+        self.canonical
+    }
+
+    /// The text to print for the group: the first text of the group that is canonical,
+    /// or the first text.
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// The copyright holders of the texts, sorted and without duplicates.
+    pub fn holders(&self) -> &[String] {
+        &self.holders
+    }
+
+    /// The indices of the texts of the group, in order.
+    pub fn entries(&self) -> &[usize] {
+        &self.entries
+    }
+}
+
+impl fmt::Debug for Group<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Group")
+            .field("license", &self.license.map(|l| l.id()))
+            .field("canonical", &self.canonical)
+            .field("holders", &self.holders)
+            .field("entries", &self.entries)
+            .finish()
+    }
+}
+
+/// Groups license texts by license and merges their copyright holders.
+///
+/// The groups are in the order of their first text. Texts that don't match any license
+/// are grouped with the texts that have the same words, as normalized by
+/// [`tokenize`](crate::text::tokenize), which leaves out their copyright lines.
+pub fn dedupe<'a>(matcher: &Matcher, texts: &[&'a str], strictness: Strictness) -> Vec<Group<'a>> {
+    let mut groups: Vec<Group<'a>> = Vec::new();
+    for (i, &text) in texts.iter().enumerate() {
+        let found = matcher.match_one(text);
+        let license = found.map(|m| m.license());
+        let canonical = found.map_or(false, |m| diff(m.license().text(), text).is_trivial());
+        let by_license = canonical
+            || strictness == Strictness::Similar
+                && found.map_or(false, |m| m.similarity() >= NEAR_IDENTICAL);
+        let group = groups.iter_mut().find(|group| {
+            if by_license {
+                group.by_license && group.license.map(|l| l.id()) == license.map(|l| l.id())
+            } else {
+                !group.by_license && tokenize(group.text).eq(tokenize(text))
+            }
+        });
+        let holders = extract_copyrights(text).into_iter().map(|s| s.holder);
+        match group {
+            Some(group) => {
+                if canonical && !group.canonical {
+                    group.canonical = true;
+                    group.text = text;
+                }
+                group.holders.extend(holders);
+                group.entries.push(i);
+            }
+            None => groups.push(Group {
+                license,
+                by_license,
+                canonical,
+                text,
+                holders: holders.collect(),
+                entries: vec![i],
+            }),
+        }
+    }
+    for group in &mut groups {
+        group.holders.sort();
+        group.holders.dedup();
+    }
+    groups
+}
@@ -0,0 +1,110 @@
+use core::fmt::{self, Display, Formatter, Write};
+
+/// The style of a [shields.io](https://shields.io) badge.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum BadgeStyle {
+    /// The `flat` style, the default.
+    Flat,
+    /// The `flat-square` style.
+    FlatSquare,
+    /// The `plastic` style.
+    Plastic,
+    /// The `for-the-badge` style.
+    ForTheBadge,
+    /// The `social` style.
+    Social,
+}
+
+impl Default for BadgeStyle {
+    fn default() -> Self {
+        BadgeStyle::Flat
+    }
+}
+
+impl BadgeStyle {
+    const fn as_str(self) -> &'static str {
+        match self {
+            BadgeStyle::Flat => "flat",
+            BadgeStyle::FlatSquare => "flat-square",
+            BadgeStyle::Plastic => "plastic",
+            BadgeStyle::ForTheBadge => "for-the-badge",
+            BadgeStyle::Social => "social",
+        }
+    }
+}
+
+/// The URL of a shields.io static badge for a license.
+///
+/// # Examples
+/// ```
+/// use license::{BadgeStyle, License};
+///
+/// let bsd = license::from_id("BSD-3-Clause").unwrap();
+/// assert_eq!(
+///     bsd.badge_url(BadgeStyle::Flat).to_string(),
+///     "https://img.shields.io/badge/license-BSD--3--Clause-blue?style=flat"
+/// );
+///
+/// let ofl = license::from_id("OFL-1.1").unwrap();
+/// assert_eq!(
+///     ofl.badge_url(BadgeStyle::ForTheBadge).to_string(),
+///     "https://img.shields.io/badge/license-OFL--1.1-blue?style=for-the-badge"
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct BadgeUrl {
+    pub(crate) id: &'static str,
+    pub(crate) style: BadgeStyle,
+}
+
+impl Display for BadgeUrl {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("https://img.shields.io/badge/license-")?;
+        for c in self.id.chars() {
+            match c {
+                '-' => f.write_str("--")?,
+                '_' => f.write_str("__")?,
+                ' ' => f.write_char('_')?,
+                'A'..='Z' | 'a'..='z' | '0'..='9' | '.' | '~' => f.write_char(c)?,
+                _ => {
+                    let mut buf = [0; 4];
+                    for byte in c.encode_utf8(&mut buf).bytes() {
+                        write!(f, "%{:02X}", byte)?;
+                    }
+                }
+            }
+        }
+        write!(f, "-blue?style={}", self.style.as_str())
+    }
+}
+
+/// A Markdown image for a license badge, optionally wrapped in a link.
+///
+/// # Examples
+/// ```
+/// use license::License;
+///
+/// let mit = license::from_id("MIT").unwrap();
+/// assert_eq!(
+///     mit.badge_markdown(None).to_string(),
+///     "![License: MIT](https://img.shields.io/badge/license-MIT-blue?style=flat)"
+/// );
+/// assert_eq!(
+///     mit.badge_markdown(Some("LICENSE")).to_string(),
+///     "[![License: MIT](https://img.shields.io/badge/license-MIT-blue?style=flat)](LICENSE)"
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct BadgeMarkdown<'a> {
+    pub(crate) url: BadgeUrl,
+    pub(crate) link: Option<&'a str>,
+}
+
+impl Display for BadgeMarkdown<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.link {
+            Some(link) => write!(f, "[![License: {}]({})]({})", self.url.id, self.url, link),
+            None => write!(f, "![License: {}]({})", self.url.id, self.url),
+        }
+    }
+}
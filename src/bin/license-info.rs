@@ -0,0 +1,127 @@
+//! Looks up licenses from the command line, as a reference integration of the library.
+//!
+//! ```text
+//! license-info <ID>              the summary of a license
+//! license-info --text <ID>       the text of a license
+//! license-info --match <FILE>    the license that a file matches best
+//! license-info --expr <EXPR>     the licenses of an expression
+//! ```
+
+#![forbid(unsafe_code)]
+
+use license::report::{self, Copyleft};
+use license::{text, License, LicenseExt, ListStyle, PackageLicense, SummaryDisplay};
+use std::env;
+use std::fs;
+use std::process;
+
+const USAGE: &str = "usage: license-info <ID> | --text <ID> | --match <FILE> | --expr <EXPR>";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["--text", id] => text_of(id),
+        ["--match", path] => match_file(path),
+        ["--expr", expression] => expression_of(expression),
+        [id] if !id.starts_with('-') => summary(id),
+        _ => Err(USAGE.to_string()),
+    };
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        process::exit(2);
+    }
+}
+
+fn lookup(id: &str) -> Result<&'static dyn License, String> {
+    license::from_id_flexible(id)
+        .map(|resolved| resolved.license)
+        .ok_or_else(|| format!("unknown license id `{}`", id))
+}
+
+fn summary(id: &str) -> Result<(), String> {
+    let license = lookup(id)?;
+    println!("{} ({})", license.name(), license.id());
+    println!("OSI approved: {}", yes_no(license.is_osi_approved()));
+    println!("FSF libre: {}", yes_no(license.is_fsf_libre()));
+    if license.is_deprecated() {
+        println!("Deprecated: yes");
+    }
+    if let Some(successor) = license.successor() {
+        println!("Newer version: {}", successor.id());
+    }
+    match license.ext() {
+        Some(ext) => {
+            println!("Copyleft: {}", Copyleft::of(ext));
+            println!();
+            print!("{}", SummaryDisplay::new(ext, ListStyle::default()));
+        }
+        None => println!("\nNo permissions, conditions or limitations are known."),
+    }
+    Ok(())
+}
+
+fn text_of(id: &str) -> Result<(), String> {
+    print!("{}", lookup(id)?.text());
+    Ok(())
+}
+
+fn match_file(path: &str) -> Result<(), String> {
+    let bytes = fs::read(path).map_err(|err| format!("{}: {}", path, err))?;
+    let candidate = text::from_utf8(&bytes).map_err(|err| format!("{}: {}", path, err))?;
+    let best = text::best_match(candidate).ok_or("no license matches")?;
+    let license = best.license();
+    println!(
+        "{} ({}), similarity {:.2}",
+        license.id(),
+        license.name(),
+        best.similarity()
+    );
+    let diff = text::diff(license.text(), candidate);
+    if diff.is_trivial() {
+        println!("The text is the canonical text, apart from copyright lines.");
+    } else {
+        println!();
+        print!("{}", diff);
+    }
+    Ok(())
+}
+
+fn expression_of(expression: &str) -> Result<(), String> {
+    let normalized = license::normalize_legacy(expression)
+        .map_err(|err| err.to_string())?
+        .to_string();
+    // The expression is only validated once per run, so leaking it is fine.
+    let parsed = PackageLicense::parse(Box::leak(normalized.clone().into_boxed_str()))
+        .map_err(|err| format!("{} in `{}`", err, normalized))?;
+    println!("{}", parsed);
+
+    let mut licenses: Vec<&'static dyn LicenseExt> = Vec::new();
+    for word in normalized.split(|c: char| c.is_whitespace() || c == '(' || c == ')') {
+        if word.is_empty() || ["AND", "OR", "WITH"].contains(&word) {
+            continue;
+        }
+        // The exceptions aren't licenses, their license was printed before them.
+        if let Ok(license) = lookup(word) {
+            match license.ext() {
+                Some(ext) => {
+                    println!("- {}: copyleft {}", ext.id(), Copyleft::of(ext));
+                    licenses.push(ext);
+                }
+                None => println!("- {}: no data", license.id()),
+            }
+        }
+    }
+    if !normalized.contains(" OR ") {
+        println!();
+        print!("{}", report::combine(&licenses));
+    }
+    Ok(())
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value {
+        "yes"
+    } else {
+        "no"
+    }
+}
@@ -0,0 +1,116 @@
+use crate::*;
+
+/// Licenses that require the complete corresponding source, including the scripts to
+/// build and install it, rather than only the licensed files.
+///
+/// This is curated data, the flags only say that the source must be disclosed.
+const CORRESPONDING_SOURCE: &[&str] = &[
+    "AGPL-3.0-only",
+    "GPL-2.0-only",
+    "GPL-2.0-only WITH Classpath-exception-2.0",
+    "GPL-3.0-only",
+    "LGPL-2.1-only",
+    "LGPL-3.0-only",
+];
+
+/// Licenses that require that the user can relink the binary with a modified version
+/// of the licensed library.
+const RELINKING: &[&str] = &["LGPL-2.1-only", "LGPL-3.0-only"];
+
+/// Licenses whose requirements for a statically linked binary depend on how the library
+/// is combined with the rest of the program.
+const NEEDS_LEGAL_REVIEW: &[&str] = &[
+    "GPL-2.0-only WITH Classpath-exception-2.0",
+    "LGPL-2.1-only",
+    "LGPL-3.0-only",
+];
+
+/// What the license requires to distribute an unmodified, statically linked binary of
+/// the software, see [`LicenseExt::binary_distribution_requirements`].
+///
+/// Unlike the [`Conditions`], these bake in the context of the distribution: changes
+/// needn't be documented since there are none, and a NOTICE file only has to be
+/// included if the software has one. When in doubt, a requirement is set.
+///
+/// # Examples
+/// ```
+/// let lgpl = license::from_id_ext("LGPL-3.0-only").unwrap();
+/// let requirements = lgpl.binary_distribution_requirements();
+/// assert!(requirements.relinking());
+/// assert!(requirements.needs_legal_review());
+///
+/// let unlicense = license::from_id_ext("Unlicense").unwrap();
+/// assert!(unlicense.binary_distribution_requirements().is_empty());
+/// ```
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
+pub struct BinaryRequirements {
+    license_text: bool,
+    notice_file: bool,
+    source: bool,
+    corresponding_source: bool,
+    relinking: bool,
+    needs_legal_review: bool,
+}
+
+impl BinaryRequirements {
+    /// The requirements of a license, from its conditions and the curated lists.
+    pub(crate) fn new(id: &str, conditions: Conditions) -> Self {
+        let corresponding_source = CORRESPONDING_SOURCE.contains(&id);
+        BinaryRequirements {
+            license_text: conditions.license_and_copyright_notice(),
+            notice_file: conditions.notice_file(),
+            source: conditions.disclose_sources() || corresponding_source,
+            corresponding_source,
+            relinking: RELINKING.contains(&id),
+            needs_legal_review: NEEDS_LEGAL_REVIEW.contains(&id) || sentinel::is_sentinel(id),
+        }
+    }
+
+    /// The license text and the copyright notices must be included.
+    pub const fn license_text(self) -> bool {
+        self.license_text
+    }
+
+    /// The NOTICE file of the software must be included, if it has one.
+    pub const fn notice_file(self) -> bool {
+        self.notice_file
+    }
+
+    /// The source of the licensed files must be made available.
+    pub const fn source(self) -> bool {
+        self.source
+    }
+
+    /// The complete corresponding source must be made available, including the scripts
+    /// to build and install it, which implies [`source`](BinaryRequirements::source).
+    pub const fn corresponding_source(self) -> bool {
+        self.corresponding_source
+    }
+
+    /// The user must be able to relink the binary with a modified version of the
+    /// licensed library, such as by providing the object files of the rest of the
+    /// program.
+    pub const fn relinking(self) -> bool {
+        self.relinking
+    }
+
+    /// The other requirements are conservative, and whether they apply depends on how
+    /// the software is combined with the rest of the program, such as when statically
+    /// linking an LGPL library.
+    ///
+    /// It is also set for the [`UNKNOWN`] and [`NONE`] sentinels, whose terms aren't
+    /// known or don't allow distribution.
+    pub const fn needs_legal_review(self) -> bool {
+        self.needs_legal_review
+    }
+
+    /// Says if nothing is required, such as for CC0-1.0.
+    pub const fn is_empty(self) -> bool {
+        !(self.license_text
+            || self.notice_file
+            || self.source
+            || self.corresponding_source
+            || self.relinking
+            || self.needs_legal_review)
+    }
+}
@@ -0,0 +1,178 @@
+use crate::*;
+use core::fmt::{self, Display, Formatter};
+
+/// A calendar date, such as the change date of a BUSL-1.1 license.
+///
+/// Dates are ordered chronologically.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// A date, or `None` if the month or the day is out of range.
+    pub const fn new(year: u16, month: u8, day: u8) -> Option<Date> {
+        let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+        if day == 0 || day > days {
+            return None;
+        }
+        Some(Date { year, month, day })
+    }
+
+    /// Parses a date written as `YYYY-MM-DD`, as in the header of a BUSL-1.1 file.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::Date;
+    ///
+    /// assert_eq!(Date::parse("2025-02-28"), Date::new(2025, 2, 28));
+    /// assert_eq!(Date::parse("2025-02-29"), None);
+    /// assert_eq!(Date::parse("28/02/2025"), None);
+    /// ```
+    pub fn parse(date: &str) -> Option<Date> {
+        let number = |digits: &str, len| {
+            if digits.len() == len && digits.bytes().all(|b| b.is_ascii_digit()) {
+                digits.parse().ok()
+            } else {
+                None
+            }
+        };
+        let mut parts = date.split('-');
+        let year = number(parts.next()?, 4)?;
+        let month = number(parts.next()?, 2)?;
+        let day = number(parts.next()?, 2)?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Date::new(year, month as u8, day as u8)
+    }
+
+    /// The year.
+    pub const fn year(self) -> u16 {
+        self.year
+    }
+
+    /// The month, from 1 to 12.
+    pub const fn month(self) -> u8 {
+        self.month
+    }
+
+    /// The day of the month, from 1.
+    pub const fn day(self) -> u8 {
+        self.day
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// The parameters that the licensor of a BUSL-1.1 work fills in, see
+/// [`busl_with_parameters`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct BuslParameters<'a> {
+    /// The date on which the change license applies.
+    pub change_date: Date,
+    /// The id of the license that applies from the change date, such as
+    /// `GPL-2.0-or-later`.
+    pub change_license_id: &'a str,
+    /// The grant of production use, or `None` if the licensor inserted "None".
+    pub additional_use_grant: Option<&'a str>,
+}
+
+/// A BUSL-1.1 license with its parameters, see [`busl_with_parameters`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ParameterizedLicense<'a> {
+    parameters: BuslParameters<'a>,
+}
+
+/// Fills in the parameters of the [BUSL-1.1](BUSL_1_1).
+///
+/// Until the change date, the work is under the BUSL-1.1, which is
+/// [source available](LicenseExt::is_source_available) and not open source. From the
+/// change date, it is under the change license.
+///
+/// The BUSL-1.1 also applies the change license on the fourth anniversary of the first
+/// publication of a version, if that comes first. The publication date isn't a parameter,
+/// so that date is left to the caller.
+///
+/// # Examples
+/// ```
+/// use license::{BuslParameters, Date};
+///
+/// let busl = license::busl_with_parameters(BuslParameters {
+///     change_date: Date::new(2027, 6, 1).unwrap(),
+///     change_license_id: "Apache-2.0",
+///     additional_use_grant: None,
+/// });
+/// assert!(!busl.conditions().disclose_sources());
+/// assert_eq!(busl.change_license().unwrap().id(), "Apache-2.0");
+/// assert_eq!(busl.license_on(Date::new(2026, 1, 1).unwrap()).unwrap().id(), "BUSL-1.1");
+/// assert_eq!(busl.license_on(Date::new(2027, 6, 1).unwrap()).unwrap().id(), "Apache-2.0");
+/// ```
+pub fn busl_with_parameters(parameters: BuslParameters) -> ParameterizedLicense {
+    ParameterizedLicense { parameters }
+}
+
+impl<'a> ParameterizedLicense<'a> {
+    /// The license before the change date, the BUSL-1.1.
+    pub fn license(&self) -> &'static dyn LicenseExt {
+        &BUSL_1_1
+    }
+
+    /// The parameters.
+    pub const fn parameters(&self) -> BuslParameters<'a> {
+        self.parameters
+    }
+
+    /// The permissions before the change date.
+    ///
+    /// The additional use grant is free text, so it isn't reflected here: production
+    /// use, which includes [`commercial_use`](Permissions::commercial_use), is only
+    /// permitted as far as the grant allows it.
+    pub fn permissions(&self) -> Permissions {
+        BUSL_1_1.permissions()
+    }
+
+    /// The conditions before the change date.
+    pub fn conditions(&self) -> Conditions {
+        BUSL_1_1.conditions()
+    }
+
+    /// The limitations before the change date.
+    pub fn limitations(&self) -> Limitations {
+        BUSL_1_1.limitations()
+    }
+
+    /// The date on which the change license applies.
+    pub const fn change_date(&self) -> Date {
+        self.parameters.change_date
+    }
+
+    /// The license from the change date, looked up with [`from_id`], or `None` if the
+    /// id is unknown.
+    pub fn change_license(&self) -> Option<&'static dyn License> {
+        from_id(self.parameters.change_license_id)
+    }
+
+    /// The license that applies on a date, which is `None` from the change date if the
+    /// change license is unknown.
+    pub fn license_on(&self, date: Date) -> Option<&'static dyn License> {
+        if date >= self.parameters.change_date {
+            self.change_license()
+        } else {
+            Some(&BUSL_1_1)
+        }
+    }
+}
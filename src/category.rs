@@ -0,0 +1,66 @@
+use crate::report::Copyleft;
+use crate::*;
+
+/// Returns the extension licenses approved by the [OSI](https://opensource.org), in the
+/// order of [`LICENSES_EXT`].
+///
+/// # Examples
+/// ```
+/// let ids: Vec<_> = license::osi_approved().map(|l| l.id()).take(4).collect();
+/// assert_eq!(ids, ["0BSD", "AFL-3.0", "AGPL-3.0-only", "APSL-2.0"]);
+/// ```
+pub fn osi_approved() -> impl Iterator<Item = &'static dyn LicenseExt> + Clone {
+    LICENSES_EXT
+        .iter()
+        .copied()
+        .filter(|license| license.is_osi_approved())
+}
+
+/// Returns the extension licenses that the [FSF](https://www.fsf.org) considers free,
+/// in the order of [`LICENSES_EXT`].
+///
+/// # Examples
+/// ```
+/// let ids: Vec<_> = license::fsf_libre().map(|l| l.id()).take(4).collect();
+/// assert_eq!(ids, ["AFL-3.0", "AGPL-3.0-only", "APSL-2.0", "Apache-2.0"]);
+/// ```
+pub fn fsf_libre() -> impl Iterator<Item = &'static dyn LicenseExt> + Clone {
+    LICENSES_EXT
+        .iter()
+        .copied()
+        .filter(|license| license.is_fsf_libre())
+}
+
+/// Returns the extension licenses with a copyleft of any strength, see [`Copyleft`], in
+/// the order of [`LICENSES_EXT`].
+///
+/// # Examples
+/// ```
+/// let ids: Vec<_> = license::copyleft().map(|l| l.id()).take(4).collect();
+/// assert_eq!(ids, ["AGPL-3.0-only", "APSL-2.0", "CC-BY-NC-SA-4.0", "CC-BY-SA-3.0"]);
+/// ```
+pub fn copyleft() -> impl Iterator<Item = &'static dyn LicenseExt> + Clone {
+    LICENSES_EXT
+        .iter()
+        .copied()
+        .filter(|license| Copyleft::of(*license) > Copyleft::None)
+}
+
+/// Returns the extension licenses without copyleft that allow commercial use,
+/// distribution and modification, in the order of [`LICENSES_EXT`].
+///
+/// Licenses without copyleft that restrict any of these, such as CC-BY-NC-4.0, are
+/// neither permissive nor [`copyleft`], and neither are the
+/// [source available](LicenseExt::is_source_available) licenses.
+///
+/// # Examples
+/// ```
+/// let ids: Vec<_> = license::permissive().map(|l| l.id()).take(4).collect();
+/// assert_eq!(ids, ["0BSD", "AFL-3.0", "Apache-2.0", "Apache-2.0 WITH SHL-2.1"]);
+/// ```
+pub fn permissive() -> impl Iterator<Item = &'static dyn LicenseExt> + Clone {
+    LICENSES_EXT
+        .iter()
+        .copied()
+        .filter(|license| license.is_permissive())
+}
@@ -0,0 +1,306 @@
+//! Checks if a work can incorporate code under another license, and why not.
+
+use crate::report::Copyleft;
+use crate::*;
+use core::fmt::{self, Display, Formatter};
+
+/// Why a work can't incorporate code under another license.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum Reason {
+    /// The component requires the combined work to be distributed under `required`,
+    /// which the `actual` license of the work doesn't allow.
+    CopyleftConflict {
+        /// The license the component requires.
+        required: &'static str,
+        /// The license of the work.
+        actual: &'static str,
+    },
+    /// The patent clauses of the component, such as a patent termination clause, are
+    /// further restrictions that the license of the work forbids.
+    PatentClauseConflict,
+    /// The component requires advertising materials to acknowledge it, a further
+    /// restriction that the license of the work forbids.
+    AdvertisingClause,
+    /// The network use clause of the component would bind the combined work. The
+    /// combination only works the other way around.
+    NetworkClauseOneWay,
+    /// The component carries the "Incompatible With Secondary Licenses" notice of the
+    /// MPL-2.0, so it can't be distributed under the GPL family with the work.
+    SecondaryLicensesExcluded,
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Reason::CopyleftConflict { required, actual } => write!(
+                f,
+                "the combined work must be distributed under {}, not {}",
+                required, actual
+            ),
+            Reason::PatentClauseConflict => f.write_str("of the patent termination clause"),
+            Reason::AdvertisingClause => f.write_str("of the advertising clause"),
+            Reason::NetworkClauseOneWay => {
+                f.write_str("its network use clause would bind the combined work")
+            }
+            Reason::SecondaryLicensesExcluded => {
+                f.write_str("it is Incompatible With Secondary Licenses")
+            }
+        }
+    }
+}
+
+/// The pairs of licenses that are known to conflict, as
+/// `(work, component, reason, resolved by)`.
+///
+/// This is curated data that can't be derived from the flags.
+const CONFLICTS: &[(&str, &str, Reason, Option<&str>)] = &[
+    (
+        "AGPL-3.0-only",
+        "MPL-2.0-no-copyleft-exception",
+        Reason::SecondaryLicensesExcluded,
+        Some("MPL-2.0"),
+    ),
+    (
+        "GPL-2.0-only",
+        "Apache-2.0",
+        Reason::PatentClauseConflict,
+        Some("GPL-3.0-only"),
+    ),
+    (
+        "GPL-2.0-only",
+        "AGPL-3.0-only",
+        Reason::CopyleftConflict {
+            required: "AGPL-3.0-only",
+            actual: "GPL-2.0-only",
+        },
+        None,
+    ),
+    (
+        "GPL-2.0-only",
+        "BSD-4-Clause",
+        Reason::AdvertisingClause,
+        None,
+    ),
+    (
+        "GPL-2.0-only",
+        "CDDL-1.0",
+        Reason::CopyleftConflict {
+            required: "CDDL-1.0",
+            actual: "GPL-2.0-only",
+        },
+        None,
+    ),
+    (
+        "GPL-2.0-only",
+        "EPL-1.0",
+        Reason::CopyleftConflict {
+            required: "EPL-1.0",
+            actual: "GPL-2.0-only",
+        },
+        Some("EPL-2.0"),
+    ),
+    (
+        "GPL-2.0-only",
+        "LGPL-3.0-only",
+        Reason::CopyleftConflict {
+            required: "LGPL-3.0-only",
+            actual: "GPL-2.0-only",
+        },
+        Some("GPL-3.0-only"),
+    ),
+    (
+        "GPL-2.0-only",
+        "MPL-2.0-no-copyleft-exception",
+        Reason::SecondaryLicensesExcluded,
+        Some("MPL-2.0"),
+    ),
+    ("GPL-2.0-only", "OpenSSL", Reason::AdvertisingClause, None),
+    (
+        "GPL-3.0-only",
+        "AGPL-3.0-only",
+        Reason::NetworkClauseOneWay,
+        Some("AGPL-3.0-only"),
+    ),
+    (
+        "GPL-3.0-only",
+        "BSD-4-Clause",
+        Reason::AdvertisingClause,
+        None,
+    ),
+    (
+        "GPL-3.0-only",
+        "EPL-1.0",
+        Reason::CopyleftConflict {
+            required: "EPL-1.0",
+            actual: "GPL-3.0-only",
+        },
+        Some("EPL-2.0"),
+    ),
+    (
+        "GPL-3.0-only",
+        "MPL-2.0-no-copyleft-exception",
+        Reason::SecondaryLicensesExcluded,
+        Some("MPL-2.0"),
+    ),
+    ("GPL-3.0-only", "OpenSSL", Reason::AdvertisingClause, None),
+    (
+        "LGPL-2.1-only",
+        "Apache-2.0",
+        Reason::PatentClauseConflict,
+        Some("LGPL-3.0-only"),
+    ),
+    (
+        "LGPL-2.1-only",
+        "MPL-2.0-no-copyleft-exception",
+        Reason::SecondaryLicensesExcluded,
+        Some("MPL-2.0"),
+    ),
+    (
+        "LGPL-3.0-only",
+        "MPL-2.0-no-copyleft-exception",
+        Reason::SecondaryLicensesExcluded,
+        Some("MPL-2.0"),
+    ),
+];
+
+/// The pairs of copyleft licenses that are known to combine, as `(work, component)`.
+const COMBINATIONS: &[(&str, &str)] = &[
+    // Section 13 of both licenses allows the combination.
+    ("AGPL-3.0-only", "GPL-3.0-only"),
+];
+
+/// A work that can't incorporate code under another license, see [`check`].
+///
+/// Its [`Display`] impl writes a sentence suitable for a report.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Incompatibility {
+    work: &'static str,
+    component: &'static str,
+    reason: Reason,
+    resolved_by: Option<&'static str>,
+}
+
+impl Incompatibility {
+    /// The id of the license of the work.
+    pub fn work(&self) -> &'static str {
+        self.work
+    }
+
+    /// The id of the license of the incorporated code.
+    pub fn component(&self) -> &'static str {
+        self.component
+    }
+
+    /// Why the work can't incorporate the code.
+    pub fn reason(&self) -> Reason {
+        self.reason
+    }
+
+    /// The id of a license that resolves the conflict, for the work or the code,
+    /// if there is a well-known one.
+    pub fn resolved_by(&self) -> Option<&'static str> {
+        self.resolved_by
+    }
+}
+
+impl Display for Incompatibility {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} cannot incorporate {} code because {}",
+            self.work, self.component, self.reason
+        )?;
+        if let Some(resolved_by) = self.resolved_by {
+            write!(f, "; {} resolves this", resolved_by)?;
+        }
+        Ok(())
+    }
+}
+
+/// The result of [`check`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum Compatibility {
+    /// The work can incorporate the code.
+    Compatible,
+    /// The work can't incorporate the code.
+    Incompatible(Incompatibility),
+    /// There is no data about the license of the code.
+    Unknown,
+}
+
+/// Checks if a work under one license can incorporate code under another.
+///
+/// Known conflicts, such as the patent clause of Apache-2.0 with GPL-2.0-only, are
+/// curated. Otherwise code under a license with a strong copyleft, see [`Copyleft`],
+/// can only be incorporated into a work under the same license or one listed in its
+/// [`compatible_outbound`](LicenseExt::compatible_outbound), while other licenses with
+/// extension data can be incorporated into any work. Code under a license without
+/// extension data is [`Unknown`](Compatibility::Unknown), unless it is curated.
+///
+/// # Examples
+/// ```
+/// use license::compat::{self, Compatibility, Reason};
+///
+/// let gpl2 = license::from_id("GPL-2.0-only").unwrap();
+/// let apache = license::from_id("Apache-2.0").unwrap();
+/// match compat::check(gpl2, apache) {
+///     Compatibility::Incompatible(i) => {
+///         assert_eq!(i.reason(), Reason::PatentClauseConflict);
+///         assert_eq!(
+///             i.to_string(),
+///             "GPL-2.0-only cannot incorporate Apache-2.0 code because of the patent \
+///              termination clause; GPL-3.0-only resolves this"
+///         );
+///     }
+///     other => panic!("{:?}", other),
+/// }
+///
+/// let gpl3 = license::from_id("GPL-3.0-only").unwrap();
+/// assert_eq!(compat::check(gpl3, apache), Compatibility::Compatible);
+/// ```
+pub fn check(work: &dyn License, component: &dyn License) -> Compatibility {
+    check_ids(work.id(), component.id(), component.ext())
+}
+
+/// The [`check`] of a work under `work` with code under `component`, whose extension
+/// data is `ext`.
+pub(crate) fn check_ids(
+    work: &'static str,
+    component: &'static str,
+    ext: Option<&'static dyn LicenseExt>,
+) -> Compatibility {
+    if work == component {
+        return Compatibility::Compatible;
+    }
+    let conflict = CONFLICTS
+        .iter()
+        .find(|(w, c, _, _)| *w == work && *c == component);
+    if let Some(&(work, component, reason, resolved_by)) = conflict {
+        return Compatibility::Incompatible(Incompatibility {
+            work,
+            component,
+            reason,
+            resolved_by,
+        });
+    }
+    if COMBINATIONS.contains(&(work, component)) {
+        return Compatibility::Compatible;
+    }
+
+    let ext = match ext {
+        Some(ext) => ext,
+        None => return Compatibility::Unknown,
+    };
+    if Copyleft::of(ext) < Copyleft::Strong || ext.compatible_outbound().contains(&work) {
+        return Compatibility::Compatible;
+    }
+    Compatibility::Incompatible(Incompatibility {
+        work,
+        component: ext.id(),
+        reason: Reason::CopyleftConflict {
+            required: ext.id(),
+            actual: work,
+        },
+        resolved_by: Some(ext.id()),
+    })
+}
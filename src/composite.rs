@@ -0,0 +1,210 @@
+use crate::*;
+use core::fmt::{self, Display, Formatter};
+
+/// Separates the texts of the licenses in [`CompositeLicense::text`].
+const TEXT_SEPARATOR: &str =
+    "\n\n--------------------------------------------------------------------------------\n\n";
+
+/// Licenses that all apply to a work, such as `Apache-2.0 AND MIT` when different files
+/// are under different licenses.
+///
+/// The composite answers conservatively: its permissions are the intersection of the
+/// permissions of the licenses, while its conditions and limitations are the union of
+/// theirs. The licenses are deduplicated and ordered by id, so the order in which they
+/// are given doesn't matter.
+///
+/// [`License`] requires `'static` strings, which can't be built at runtime without
+/// allocating, so the composite doesn't implement [`LicenseExt`]. Its id and text are
+/// rendered by [`Display`] wrappers instead, and with the `alloc` feature `data` makes
+/// the `LicenseData` that the code written for every license takes, such as the
+/// Markdown renderer, and its id can be evaluated by a `Policy`.
+///
+/// # Examples
+/// ```
+/// use license::CompositeLicense;
+///
+/// let licenses = ["MIT", "Apache-2.0", "MIT"]
+///     .iter()
+///     .map(|id| license::from_id_ext(id).unwrap())
+///     .collect::<Vec<_>>();
+/// let composite = CompositeLicense::all_of(&licenses);
+/// assert_eq!(composite.id().to_string(), "Apache-2.0 AND MIT");
+/// assert!(!composite.permissions().patent_rights());
+/// assert!(composite.conditions().notice_file());
+/// assert!(composite.limitations().no_trademark_rights());
+/// ```
+#[derive(Copy, Clone)]
+pub struct CompositeLicense<'a> {
+    licenses: &'a [&'static dyn LicenseExt],
+}
+
+impl<'a> CompositeLicense<'a> {
+    /// Combines licenses that all apply.
+    pub fn all_of(licenses: &'a [&'static dyn LicenseExt]) -> Self {
+        CompositeLicense { licenses }
+    }
+
+    /// The licenses, deduplicated and ordered by id.
+    pub fn licenses(&self) -> impl Iterator<Item = &'static dyn LicenseExt> + Clone + 'a {
+        Sorted::new(self.licenses.iter().copied())
+    }
+
+    /// The combined permissions, conditions and limitations.
+    ///
+    /// A composite of no licenses has no flags set.
+    pub fn metadata(&self) -> Metadata {
+        let mut licenses = self.licenses.iter();
+        let first = match licenses.next() {
+            Some(first) => first.metadata(),
+            None => return Metadata::default(),
+        };
+        licenses.fold(first, |metadata, l| {
+            Metadata::new(
+                metadata.permissions().intersection(l.permissions()),
+                metadata.conditions().union(l.conditions()),
+                metadata.limitations().union(l.limitations()),
+            )
+        })
+    }
+
+    /// The permissions that every license grants.
+    pub fn permissions(&self) -> Permissions {
+        self.metadata().permissions()
+    }
+
+    /// The conditions of any of the licenses.
+    pub fn conditions(&self) -> Conditions {
+        self.metadata().conditions()
+    }
+
+    /// The limitations of any of the licenses.
+    pub fn limitations(&self) -> Limitations {
+        self.metadata().limitations()
+    }
+
+    /// The SPDX expression of the composite, the ids joined with `AND`.
+    pub fn id(&self) -> impl Display + 'a {
+        CompositeId(*self)
+    }
+
+    /// The texts of the licenses, separated by a line of dashes.
+    pub fn text(&self) -> impl Display + 'a {
+        CompositeText(*self)
+    }
+
+    /// The data of the composite, like [`LicenseData::of`] of a license.
+    ///
+    /// The name is the names of the licenses joined with ` and `, the composite is OSI
+    /// approved and FSF libre if every license is, and deprecated if any is. The URLs
+    /// are those of the licenses, and it has no header.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::CompositeLicense;
+    ///
+    /// let licenses = [license::from_id_ext("MIT").unwrap(), license::from_id_ext("Apache-2.0").unwrap()];
+    /// let data = CompositeLicense::all_of(&licenses).data();
+    /// assert_eq!(data.id, "Apache-2.0 AND MIT");
+    /// assert_eq!(data.name, "Apache License 2.0 and MIT License");
+    /// assert!(data.osi_approved);
+    /// assert!(data.markdown().to_string().starts_with("# Apache License 2.0 and MIT License\n"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn data(&self) -> LicenseData<'static> {
+        use alloc::string::{String, ToString};
+
+        let mut name = String::new();
+        for (i, license) in self.licenses().enumerate() {
+            if i > 0 {
+                name.push_str(" and ");
+            }
+            name.push_str(license.name());
+        }
+        let mut data = LicenseData::new(self.id().to_string(), name, self.text().to_string());
+        data.osi_approved = self.licenses().all(|license| license.is_osi_approved());
+        data.fsf_libre = self.licenses().all(|license| license.is_fsf_libre());
+        data.deprecated = self.licenses().any(|license| license.is_deprecated());
+        data.see_also = self
+            .licenses()
+            .flat_map(|license| license.see_also().iter().map(|&url| url.into()))
+            .collect();
+        data.metadata = self.metadata();
+        data
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<CompositeLicense<'_>> for LicenseData<'static> {
+    fn from(composite: CompositeLicense<'_>) -> Self {
+        composite.data()
+    }
+}
+
+impl fmt::Debug for CompositeLicense<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("CompositeLicense")
+            .field("id", &format_args!("{}", self.id()))
+            .finish()
+    }
+}
+
+/// Yields licenses in the order of their ids, skipping duplicates, without allocating.
+#[derive(Clone)]
+pub(crate) struct Sorted<I> {
+    licenses: I,
+    last: Option<&'static str>,
+}
+
+impl<I> Sorted<I> {
+    pub(crate) fn new(licenses: I) -> Self {
+        Sorted {
+            licenses,
+            last: None,
+        }
+    }
+}
+
+impl<I: Iterator<Item = &'static dyn LicenseExt> + Clone> Iterator for Sorted<I> {
+    type Item = &'static dyn LicenseExt;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let last = self.last;
+        let next = self
+            .licenses
+            .clone()
+            .filter(|l| !matches!(last, Some(last) if l.id() <= last))
+            .min_by_key(|l| l.id())?;
+        self.last = Some(next.id());
+        Some(next)
+    }
+}
+
+struct CompositeId<'a>(CompositeLicense<'a>);
+
+impl Display for CompositeId<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (i, license) in self.0.licenses().enumerate() {
+            if i > 0 {
+                f.write_str(" AND ")?;
+            }
+            f.write_str(license.id())?;
+        }
+        Ok(())
+    }
+}
+
+struct CompositeText<'a>(CompositeLicense<'a>);
+
+impl Display for CompositeText<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (i, license) in self.0.licenses().enumerate() {
+            if i > 0 {
+                f.write_str(TEXT_SEPARATOR)?;
+            }
+            f.write_str(license.text().trim())?;
+        }
+        Ok(())
+    }
+}
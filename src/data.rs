@@ -0,0 +1,209 @@
+use crate::*;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+/// The data of a license as one value, which borrows the `'static` strings of the
+/// licenses of this crate and owns those of a license made at runtime.
+///
+/// The accessors of [`License`] return `&'static str`, which a license read from a file
+/// or filled in from a template can't provide. A renderer written against this type
+/// works for both, such as [`markdown`](LicenseData::markdown).
+///
+/// The fields are public so that a license made at runtime can be filled in after
+/// [`new`](LicenseData::new), and the struct is `#[non_exhaustive]` so that fields can
+/// be added later.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use license::LicenseData;
+///
+/// let mit = LicenseData::of(license::from_id_ext("MIT").unwrap());
+/// assert_eq!(mit.id, "MIT");
+/// assert!(mit.metadata.conditions().license_and_copyright_notice());
+///
+/// let mut custom = LicenseData::new(
+///     String::from("LicenseRef-Example"),
+///     String::from("Example License"),
+///     String::from("Use it as you like."),
+/// );
+/// custom.metadata = mit.metadata;
+/// assert_eq!(custom.metadata, mit.metadata);
+/// ```
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct LicenseData<'a> {
+    /// The id, see [`License::id`].
+    pub id: Cow<'a, str>,
+    /// The name, see [`License::name`].
+    pub name: Cow<'a, str>,
+    /// The text, see [`License::text`].
+    pub text: Cow<'a, str>,
+    /// The standard header, see [`License::header`].
+    pub header: Option<Cow<'a, str>>,
+    /// Says if the license is OSI approved, see [`License::is_osi_approved`].
+    pub osi_approved: bool,
+    /// Says if the license is FSF libre, see [`License::is_fsf_libre`].
+    pub fsf_libre: bool,
+    /// Says if the id is deprecated, see [`License::is_deprecated`].
+    pub deprecated: bool,
+    /// The URLs of the license, see [`License::see_also`].
+    pub see_also: Vec<Cow<'a, str>>,
+    /// The permissions, conditions and limitations, see [`LicenseExt::metadata`].
+    pub metadata: Metadata,
+}
+
+impl<'a> LicenseData<'a> {
+    /// The data of a license made at runtime, without a header, URLs or flags.
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        name: impl Into<Cow<'a, str>>,
+        text: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        LicenseData {
+            id: id.into(),
+            name: name.into(),
+            text: text.into(),
+            header: None,
+            osi_approved: false,
+            fsf_libre: false,
+            deprecated: false,
+            see_also: Vec::new(),
+            metadata: Metadata::default(),
+        }
+    }
+
+    /// Makes the data own its strings, copying the borrowed ones.
+    pub fn into_owned(self) -> LicenseData<'static> {
+        LicenseData {
+            id: Cow::Owned(self.id.into_owned()),
+            name: Cow::Owned(self.name.into_owned()),
+            text: Cow::Owned(self.text.into_owned()),
+            header: self.header.map(|header| Cow::Owned(header.into_owned())),
+            osi_approved: self.osi_approved,
+            fsf_libre: self.fsf_libre,
+            deprecated: self.deprecated,
+            see_also: self
+                .see_also
+                .into_iter()
+                .map(|url| Cow::Owned(url.into_owned()))
+                .collect(),
+            metadata: self.metadata,
+        }
+    }
+
+    /// Renders the data as Markdown, see [`LicenseMarkdown`].
+    pub fn markdown(&self) -> LicenseMarkdown<'_> {
+        LicenseMarkdown { data: self }
+    }
+}
+
+impl LicenseData<'static> {
+    /// The data of a license, borrowing its strings.
+    pub fn of(license: &dyn LicenseExt) -> Self {
+        LicenseData {
+            id: Cow::Borrowed(license.id()),
+            name: Cow::Borrowed(license.name()),
+            text: Cow::Borrowed(license.text()),
+            header: license.header().map(Cow::Borrowed),
+            osi_approved: license.is_osi_approved(),
+            fsf_libre: license.is_fsf_libre(),
+            deprecated: license.is_deprecated(),
+            see_also: license.see_also().iter().map(|&url| url.into()).collect(),
+            metadata: license.metadata(),
+        }
+    }
+}
+
+impl From<&dyn LicenseExt> for LicenseData<'static> {
+    fn from(license: &dyn LicenseExt) -> Self {
+        LicenseData::of(license)
+    }
+}
+
+/// The Markdown of a [`LicenseData`], see [`LicenseData::markdown`].
+///
+/// The name is the heading, followed by a list of the id, the OSI and FSF status and
+/// the URLs, and a section for each of the permissions, the conditions and the
+/// limitations. As in the [report](crate::report::CombinedReport), empty sections are
+/// left out and noted on a line of the list, such as `No conditions.`, and the
+/// [`UNKNOWN`] and [`NONE`] sentinels are noted instead of their flags. The text isn't
+/// rendered.
+///
+/// # Examples
+/// ```
+/// use license::LicenseData;
+///
+/// let data = LicenseData::of(license::from_id_ext("0BSD").unwrap());
+/// let markdown = data.markdown().to_string();
+/// assert!(markdown.starts_with(
+///     "# BSD Zero Clause License\n\
+///      \n\
+///      - Id: `0BSD`\n\
+///      - OSI approved: yes\n\
+///      - FSF libre: no\n\
+///      - See also: <http://landley.net/toybox/license.html>\n\
+///      - No conditions.\n"
+/// ));
+/// assert!(markdown.ends_with("- Includes a limitation of liability.\n- Does not provide any warranty.\n"));
+/// ```
+#[derive(Copy, Clone)]
+pub struct LicenseMarkdown<'a> {
+    data: &'a LicenseData<'a>,
+}
+
+impl fmt::Debug for LicenseMarkdown<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("LicenseMarkdown")
+            .field("license", &self.data.id)
+            .finish()
+    }
+}
+
+impl Display for LicenseMarkdown<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let data = self.data;
+        let yes = |flag| if flag { "yes" } else { "no" };
+        writeln!(f, "# {}\n", data.name)?;
+        writeln!(f, "- Id: `{}`", data.id)?;
+        writeln!(f, "- OSI approved: {}", yes(data.osi_approved))?;
+        writeln!(f, "- FSF libre: {}", yes(data.fsf_libre))?;
+        if data.deprecated {
+            f.write_str("- Deprecated: yes\n")?;
+        }
+        if !data.see_also.is_empty() {
+            f.write_str("- See also: ")?;
+            for (i, url) in data.see_also.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "<{}>", url)?;
+            }
+            f.write_str("\n")?;
+        }
+        if let Some(note) = sentinel::note(&data.id) {
+            return writeln!(f, "- {}", note);
+        }
+        let metadata = data.metadata;
+        let none = [
+            (metadata.permissions().is_empty(), Permissions::NOTE),
+            (metadata.conditions().is_empty(), Conditions::NOTE),
+            (metadata.limitations().is_empty(), Limitations::NOTE),
+        ];
+        for &(_, note) in none.iter().filter(|(empty, _)| *empty) {
+            writeln!(f, "- {}", note)?;
+        }
+        if !metadata.permissions().is_empty() {
+            write!(f, "\n## Permissions\n\n{}", metadata.permissions())?;
+        }
+        if !metadata.conditions().is_empty() {
+            write!(f, "\n## Conditions\n\n{}", metadata.conditions())?;
+        }
+        if !metadata.limitations().is_empty() {
+            write!(f, "\n## Limitations\n\n{}", metadata.limitations())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+/// Something a lenient function accepted but that isn't SPDX, reported to a
+/// [`Diagnostics`] hook.
+///
+/// Its [`Display`] impl writes a message for a warning, such as
+/// ``deprecated license id `GPL-2.0+`, use `GPL-2.0-or-later` ``.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Diagnostic<'a> {
+    /// A deprecated SPDX id, such as `GPL-2.0` or `GPL-2.0+`.
+    #[non_exhaustive]
+    DeprecatedId {
+        /// The id as written.
+        given: &'a str,
+        /// The id that replaces it, or the id itself for a deprecated license that
+        /// nothing replaces.
+        canonical: &'static str,
+    },
+    /// A legacy name that isn't an SPDX id, such as `GPLv3`, or an id in another ASCII
+    /// case, such as `mit`.
+    #[non_exhaustive]
+    AliasUsed {
+        /// The name as written.
+        given: &'a str,
+        /// The id that it stands for.
+        canonical: &'static str,
+    },
+    /// A `/` between two licenses, as in the legacy Cargo `license` field, that was
+    /// taken as `OR`.
+    #[non_exhaustive]
+    LegacySeparator {
+        /// The byte offset of the `/`.
+        position: usize,
+    },
+    /// An operator in another ASCII case, such as `and`.
+    #[non_exhaustive]
+    OperatorCase {
+        /// The operator as written.
+        given: &'a str,
+        /// The operator in SPDX casing.
+        canonical: &'static str,
+    },
+}
+
+impl Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Diagnostic::DeprecatedId { given, canonical } if given == canonical => {
+                write!(f, "deprecated license id `{}`", given)
+            }
+            Diagnostic::DeprecatedId { given, canonical } => {
+                write!(f, "deprecated license id `{}`, use `{}`", given, canonical)
+            }
+            Diagnostic::AliasUsed { given, canonical } => {
+                write!(f, "license alias `{}`, use `{}`", given, canonical)
+            }
+            Diagnostic::LegacySeparator { position } => {
+                write!(f, "`/` at position {}, use `OR`", position)
+            }
+            Diagnostic::OperatorCase { given, canonical } => {
+                write!(f, "operator `{}`, use `{}`", given, canonical)
+            }
+        }
+    }
+}
+
+/// A hook that receives the [`Diagnostic`]s of the lenient functions, such as
+/// [`normalize_legacy_with_diagnostics`](crate::normalize_legacy_with_diagnostics), so
+/// that the caller can log them.
+///
+/// It is implemented for closures, and for a `Vec` that collects the diagnostics. The
+/// strict functions, such as [`from_id_ext`](crate::from_id_ext), never report any.
+///
+/// # Examples
+/// ```
+/// let mut warnings = Vec::new();
+/// let mut log = |diagnostic: license::Diagnostic| warnings.push(diagnostic.to_string());
+/// license::from_id_flexible_with_diagnostics("GPL-2.0+", &mut log).unwrap();
+/// assert_eq!(warnings, ["deprecated license id `GPL-2.0+`, use `GPL-2.0-or-later`"]);
+/// ```
+pub trait Diagnostics<'a> {
+    /// Receives a diagnostic, in the order of the input.
+    fn report(&mut self, diagnostic: Diagnostic<'a>);
+}
+
+impl<'a, F: FnMut(Diagnostic<'a>)> Diagnostics<'a> for F {
+    fn report(&mut self, diagnostic: Diagnostic<'a>) {
+        self(diagnostic)
+    }
+}
+
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+impl<'a> Diagnostics<'a> for Vec<Diagnostic<'a>> {
+    fn report(&mut self, diagnostic: Diagnostic<'a>) {
+        self.push(diagnostic)
+    }
+}
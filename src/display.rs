@@ -0,0 +1,394 @@
+use crate::*;
+use core::fmt::{self, Display, Formatter, Write};
+
+/// How the lists of flags are rendered, see [`Permissions::list`].
+///
+/// The default style is the one of the [`Display`] impls of the flags, a `-`
+/// bullet and capitalized sentences, each followed by a newline.
+///
+/// With the alternate flag, `{:#}`, the lists are rendered on one line instead, for
+/// a table cell for example: the sentences are separated by spaces, without bullets
+/// and without a trailing newline. Width and fill are ignored.
+///
+/// A list without flags is never blank: it is the sentence saying so, such as
+/// `Has no conditions.`, or `(none)` on one line. The renderers with sections, such as
+/// [`SummaryDisplay`] and the [report](crate::report::CombinedReport), leave the empty
+/// sections out and note them instead, such as `No conditions.`, and the
+/// [JSON dump](crate::export) keeps them as empty arrays.
+///
+/// # Examples
+/// ```
+/// use license::ListStyle;
+///
+/// let cond = license::from_id_ext("MIT").unwrap().conditions();
+/// let style = ListStyle {
+///     bullet: '*',
+///     trailing_newline: false,
+///     capitalize: false,
+/// };
+/// assert_eq!(
+///     format!("<{}>", cond.list(style)),
+///     "<* the license and copyright notice must be included with the software.>"
+/// );
+/// assert_eq!(cond.list(ListStyle::default()).to_string(), cond.to_string());
+///
+/// let perm = license::from_id_ext("MIT").unwrap().permissions();
+/// assert_eq!(
+///     format!("{:#}", perm),
+///     "May be used for commercial purposes. May be distributed. May be modified. \
+///      May be used for private purposes."
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ListStyle {
+    /// The character that starts every item, followed by a space.
+    pub bullet: char,
+    /// Says if the last line is followed by a newline.
+    ///
+    /// Lines are always separated by newlines.
+    pub trailing_newline: bool,
+    /// Says if the items start with a capital letter.
+    pub capitalize: bool,
+}
+
+impl Default for ListStyle {
+    fn default() -> Self {
+        ListStyle {
+            bullet: '-',
+            trailing_newline: true,
+            capitalize: true,
+        }
+    }
+}
+
+/// Writes lines to a formatter, separating them with newlines, or with spaces if inline.
+struct Lines<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    style: ListStyle,
+    inline: bool,
+    empty: bool,
+}
+
+impl<'a, 'b> Lines<'a, 'b> {
+    fn new(f: &'a mut Formatter<'b>, style: ListStyle) -> Self {
+        Lines {
+            f,
+            style,
+            inline: false,
+            empty: true,
+        }
+    }
+
+    /// Writes the items on one line, without bullets or a trailing newline.
+    fn inline(self) -> Self {
+        Lines {
+            inline: true,
+            ..self
+        }
+    }
+
+    fn start(&mut self) -> fmt::Result {
+        if !self.empty {
+            self.f.write_char(if self.inline { ' ' } else { '\n' })?;
+        }
+        self.empty = false;
+        Ok(())
+    }
+
+    fn heading(&mut self, heading: &str) -> fmt::Result {
+        self.start()?;
+        self.f.write_str(heading)
+    }
+
+    fn items(&mut self, sentences: impl Iterator<Item = &'static str>) -> fmt::Result {
+        for sentence in sentences {
+            self.start()?;
+            if !self.inline {
+                self.f.write_char(self.style.bullet)?;
+                self.f.write_char(' ')?;
+            }
+            if self.style.capitalize {
+                self.f.write_str(sentence)?;
+            } else {
+                let mut chars = sentence.chars();
+                for c in chars.next().into_iter().flat_map(char::to_lowercase) {
+                    self.f.write_char(c)?;
+                }
+                self.f.write_str(chars.as_str())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes sentences on one line.
+    fn note(&mut self, sentences: impl Iterator<Item = &'static str>) -> fmt::Result {
+        for (i, sentence) in sentences.enumerate() {
+            if i == 0 {
+                self.start()?;
+            } else {
+                self.f.write_char(' ')?;
+            }
+            self.f.write_str(sentence)?;
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> fmt::Result {
+        if self.style.trailing_newline && !self.inline && !self.empty {
+            self.f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_list {
+    ($($(#[$attr:meta])* $list:ident($flags:ident, $field:ident);)*) => {
+        $($(#[$attr])*
+        #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+        pub struct $list<'a> {
+            $field: &'a $flags,
+            style: ListStyle,
+        }
+
+        impl $flags {
+            #[doc = concat!("Renders the flags that are set as a list in the given style, see [`", stringify!($list), "`].")]
+            pub fn list(&self, style: ListStyle) -> $list<'_> {
+                $list {
+                    $field: self,
+                    style,
+                }
+            }
+        }
+
+        impl Display for $list<'_> {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                let inline = f.alternate();
+                let mut lines = Lines::new(f, self.style);
+                if inline {
+                    lines = lines.inline();
+                }
+                if self.$field.is_empty() && inline {
+                    lines.note(core::iter::once("(none)"))?;
+                } else if self.$field.is_empty() {
+                    lines.items(core::iter::once($flags::EMPTY))?;
+                } else {
+                    lines.items(self.$field.sentences())?;
+                }
+                lines.finish()
+            }
+        })*
+    };
+}
+
+impl_list! {
+    /// The permissions of a license rendered as a list, without allocating.
+    PermissionsList(Permissions, permissions);
+    /// The conditions of a license rendered as a list, without allocating.
+    ConditionsList(Conditions, conditions);
+    /// The limitations of a license rendered as a list, without allocating.
+    LimitationsList(Limitations, limitations);
+}
+
+/// The permissions, conditions and limitations of a license rendered under
+/// headings, without allocating.
+///
+/// Empty sections are left out, and noted on the last line. The [`UNKNOWN`] and [`NONE`]
+/// sentinels are rendered as the sentence that says what they are instead, such as
+/// `License could not be determined.`
+///
+/// # Examples
+/// ```
+/// use license::{ListStyle, SummaryDisplay};
+///
+/// let wtfpl = license::from_id_ext("WTFPL").unwrap();
+/// assert_eq!(
+///     SummaryDisplay::new(wtfpl, ListStyle::default()).to_string(),
+///     "Permissions:\n\
+///      - May be used for commercial purposes.\n\
+///      - May be distributed.\n\
+///      - May be modified.\n\
+///      - May be used for private purposes.\n\
+///      No conditions. No limitations.\n"
+/// );
+/// ```
+#[derive(Copy, Clone)]
+pub struct SummaryDisplay<'a> {
+    license: &'a dyn LicenseExt,
+    style: ListStyle,
+}
+
+impl<'a> SummaryDisplay<'a> {
+    /// Creates the summary of a license in the given style.
+    pub fn new(license: &'a dyn LicenseExt, style: ListStyle) -> Self {
+        SummaryDisplay { license, style }
+    }
+}
+
+impl fmt::Debug for SummaryDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("SummaryDisplay")
+            .field("license", &self.license.id())
+            .field("style", &self.style)
+            .finish()
+    }
+}
+
+impl Display for SummaryDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let permissions = self.license.permissions();
+        let conditions = self.license.conditions();
+        let limitations = self.license.limitations();
+        let mut lines = Lines::new(f, self.style);
+        if let Some(note) = sentinel::note(self.license.id()) {
+            lines.note(core::iter::once(note))?;
+            return lines.finish();
+        }
+        if !permissions.is_empty() {
+            lines.heading("Permissions:")?;
+            lines.items(permissions.sentences())?;
+        }
+        if !conditions.is_empty() {
+            lines.heading("Conditions:")?;
+            lines.items(conditions.sentences())?;
+        }
+        if !limitations.is_empty() {
+            lines.heading("Limitations:")?;
+            lines.items(limitations.sentences())?;
+        }
+        let none = [
+            (permissions.is_empty(), Permissions::NOTE),
+            (conditions.is_empty(), Conditions::NOTE),
+            (limitations.is_empty(), Limitations::NOTE),
+        ];
+        lines.note(
+            none.iter()
+                .filter(|(empty, _)| *empty)
+                .map(|&(_, note)| note),
+        )?;
+        lines.finish()
+    }
+}
+
+/// A fixed-width summary of the flags of a license on one line, for a table cell, see
+/// [`LicenseExt::compact_legend`].
+///
+/// Every flag has a position and a symbol, and a flag that isn't set is a dash, so that
+/// the cells of different licenses line up. The groups are separated by ` | ` and the
+/// flags are in bit order, as in [`Permission::ALL`]:
+///
+/// | Flag | Symbol | ASCII |
+/// |------|--------|-------|
+/// | Permissions | `✔` each | `C` commercial use, `D` distribution, `M` modification, `P` patent rights, `U` private use |
+/// | [`DiscloseSources`](Condition::DiscloseSources) | `ⓢ` | `S` |
+/// | [`DocumentChanges`](Condition::DocumentChanges) | `ⓓ` | `D` |
+/// | [`LicenseAndCopyrightNotice`](Condition::LicenseAndCopyrightNotice) | `©` | `C` |
+/// | [`NetworkUseIsDistribution`](Condition::NetworkUseIsDistribution) | `ⓝ` | `N` |
+/// | [`SameLicense`](Condition::SameLicense) | `ⓛ` | `L` |
+/// | [`NoticeFile`](Condition::NoticeFile) | `ⓘ` | `F` |
+/// | [`MarkModifications`](Condition::MarkModifications) | `ⓜ` | `M` |
+/// | Limitations | `⚠` each | `L` no liability, `T` no trademark rights, `W` no warranty, `P` no patent rights |
+/// | Not set | `–` | `-` |
+///
+/// A legend is always [`WIDTH`](CompactLegend::WIDTH) characters long. The symbols are
+/// one column wide in most terminals, but some fonts draw `⚠` wider, and
+/// [`ascii`](CompactLegend::ascii) is there for the terminals that can't draw them. The
+/// mapping is stable: a new flag is appended to its group, making the legend wider.
+///
+/// # Examples
+/// ```
+/// let mit = license::from_id_ext("MIT").unwrap().compact_legend();
+/// assert_eq!(mit.to_string(), "✔✔✔–✔ | ––©–––– | ⚠–⚠–");
+/// assert_eq!(mit.ascii().to_string(), "CDM-U | --C---- | L-W-");
+/// assert_eq!(mit.to_string().chars().count(), license::CompactLegend::WIDTH);
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct CompactLegend {
+    metadata: Metadata,
+    ascii: bool,
+}
+
+impl CompactLegend {
+    /// The number of characters of every legend.
+    pub const WIDTH: usize =
+        Permission::ALL.len() + Condition::ALL.len() + Limitation::ALL.len() + 2 * SEPARATOR.len();
+
+    pub(crate) const fn new(metadata: Metadata) -> Self {
+        CompactLegend {
+            metadata,
+            ascii: false,
+        }
+    }
+
+    /// The same legend in plain ASCII, for terminals without Unicode symbols.
+    pub const fn ascii(self) -> Self {
+        CompactLegend {
+            ascii: true,
+            ..self
+        }
+    }
+}
+
+/// The separator of the groups of a [`CompactLegend`].
+const SEPARATOR: &str = " | ";
+
+impl Display for CompactLegend {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let pick = |set: bool, (symbol, ascii): (char, char)| match (set, self.ascii) {
+            (true, false) => symbol,
+            (true, true) => ascii,
+            (false, false) => '–',
+            (false, true) => '-',
+        };
+        let (permissions, conditions, limitations) = (
+            self.metadata.permissions(),
+            self.metadata.conditions(),
+            self.metadata.limitations(),
+        );
+        for &flag in Permission::ALL.iter() {
+            f.write_char(pick(permissions.contains(flag), permission_symbols(flag)))?;
+        }
+        f.write_str(SEPARATOR)?;
+        for &flag in Condition::ALL.iter() {
+            f.write_char(pick(conditions.contains(flag), condition_symbols(flag)))?;
+        }
+        f.write_str(SEPARATOR)?;
+        for &flag in Limitation::ALL.iter() {
+            f.write_char(pick(limitations.contains(flag), limitation_symbols(flag)))?;
+        }
+        Ok(())
+    }
+}
+
+/// The symbol and the ASCII letter of a permission in a [`CompactLegend`].
+const fn permission_symbols(flag: Permission) -> (char, char) {
+    match flag {
+        Permission::CommercialUse => ('✔', 'C'),
+        Permission::Distribution => ('✔', 'D'),
+        Permission::Modification => ('✔', 'M'),
+        Permission::PatentRights => ('✔', 'P'),
+        Permission::PrivateUse => ('✔', 'U'),
+    }
+}
+
+/// The symbol and the ASCII letter of a condition in a [`CompactLegend`].
+const fn condition_symbols(flag: Condition) -> (char, char) {
+    match flag {
+        Condition::DiscloseSources => ('ⓢ', 'S'),
+        Condition::DocumentChanges => ('ⓓ', 'D'),
+        Condition::LicenseAndCopyrightNotice => ('©', 'C'),
+        Condition::NetworkUseIsDistribution => ('ⓝ', 'N'),
+        Condition::SameLicense => ('ⓛ', 'L'),
+        Condition::NoticeFile => ('ⓘ', 'F'),
+        Condition::MarkModifications => ('ⓜ', 'M'),
+    }
+}
+
+/// The symbol and the ASCII letter of a limitation in a [`CompactLegend`].
+const fn limitation_symbols(flag: Limitation) -> (char, char) {
+    match flag {
+        Limitation::NoLiability => ('⚠', 'L'),
+        Limitation::NoTrademarkRights => ('⚠', 'T'),
+        Limitation::NoWarranty => ('⚠', 'W'),
+        Limitation::NoPatentRights => ('⚠', 'P'),
+    }
+}
@@ -0,0 +1,489 @@
+//! The errors of the fallible functions of the crate.
+//!
+//! An error holds the input it is about as fields, such as the expression and the
+//! position of an unknown id in it, and its [`Display`] message names them. The errors
+//! and their variants are `#[non_exhaustive]`, so that more can be reported later
+//! without a breaking change.
+//!
+//! With the `std` feature, the errors implement `std::error::Error`. They don't
+//! implement `core::error::Error`, which needs a newer Rust than the minimum supported
+//! version.
+//!
+//! There is no crate-wide error: the errors come from unrelated functions, and one type
+//! for all of them would only add variants that a caller can't get. The items of this
+//! module are also re-exported at the crate root.
+//!
+//! # Examples
+//! ```
+//! use license::error::PackageLicenseError;
+//!
+//! let err = license::PackageLicense::parse("MIT OR Apache").unwrap_err();
+//! assert!(matches!(err, PackageLicenseError::UnknownLicense { position: 7, .. }));
+//! assert_eq!(err.offending(), Some("Apache"));
+//! assert_eq!(err.to_string(), "unknown license id `Apache` at position 7 of `MIT OR Apache`");
+//! ```
+
+use crate::package::{id_end, Invalid, Token};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{self, Display, Formatter};
+
+/// The error returned by [`PackageLicense::parse`](crate::PackageLicense::parse).
+///
+/// Positions are byte offsets into the expression.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PackageLicenseError {
+    /// The expression is empty.
+    Empty,
+    /// A license id is not on the SPDX License List.
+    #[non_exhaustive]
+    UnknownLicense {
+        /// The expression.
+        expression: &'static str,
+        /// Where the id starts.
+        position: usize,
+    },
+    /// An exception id is not on the SPDX License Exceptions List.
+    #[non_exhaustive]
+    UnknownException {
+        /// The expression.
+        expression: &'static str,
+        /// Where the id starts.
+        position: usize,
+    },
+    /// The expression is not well formed.
+    #[non_exhaustive]
+    Syntax {
+        /// The expression.
+        expression: &'static str,
+        /// Where the unexpected token starts, which is the length of the expression if
+        /// it ends too early.
+        position: usize,
+    },
+}
+
+impl PackageLicenseError {
+    /// Where the error is in the expression, or `None` if it is empty.
+    pub const fn position(&self) -> Option<usize> {
+        match *self {
+            PackageLicenseError::Empty => None,
+            PackageLicenseError::UnknownLicense { position, .. }
+            | PackageLicenseError::UnknownException { position, .. }
+            | PackageLicenseError::Syntax { position, .. } => Some(position),
+        }
+    }
+
+    /// The unknown id or the unexpected token, or `None` if the expression is empty or
+    /// ends too early.
+    pub fn offending(&self) -> Option<&'static str> {
+        let (expression, invalid) = self.invalid();
+        offending(expression, invalid)
+    }
+
+    fn invalid(&self) -> (&'static str, Invalid) {
+        match *self {
+            PackageLicenseError::Empty => ("", Invalid::Empty),
+            PackageLicenseError::UnknownLicense {
+                expression,
+                position,
+            } => (expression, Invalid::UnknownLicense(position)),
+            PackageLicenseError::UnknownException {
+                expression,
+                position,
+            } => (expression, Invalid::UnknownException(position)),
+            PackageLicenseError::Syntax {
+                expression,
+                position,
+            } => (expression, Invalid::Syntax(position)),
+        }
+    }
+}
+
+/// The id or the token at the position of `invalid`.
+fn offending(expression: &str, invalid: Invalid) -> Option<&str> {
+    let position = match invalid {
+        Invalid::Empty => return None,
+        Invalid::UnknownLicense(position)
+        | Invalid::UnknownException(position)
+        | Invalid::Syntax(position) => position,
+    };
+    let bytes = expression.as_bytes();
+    let (token, start, end) = crate::package::token(bytes, position)?;
+    let end = match token {
+        Token::Id => id_end(bytes, end),
+        _ => end,
+    };
+    Some(&expression[start..end])
+}
+
+/// Writes the message of an invalid expression.
+fn write_invalid(f: &mut Formatter, expression: &str, invalid: Invalid) -> fmt::Result {
+    let offending = offending(expression, invalid).unwrap_or_default();
+    match invalid {
+        Invalid::Empty => f.write_str("the license expression is empty"),
+        Invalid::UnknownLicense(position) => write!(
+            f,
+            "unknown license id `{}` at position {} of `{}`",
+            offending, position, expression
+        ),
+        Invalid::UnknownException(position) => write!(
+            f,
+            "unknown exception id `{}` at position {} of `{}`",
+            offending, position, expression
+        ),
+        Invalid::Syntax(position) if offending.is_empty() => write!(
+            f,
+            "invalid license expression `{}`: unexpected end at position {}",
+            expression, position
+        ),
+        Invalid::Syntax(position) => write!(
+            f,
+            "invalid license expression `{}`: unexpected `{}` at position {}",
+            expression, offending, position
+        ),
+    }
+}
+
+impl Display for PackageLicenseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (expression, invalid) = self.invalid();
+        write_invalid(f, expression, invalid)
+    }
+}
+
+/// The error returned when an expression is parsed at runtime, such as by
+/// [`Expression::parse`](crate::expression::Expression::parse).
+///
+/// It is a [`PackageLicenseError`] for an expression that is borrowed, with the same
+/// messages.
+#[cfg(feature = "alloc")]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ExpressionError<'a> {
+    /// The expression is empty.
+    Empty,
+    /// A license id is not on the SPDX License List, nor a `LicenseRef-` id.
+    #[non_exhaustive]
+    UnknownLicense {
+        /// The expression.
+        expression: &'a str,
+        /// Where the id starts.
+        position: usize,
+    },
+    /// An exception id is not on the SPDX License Exceptions List.
+    #[non_exhaustive]
+    UnknownException {
+        /// The expression.
+        expression: &'a str,
+        /// Where the id starts.
+        position: usize,
+    },
+    /// The expression is not well formed.
+    #[non_exhaustive]
+    Syntax {
+        /// The expression.
+        expression: &'a str,
+        /// Where the unexpected token starts, which is the length of the expression if
+        /// it ends too early.
+        position: usize,
+    },
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> ExpressionError<'a> {
+    pub(crate) fn new(expression: &'a str, invalid: Invalid) -> Self {
+        match invalid {
+            Invalid::Empty => ExpressionError::Empty,
+            Invalid::UnknownLicense(position) => ExpressionError::UnknownLicense {
+                expression,
+                position,
+            },
+            Invalid::UnknownException(position) => ExpressionError::UnknownException {
+                expression,
+                position,
+            },
+            Invalid::Syntax(position) => ExpressionError::Syntax {
+                expression,
+                position,
+            },
+        }
+    }
+
+    /// Where the error is in the expression, or `None` if it is empty.
+    pub fn position(&self) -> Option<usize> {
+        match *self {
+            ExpressionError::Empty => None,
+            ExpressionError::UnknownLicense { position, .. }
+            | ExpressionError::UnknownException { position, .. }
+            | ExpressionError::Syntax { position, .. } => Some(position),
+        }
+    }
+
+    /// The unknown id or the unexpected token, or `None` if the expression is empty or
+    /// ends too early.
+    pub fn offending(&self) -> Option<&'a str> {
+        let (expression, invalid) = self.invalid();
+        offending(expression, invalid)
+    }
+
+    fn invalid(&self) -> (&'a str, Invalid) {
+        match *self {
+            ExpressionError::Empty => ("", Invalid::Empty),
+            ExpressionError::UnknownLicense {
+                expression,
+                position,
+            } => (expression, Invalid::UnknownLicense(position)),
+            ExpressionError::UnknownException {
+                expression,
+                position,
+            } => (expression, Invalid::UnknownException(position)),
+            ExpressionError::Syntax {
+                expression,
+                position,
+            } => (expression, Invalid::Syntax(position)),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<PackageLicenseError> for ExpressionError<'static> {
+    fn from(err: PackageLicenseError) -> Self {
+        let (expression, invalid) = err.invalid();
+        ExpressionError::new(expression, invalid)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Display for ExpressionError<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (expression, invalid) = self.invalid();
+        write_invalid(f, expression, invalid)
+    }
+}
+
+/// The error returned by [`normalize_legacy`](crate::normalize_legacy).
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum NormalizeError<'a> {
+    /// The field is empty.
+    Empty,
+    /// A `/` is not between two licenses.
+    #[non_exhaustive]
+    EmptyOperand {
+        /// The field.
+        field: &'a str,
+        /// The byte offset of the `/` in the field.
+        position: usize,
+    },
+}
+
+impl Display for NormalizeError<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            NormalizeError::Empty => f.write_str("the license field is empty"),
+            NormalizeError::EmptyOperand { field, position } => write!(
+                f,
+                "the `/` at position {} of `{}` is not between two licenses",
+                position, field
+            ),
+        }
+    }
+}
+
+/// The error returned by the constructors of
+/// [`CopyrightYears`](crate::params::CopyrightYears).
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CopyrightYearsError {
+    /// The first year is after the last year.
+    #[non_exhaustive]
+    Reversed {
+        /// The first year.
+        start: u16,
+        /// The last year.
+        end: u16,
+    },
+    /// The first year is after the current year.
+    #[non_exhaustive]
+    FutureStart {
+        /// The first year.
+        start: u16,
+        /// The current year.
+        current: u16,
+    },
+}
+
+impl Display for CopyrightYearsError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            CopyrightYearsError::Reversed { start, end } => write!(
+                f,
+                "the copyright years `{}-{}` end before they start",
+                start, end
+            ),
+            CopyrightYearsError::FutureStart { start, current } => write!(
+                f,
+                "the copyright years start in {}, after the current year {}",
+                start, current
+            ),
+        }
+    }
+}
+
+/// The error returned by [`Registry::register`](crate::Registry::register).
+#[cfg(feature = "alloc")]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum RegisterError {
+    /// The id is the id of a license of this crate.
+    #[non_exhaustive]
+    Builtin {
+        /// The id.
+        id: &'static str,
+    },
+    /// A license with the id is already registered.
+    #[non_exhaustive]
+    Registered {
+        /// The id.
+        id: &'static str,
+    },
+}
+
+#[cfg(feature = "alloc")]
+impl Display for RegisterError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            RegisterError::Builtin { id } => {
+                write!(f, "`{}` is the id of a builtin license", id)
+            }
+            RegisterError::Registered { id } => {
+                write!(f, "a license with the id `{}` is already registered", id)
+            }
+        }
+    }
+}
+
+/// The error returned by [`Overrides::set`](crate::overrides::Overrides::set), and when
+/// a document of overrides is deserialized.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum OverrideError {
+    /// The license id is not the id of a license with flags.
+    #[non_exhaustive]
+    UnknownLicense {
+        /// The id.
+        id: String,
+        /// The nearest id of a license with flags, if it is close enough to be a likely
+        /// typo.
+        suggestion: Option<&'static str>,
+    },
+}
+
+#[cfg(feature = "alloc")]
+impl Display for OverrideError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            OverrideError::UnknownLicense {
+                id,
+                suggestion: Some(suggestion),
+            } => write!(
+                f,
+                "unknown license id `{}`, did you mean `{}`?",
+                id, suggestion
+            ),
+            OverrideError::UnknownLicense { id, .. } => write!(f, "unknown license id `{}`", id),
+        }
+    }
+}
+
+/// The error returned by [`TagSet::insert`](crate::tags::TagSet::insert), and when a
+/// document of tags is deserialized.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum TagError {
+    /// The license id is not known to the crate.
+    #[non_exhaustive]
+    UnknownLicense {
+        /// The id.
+        id: String,
+        /// The nearest known id, if it is close enough to be a likely typo.
+        suggestion: Option<&'static str>,
+    },
+}
+
+#[cfg(feature = "alloc")]
+impl Display for TagError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TagError::UnknownLicense {
+                id,
+                suggestion: Some(suggestion),
+            } => write!(
+                f,
+                "unknown license id `{}`, did you mean `{}`?",
+                id, suggestion
+            ),
+            TagError::UnknownLicense { id, .. } => write!(f, "unknown license id `{}`", id),
+        }
+    }
+}
+
+/// The error returned by [`Policy::allow`](crate::policy::Policy::allow) and
+/// [`Policy::deny`](crate::policy::Policy::deny), and when a policy is deserialized.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PolicyError {
+    /// The id is not a single license of an expression.
+    #[non_exhaustive]
+    UnknownLicense {
+        /// The id.
+        id: String,
+        /// The nearest known id, if it is close enough to be a likely typo.
+        suggestion: Option<&'static str>,
+    },
+}
+
+#[cfg(feature = "alloc")]
+impl Display for PolicyError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            PolicyError::UnknownLicense {
+                id,
+                suggestion: Some(suggestion),
+            } => write!(
+                f,
+                "unknown license id `{}`, did you mean `{}`?",
+                id, suggestion
+            ),
+            PolicyError::UnknownLicense { id, .. } => write!(f, "unknown license id `{}`", id),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PackageLicenseError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ExpressionError<'_> {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NormalizeError<'_> {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CopyrightYearsError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OverrideError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PolicyError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RegisterError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TagError {}
@@ -54,7 +54,53 @@ pub fn from_id_exception(id: &str) -> Option<&'static dyn crate::LicenseExceptio
     }
 }
 
+/// The ids of all exceptions, sorted, for lookups in constant expressions.
+pub(crate) const EXCEPTION_IDS: &[&str] = &[
+    "389-exception",
+    "Autoconf-exception-2.0",
+    "Autoconf-exception-3.0",
+    "Bison-exception-2.2",
+    "Bootloader-exception",
+    "CLISP-exception-2.0",
+    "Classpath-exception-2.0",
+    "DigiRule-FOSS-exception",
+    "FLTK-exception",
+    "Fawkes-Runtime-exception",
+    "Font-exception-2.0",
+    "GCC-exception-2.0",
+    "GCC-exception-3.1",
+    "GPL-3.0-linking-exception",
+    "GPL-3.0-linking-source-exception",
+    "GPL-CC-1.0",
+    "LGPL-3.0-linking-exception",
+    "LLVM-exception",
+    "LZMA-exception",
+    "Libtool-exception",
+    "Linux-syscall-note",
+    "Nokia-Qt-exception-1.1",
+    "OCCT-exception-1.0",
+    "OCaml-LGPL-linking-exception",
+    "OpenJDK-assembly-exception-1.0",
+    "PS-or-PDF-font-exception-20170817",
+    "Qt-GPL-exception-1.0",
+    "Qt-LGPL-exception-1.1",
+    "Qwt-exception-1.0",
+    "SHL-2.0",
+    "SHL-2.1",
+    "Swift-exception",
+    "Universal-FOSS-exception-1.0",
+    "WxWindows-exception-3.1",
+    "eCos-exception-2.0",
+    "freertos-exception-2.0",
+    "gnu-javamail-exception",
+    "i2p-gpl-java-exception",
+    "mif-exception",
+    "openvpn-openssl-exception",
+    "u-boot-exception-2.0",
+];
+
 /// The PS/PDF font exception (2017-08-17).
+#[doc(alias = "PS-or-PDF-font-exception-20170817")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct PS_or_PDF_font_exception_20170817;
 
@@ -85,6 +131,7 @@ impl crate::LicenseException for PS_or_PDF_font_exception_20170817 {
 }
 
 /// The Qt GPL exception 1.0.
+#[doc(alias = "Qt-GPL-exception-1.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Qt_GPL_exception_1_0;
 
@@ -115,6 +162,7 @@ impl crate::LicenseException for Qt_GPL_exception_1_0 {
 }
 
 /// The Qt LGPL exception 1.1.
+#[doc(alias = "Qt-LGPL-exception-1.1")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Qt_LGPL_exception_1_1;
 
@@ -145,6 +193,7 @@ impl crate::LicenseException for Qt_LGPL_exception_1_1 {
 }
 
 /// The Qwt exception 1.0.
+#[doc(alias = "Qwt-exception-1.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Qwt_exception_1_0;
 
@@ -175,6 +224,7 @@ impl crate::LicenseException for Qwt_exception_1_0 {
 }
 
 /// The Solderpad Hardware License v2.0.
+#[doc(alias = "SHL-2.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct SHL_2_0;
 
@@ -205,6 +255,7 @@ impl crate::LicenseException for SHL_2_0 {
 }
 
 /// The Solderpad Hardware License v2.1.
+#[doc(alias = "SHL-2.1")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct SHL_2_1;
 
@@ -235,6 +286,7 @@ impl crate::LicenseException for SHL_2_1 {
 }
 
 /// The Swift Exception.
+#[doc(alias = "Swift-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Swift_exception;
 
@@ -265,6 +317,7 @@ impl crate::LicenseException for Swift_exception {
 }
 
 /// The Universal FOSS Exception, Version 1.0.
+#[doc(alias = "Universal-FOSS-exception-1.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Universal_FOSS_exception_1_0;
 
@@ -295,6 +348,7 @@ impl crate::LicenseException for Universal_FOSS_exception_1_0 {
 }
 
 /// The WxWindows Library Exception 3.1.
+#[doc(alias = "WxWindows-exception-3.1")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct WxWindows_exception_3_1;
 
@@ -325,6 +379,7 @@ impl crate::LicenseException for WxWindows_exception_3_1 {
 }
 
 /// The eCos exception 2.0.
+#[doc(alias = "eCos-exception-2.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct eCos_exception_2_0;
 
@@ -355,6 +410,7 @@ impl crate::LicenseException for eCos_exception_2_0 {
 }
 
 /// The FreeRTOS Exception 2.0.
+#[doc(alias = "freertos-exception-2.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct freertos_exception_2_0;
 
@@ -385,6 +441,7 @@ impl crate::LicenseException for freertos_exception_2_0 {
 }
 
 /// The GNU JavaMail exception.
+#[doc(alias = "gnu-javamail-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct gnu_javamail_exception;
 
@@ -415,6 +472,7 @@ impl crate::LicenseException for gnu_javamail_exception {
 }
 
 /// The i2p GPL+Java Exception.
+#[doc(alias = "i2p-gpl-java-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct i2p_gpl_java_exception;
 
@@ -445,6 +503,7 @@ impl crate::LicenseException for i2p_gpl_java_exception {
 }
 
 /// The Macros and Inline Functions Exception.
+#[doc(alias = "mif-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct mif_exception;
 
@@ -479,6 +538,7 @@ impl crate::LicenseException for mif_exception {
 }
 
 /// The OpenVPN OpenSSL Exception.
+#[doc(alias = "openvpn-openssl-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct openvpn_openssl_exception;
 
@@ -509,6 +569,7 @@ impl crate::LicenseException for openvpn_openssl_exception {
 }
 
 /// The U-Boot exception 2.0.
+#[doc(alias = "u-boot-exception-2.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct u_boot_exception_2_0;
 
@@ -539,6 +600,7 @@ impl crate::LicenseException for u_boot_exception_2_0 {
 }
 
 /// The 389 Directory Server Exception.
+#[doc(alias = "389-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct exception_389;
 
@@ -569,6 +631,7 @@ impl crate::LicenseException for exception_389 {
 }
 
 /// The Autoconf exception 2.0.
+#[doc(alias = "Autoconf-exception-2.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Autoconf_exception_2_0;
 
@@ -602,6 +665,7 @@ impl crate::LicenseException for Autoconf_exception_2_0 {
 }
 
 /// The Autoconf exception 3.0.
+#[doc(alias = "Autoconf-exception-3.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Autoconf_exception_3_0;
 
@@ -632,6 +696,7 @@ impl crate::LicenseException for Autoconf_exception_3_0 {
 }
 
 /// The Bison exception 2.2.
+#[doc(alias = "Bison-exception-2.2")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Bison_exception_2_2;
 
@@ -662,6 +727,7 @@ impl crate::LicenseException for Bison_exception_2_2 {
 }
 
 /// The Bootloader Distribution Exception.
+#[doc(alias = "Bootloader-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Bootloader_exception;
 
@@ -692,6 +758,7 @@ impl crate::LicenseException for Bootloader_exception {
 }
 
 /// The CLISP exception 2.0.
+#[doc(alias = "CLISP-exception-2.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct CLISP_exception_2_0;
 
@@ -722,6 +789,7 @@ impl crate::LicenseException for CLISP_exception_2_0 {
 }
 
 /// The Classpath exception 2.0.
+#[doc(alias = "Classpath-exception-2.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Classpath_exception_2_0;
 
@@ -755,6 +823,7 @@ impl crate::LicenseException for Classpath_exception_2_0 {
 }
 
 /// The DigiRule FOSS License Exception.
+#[doc(alias = "DigiRule-FOSS-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct DigiRule_FOSS_exception;
 
@@ -785,6 +854,7 @@ impl crate::LicenseException for DigiRule_FOSS_exception {
 }
 
 /// The FLTK exception.
+#[doc(alias = "FLTK-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct FLTK_exception;
 
@@ -815,6 +885,7 @@ impl crate::LicenseException for FLTK_exception {
 }
 
 /// The Fawkes Runtime Exception.
+#[doc(alias = "Fawkes-Runtime-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Fawkes_Runtime_exception;
 
@@ -845,6 +916,7 @@ impl crate::LicenseException for Fawkes_Runtime_exception {
 }
 
 /// The Font exception 2.0.
+#[doc(alias = "Font-exception-2.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Font_exception_2_0;
 
@@ -875,6 +947,7 @@ impl crate::LicenseException for Font_exception_2_0 {
 }
 
 /// The GCC Runtime Library exception 2.0.
+#[doc(alias = "GCC-exception-2.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct GCC_exception_2_0;
 
@@ -905,6 +978,7 @@ impl crate::LicenseException for GCC_exception_2_0 {
 }
 
 /// The GCC Runtime Library exception 3.1.
+#[doc(alias = "GCC-exception-3.1")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct GCC_exception_3_1;
 
@@ -935,6 +1009,7 @@ impl crate::LicenseException for GCC_exception_3_1 {
 }
 
 /// The GPL-3.0 Linking Exception.
+#[doc(alias = "GPL-3.0-linking-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct GPL_3_0_linking_exception;
 
@@ -965,6 +1040,7 @@ impl crate::LicenseException for GPL_3_0_linking_exception {
 }
 
 /// The GPL-3.0 Linking Exception (with Corresponding Source).
+#[doc(alias = "GPL-3.0-linking-source-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct GPL_3_0_linking_source_exception;
 
@@ -998,6 +1074,7 @@ impl crate::LicenseException for GPL_3_0_linking_source_exception {
 }
 
 /// The GPL Cooperation Commitment 1.0.
+#[doc(alias = "GPL-CC-1.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct GPL_CC_1_0;
 
@@ -1031,6 +1108,7 @@ impl crate::LicenseException for GPL_CC_1_0 {
 }
 
 /// The LGPL-3.0 Linking Exception.
+#[doc(alias = "LGPL-3.0-linking-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct LGPL_3_0_linking_exception;
 
@@ -1065,6 +1143,7 @@ impl crate::LicenseException for LGPL_3_0_linking_exception {
 }
 
 /// The LLVM Exception.
+#[doc(alias = "LLVM-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct LLVM_exception;
 
@@ -1095,6 +1174,7 @@ impl crate::LicenseException for LLVM_exception {
 }
 
 /// The LZMA exception.
+#[doc(alias = "LZMA-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct LZMA_exception;
 
@@ -1125,6 +1205,7 @@ impl crate::LicenseException for LZMA_exception {
 }
 
 /// The Libtool Exception.
+#[doc(alias = "Libtool-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Libtool_exception;
 
@@ -1155,6 +1236,7 @@ impl crate::LicenseException for Libtool_exception {
 }
 
 /// The Linux Syscall Note.
+#[doc(alias = "Linux-syscall-note")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Linux_syscall_note;
 
@@ -1185,6 +1267,7 @@ impl crate::LicenseException for Linux_syscall_note {
 }
 
 /// The Nokia Qt LGPL exception 1.1.
+#[doc(alias = "Nokia-Qt-exception-1.1")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Nokia_Qt_exception_1_1;
 
@@ -1215,6 +1298,7 @@ impl crate::LicenseException for Nokia_Qt_exception_1_1 {
 }
 
 /// The Open CASCADE Exception 1.0.
+#[doc(alias = "OCCT-exception-1.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct OCCT_exception_1_0;
 
@@ -1245,6 +1329,7 @@ impl crate::LicenseException for OCCT_exception_1_0 {
 }
 
 /// The OCaml LGPL Linking Exception.
+#[doc(alias = "OCaml-LGPL-linking-exception")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct OCaml_LGPL_linking_exception;
 
@@ -1275,6 +1360,7 @@ impl crate::LicenseException for OCaml_LGPL_linking_exception {
 }
 
 /// The OpenJDK Assembly exception 1.0.
+#[doc(alias = "OpenJDK-assembly-exception-1.0")]
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct OpenJDK_assembly_exception_1_0;
 
@@ -0,0 +1,278 @@
+//! A dump of every license as JSON or CSV, for tools that aren't written in Rust.
+//!
+//! # Schema
+//!
+//! Every license has these fields, in this order:
+//!
+//! | Field | Value |
+//! | --- | --- |
+//! | `schema_version` | [`SCHEMA_VERSION`] |
+//! | `id` | the SPDX id |
+//! | `name` | the full name |
+//! | `osi_approved` | `true` or `false` |
+//! | `fsf_libre` | `true` or `false` |
+//! | `deprecated` | `true` or `false` |
+//! | `family` | `gnu`, `bsd`, `creative_commons`, `mozilla`, `apache`, `public_domain` or `other`, see [`Family`] |
+//! | `copyleft` | `none`, `weak`, `strong` or `network`, see [`Copyleft`] |
+//! | `permissions` | the names of the [`Permissions`] methods that are `true` |
+//! | `conditions` | the names of the [`Conditions`] methods that are `true` |
+//! | `limitations` | the names of the [`Limitations`] methods that are `true` |
+//! | `text` | the text, only if asked for with [`Dump::with_texts`] |
+//!
+//! The licenses without extension data have no `copyleft`, `permissions`, `conditions`
+//! and `limitations`. In JSON these are `null`, and the lists are arrays. In CSV they are
+//! empty, the lists are separated by spaces, and the schema version is repeated on every
+//! row. The dump has every license of [`LICENSES`], ordered by id, followed by the
+//! licenses combined with an exception.
+//!
+//! The schema version is raised when a field changes or is removed. A field that is added
+//! at the end doesn't raise it.
+
+use crate::report::Copyleft;
+use crate::*;
+use core::fmt::{self, Display, Formatter, Write};
+
+/// The version of the schema of the dump.
+pub const SCHEMA_VERSION: u32 = 1;
+
+const FIELDS: [&str; 11] = [
+    "schema_version",
+    "id",
+    "name",
+    "osi_approved",
+    "fsf_libre",
+    "deprecated",
+    "family",
+    "copyleft",
+    "permissions",
+    "conditions",
+    "limitations",
+];
+
+/// The format of a [`Dump`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum DumpFormat {
+    /// A JSON object with the `schema_version` and the `licenses` array, one license
+    /// per line.
+    Json,
+    /// A CSV table with a header row, quoted as in RFC 4180.
+    Csv,
+}
+
+/// The dump of every license, see [`dump_all`].
+///
+/// Its [`Display`] impl renders the dump.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Dump {
+    format: DumpFormat,
+    texts: bool,
+}
+
+/// Dumps every license, see the [schema](self#schema).
+///
+/// # Examples
+/// ```
+/// use license::export::{self, DumpFormat};
+///
+/// let csv = export::dump_all(DumpFormat::Csv).to_string();
+/// let mit = csv.lines().find(|line| line.starts_with("1,MIT,")).unwrap();
+/// assert!(mit.contains(",true,true,false,other,none,"));
+///
+/// let json = export::dump_all(DumpFormat::Json).with_texts().to_string();
+/// assert!(json.starts_with("{\"schema_version\":1,\"licenses\":["));
+/// ```
+pub fn dump_all(format: DumpFormat) -> Dump {
+    Dump {
+        format,
+        texts: false,
+    }
+}
+
+impl Dump {
+    /// Adds the `text` field, which makes the dump several megabytes larger.
+    pub fn with_texts(self) -> Self {
+        Dump {
+            texts: true,
+            ..self
+        }
+    }
+
+    fn json(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{{\"schema_version\":{},\"licenses\":[", SCHEMA_VERSION)?;
+        for (i, license) in LICENSES.iter().enumerate() {
+            f.write_str(if i == 0 { "\n" } else { ",\n" })?;
+            self.json_record(f, *license)?;
+        }
+        for license in with_exception() {
+            f.write_str(",\n")?;
+            self.json_record(f, license)?;
+        }
+        f.write_str("\n]}\n")
+    }
+
+    fn json_record<L: License + ?Sized>(&self, f: &mut Formatter, license: &L) -> fmt::Result {
+        write!(
+            f,
+            "{{\"schema_version\":{},\"id\":{},\"name\":{},\"osi_approved\":{},\"fsf_libre\":{},\"deprecated\":{},\"family\":\"{}\"",
+            SCHEMA_VERSION,
+            Json(license.id()),
+            Json(license.name()),
+            license.is_osi_approved(),
+            license.is_fsf_libre(),
+            license.is_deprecated(),
+            family(license.family()),
+        )?;
+        match license.ext() {
+            Some(ext) => write!(
+                f,
+                ",\"copyleft\":\"{}\",\"permissions\":[{}],\"conditions\":[{}],\"limitations\":[{}]",
+                Copyleft::of(ext),
+                Names(ext.permissions().iter().map(Permission::name), "\"", ","),
+                Names(ext.conditions().iter().map(Condition::name), "\"", ","),
+                Names(ext.limitations().iter().map(Limitation::name), "\"", ","),
+            )?,
+            None => f.write_str(
+                ",\"copyleft\":null,\"permissions\":null,\"conditions\":null,\"limitations\":null",
+            )?,
+        }
+        if self.texts {
+            write!(f, ",\"text\":{}", Json(license.text()))?;
+        }
+        f.write_char('}')
+    }
+
+    fn csv(&self, f: &mut Formatter) -> fmt::Result {
+        for (i, field) in FIELDS.iter().enumerate() {
+            if i > 0 {
+                f.write_char(',')?;
+            }
+            f.write_str(field)?;
+        }
+        if self.texts {
+            f.write_str(",text")?;
+        }
+        f.write_str("\r\n")?;
+        for license in LICENSES {
+            self.csv_record(f, *license)?;
+        }
+        for license in with_exception() {
+            self.csv_record(f, license)?;
+        }
+        Ok(())
+    }
+
+    fn csv_record<L: License + ?Sized>(&self, f: &mut Formatter, license: &L) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},",
+            SCHEMA_VERSION,
+            Csv(license.id()),
+            Csv(license.name()),
+            license.is_osi_approved(),
+            license.is_fsf_libre(),
+            license.is_deprecated(),
+            family(license.family()),
+        )?;
+        if let Some(ext) = license.ext() {
+            write!(
+                f,
+                "{},{},{},{}",
+                Copyleft::of(ext),
+                Names(ext.permissions().iter().map(Permission::name), "", " "),
+                Names(ext.conditions().iter().map(Condition::name), "", " "),
+                Names(ext.limitations().iter().map(Limitation::name), "", " "),
+            )?;
+        } else {
+            f.write_str(",,,")?;
+        }
+        if self.texts {
+            write!(f, ",{}", Csv(license.text()))?;
+        }
+        f.write_str("\r\n")
+    }
+}
+
+impl Display for Dump {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.format {
+            DumpFormat::Json => self.json(f),
+            DumpFormat::Csv => self.csv(f),
+        }
+    }
+}
+
+/// The licenses combined with an exception, which aren't in [`LICENSES`].
+fn with_exception() -> impl Iterator<Item = &'static dyn LicenseExt> {
+    LICENSES_EXT
+        .iter()
+        .copied()
+        .filter(|l| from_id(l.id()).is_none())
+}
+
+fn family(family: Family) -> &'static str {
+    match family {
+        Family::Gnu => "gnu",
+        Family::Bsd => "bsd",
+        Family::CreativeCommons => "creative_commons",
+        Family::Mozilla => "mozilla",
+        Family::Apache => "apache",
+        Family::PublicDomain => "public_domain",
+        Family::Other(_) => "other",
+    }
+}
+
+/// Writes the names of the set flags, quoted and separated as given.
+struct Names<I>(I, &'static str, &'static str);
+
+impl<I: Iterator<Item = &'static str> + Clone> Display for Names<I> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let Names(names, quote, separator) = self;
+        for (i, name) in names.clone().enumerate() {
+            if i > 0 {
+                f.write_str(separator)?;
+            }
+            write!(f, "{}{}{}", quote, name, quote)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a JSON string.
+pub(crate) struct Json<'a>(pub(crate) &'a str);
+
+impl Display for Json<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_char('"')?;
+        for c in self.0.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '\t' => f.write_str("\\t")?,
+                c if c.is_control() => write!(f, "\\u{:04x}", u32::from(c))?,
+                c => f.write_char(c)?,
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+/// Writes a CSV field, quoted if needed.
+struct Csv<'a>(&'a str);
+
+impl Display for Csv<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if !self.0.contains(|c| matches!(c, ',' | '"' | '\n' | '\r')) {
+            return f.write_str(self.0);
+        }
+        f.write_char('"')?;
+        for c in self.0.chars() {
+            if c == '"' {
+                f.write_char('"')?;
+            }
+            f.write_char(c)?;
+        }
+        f.write_char('"')
+    }
+}
@@ -0,0 +1,415 @@
+//! License expressions parsed at runtime into a tree that can be walked and rebuilt.
+//!
+//! An [`Expression`] is parsed from any string, such as a manifest field read at
+//! runtime, with the same grammar as [`PackageLicense::parse`], and also accepts
+//! `LicenseRef-` ids. Its nodes are public, so that a tool can collect, test and rewrite
+//! the [`Leaf`] licenses without printing and parsing the expression again.
+//!
+//! Requires the `alloc` feature.
+//!
+//! # Examples
+//! ```
+//! use license::expression::{Expression, Leaf};
+//!
+//! let expression = Expression::parse("MIT OR (GPL-2.0 AND LicenseRef-Vendor)").unwrap();
+//! let upgraded = expression.map_leaves(|leaf| match leaf {
+//!     Leaf::License { id: "GPL-2.0", or_later } => Leaf::License { id: "GPL-2.0-only", or_later },
+//!     leaf => leaf,
+//! });
+//! assert_eq!(upgraded.to_string(), "MIT OR GPL-2.0-only AND LicenseRef-Vendor");
+//! assert!(upgraded.any(|leaf| leaf.is_license_ref()));
+//! ```
+
+use crate::package::{id_end, validate, Token, Tokens};
+use crate::*;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+/// A license expression, see the [module](self).
+///
+/// Chains of the same operator are one node, so `MIT AND (ISC AND Zlib)` is an `And` of
+/// three leaves. Its [`Display`] impl writes the ids as on the SPDX lists, with single
+/// spaces and only the parentheses that are needed.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Expression {
+    /// A single license.
+    Leaf(Leaf),
+    /// Licenses that all apply.
+    And(Vec<Expression>),
+    /// Licenses of which one applies.
+    Or(Vec<Expression>),
+}
+
+/// A license of an [`Expression`].
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Leaf {
+    /// A license on the SPDX License List.
+    License {
+        /// The id of the license.
+        id: &'static str,
+        /// Says if the id is followed by `+`.
+        or_later: bool,
+    },
+    /// A license on the SPDX License List with an exception.
+    WithException {
+        /// The id of the license.
+        id: &'static str,
+        /// Says if the id is followed by `+`.
+        or_later: bool,
+        /// The id of the exception.
+        exception: &'static str,
+    },
+    /// A license defined outside of the SPDX lists, such as `LicenseRef-Vendor`.
+    LicenseRef {
+        /// The `DocumentRef-` id of the document that defines the license, if any.
+        document: Option<String>,
+        /// The `LicenseRef-` id.
+        license: String,
+        /// The id of the exception, if any.
+        exception: Option<&'static str>,
+    },
+}
+
+impl Expression {
+    /// Parses an expression, with the syntax of [`PackageLicense::parse`], where
+    /// `LicenseRef-` ids, optionally prefixed with `DocumentRef-` ids and a `:`, are
+    /// also accepted.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::expression::{Expression, Leaf};
+    /// use license::ExpressionError;
+    ///
+    /// let expression = Expression::parse("GPL-2.0+ WITH Classpath-exception-2.0").unwrap();
+    /// assert_eq!(
+    ///     expression,
+    ///     Expression::Leaf(Leaf::WithException {
+    ///         id: "GPL-2.0",
+    ///         or_later: true,
+    ///         exception: "Classpath-exception-2.0",
+    ///     })
+    /// );
+    ///
+    /// let err = Expression::parse("MIT OR Apache").unwrap_err();
+    /// assert!(matches!(err, ExpressionError::UnknownLicense { position: 7, .. }));
+    /// ```
+    pub fn parse(expression: &str) -> Result<Self, ExpressionError<'_>> {
+        validate(expression.as_bytes(), true)
+            .map_err(|invalid| ExpressionError::new(expression, invalid))?;
+        Ok(or(&mut Tokens::new(expression)))
+    }
+
+    /// The leaves, from left to right.
+    pub fn leaves(&self) -> impl Iterator<Item = &Leaf> {
+        let mut stack = vec![self];
+        core::iter::from_fn(move || loop {
+            match stack.pop()? {
+                Expression::Leaf(leaf) => return Some(leaf),
+                Expression::And(parts) | Expression::Or(parts) => stack.extend(parts.iter().rev()),
+            }
+        })
+    }
+
+    /// Replaces every leaf with `f` of it, keeping the operators.
+    pub fn map_leaves<F: FnMut(Leaf) -> Leaf>(self, mut f: F) -> Self {
+        self.map_with(&mut f)
+    }
+
+    fn map_with(self, f: &mut dyn FnMut(Leaf) -> Leaf) -> Self {
+        match self {
+            Expression::Leaf(leaf) => Expression::Leaf(f(leaf)),
+            Expression::And(parts) => {
+                Expression::And(parts.into_iter().map(|part| part.map_with(f)).collect())
+            }
+            Expression::Or(parts) => {
+                Expression::Or(parts.into_iter().map(|part| part.map_with(f)).collect())
+            }
+        }
+    }
+
+    /// Says if `f` is true for any leaf.
+    pub fn any<F: FnMut(&Leaf) -> bool>(&self, f: F) -> bool {
+        self.leaves().any(f)
+    }
+
+    /// Says if `f` is true for every leaf.
+    pub fn all<F: FnMut(&Leaf) -> bool>(&self, f: F) -> bool {
+        self.leaves().all(f)
+    }
+
+    /// Writes the expression in a style, see [`ExprStyle`].
+    ///
+    /// The [`Display`] impl writes the default style. The written expression parses
+    /// back to the same expression, once [`normalize_legacy`] fixed the casing of
+    /// lowercase operators.
+    pub fn to_string_styled(&self, style: ExprStyle) -> String {
+        Styled(self, style).to_string()
+    }
+}
+
+impl From<PackageLicense> for Expression {
+    fn from(license: PackageLicense) -> Self {
+        or(&mut Tokens::new(license.expression()))
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Styled(self, ExprStyle::new()).fmt(f)
+    }
+}
+
+/// How [`Expression::to_string_styled`] writes an expression.
+///
+/// The default style is the one of the [`Display`] impl of [`Expression`]: uppercase
+/// operators and only the parentheses that are needed, as SPDX writes expressions.
+///
+/// # Examples
+/// ```
+/// use license::expression::{ExprStyle, Expression};
+///
+/// let expression = Expression::parse("MIT AND ISC OR Apache-2.0 WITH LLVM-exception").unwrap();
+/// let style = ExprStyle::new().full_parentheses();
+/// assert_eq!(
+///     expression.to_string_styled(style),
+///     "(MIT AND ISC) OR Apache-2.0 WITH LLVM-exception"
+/// );
+/// assert_eq!(
+///     expression.to_string_styled(style.lowercase_operators()),
+///     "(MIT and ISC) or Apache-2.0 with LLVM-exception"
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
+pub struct ExprStyle {
+    full_parentheses: bool,
+    lowercase_operators: bool,
+}
+
+impl ExprStyle {
+    /// The default style.
+    pub const fn new() -> Self {
+        ExprStyle {
+            full_parentheses: false,
+            lowercase_operators: false,
+        }
+    }
+
+    /// Writes parentheses around every `AND` and `OR` that is part of another one, for
+    /// the linters that don't rely on the precedence of `AND` over `OR`.
+    pub const fn full_parentheses(self) -> Self {
+        ExprStyle {
+            full_parentheses: true,
+            ..self
+        }
+    }
+
+    /// Writes the operators as `and`, `or` and `with`, which SPDX also accepts.
+    pub const fn lowercase_operators(self) -> Self {
+        ExprStyle {
+            lowercase_operators: true,
+            ..self
+        }
+    }
+
+    const fn operator(self, upper: &'static str, lower: &'static str) -> &'static str {
+        if self.lowercase_operators {
+            lower
+        } else {
+            upper
+        }
+    }
+}
+
+/// An expression written in a style, see [`ExprStyle`].
+struct Styled<'a>(&'a Expression, ExprStyle);
+
+impl Display for Styled<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let Styled(expression, style) = *self;
+        let (parts, operator) = match expression {
+            Expression::Leaf(leaf) => return StyledLeaf(leaf, style).fmt(f),
+            Expression::And(parts) => (parts, style.operator(" AND ", " and ")),
+            Expression::Or(parts) => (parts, style.operator(" OR ", " or ")),
+        };
+        for (i, part) in parts.iter().enumerate() {
+            if i > 0 {
+                f.write_str(operator)?;
+            }
+            let parenthesized = match (expression, part) {
+                (_, Expression::Leaf(_)) => false,
+                (Expression::And(_), Expression::Or(_)) => true,
+                _ => style.full_parentheses,
+            };
+            if parenthesized {
+                write!(f, "({})", Styled(part, style))?;
+            } else {
+                Styled(part, style).fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Leaf {
+    /// The license, or `None` for a `LicenseRef-` id.
+    pub fn license(&self) -> Option<&'static dyn License> {
+        match self {
+            Leaf::License { id, .. } | Leaf::WithException { id, .. } => from_id(id),
+            Leaf::LicenseRef { .. } => None,
+        }
+    }
+
+    /// The exception, if any.
+    pub fn exception(&self) -> Option<&'static dyn LicenseException> {
+        match self {
+            Leaf::WithException { exception, .. }
+            | Leaf::LicenseRef {
+                exception: Some(exception),
+                ..
+            } => from_id_exception(exception),
+            _ => None,
+        }
+    }
+
+    /// Says if it is a `LicenseRef-` id.
+    pub fn is_license_ref(&self) -> bool {
+        matches!(self, Leaf::LicenseRef { .. })
+    }
+}
+
+impl Display for Leaf {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        StyledLeaf(self, ExprStyle::new()).fmt(f)
+    }
+}
+
+/// A leaf written in a style, see [`ExprStyle`].
+struct StyledLeaf<'a>(&'a Leaf, ExprStyle);
+
+impl Display for StyledLeaf<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let StyledLeaf(leaf, style) = *self;
+        let with = style.operator(" WITH ", " with ");
+        match leaf {
+            Leaf::License { id, or_later } => {
+                write!(f, "{}{}", id, if *or_later { "+" } else { "" })
+            }
+            Leaf::WithException {
+                id,
+                or_later,
+                exception,
+            } => write!(
+                f,
+                "{}{}{}{}",
+                id,
+                if *or_later { "+" } else { "" },
+                with,
+                exception
+            ),
+            Leaf::LicenseRef {
+                document,
+                license,
+                exception,
+            } => {
+                if let Some(document) = document {
+                    write!(f, "{}:", document)?;
+                }
+                f.write_str(license)?;
+                match exception {
+                    Some(exception) => write!(f, "{}{}", with, exception),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+// The builders below read an expression that is already validated.
+
+fn or(tokens: &mut Tokens) -> Expression {
+    let mut parts = vec![and(tokens)];
+    while let Some(Token::Or) = tokens.peek() {
+        tokens.next();
+        parts.push(and(tokens));
+    }
+    join(parts, false)
+}
+
+fn and(tokens: &mut Tokens) -> Expression {
+    let mut parts = vec![term(tokens)];
+    while let Some(Token::And) = tokens.peek() {
+        tokens.next();
+        parts.push(term(tokens));
+    }
+    join(parts, true)
+}
+
+fn term(tokens: &mut Tokens) -> Expression {
+    let written = match tokens
+        .next()
+        .expect("a validated expression has no empty terms")
+    {
+        (Token::Open, _) => {
+            let expression = or(tokens);
+            tokens.next();
+            return expression;
+        }
+        (_, written) => written,
+    };
+    let id = &written[..id_end(written.as_bytes(), written.len())];
+    let or_later = id.len() < written.len();
+    let exception = match tokens.peek() {
+        Some(Token::With) => {
+            tokens.next();
+            tokens.next().map(|(_, exception)| exception)
+        }
+        _ => None,
+    };
+    let exception = exception.and_then(|e| known(EXCEPTION_IDS, e));
+    match (known(LICENSE_IDS, id), exception) {
+        (Some(id), Some(exception)) => Expression::Leaf(Leaf::WithException {
+            id,
+            or_later,
+            exception,
+        }),
+        (Some(id), None) => Expression::Leaf(Leaf::License { id, or_later }),
+        (None, _) => Expression::Leaf(match id.split_once(':') {
+            Some((document, license)) => Leaf::LicenseRef {
+                document: Some(document.into()),
+                license: license.into(),
+                exception,
+            },
+            None => Leaf::LicenseRef {
+                document: None,
+                license: id.into(),
+                exception,
+            },
+        }),
+    }
+}
+
+fn known(ids: &[&'static str], id: &str) -> Option<&'static str> {
+    ids.iter().copied().find(|known| *known == id)
+}
+
+/// Joins the parts of an `AND` or an `OR`, merging the parts that are the same
+/// operator.
+fn join(parts: Vec<Expression>, and: bool) -> Expression {
+    let mut joined = Vec::with_capacity(parts.len());
+    for part in parts {
+        match part {
+            Expression::And(inner) if and => joined.extend(inner),
+            Expression::Or(inner) if !and => joined.extend(inner),
+            part => joined.push(part),
+        }
+    }
+    match (joined.len(), and) {
+        (1, _) => joined.remove(0),
+        (_, true) => Expression::And(joined),
+        (_, false) => Expression::Or(joined),
+    }
+}
@@ -1,10 +1,116 @@
 #![allow(clippy::needless_update)]
 
 use crate::*;
+use core::cmp::Ordering;
 use core::fmt::{self, Display, Formatter};
 
+/// The name, the label and the description of a flag, see [`Permissions::FLAGS`].
+///
+/// # Examples
+/// ```
+/// use license::{Condition, Conditions};
+///
+/// let mit = license::from_id_ext("MIT").unwrap().conditions();
+/// let checked: Vec<_> = Conditions::FLAGS
+///     .iter()
+///     .zip(Condition::ALL.iter())
+///     .map(|(info, &flag)| (info.label, mit.contains(flag)))
+///     .collect();
+/// assert_eq!(checked[2], ("License and copyright notice", true));
+/// assert_eq!(checked.len(), Conditions::FLAGS.len());
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct FlagInfo {
+    /// The name of the getter of the flag, in snake case, such as `"commercial_use"`.
+    pub name: &'static str,
+    /// The short label of the flag, for a table heading or a badge.
+    pub label: &'static str,
+    /// The sentence describing the flag, as the [`Display`] impl of the flags renders it.
+    pub description: &'static str,
+}
+
+macro_rules! impl_flag {
+    (
+        $(#[$attr:meta])*
+        pub enum $flag:ident in $flags:ident {
+            $($variant:ident => $name:ident, $label:literal, $description:literal;)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+        pub enum $flag {
+            $(#[doc = $description]
+            $variant,)*
+        }
+
+        impl $flag {
+            const COUNT: usize = [$(stringify!($variant)),*].len();
+
+            /// Every flag, in bit order.
+            pub const ALL: [$flag; Self::COUNT] = [$($flag::$variant),*];
+
+            /// The name of the getter of the flag, in snake case.
+            pub const fn name(self) -> &'static str {
+                $flags::FLAGS[self as usize].name
+            }
+
+            /// The short label of the flag, for a table heading or a badge.
+            pub const fn label(self) -> &'static str {
+                $flags::FLAGS[self as usize].label
+            }
+
+            /// The sentence describing the flag, as the [`Display`] impl of the flags renders it.
+            pub const fn description(self) -> &'static str {
+                $flags::FLAGS[self as usize].description
+            }
+
+            #[doc = concat!("The bit of the flag, see [`", stringify!($flags), "::bits`].")]
+            pub const fn bit(self) -> u32 {
+                1 << self as u32
+            }
+        }
+
+        impl $flags {
+            /// The name, the label and the description of every flag, in bit order.
+            ///
+            #[doc = concat!(
+                "A new flag takes the next bit, so it is only ever appended, and the index of \
+                 a flag is the same as in [`", stringify!($flag), "::ALL`]."
+            )]
+            pub const FLAGS: &'static [FlagInfo] = &[$(FlagInfo {
+                name: stringify!($name),
+                label: $label,
+                description: $description,
+            }),*];
+
+            /// Says if the flag is set.
+            pub const fn contains(self, flag: $flag) -> bool {
+                self.bits() & flag.bit() != 0
+            }
+
+            /// The flags that are set, in bit order.
+            pub fn iter(self) -> impl Iterator<Item = $flag> + Clone {
+                $flag::ALL
+                    .iter()
+                    .copied()
+                    .filter(move |&flag| self.contains(flag))
+            }
+
+            /// The sentences describing the flags that are set, in bit order.
+            pub(crate) fn sentences(self) -> impl Iterator<Item = &'static str> + Clone {
+                self.iter().map($flag::description)
+            }
+        }
+    };
+}
+
 /// Returns an extension license based on the provided id.
 ///
+/// The lookup goes through [`from_id`] and [`License::ext`], so both functions agree on
+/// every license. Ids of license and exception combinations, which [`from_id`] doesn't
+/// know, are resolved on their own.
+///
 /// # Examples
 /// ```
 /// let mit = license::from_id_ext("MIT").unwrap();
@@ -12,27 +118,241 @@ use core::fmt::{self, Display, Formatter};
 /// assert!(perm.private_use() && perm.commercial_use());
 /// ```
 pub fn from_id_ext(id: &str) -> Option<&'static dyn LicenseExt> {
-    match id {
-        "AFL-3.0" => Some(&AFL_3_0),
-        "AGPL-3.0-only" => Some(&AGPL_3_0_only),
-        "Apache-2.0" => Some(&Apache_2_0),
-        "0BSD" => Some(&BSD_0),
-        "BSD-2-Clause" => Some(&BSD_2_Clause),
-        "BSD-3-Clause" => Some(&BSD_3_Clause),
-        "BSD-3-Clause-Clear" => Some(&BSD_3_Clause_Clear),
-        "BSL-1.0" => Some(&BSL_1_0),
-        "CC0-1.0" => Some(&CC0_1_0),
-        "ECL-2.0" => Some(&ECL_2_0),
-        "GPL-3.0-only" => Some(&GPL_3_0_only),
-        "LGPL-3.0-only" => Some(&LGPL_3_0_only),
-        "MIT" => Some(&MIT),
-        "MPL-2.0" => Some(&MPL_2_0),
-        "MS-PL" => Some(&MS_PL),
-        "OSL-3.0" => Some(&OSL_3_0),
-        "Unlicense" => Some(&Unlicense),
-        "WTFPL" => Some(&WTFPL),
-        "Zlib" => Some(&Zlib),
-        _ => None,
+    from_id(id)
+        .and_then(|l| l.ext())
+        .or_else(|| from_id_with(id))
+}
+
+/// Returns an extension license based on its [`code`](LicenseExt::code).
+///
+/// # Examples
+/// ```
+/// let mit = license::from_id_ext("MIT").unwrap();
+/// assert_eq!(license::from_code(mit.code()).unwrap().id(), "MIT");
+/// assert!(license::from_code(0).is_none());
+/// ```
+pub fn from_code(code: u16) -> Option<&'static dyn LicenseExt> {
+    if code == 0 {
+        return None;
+    }
+    LICENSES_EXT.iter().copied().find(|l| l.code() == code)
+}
+
+/// Returns an extension license based on its [`slug`](LicenseExt::slug).
+///
+/// The [sentinels](UNKNOWN) are resolved too. Slugs are lowercase, so the lookup is
+/// exact and an id, such as `MIT`, isn't resolved.
+///
+/// # Examples
+/// ```
+/// let gpl = license::from_slug("gpl-2.0-only-with-classpath-exception-2.0").unwrap();
+/// assert_eq!(gpl.id(), "GPL-2.0-only WITH Classpath-exception-2.0");
+/// assert_eq!(license::from_slug("noassertion").unwrap().id(), "NOASSERTION");
+/// assert!(license::from_slug("MIT").is_none());
+/// assert!(license::from_slug("").is_none());
+/// ```
+pub fn from_slug(slug: &str) -> Option<&'static dyn LicenseExt> {
+    if slug.is_empty() {
+        return None;
+    }
+    LICENSES_EXT
+        .iter()
+        .chain(&[UNKNOWN, NONE])
+        .copied()
+        .find(|l| l.slug() == slug)
+}
+
+/// Resolves the ids and yields the licenses that impose obligations when the software is only
+/// offered as a network service, see [`LicenseExt::triggers_on_network_use`].
+///
+/// Ids that can't be resolved by [`from_id_ext`] are yielded as errors, since a license
+/// that could not be checked is a risk in itself.
+///
+/// # Examples
+/// ```
+/// let ids = ["MIT", "AGPL-3.0-only", "Apache-2.0", "Custom-1.0", "OSL-3.0"];
+/// let risky: Vec<_> = license::saas_risky(&ids)
+///     .map(|l| l.map(|l| l.id()))
+///     .collect();
+/// assert_eq!(risky, [Ok("AGPL-3.0-only"), Err("Custom-1.0"), Ok("OSL-3.0")]);
+/// ```
+pub fn saas_risky<'a>(
+    ids: &'a [&'a str],
+) -> impl Iterator<Item = Result<&'static dyn LicenseExt, &'a str>> + 'a {
+    ids.iter()
+        .map(|&id| from_id_ext(id).ok_or(id))
+        .filter(|license| license.map_or(true, |l| l.triggers_on_network_use()))
+}
+
+/// How [`find_matching`] compares the flags of a license with the requested ones.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum MatchMode {
+    /// The permissions, conditions and limitations must all be equal.
+    Exact,
+    /// The license must grant at least the permissions, and impose
+    /// at most the conditions and limitations.
+    AtLeast,
+}
+
+/// Returns the licenses whose flags match the provided ones, in the order of [`LICENSES_EXT`].
+///
+/// # Examples
+/// ```
+/// use license::MatchMode;
+///
+/// let mit = license::from_id_ext("MIT").unwrap();
+/// let (p, c, l) = (mit.permissions(), mit.conditions(), mit.limitations());
+///
+/// let exact: Vec<_> = license::find_matching(p, c, l, MatchMode::Exact)
+///     .map(|l| l.id())
+///     .collect();
+/// assert_eq!(
+///     exact,
+///     ["BSD-2-Clause", "BSD-3-Clause", "BSD-4-Clause", "BSL-1.0", "MIT", "MIT-advertising", "MirOS"]
+/// );
+///
+/// let at_least: Vec<_> = license::find_matching(p, c, l, MatchMode::AtLeast)
+///     .map(|l| l.id())
+///     .collect();
+/// assert_eq!(
+///     at_least,
+///     [
+///         "0BSD",
+///         "BSD-2-Clause",
+///         "BSD-3-Clause",
+///         "BSD-4-Clause",
+///         "BSL-1.0",
+///         "MIT",
+///         "MIT-advertising",
+///         "MirOS",
+///         "NTP",
+///         "Unlicense",
+///         "WTFPL"
+///     ]
+/// );
+/// ```
+pub fn find_matching(
+    permissions: Permissions,
+    conditions: Conditions,
+    limitations: Limitations,
+    mode: MatchMode,
+) -> impl Iterator<Item = &'static dyn LicenseExt> {
+    LICENSES_EXT
+        .iter()
+        .copied()
+        .filter(move |license| match mode {
+            MatchMode::Exact => {
+                license.permissions() == permissions
+                    && license.conditions() == conditions
+                    && license.limitations() == limitations
+            }
+            MatchMode::AtLeast => {
+                permissions.is_subset(license.permissions())
+                    && license.conditions().is_subset(conditions)
+                    && license.limitations().is_subset(limitations)
+            }
+        })
+}
+
+const GPL_2_0: &[&str] = &["GPL-2.0-only", "GPL-2.0-or-later"];
+const GPL_3_0: &[&str] = &["GPL-3.0-only", "GPL-3.0-or-later"];
+const GPL_2_0_AND_3_0: &[&str] = &[
+    "GPL-2.0-only",
+    "GPL-2.0-or-later",
+    "GPL-3.0-only",
+    "GPL-3.0-or-later",
+];
+
+/// The licenses an exception is meant to be combined with, see [`LicenseException::applicable_to`].
+pub(crate) fn applicable_to(exception_id: &str) -> &'static [&'static str] {
+    match exception_id {
+        "Autoconf-exception-2.0" => GPL_2_0,
+        "Autoconf-exception-3.0" => GPL_3_0,
+        "Bison-exception-2.2" => GPL_2_0_AND_3_0,
+        "Classpath-exception-2.0" => GPL_2_0_AND_3_0,
+        "Font-exception-2.0" => GPL_2_0_AND_3_0,
+        "GCC-exception-2.0" => GPL_2_0,
+        "GCC-exception-3.1" => GPL_3_0,
+        "GPL-3.0-linking-exception" => GPL_3_0,
+        "GPL-3.0-linking-source-exception" => GPL_3_0,
+        "LGPL-3.0-linking-exception" => &["LGPL-3.0-only", "LGPL-3.0-or-later"],
+        "LLVM-exception" => &["Apache-2.0"],
+        "Libtool-exception" => GPL_2_0,
+        "Linux-syscall-note" => &["GPL-2.0-only"],
+        "Nokia-Qt-exception-1.1" => &["LGPL-2.1-only"],
+        "OpenJDK-assembly-exception-1.0" => &["GPL-2.0-only"],
+        "Qt-GPL-exception-1.0" => &["GPL-3.0-only"],
+        "Qt-LGPL-exception-1.1" => &["LGPL-2.1-only", "LGPL-3.0-only"],
+        "SHL-2.0" => &["Apache-2.0"],
+        "SHL-2.1" => &["Apache-2.0"],
+        "Swift-exception" => &["Apache-2.0"],
+        "eCos-exception-2.0" => &["GPL-2.0-or-later"],
+        "u-boot-exception-2.0" => GPL_2_0,
+        _ => &[],
+    }
+}
+
+/// The data of an extension license, see [`LicenseExt::metadata`].
+///
+/// The fields are private so that new ones can be added with a default without
+/// breaking code that creates metadata with [`new`](Metadata::new).
+///
+/// # Examples
+/// ```
+/// use license::{Conditions, Limitations, LicenseExt, Metadata, Permissions};
+///
+/// let mit = license::from_id_ext("MIT").unwrap();
+/// let metadata = Metadata::new(
+///     Permissions::from_bits(0b10111).unwrap(),
+///     Conditions::from_bits(0b00100).unwrap(),
+///     Limitations::from_bits(0b0101).unwrap(),
+/// );
+/// assert_eq!(mit.metadata(), metadata);
+/// assert_eq!(mit.permissions(), metadata.permissions());
+/// ```
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
+pub struct Metadata {
+    permissions: Permissions,
+    conditions: Conditions,
+    limitations: Limitations,
+}
+
+impl Metadata {
+    /// Creates the metadata of a license.
+    pub const fn new(
+        permissions: Permissions,
+        conditions: Conditions,
+        limitations: Limitations,
+    ) -> Self {
+        Metadata {
+            permissions,
+            conditions,
+            limitations,
+        }
+    }
+
+    /// The permissions of the license.
+    pub const fn permissions(self) -> Permissions {
+        self.permissions
+    }
+
+    /// The conditions of the license.
+    pub const fn conditions(self) -> Conditions {
+        self.conditions
+    }
+
+    /// The limitations of the license.
+    pub const fn limitations(self) -> Limitations {
+        self.limitations
+    }
+
+    /// Says if the flag is set.
+    pub const fn contains(self, flag: Flag) -> bool {
+        match flag {
+            Flag::Permission(flag) => self.permissions.contains(flag),
+            Flag::Condition(flag) => self.conditions.contains(flag),
+            Flag::Limitation(flag) => self.limitations.contains(flag),
+        }
     }
 }
 
@@ -44,7 +364,26 @@ pub fn from_id_ext(id: &str) -> Option<&'static dyn LicenseExt> {
 /// let perm = mit.permissions();
 /// assert!(perm.private_use() && perm.commercial_use());
 /// ```
-#[derive(Copy, Clone, Debug, Default, Hash, Ord, PartialOrd, Eq, PartialEq)]
+///
+/// # Ordering and compatibility
+///
+/// Each flag is assigned a fixed bit, exposed through [`bits`](Permissions::bits):
+///
+/// | Bit | Flag |
+/// |-----|------|
+/// | `1` | [`commercial_use`](Permissions::commercial_use) |
+/// | `2` | [`distribution`](Permissions::distribution) |
+/// | `4` | [`modification`](Permissions::modification) |
+/// | `8` | [`patent_rights`](Permissions::patent_rights) |
+/// | `16` | [`private_use`](Permissions::private_use) |
+///
+/// Values are ordered flag by flag from the top of the table, where a flag that isn't
+/// set sorts first, as if the struct derived [`Ord`]. Flags added in later releases get
+/// the next unused bit and sort after the others, so the bits and the relative order
+/// of existing values never change.
+/// The fields are private, so adding a flag is not a breaking change and the struct
+/// is deliberately not `#[non_exhaustive]`.
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
 pub struct Permissions {
     commercial_use: bool,
     distribution: bool,
@@ -78,26 +417,163 @@ impl Permissions {
     pub const fn private_use(self) -> bool {
         self.private_use
     }
+
+    /// The bit representation of the flags.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::Permissions;
+    ///
+    /// let mit = license::from_id_ext("MIT").unwrap().permissions();
+    /// assert_eq!(mit.bits(), 0b10111);
+    /// assert_eq!(Permissions::from_bits(mit.bits()), Some(mit));
+    ///
+    /// let patent_rights = Permissions::from_bits(0b01000).unwrap();
+    /// assert!(Permissions::default() < patent_rights && patent_rights < mit);
+    /// ```
+    pub const fn bits(self) -> u32 {
+        self.commercial_use as u32
+            | (self.distribution as u32) << 1
+            | (self.modification as u32) << 2
+            | (self.patent_rights as u32) << 3
+            | (self.private_use as u32) << 4
+    }
+
+    /// Creates the flags from their bit representation.
+    ///
+    /// Returns `None` if any unknown bit is set.
+    pub const fn from_bits(bits: u32) -> Option<Self> {
+        if bits & !Self::MASK != 0 {
+            return None;
+        }
+        Some(Self::from_bits_truncate(bits))
+    }
+
+    /// Creates the flags from their bit representation, ignoring unknown bits.
+    pub const fn from_bits_truncate(bits: u32) -> Self {
+        Permissions {
+            commercial_use: bits & 1 != 0,
+            distribution: bits & 2 != 0,
+            modification: bits & 4 != 0,
+            patent_rights: bits & 8 != 0,
+            private_use: bits & 16 != 0,
+        }
+    }
+
+    const MASK: u32 = 0b11111;
+
+    /// The flags that are set in either `self` or `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// let a = license::from_id_ext("MIT").unwrap().permissions();
+    /// let b = license::from_id_ext("Apache-2.0").unwrap().permissions();
+    /// let union = a.union(b);
+    /// assert!(a.is_subset(union) && b.is_subset(union));
+    /// assert_eq!(a.intersection(b), a);
+    /// assert!(b.difference(a).patent_rights());
+    /// assert_eq!(a.difference(b), Default::default());
+    /// ```
+    pub const fn union(self, other: Self) -> Self {
+        Self::from_bits_truncate(self.bits() | other.bits())
+    }
+
+    /// The flags that are set in both `self` and `other`.
+    pub const fn intersection(self, other: Self) -> Self {
+        Self::from_bits_truncate(self.bits() & other.bits())
+    }
+
+    /// The flags that are set in `self` but not in `other`.
+    pub const fn difference(self, other: Self) -> Self {
+        Self::from_bits_truncate(self.bits() & !other.bits())
+    }
+
+    /// Says if every flag that is set in `self` is also set in `other`.
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.bits() & !other.bits() == 0
+    }
+
+    /// No flags set, the same as [`Default::default`].
+    ///
+    /// A license of which nothing is known has no flags at all, the lookups such as
+    /// [`from_id_ext`] return `None` for it, so this only ever means that the license
+    /// legitimately sets none of these flags. Empty flags are rendered as an explicit
+    /// sentence rather than as nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::{Conditions, Permissions};
+    ///
+    /// let wtfpl = license::from_id_ext("WTFPL").unwrap();
+    /// assert!(wtfpl.conditions().is_empty());
+    /// assert_eq!(wtfpl.conditions(), Conditions::NONE);
+    /// assert_eq!(wtfpl.conditions().to_string(), "- Has no conditions.\n");
+    /// assert_eq!(Permissions::NONE.to_string(), "- Grants no permissions.\n");
+    ///
+    /// // Nothing is known about AAL, which is not the same as having no conditions.
+    /// assert!(license::from_id_ext("AAL").is_none());
+    /// ```
+    pub const NONE: Self = Self::from_bits_truncate(0);
+
+    /// Says if no flag is set.
+    pub const fn is_empty(self) -> bool {
+        self.bits() == 0
+    }
+}
+
+impl PartialOrd for Permissions {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Permissions {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The lowest bit is the first flag, which decides first.
+        self.bits().reverse_bits().cmp(&other.bits().reverse_bits())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Permissions {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_bits_truncate(u.arbitrary()?))
+    }
+}
+
+impl Permissions {
+    /// The sentence of a list without flags, so that it is never silently blank.
+    pub(crate) const EMPTY: &'static str = "Grants no permissions.";
+
+    /// The note of a summary that leaves the empty list out.
+    pub(crate) const NOTE: &'static str = "No permissions.";
+}
+
+impl_flag! {
+    /// A flag of the [`Permissions`].
+    ///
+    /// # Examples
+    /// ```
+    /// use license::Permission;
+    ///
+    /// let apache = license::from_id_ext("Apache-2.0").unwrap().permissions();
+    /// assert!(apache.contains(Permission::PatentRights));
+    /// assert_eq!(Permission::PatentRights.name(), "patent_rights");
+    /// assert_eq!(Permission::PatentRights.label(), "Patent rights");
+    /// assert_eq!(apache.iter().count(), 5);
+    /// ```
+    pub enum Permission in Permissions {
+        CommercialUse => commercial_use, "Commercial use", "May be used for commercial purposes.";
+        Distribution => distribution, "Distribution", "May be distributed.";
+        Modification => modification, "Modification", "May be modified.";
+        PatentRights => patent_rights, "Patent rights", "Provides an express grant of patent rights from contributors.";
+        PrivateUse => private_use, "Private use", "May be used for private purposes.";
+    }
 }
 
 impl Display for Permissions {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        if self.commercial_use {
-            f.write_str("- May be used for commercial purposes.\n")?;
-        }
-        if self.distribution {
-            f.write_str("- May be distributed.\n")?;
-        }
-        if self.modification {
-            f.write_str("- May be modified.\n")?;
-        }
-        if self.patent_rights {
-            f.write_str("- Provides an express grant of patent rights from contributors.\n")?;
-        }
-        if self.private_use {
-            f.write_str("- May be used for private purposes.\n")?;
-        }
-        Ok(())
+        self.list(ListStyle::default()).fmt(f)
     }
 }
 
@@ -109,13 +585,36 @@ impl Display for Permissions {
 /// let cond = mit.conditions();
 /// assert!(cond.license_and_copyright_notice());
 /// ```
-#[derive(Copy, Clone, Debug, Default, Hash, Ord, PartialOrd, Eq, PartialEq)]
+///
+/// # Ordering and compatibility
+///
+/// Each flag is assigned a fixed bit, exposed through [`bits`](Conditions::bits):
+///
+/// | Bit | Flag |
+/// |-----|------|
+/// | `1` | [`disclose_sources`](Conditions::disclose_sources) |
+/// | `2` | [`document_changes`](Conditions::document_changes) |
+/// | `4` | [`license_and_copyright_notice`](Conditions::license_and_copyright_notice) |
+/// | `8` | [`network_use_is_distribution`](Conditions::network_use_is_distribution) |
+/// | `16` | [`same_license`](Conditions::same_license) |
+/// | `32` | [`notice_file`](Conditions::notice_file) |
+/// | `64` | [`mark_modifications`](Conditions::mark_modifications) |
+///
+/// Values are ordered flag by flag from the top of the table, where a flag that isn't
+/// set sorts first, as if the struct derived [`Ord`]. Flags added in later releases get
+/// the next unused bit and sort after the others, so the bits and the relative order
+/// of existing values never change.
+/// The fields are private, so adding a flag is not a breaking change and the struct
+/// is deliberately not `#[non_exhaustive]`.
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
 pub struct Conditions {
     disclose_sources: bool,
     document_changes: bool,
     license_and_copyright_notice: bool,
     network_use_is_distribution: bool,
     same_license: bool,
+    notice_file: bool,
+    mark_modifications: bool,
 }
 
 impl Conditions {
@@ -125,6 +624,10 @@ impl Conditions {
     }
 
     /// Changes made to the code must be documented.
+    ///
+    /// This is the GPL-style requirement to state the changes and their dates.
+    /// Licenses that only require altered versions to be marked as such set
+    /// [`mark_modifications`](Conditions::mark_modifications) instead.
     pub const fn document_changes(self) -> bool {
         self.document_changes
     }
@@ -144,30 +647,168 @@ impl Conditions {
     pub const fn same_license(self) -> bool {
         self.same_license
     }
+
+    /// The attribution notices in a NOTICE file must be included with the software.
+    ///
+    /// This is in addition to the [license and copyright notice](Conditions::license_and_copyright_notice).
+    ///
+    /// # Examples
+    /// ```
+    /// let apache = license::from_id_ext("Apache-2.0").unwrap();
+    /// let mit = license::from_id_ext("MIT").unwrap();
+    /// assert!(apache.conditions().notice_file());
+    /// assert!(!mit.conditions().notice_file());
+    /// ```
+    pub const fn notice_file(self) -> bool {
+        self.notice_file
+    }
+
+    /// Altered source versions must be plainly marked as such.
+    ///
+    /// # Examples
+    /// ```
+    /// let zlib = license::from_id_ext("Zlib").unwrap();
+    /// let gpl = license::from_id_ext("GPL-3.0-only").unwrap();
+    /// assert!(zlib.conditions().mark_modifications());
+    /// assert!(!zlib.conditions().document_changes());
+    /// assert!(gpl.conditions().document_changes());
+    /// ```
+    pub const fn mark_modifications(self) -> bool {
+        self.mark_modifications
+    }
+
+    /// The bit representation of the flags.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::Conditions;
+    ///
+    /// let mit = license::from_id_ext("MIT").unwrap().conditions();
+    /// let gpl = license::from_id_ext("GPL-3.0-only").unwrap().conditions();
+    /// assert_eq!(mit.bits(), 0b00100);
+    /// assert_eq!(gpl.bits(), 0b10111);
+    /// assert_eq!(Conditions::from_bits(gpl.bits()), Some(gpl));
+    /// assert!(Conditions::default() < mit && mit < gpl);
+    /// ```
+    pub const fn bits(self) -> u32 {
+        self.disclose_sources as u32
+            | (self.document_changes as u32) << 1
+            | (self.license_and_copyright_notice as u32) << 2
+            | (self.network_use_is_distribution as u32) << 3
+            | (self.same_license as u32) << 4
+            | (self.notice_file as u32) << 5
+            | (self.mark_modifications as u32) << 6
+    }
+
+    /// Creates the flags from their bit representation.
+    ///
+    /// Returns `None` if any unknown bit is set.
+    pub const fn from_bits(bits: u32) -> Option<Self> {
+        if bits & !Self::MASK != 0 {
+            return None;
+        }
+        Some(Self::from_bits_truncate(bits))
+    }
+
+    /// Creates the flags from their bit representation, ignoring unknown bits.
+    pub const fn from_bits_truncate(bits: u32) -> Self {
+        Conditions {
+            disclose_sources: bits & 1 != 0,
+            document_changes: bits & 2 != 0,
+            license_and_copyright_notice: bits & 4 != 0,
+            network_use_is_distribution: bits & 8 != 0,
+            same_license: bits & 16 != 0,
+            notice_file: bits & 32 != 0,
+            mark_modifications: bits & 64 != 0,
+        }
+    }
+
+    const MASK: u32 = 0b111_1111;
+
+    /// The flags that are set in either `self` or `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// let a = license::from_id_ext("MIT").unwrap().conditions();
+    /// let b = license::from_id_ext("GPL-3.0-only").unwrap().conditions();
+    /// let union = a.union(b);
+    /// assert!(a.is_subset(union) && b.is_subset(union));
+    /// assert_eq!(a.intersection(b), a);
+    /// assert!(b.difference(a).same_license());
+    /// assert_eq!(a.difference(b), Default::default());
+    /// ```
+    pub const fn union(self, other: Self) -> Self {
+        Self::from_bits_truncate(self.bits() | other.bits())
+    }
+
+    /// The flags that are set in both `self` and `other`.
+    pub const fn intersection(self, other: Self) -> Self {
+        Self::from_bits_truncate(self.bits() & other.bits())
+    }
+
+    /// The flags that are set in `self` but not in `other`.
+    pub const fn difference(self, other: Self) -> Self {
+        Self::from_bits_truncate(self.bits() & !other.bits())
+    }
+
+    /// Says if every flag that is set in `self` is also set in `other`.
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.bits() & !other.bits() == 0
+    }
+
+    /// No flags set, see [`Permissions::NONE`].
+    pub const NONE: Self = Self::from_bits_truncate(0);
+
+    /// Says if no flag is set.
+    pub const fn is_empty(self) -> bool {
+        self.bits() == 0
+    }
+}
+
+impl PartialOrd for Conditions {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Conditions {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The lowest bit is the first flag, which decides first.
+        self.bits().reverse_bits().cmp(&other.bits().reverse_bits())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Conditions {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_bits_truncate(u.arbitrary()?))
+    }
+}
+
+impl Conditions {
+    /// The sentence of a list without flags, so that it is never silently blank.
+    pub(crate) const EMPTY: &'static str = "Has no conditions.";
+
+    /// The note of a summary that leaves the empty list out.
+    pub(crate) const NOTE: &'static str = "No conditions.";
+}
+
+impl_flag! {
+    /// A flag of the [`Conditions`].
+    pub enum Condition in Conditions {
+        DiscloseSources => disclose_sources, "Disclose sources", "Source code must be made available when the software is distributed.";
+        DocumentChanges => document_changes, "Document changes", "Changes made to the code must be documented.";
+        LicenseAndCopyrightNotice => license_and_copyright_notice, "License and copyright notice", "The license and copyright notice must be included with the software.";
+        NetworkUseIsDistribution => network_use_is_distribution, "Network use is distribution", "Users who interact with the software via network are given the right to receive a copy of the source code.";
+        SameLicense => same_license, "Same license", "Modifications must be released under the same license.";
+        NoticeFile => notice_file, "NOTICE file", "The attribution notices in a NOTICE file must be included with the software.";
+        MarkModifications => mark_modifications, "Mark modifications", "Altered source versions must be plainly marked as such.";
+    }
 }
 
 impl Display for Conditions {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        if self.disclose_sources {
-            f.write_str(
-                "- Source code must be made available when the software is distributed.\n",
-            )?;
-        }
-        if self.document_changes {
-            f.write_str("- Changes made to the code must be documented.\n")?;
-        }
-        if self.license_and_copyright_notice {
-            f.write_str(
-                "- The license and copyright notice must be included with the software.\n",
-            )?;
-        }
-        if self.network_use_is_distribution {
-            f.write_str("- Users who interact with the software via network are given the right to receive a copy of the source code.\n")?;
-        }
-        if self.same_license {
-            f.write_str("- Modifications must be released under the same license.\n")?;
-        }
-        Ok(())
+        self.list(ListStyle::default()).fmt(f)
     }
 }
 
@@ -179,7 +820,25 @@ impl Display for Conditions {
 /// let lim = mit.limitations();
 /// assert!(lim.no_liability() && lim.no_warranty());
 /// ```
-#[derive(Copy, Clone, Debug, Default, Hash, Ord, PartialOrd, Eq, PartialEq)]
+///
+/// # Ordering and compatibility
+///
+/// Each flag is assigned a fixed bit, exposed through [`bits`](Limitations::bits):
+///
+/// | Bit | Flag |
+/// |-----|------|
+/// | `1` | [`no_liability`](Limitations::no_liability) |
+/// | `2` | [`no_trademark_rights`](Limitations::no_trademark_rights) |
+/// | `4` | [`no_warranty`](Limitations::no_warranty) |
+/// | `8` | [`no_patent_rights`](Limitations::no_patent_rights) |
+///
+/// Values are ordered flag by flag from the top of the table, where a flag that isn't
+/// set sorts first, as if the struct derived [`Ord`]. Flags added in later releases get
+/// the next unused bit and sort after the others, so the bits and the relative order
+/// of existing values never change.
+/// The fields are private, so adding a flag is not a breaking change and the struct
+/// is deliberately not `#[non_exhaustive]`.
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
 pub struct Limitations {
     no_liability: bool,
     no_trademark_rights: bool,
@@ -207,153 +866,954 @@ impl Limitations {
     pub const fn no_patent_rights(self) -> bool {
         self.no_patent_rights
     }
+
+    /// The bit representation of the flags.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::Limitations;
+    ///
+    /// let mit = license::from_id_ext("MIT").unwrap().limitations();
+    /// let cc0 = license::from_id_ext("CC0-1.0").unwrap().limitations();
+    /// assert_eq!(mit.bits(), 0b0101);
+    /// assert_eq!(cc0.bits(), 0b1111);
+    /// assert_eq!(Limitations::from_bits(0b1_0000), None);
+    /// assert!(Limitations::default() < mit && mit < cc0);
+    /// ```
+    pub const fn bits(self) -> u32 {
+        self.no_liability as u32
+            | (self.no_trademark_rights as u32) << 1
+            | (self.no_warranty as u32) << 2
+            | (self.no_patent_rights as u32) << 3
+    }
+
+    /// Creates the flags from their bit representation.
+    ///
+    /// Returns `None` if any unknown bit is set.
+    pub const fn from_bits(bits: u32) -> Option<Self> {
+        if bits & !Self::MASK != 0 {
+            return None;
+        }
+        Some(Self::from_bits_truncate(bits))
+    }
+
+    /// Creates the flags from their bit representation, ignoring unknown bits.
+    pub const fn from_bits_truncate(bits: u32) -> Self {
+        Limitations {
+            no_liability: bits & 1 != 0,
+            no_trademark_rights: bits & 2 != 0,
+            no_warranty: bits & 4 != 0,
+            no_patent_rights: bits & 8 != 0,
+        }
+    }
+
+    const MASK: u32 = 0b1111;
+
+    /// The flags that are set in either `self` or `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// let a = license::from_id_ext("MIT").unwrap().limitations();
+    /// let b = license::from_id_ext("CC0-1.0").unwrap().limitations();
+    /// let union = a.union(b);
+    /// assert!(a.is_subset(union) && b.is_subset(union));
+    /// assert_eq!(a.intersection(b), a);
+    /// assert!(b.difference(a).no_patent_rights());
+    /// assert_eq!(a.difference(b), Default::default());
+    /// ```
+    pub const fn union(self, other: Self) -> Self {
+        Self::from_bits_truncate(self.bits() | other.bits())
+    }
+
+    /// The flags that are set in both `self` and `other`.
+    pub const fn intersection(self, other: Self) -> Self {
+        Self::from_bits_truncate(self.bits() & other.bits())
+    }
+
+    /// The flags that are set in `self` but not in `other`.
+    pub const fn difference(self, other: Self) -> Self {
+        Self::from_bits_truncate(self.bits() & !other.bits())
+    }
+
+    /// Says if every flag that is set in `self` is also set in `other`.
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.bits() & !other.bits() == 0
+    }
+
+    /// No flags set, see [`Permissions::NONE`].
+    pub const NONE: Self = Self::from_bits_truncate(0);
+
+    /// Says if no flag is set.
+    pub const fn is_empty(self) -> bool {
+        self.bits() == 0
+    }
+}
+
+impl PartialOrd for Limitations {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Limitations {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The lowest bit is the first flag, which decides first.
+        self.bits().reverse_bits().cmp(&other.bits().reverse_bits())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Limitations {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_bits_truncate(u.arbitrary()?))
+    }
+}
+
+impl Limitations {
+    /// The sentence of a list without flags, so that it is never silently blank.
+    pub(crate) const EMPTY: &'static str = "Has no limitations.";
+
+    /// The note of a summary that leaves the empty list out.
+    pub(crate) const NOTE: &'static str = "No limitations.";
+}
+
+impl_flag! {
+    /// A flag of the [`Limitations`].
+    pub enum Limitation in Limitations {
+        NoLiability => no_liability, "No liability", "Includes a limitation of liability.";
+        NoTrademarkRights => no_trademark_rights, "No trademark rights", "Does not grant trademark rights.";
+        NoWarranty => no_warranty, "No warranty", "Does not provide any warranty.";
+        NoPatentRights => no_patent_rights, "No patent rights", "Does not provide any rights in the patents of contributors.";
+    }
 }
 
 impl Display for Limitations {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        if self.no_liability {
-            f.write_str("- Includes a limitation of liability.\n")?;
-        }
-        if self.no_trademark_rights {
-            f.write_str("- Does not grant trademark rights.\n")?;
+        self.list(ListStyle::default()).fmt(f)
+    }
+}
+
+/// A permission, a condition or a limitation, see [`LicenseExt::flag_provenance`].
+///
+/// # Examples
+/// ```
+/// use license::{Condition, Flag};
+///
+/// let flag = Flag::from(Condition::SameLicense);
+/// assert_eq!(flag, Flag::Condition(Condition::SameLicense));
+/// assert_eq!(flag.label(), "Same license");
+/// assert!(!license::from_id_ext("MIT").unwrap().metadata().contains(flag));
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Flag {
+    /// A flag of the [`Permissions`].
+    Permission(Permission),
+    /// A flag of the [`Conditions`].
+    Condition(Condition),
+    /// A flag of the [`Limitations`].
+    Limitation(Limitation),
+}
+
+impl Flag {
+    /// The name of the getter of the flag, in snake case.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Flag::Permission(flag) => flag.name(),
+            Flag::Condition(flag) => flag.name(),
+            Flag::Limitation(flag) => flag.name(),
         }
-        if self.no_warranty {
-            f.write_str("- Does not provide any warranty.\n")?;
+    }
+
+    /// The short label of the flag, for a table heading or a badge.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Flag::Permission(flag) => flag.label(),
+            Flag::Condition(flag) => flag.label(),
+            Flag::Limitation(flag) => flag.label(),
         }
-        if self.no_patent_rights {
-            f.write_str("- Does not provide any rights in the patents of contributors.\n")?;
+    }
+
+    /// The sentence describing the flag, as the [`Display`] impl of the flags renders it.
+    pub const fn description(self) -> &'static str {
+        match self {
+            Flag::Permission(flag) => flag.description(),
+            Flag::Condition(flag) => flag.description(),
+            Flag::Limitation(flag) => flag.description(),
         }
-        Ok(())
     }
 }
 
+impl From<Permission> for Flag {
+    fn from(flag: Permission) -> Self {
+        Flag::Permission(flag)
+    }
+}
+
+impl From<Condition> for Flag {
+    fn from(flag: Condition) -> Self {
+        Flag::Condition(flag)
+    }
+}
+
+impl From<Limitation> for Flag {
+    fn from(flag: Limitation) -> Self {
+        Flag::Limitation(flag)
+    }
+}
+
+/// The span of a flag that a license sets, among the spans of its flags by name, see
+/// [`LicenseExt::flag_provenance`].
+fn provenance(
+    metadata: Metadata,
+    flag: Flag,
+    spans: &[(&str, text::TextSpan)],
+) -> Option<text::TextSpan> {
+    if !metadata.contains(flag) {
+        return None;
+    }
+    spans
+        .iter()
+        .find(|(name, _)| *name == flag.name())
+        .map(|(_, span)| span.clone())
+}
+
 macro_rules! impl_ext {
     (
-        $(impl $struct:ident {
+        $($(#[$attr:meta])*
+        impl $struct:ident {
+            code: $code:literal;
+            slug: $slug:literal;
+            $(provenance: [$($flag:ident: $start:literal..$end:literal),* $(,)?];)?
+            $(endorsement: $endorsement_start:literal..$endorsement_end:literal;)?
             permissions: $($permissions:ident)|*;
             conditions: $($conditions:ident)|*;
             limitations: $($limitations:ident)|*;
+            patents: $patents:ident;
+            $($method:item)*
         })*
     ) => {
-        $(impl LicenseExt for $struct {
-            fn permissions(&self) -> Permissions {
+        /// All licenses that implement [`LicenseExt`], sorted by id, which is the order of
+        /// `dyn LicenseExt`.
+        ///
+        /// # Examples
+        /// ```
+        /// let ids: Vec<_> = license::LICENSES_EXT.iter().map(|l| l.id()).collect();
+        /// assert_eq!(ids[..3], ["0BSD", "AFL-3.0", "AGPL-3.0-only"]);
+        /// ```
+        pub const LICENSES_EXT: &[&dyn LicenseExt] = &[$(&$struct),*];
+
+        $(impl $struct {
+            const METADATA: Metadata = Metadata::new(
                 Permissions {
                     $($permissions: true,)*
-                    ..Default::default()
-                }
-            }
-
-            fn conditions(&self) -> Conditions {
+                    patent_rights: PatentPosture::$patents.grants_patent_rights(),
+                    ..Permissions::from_bits_truncate(0)
+                },
                 Conditions {
                     $($conditions: true,)*
-                    ..Default::default()
-                }
-            }
-
-            fn limitations(&self) -> Limitations {
+                    ..Conditions::from_bits_truncate(0)
+                },
                 Limitations {
                     $($limitations: true,)*
-                    ..Default::default()
-                }
+                    no_patent_rights: PatentPosture::$patents.excludes_patent_rights(),
+                    ..Limitations::from_bits_truncate(0)
+                },
+            );
+        }
+
+        $(#[$attr])*
+        impl LicenseExt for $struct {
+            fn metadata(&self) -> Metadata {
+                Self::METADATA
+            }
+
+            fn code(&self) -> u16 {
+                $code
+            }
+
+            fn slug(&self) -> &'static str {
+                $slug
             }
+
+            fn patent_posture(&self) -> PatentPosture {
+                PatentPosture::$patents
+            }
+
+            $(fn flag_provenance(&self, flag: Flag) -> Option<text::TextSpan> {
+                provenance(Self::METADATA, flag, &[$((stringify!($flag), $start..$end)),*])
+            })?
+
+            $(fn endorsement_restriction(&self) -> Option<&'static str> {
+                self.text().get($endorsement_start..$endorsement_end)
+            })?
+
+            $($method)*
         })*
     };
 }
 
 impl_ext! {
+    impl ZERO_BSD {
+        code: 1;
+        slug: "0bsd";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: ;
+        limitations: no_liability | no_warranty;
+        patents: Silent;
+    }
     impl AFL_3_0 {
-        permissions: commercial_use | distribution | modification | patent_rights | private_use;
+        code: 2;
+        slug: "afl-3.0";
+        permissions: commercial_use | distribution | modification | private_use;
         conditions:  document_changes | license_and_copyright_notice;
         limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: GrantWithTermination;
     }
     impl AGPL_3_0_only {
-        permissions: commercial_use | distribution | modification | patent_rights | private_use;
+        code: 3;
+        slug: "agpl-3.0-only";
+        permissions: commercial_use | distribution | modification | private_use;
         conditions: disclose_sources | document_changes | license_and_copyright_notice | network_use_is_distribution | same_license;
         limitations: no_liability | no_warranty;
+        // Patent litigation is a further restriction that section 10 forbids, so it ends
+        // the rights only as any other violation does.
+        patents: ExpressGrant;
+
+        fn apply_instructions<'a>(
+            &self,
+            program: &'a str,
+            author: &'a str,
+            year: u16,
+        ) -> Option<ApplyInstructions<'a>> {
+            ApplyInstructions::gnu(self, false, program, author, year)
+        }
+    }
+    impl APSL_2_0 {
+        code: 4;
+        slug: "apsl-2.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | document_changes | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: GrantWithTermination;
+
+        // Externally deploying the software, which includes serving it to third parties
+        // over a network, counts as distribution.
+        fn triggers_on_network_use(&self) -> bool {
+            true
+        }
     }
     impl Apache_2_0 {
-        permissions: commercial_use | distribution | modification | patent_rights | private_use;
-        conditions: document_changes | license_and_copyright_notice;
+        code: 5;
+        slug: "apache-2.0";
+        provenance: [
+            commercial_use: 3130..3512,
+            distribution: 4462..4685,
+            modification: 3130..3512,
+            patent_rights: 3514..4460,
+            private_use: 3130..3512,
+            document_changes: 4797..4901,
+            license_and_copyright_notice: 4692..5169,
+            notice_file: 5176..6123,
+            no_liability: 7798..8503,
+            no_trademark_rights: 6936..7211,
+            no_warranty: 7213..7796,
+        ];
+        endorsement: 6951..7211;
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: document_changes | license_and_copyright_notice | notice_file;
         limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: GrantWithTermination;
+
+        fn notice<'a>(&self, holder: &'a str, year: u16) -> Option<Notice<'a>> {
+            let text = self.text();
+            let start = text.find("Copyright [yyyy]")?;
+            Some(Notice::new(&text[start..], holder, year))
+        }
     }
-    impl BSD_0 {
+    /// The flags are those of Apache-2.0, which the Solderpad text wraps.
+    impl Apache_2_0_WITH_SHL_2_1 {
+        code: 57;
+        slug: "apache-2.0-with-shl-2.1";
         permissions: commercial_use | distribution | modification | private_use;
-        conditions: ;
-        limitations: no_liability | no_warranty;
+        conditions: document_changes | license_and_copyright_notice | notice_file;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: GrantWithTermination;
+
+        fn notice<'a>(&self, holder: &'a str, year: u16) -> Option<Notice<'a>> {
+            Some(Notice::new(self.header()?, holder, year))
+        }
     }
     impl BSD_2_Clause {
+        code: 6;
+        slug: "bsd-2-clause";
         permissions: commercial_use | distribution | modification | private_use;
         conditions: license_and_copyright_notice;
         limitations: no_liability | no_warranty;
+        patents: Silent;
     }
     impl BSD_3_Clause {
+        code: 7;
+        slug: "bsd-3-clause";
+        endorsement: 535..722;
         permissions: commercial_use | distribution | modification | private_use;
         conditions: license_and_copyright_notice;
         limitations: no_liability | no_warranty;
+        patents: Silent;
+
+        fn notice<'a>(&self, holder: &'a str, year: u16) -> Option<Notice<'a>> {
+            Some(Notice::new(self.text(), holder, year))
+        }
     }
     impl BSD_3_Clause_Clear {
+        code: 8;
+        slug: "bsd-3-clause-clear";
+        endorsement: 642..829;
         permissions: commercial_use | distribution | modification | private_use;
         conditions: license_and_copyright_notice;
-        limitations: no_liability | no_warranty | no_patent_rights;
+        limitations: no_liability | no_warranty;
+        patents: ExplicitNonGrant;
+    }
+    impl BSD_4_Clause {
+        code: 59;
+        slug: "bsd-4-clause";
+        endorsement: 719..906;
+        permissions: commercial_use | distribution | modification | private_use;
+        // Advertising materials must also acknowledge the organization, which no flag
+        // describes.
+        conditions: license_and_copyright_notice;
+        limitations: no_liability | no_warranty;
+        patents: Silent;
     }
     impl BSL_1_0 {
+        code: 9;
+        slug: "bsl-1.0";
         permissions: commercial_use | distribution | modification | private_use;
         conditions: license_and_copyright_notice;
         limitations: no_liability | no_warranty;
+        patents: Silent;
+    }
+    /// Production use needs an additional use grant until the change date, see
+    /// [`busl_with_parameters`].
+    impl BUSL_1_1 {
+        code: 50;
+        slug: "busl-1.1";
+        permissions: distribution | modification | private_use;
+        conditions: license_and_copyright_notice;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: Silent;
+
+        fn is_source_available(&self) -> bool {
+            true
+        }
+    }
+    impl CC_BY_4_0 {
+        code: 10;
+        slug: "cc-by-4.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: document_changes | license_and_copyright_notice;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: ExplicitNonGrant;
+    }
+    impl CC_BY_NC_4_0 {
+        code: 11;
+        slug: "cc-by-nc-4.0";
+        permissions: distribution | modification | private_use;
+        conditions: document_changes | license_and_copyright_notice;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: ExplicitNonGrant;
+    }
+    impl CC_BY_NC_ND_4_0 {
+        code: 12;
+        slug: "cc-by-nc-nd-4.0";
+        permissions: distribution | private_use;
+        conditions: license_and_copyright_notice;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: ExplicitNonGrant;
+    }
+    impl CC_BY_NC_SA_4_0 {
+        code: 13;
+        slug: "cc-by-nc-sa-4.0";
+        permissions: distribution | modification | private_use;
+        conditions: document_changes | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: ExplicitNonGrant;
+    }
+    impl CC_BY_ND_4_0 {
+        code: 14;
+        slug: "cc-by-nd-4.0";
+        permissions: commercial_use | distribution | private_use;
+        conditions: license_and_copyright_notice;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: ExplicitNonGrant;
+    }
+    impl CC_BY_SA_3_0 {
+        code: 15;
+        slug: "cc-by-sa-3.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: document_changes | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: ExplicitNonGrant;
+    }
+    impl CC_BY_SA_4_0 {
+        code: 16;
+        slug: "cc-by-sa-4.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: document_changes | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: ExplicitNonGrant;
     }
     impl CC0_1_0 {
+        code: 17;
+        slug: "cc0-1.0";
         permissions: commercial_use | distribution | modification | private_use;
         conditions: ;
-        limitations: no_liability | no_trademark_rights | no_warranty | no_patent_rights;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: ExplicitNonGrant;
+    }
+    impl CECILL_2_0 {
+        code: 18;
+        slug: "cecill-2.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_warranty;
+        patents: ExpressGrant;
+    }
+    impl CECILL_2_1 {
+        code: 19;
+        slug: "cecill-2.1";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_warranty;
+        patents: ExpressGrant;
+    }
+    /// CERN-OHL-P-2.0 is permissive: modified sources must carry a notice of the changes,
+    /// and may be conveyed under other terms.
+    impl CERN_OHL_P_2_0 {
+        code: 54;
+        slug: "cern-ohl-p-2.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: document_changes | license_and_copyright_notice;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: GrantWithTermination;
+    }
+    /// The flags are those of CERN-OHL-W-2.0, which only reaches the covered source. The
+    /// strong reciprocity, which also covers the external material that a product is
+    /// made with, is in its [`Copyleft`](crate::report::Copyleft) class.
+    impl CERN_OHL_S_2_0 {
+        code: 56;
+        slug: "cern-ohl-s-2.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | document_changes | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: GrantWithTermination;
+    }
+    /// The reciprocity of CERN-OHL-W-2.0 only covers the covered source, and a product may
+    /// combine it with external material under other terms.
+    impl CERN_OHL_W_2_0 {
+        code: 55;
+        slug: "cern-ohl-w-2.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | document_changes | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: GrantWithTermination;
+    }
+    impl CPL_1_0 {
+        code: 20;
+        slug: "cpl-1.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_warranty;
+        patents: GrantWithTermination;
+    }
+    /// The DL-DE-BY-2.0 has no disclaimer of warranty or liability, and requires that
+    /// changes to the data are marked.
+    impl DL_DE_BY_2_0 {
+        code: 52;
+        slug: "dl-de-by-2.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: license_and_copyright_notice | mark_modifications;
+        limitations: ;
+        patents: Silent;
     }
     impl ECL_2_0 {
-        permissions: commercial_use | distribution | modification | patent_rights | private_use;
+        code: 21;
+        slug: "ecl-2.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: document_changes | license_and_copyright_notice | notice_file;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: GrantWithTermination;
+    }
+    /// The flags are those of EPL-2.0, but two differences can't be expressed by them.
+    /// EPL-1.0 is governed by the laws of the State of New York, while EPL-2.0 has no
+    /// choice of law. EPL-1.0 also has no Secondary License provision, so it is
+    /// incompatible with the GPL.
+    impl EPL_1_0 {
+        code: 22;
+        slug: "epl-1.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_warranty;
+        patents: GrantWithTermination;
+    }
+    /// The initial contributor may designate a Secondary License, such as GPL-2.0, which
+    /// makes the program available under it when combined with GPL code. The flags
+    /// describe the EPL-2.0 terms alone.
+    impl EPL_2_0 {
+        code: 23;
+        slug: "epl-2.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_warranty;
+        patents: GrantWithTermination;
+    }
+    impl EUPL_1_1 {
+        code: 24;
+        slug: "eupl-1.1";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | document_changes | license_and_copyright_notice | network_use_is_distribution | same_license;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: ExpressGrant;
+
+        // The compatible licences of its appendix.
+        fn compatible_outbound(&self) -> &'static [&'static str] {
+            &["CECILL-2.0", "CPL-1.0", "EPL-1.0", "GPL-2.0-only", "OSL-2.1", "OSL-3.0"]
+        }
+    }
+    impl EUPL_1_2 {
+        code: 25;
+        slug: "eupl-1.2";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | document_changes | license_and_copyright_notice | network_use_is_distribution | same_license;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: ExpressGrant;
+
+        // The compatible licences of its appendix. CC-BY-SA-3.0 is only listed for works
+        // other than software.
+        fn compatible_outbound(&self) -> &'static [&'static str] {
+            &[
+                "AGPL-3.0-only",
+                "CC-BY-SA-3.0",
+                "CECILL-2.0",
+                "CECILL-2.1",
+                "EPL-1.0",
+                "EUPL-1.1",
+                "EUPL-1.2",
+                "GPL-2.0-only",
+                "GPL-3.0-only",
+                "LGPL-2.1-only",
+                "LGPL-3.0-only",
+                "LiLiQ-R-1.1",
+                "LiLiQ-Rplus-1.1",
+                "MPL-2.0",
+                "OSL-2.1",
+                "OSL-3.0",
+            ]
+        }
+    }
+    /// The software may not be provided as a hosted or managed service, and its license
+    /// key functionality may not be circumvented.
+    impl Elastic_2_0 {
+        code: 51;
+        slug: "elastic-2.0";
+        permissions: commercial_use | distribution | modification | private_use;
         conditions: document_changes | license_and_copyright_notice;
         limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: GrantWithTermination;
+
+        fn is_source_available(&self) -> bool {
+            true
+        }
+    }
+    /// Invariant Sections, Front-Cover Texts and Back-Cover Texts designated
+    /// by the licensor may not be modified or removed, so the modification
+    /// permission does not extend to them.
+    impl GFDL_1_3_only {
+        code: 26;
+        slug: "gfdl-1.3-only";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: document_changes | license_and_copyright_notice | same_license;
+        limitations: ;
+        patents: Silent;
+    }
+    /// Invariant Sections, Front-Cover Texts and Back-Cover Texts designated
+    /// by the licensor may not be modified or removed, so the modification
+    /// permission does not extend to them.
+    impl GFDL_1_3_or_later {
+        code: 27;
+        slug: "gfdl-1.3-or-later";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: document_changes | license_and_copyright_notice | same_license;
+        limitations: ;
+        patents: Silent;
+    }
+    impl GPL_2_0_only {
+        code: 28;
+        slug: "gpl-2.0-only";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | document_changes | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_warranty;
+        patents: Silent;
+    }
+    impl GPL_2_0_only_WITH_Classpath_exception_2_0 {
+        code: 29;
+        slug: "gpl-2.0-only-with-classpath-exception-2.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | document_changes | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_warranty;
+        patents: Silent;
     }
     impl GPL_3_0_only {
-        permissions: commercial_use | distribution | modification | patent_rights | private_use;
+        code: 30;
+        slug: "gpl-3.0-only";
+        provenance: [
+            commercial_use: 10193..10320,
+            distribution: 9738..10191,
+            modification: 10322..10567,
+            patent_rights: 24793..25049,
+            private_use: 8089..8767,
+            disclose_sources: 12178..12391,
+            document_changes: 10574..10672,
+            license_and_copyright_notice: 9738..10191,
+            same_license: 10904..11339,
+            no_liability: 30952..31583,
+            no_warranty: 30373..30950,
+        ];
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | document_changes | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_warranty;
+        // Patent litigation is a further restriction that section 10 forbids, so it ends
+        // the rights only as any other violation does.
+        patents: ExpressGrant;
+
+        fn apply_instructions<'a>(
+            &self,
+            program: &'a str,
+            author: &'a str,
+            year: u16,
+        ) -> Option<ApplyInstructions<'a>> {
+            ApplyInstructions::gnu(self, false, program, author, year)
+        }
+    }
+    impl LGPL_2_1_only {
+        code: 31;
+        slug: "lgpl-2.1-only";
+        permissions: commercial_use | distribution | modification | private_use;
         conditions: disclose_sources | document_changes | license_and_copyright_notice | same_license;
         limitations: no_liability | no_warranty;
+        patents: Silent;
     }
     impl LGPL_3_0_only {
-        permissions: commercial_use | distribution | modification | patent_rights | private_use;
+        code: 32;
+        slug: "lgpl-3.0-only";
+        permissions: commercial_use | distribution | modification | private_use;
         conditions: disclose_sources | document_changes | license_and_copyright_notice | same_license;
         limitations: no_liability | no_warranty;
+        patents: ExpressGrant;
+
+        // The LGPL has no instructions of its own, the FSF recommends those of the GPL
+        // with "Lesser" added.
+        fn apply_instructions<'a>(
+            &self,
+            program: &'a str,
+            author: &'a str,
+            year: u16,
+        ) -> Option<ApplyInstructions<'a>> {
+            ApplyInstructions::gnu(&GPL_3_0_only, true, program, author, year)
+        }
+    }
+    impl LiLiQ_R_1_1 {
+        code: 33;
+        slug: "liliq-r-1.1";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_warranty;
+        patents: ExpressGrant;
+    }
+    impl LiLiQ_Rplus_1_1 {
+        code: 34;
+        slug: "liliq-rplus-1.1";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_warranty;
+        patents: ExpressGrant;
     }
     impl MIT {
+        code: 35;
+        slug: "mit";
+        provenance: [
+            commercial_use: 55..487,
+            distribution: 55..487,
+            modification: 55..487,
+            private_use: 55..487,
+            license_and_copyright_notice: 489..615,
+            no_liability: 819..1077,
+            no_warranty: 617..818,
+        ];
         permissions: commercial_use | distribution | modification | private_use;
         conditions: license_and_copyright_notice;
         limitations: no_liability | no_warranty;
+        patents: Silent;
+
+        fn notice<'a>(&self, holder: &'a str, year: u16) -> Option<Notice<'a>> {
+            Some(Notice::new(self.text(), holder, year))
+        }
+    }
+    impl MIT_CMU {
+        code: 45;
+        slug: "mit-cmu";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: license_and_copyright_notice;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: Silent;
+    }
+    impl MIT_advertising {
+        code: 46;
+        slug: "mit-advertising";
+        permissions: commercial_use | distribution | modification | private_use;
+        // The notice must also be included in marketing and publicity materials, and
+        // the use of the software acknowledged, which no flag describes.
+        conditions: license_and_copyright_notice;
+        limitations: no_liability | no_warranty;
+        patents: Silent;
+    }
+    impl MITNFA {
+        code: 47;
+        slug: "mitnfa";
+        permissions: commercial_use | distribution | modification | private_use;
+        // Modified versions must not report bugs to the original author.
+        conditions: license_and_copyright_notice | mark_modifications;
+        limitations: no_liability | no_warranty;
+        patents: Silent;
     }
     impl MPL_2_0 {
-        permissions: commercial_use | distribution | modification | patent_rights | private_use;
+        code: 36;
+        slug: "mpl-2.0";
+        provenance: [
+            commercial_use: 3056..3470,
+            distribution: 3170..3470,
+            modification: 3170..3470,
+            patent_rights: 3482..3656,
+            private_use: 3170..3470,
+            disclose_sources: 6220..6541,
+            license_and_copyright_notice: 7514..7866,
+            same_license: 5636..6100,
+            no_liability: 11501..12421,
+            no_trademark_rights: 4622..4806,
+            no_warranty: 10775..11499,
+        ];
+        permissions: commercial_use | distribution | modification | private_use;
         conditions: disclose_sources | license_and_copyright_notice | same_license;
         limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: GrantWithTermination;
+
+        fn mpl_secondary_license_allowed(&self) -> Option<bool> {
+            Some(true)
+        }
+    }
+    impl MPL_2_0_no_copyleft_exception {
+        code: 49;
+        slug: "mpl-2.0-no-copyleft-exception";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: GrantWithTermination;
+
+        fn mpl_secondary_license_allowed(&self) -> Option<bool> {
+            Some(false)
+        }
     }
     impl MS_PL {
-        permissions: commercial_use | distribution | modification | patent_rights | private_use;
+        code: 37;
+        slug: "ms-pl";
+        endorsement: 1464..1554;
+        permissions: commercial_use | distribution | modification | private_use;
         conditions: license_and_copyright_notice;
         limitations: no_trademark_rights | no_warranty;
+        patents: GrantWithTermination;
+    }
+    impl MirOS {
+        code: 38;
+        slug: "miros";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: license_and_copyright_notice;
+        limitations: no_liability | no_warranty;
+        patents: Silent;
+    }
+    /// The NTP License disclaims warranties but, unlike most permissive
+    /// licenses, has no limitation of liability.
+    impl NTP {
+        code: 39;
+        slug: "ntp";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: license_and_copyright_notice;
+        limitations: no_warranty;
+        patents: Silent;
+    }
+    impl OGL_UK_3_0 {
+        code: 53;
+        slug: "ogl-uk-3.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: license_and_copyright_notice;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: ExplicitNonGrant;
+    }
+    impl OSL_2_1 {
+        code: 40;
+        slug: "osl-2.1";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | document_changes | license_and_copyright_notice | network_use_is_distribution | same_license;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: GrantWithTermination;
     }
     impl OSL_3_0 {
-        permissions: commercial_use | distribution | modification | patent_rights | private_use;
+        code: 41;
+        slug: "osl-3.0";
+        permissions: commercial_use | distribution | modification | private_use;
         conditions: disclose_sources | document_changes | license_and_copyright_notice | network_use_is_distribution | same_license;
         limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: GrantWithTermination;
+    }
+    /// The Documentation, which are the design files, is reciprocal: modified copies must
+    /// name the changes and stay under the license, and whoever distributes Products must
+    /// also make their Documentation available. The patent grant is an immunity from suit.
+    impl TAPR_OHL_1_0 {
+        code: 58;
+        slug: "tapr-ohl-1.0";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: disclose_sources | document_changes | license_and_copyright_notice | same_license;
+        limitations: no_liability | no_warranty;
+        patents: ExpressGrant;
     }
     impl Unlicense {
+        code: 42;
+        slug: "unlicense";
         permissions: commercial_use | distribution | modification | private_use;
         conditions: ;
         limitations: no_liability | no_warranty;
+        patents: Silent;
     }
     impl WTFPL {
+        code: 43;
+        slug: "wtfpl";
         permissions: commercial_use | distribution | modification | private_use;
         conditions: ;
         limitations: ;
+        patents: Silent;
+    }
+    impl X11 {
+        code: 48;
+        slug: "x11";
+        permissions: commercial_use | distribution | modification | private_use;
+        conditions: license_and_copyright_notice;
+        limitations: no_liability | no_trademark_rights | no_warranty;
+        patents: Silent;
     }
     impl Zlib {
+        code: 44;
+        slug: "zlib";
         permissions: commercial_use | distribution | modification | private_use;
-        conditions: document_changes | license_and_copyright_notice;
+        conditions: license_and_copyright_notice | mark_modifications;
         limitations: no_liability | no_warranty;
+        patents: Silent;
     }
 }
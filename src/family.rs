@@ -0,0 +1,65 @@
+use crate::*;
+
+mod named;
+
+pub(crate) use named::named_family;
+
+/// A group of related licenses, see [`License::family`].
+///
+/// The families are curated data that is not part of SPDX. Licenses that
+/// don't belong to one of the named families are their own family,
+/// [`Other`](Family::Other) with their id.
+///
+/// # Examples
+/// ```
+/// use license::{Family, License};
+///
+/// let lgpl = license::from_id("LGPL-2.1-only").unwrap();
+/// let bsd = license::from_id("0BSD").unwrap();
+/// let cc0 = license::from_id("CC0-1.0").unwrap();
+/// let isc = license::from_id("ISC").unwrap();
+/// assert_eq!(lgpl.family(), Family::Gnu);
+/// assert_eq!(bsd.family(), Family::Bsd);
+/// assert_eq!(cc0.family(), Family::PublicDomain);
+/// assert_eq!(isc.family(), Family::Other("ISC"));
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Family {
+    /// The licenses of the GNU project: the GPL, LGPL, AGPL and GFDL.
+    Gnu,
+    /// The BSD licenses and their variants, including 0BSD.
+    Bsd,
+    /// The Creative Commons licenses.
+    ///
+    /// CC0 and the public domain certification are in [`PublicDomain`](Family::PublicDomain).
+    CreativeCommons,
+    /// The Mozilla and Netscape Public Licenses.
+    Mozilla,
+    /// The Apache Licenses.
+    Apache,
+    /// Public domain dedications and notices, such as CC0 and the Unlicense.
+    PublicDomain,
+    /// A license outside of the named families, with its id.
+    Other(&'static str),
+}
+
+/// Returns the extension licenses of a family, in the order of [`LICENSES_EXT`].
+///
+/// # Examples
+/// ```
+/// use license::Family;
+///
+/// let bsd: Vec<_> = license::by_family(Family::Bsd).map(|l| l.id()).collect();
+/// assert_eq!(bsd, ["0BSD", "BSD-2-Clause", "BSD-3-Clause", "BSD-3-Clause-Clear", "BSD-4-Clause"]);
+/// ```
+pub fn by_family(family: Family) -> impl Iterator<Item = &'static dyn LicenseExt> {
+    LICENSES_EXT
+        .iter()
+        .copied()
+        .filter(move |license| license.family() == family)
+}
+
+/// The family of a license, see [`License::family`].
+pub(crate) fn family(id: &'static str) -> Family {
+    named_family(id).unwrap_or(Family::Other(id))
+}
@@ -0,0 +1,135 @@
+//! The rule of the named families, which the generator in `run/` shares to group the
+//! licenses before they are built.
+
+use crate::Family;
+
+/// The named family of a license id, or `None` if it is its own family.
+pub(crate) fn named_family(id: &str) -> Option<Family> {
+    let family = match id {
+        "AGPL-3.0"
+        | "AGPL-3.0-only"
+        | "AGPL-3.0-or-later"
+        | "GFDL-1.1"
+        | "GFDL-1.1-invariants-only"
+        | "GFDL-1.1-invariants-or-later"
+        | "GFDL-1.1-no-invariants-only"
+        | "GFDL-1.1-no-invariants-or-later"
+        | "GFDL-1.1-only"
+        | "GFDL-1.1-or-later"
+        | "GFDL-1.2"
+        | "GFDL-1.2-invariants-only"
+        | "GFDL-1.2-invariants-or-later"
+        | "GFDL-1.2-no-invariants-only"
+        | "GFDL-1.2-no-invariants-or-later"
+        | "GFDL-1.2-only"
+        | "GFDL-1.2-or-later"
+        | "GFDL-1.3"
+        | "GFDL-1.3-invariants-only"
+        | "GFDL-1.3-invariants-or-later"
+        | "GFDL-1.3-no-invariants-only"
+        | "GFDL-1.3-no-invariants-or-later"
+        | "GFDL-1.3-only"
+        | "GFDL-1.3-or-later"
+        | "GPL-1.0"
+        | "GPL-1.0+"
+        | "GPL-1.0-only"
+        | "GPL-1.0-or-later"
+        | "GPL-2.0"
+        | "GPL-2.0+"
+        | "GPL-2.0-only"
+        | "GPL-2.0-or-later"
+        | "GPL-2.0-with-GCC-exception"
+        | "GPL-2.0-with-autoconf-exception"
+        | "GPL-2.0-with-bison-exception"
+        | "GPL-2.0-with-classpath-exception"
+        | "GPL-2.0-with-font-exception"
+        | "GPL-3.0"
+        | "GPL-3.0+"
+        | "GPL-3.0-only"
+        | "GPL-3.0-or-later"
+        | "GPL-3.0-with-GCC-exception"
+        | "GPL-3.0-with-autoconf-exception"
+        | "LGPL-2.0"
+        | "LGPL-2.0+"
+        | "LGPL-2.0-only"
+        | "LGPL-2.0-or-later"
+        | "LGPL-2.1"
+        | "LGPL-2.1+"
+        | "LGPL-2.1-only"
+        | "LGPL-2.1-or-later"
+        | "LGPL-3.0"
+        | "LGPL-3.0+"
+        | "LGPL-3.0-only"
+        | "LGPL-3.0-or-later" => Family::Gnu,
+        "0BSD"
+        | "AMPAS"
+        | "BSD-1-Clause"
+        | "BSD-2-Clause"
+        | "BSD-2-Clause-FreeBSD"
+        | "BSD-2-Clause-NetBSD"
+        | "BSD-2-Clause-Patent"
+        | "BSD-2-Clause-Views"
+        | "BSD-3-Clause"
+        | "BSD-3-Clause-Attribution"
+        | "BSD-3-Clause-Clear"
+        | "BSD-3-Clause-LBNL"
+        | "BSD-3-Clause-Modification"
+        | "BSD-3-Clause-No-Nuclear-License"
+        | "BSD-3-Clause-No-Nuclear-License-2014"
+        | "BSD-3-Clause-No-Nuclear-Warranty"
+        | "BSD-3-Clause-Open-MPI"
+        | "BSD-4-Clause"
+        | "BSD-4-Clause-Shortened"
+        | "BSD-4-Clause-UC"
+        | "BSD-Protection"
+        | "BSD-Source-Code"
+        | "FreeBSD-DOC" => Family::Bsd,
+        "CC-BY-1.0"
+        | "CC-BY-2.0"
+        | "CC-BY-2.5"
+        | "CC-BY-3.0"
+        | "CC-BY-3.0-AT"
+        | "CC-BY-3.0-US"
+        | "CC-BY-4.0"
+        | "CC-BY-NC-1.0"
+        | "CC-BY-NC-2.0"
+        | "CC-BY-NC-2.5"
+        | "CC-BY-NC-3.0"
+        | "CC-BY-NC-4.0"
+        | "CC-BY-NC-ND-1.0"
+        | "CC-BY-NC-ND-2.0"
+        | "CC-BY-NC-ND-2.5"
+        | "CC-BY-NC-ND-3.0"
+        | "CC-BY-NC-ND-3.0-IGO"
+        | "CC-BY-NC-ND-4.0"
+        | "CC-BY-NC-SA-1.0"
+        | "CC-BY-NC-SA-2.0"
+        | "CC-BY-NC-SA-2.5"
+        | "CC-BY-NC-SA-3.0"
+        | "CC-BY-NC-SA-4.0"
+        | "CC-BY-ND-1.0"
+        | "CC-BY-ND-2.0"
+        | "CC-BY-ND-2.5"
+        | "CC-BY-ND-3.0"
+        | "CC-BY-ND-4.0"
+        | "CC-BY-SA-1.0"
+        | "CC-BY-SA-2.0"
+        | "CC-BY-SA-2.0-UK"
+        | "CC-BY-SA-2.1-JP"
+        | "CC-BY-SA-2.5"
+        | "CC-BY-SA-3.0"
+        | "CC-BY-SA-3.0-AT"
+        | "CC-BY-SA-4.0" => Family::CreativeCommons,
+        "MPL-1.0"
+        | "MPL-1.1"
+        | "MPL-2.0"
+        | "MPL-2.0-no-copyleft-exception"
+        | "NPL-1.0"
+        | "NPL-1.1" => Family::Mozilla,
+        "Apache-1.0" | "Apache-1.1" | "Apache-2.0" => Family::Apache,
+        "CC-PDDC" | "CC0-1.0" | "NIST-PD" | "NIST-PD-fallback" | "PDDL-1.0" | "SAX-PD"
+        | "Unlicense" | "libselinux-1.0" => Family::PublicDomain,
+        _ => return None,
+    };
+    Some(family)
+}
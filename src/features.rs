@@ -0,0 +1,103 @@
+macro_rules! build_features {
+    (
+        $(#[$attr:meta])*
+        pub struct $struct:ident {
+            $(#[doc = $first_doc:literal])*
+            $first:ident: $first_feature:literal => $first_flag:ident,
+            $($(#[doc = $doc:literal])* $field:ident: $feature:literal => $flag:ident,)*
+        }
+    ) => {
+        $(#[$attr])*
+        pub struct $struct {
+            $first: bool,
+            $($field: bool,)*
+        }
+
+        impl $struct {
+            $(#[doc = $first_doc])*
+            pub const fn $first(self) -> bool {
+                self.$first
+            }
+
+            $($(#[doc = $doc])*
+            pub const fn $field(self) -> bool {
+                self.$field
+            }
+            )*
+        }
+
+        /// The features that the crate was built with, see [`BuildFeatures`].
+        pub const fn features() -> $struct {
+            $struct {
+                $first: cfg!(feature = $first_feature),
+                $($field: cfg!(feature = $feature),)*
+            }
+        }
+
+        build_features!(@flag $first_flag $first_feature);
+        $(build_features!(@flag $flag $feature);)*
+
+        /// The features of the crate, each preceded by `+` if the crate was built with it
+        /// and by `-` otherwise, separated by spaces and in alphabetical order, such as
+        /// `+alloc -arbitrary -cli -fs -rayon -serde -std`.
+        ///
+        /// It is meant for logs and version output, while [`features`] is meant for code.
+        ///
+        /// # Examples
+        /// ```
+        /// let std = license::FEATURES_STRING.split(' ').any(|feature| feature == "+std");
+        /// assert_eq!(std, license::features().std());
+        /// ```
+        pub const FEATURES_STRING: &str = concat!($first_flag!(), $(" ", $flag!()),*);
+    };
+    (@flag $flag:ident $feature:literal) => {
+        #[cfg(feature = $feature)]
+        macro_rules! $flag {
+            () => {
+                concat!("+", $feature)
+            };
+        }
+        #[cfg(not(feature = $feature))]
+        macro_rules! $flag {
+            () => {
+                concat!("-", $feature)
+            };
+        }
+    };
+}
+
+build_features! {
+    /// The optional features that the crate was built with, see [`features`].
+    ///
+    /// When the crate is an indirect dependency, its features are chosen by the other
+    /// crates of the build, so that a tool can't tell from its own `cfg` which ones are
+    /// available. Every feature of the crate has a method, and features that optional
+    /// dependencies enable, such as `serde`, are included.
+    ///
+    /// # Examples
+    /// ```
+    /// let features = license::features();
+    /// if features.alloc() {
+    ///     // `text::Matcher` and `text::match_all` are available.
+    /// }
+    /// assert!(!features.fs() || features.std());
+    /// assert!(!features.std() || features.alloc());
+    /// ```
+    #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+    pub struct BuildFeatures {
+        /// The `alloc` feature, for the APIs that allocate, such as `text::Matcher`.
+        alloc: "alloc" => alloc_flag,
+        /// The `arbitrary` feature, for `arbitrary::Arbitrary` on the flags.
+        arbitrary: "arbitrary" => arbitrary_flag,
+        /// The `cli` feature, for the `license-info` binary.
+        cli: "cli" => cli_flag,
+        /// The `fs` feature, for writing license files with `license::write`.
+        fs: "fs" => fs_flag,
+        /// The `rayon` feature, for matching texts in parallel together with `alloc`.
+        rayon: "rayon" => rayon_flag,
+        /// The `serde` feature, for serializing and deserializing the data of the crate.
+        serde: "serde" => serde_flag,
+        /// The `std` feature, for `std::error::Error` on the errors and the match cache.
+        std: "std" => std_flag,
+    }
+}
@@ -0,0 +1,75 @@
+use crate::normalize::LEGACY;
+use crate::with::DEPRECATED_WITH;
+use crate::*;
+
+/// The strings that resolve to a license, see [`License::identifiers`].
+///
+/// They are read from the same tables as the lookups, so that they can't disagree:
+///
+/// - The [`id`](Identifiers::id) is found by [`from_id`], or by [`from_id_ext`] for a
+///   license combined with an exception.
+/// - The [`aliases`](Identifiers::aliases) and the [`deprecated_forms`](Identifiers::deprecated_forms)
+///   are rewritten to the id by [`normalize_legacy`], or found by [`from_id_ext`] for a
+///   license combined with an exception. [`normalize_legacy`] also accepts them in any
+///   ASCII case.
+///
+/// The [`name`](Identifiers::name) isn't looked up by this crate, but it is what people
+/// are likely to type, such as in an autocomplete field. Neither are the forms with a
+/// trailing `+` of [`from_id_flexible`] listed, since any id takes one.
+///
+/// Every list is in the order of its table.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Identifiers {
+    id: &'static str,
+    name: &'static str,
+}
+
+impl Identifiers {
+    pub(crate) fn new(id: &'static str, name: &'static str) -> Self {
+        Identifiers { id, name }
+    }
+
+    /// The SPDX id of the license.
+    pub fn id(&self) -> &'static str {
+        self.id
+    }
+
+    /// The full name of the license.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The legacy names of the license that aren't SPDX ids, such as `GPLv2`.
+    pub fn aliases(&self) -> impl Iterator<Item = &'static str> + Clone {
+        let id = self.id;
+        LEGACY
+            .iter()
+            .filter(move |(legacy, replacement, _)| *replacement == id && from_id(legacy).is_none())
+            .map(|(legacy, _, _)| *legacy)
+    }
+
+    /// The deprecated SPDX ids that now mean the license, such as `GPL-2.0` for
+    /// `GPL-2.0-only`.
+    ///
+    /// These are also the ids of deprecated licenses of their own, which [`from_id`]
+    /// returns.
+    pub fn deprecated_forms(&self) -> impl Iterator<Item = &'static str> + Clone {
+        let id = self.id;
+        let legacy = LEGACY
+            .iter()
+            .filter(move |(legacy, replacement, _)| *replacement == id && from_id(legacy).is_some())
+            .map(|(legacy, _, _)| *legacy);
+        let with = DEPRECATED_WITH
+            .iter()
+            .filter(move |(_, replacement)| *replacement == id)
+            .map(|(deprecated, _)| *deprecated);
+        legacy.chain(with)
+    }
+
+    /// The id, then the aliases and the deprecated forms.
+    pub fn all(&self) -> impl Iterator<Item = &'static str> + Clone {
+        core::iter::once(self.id)
+            .chain(self.aliases())
+            .chain(self.deprecated_forms())
+    }
+}
@@ -0,0 +1,244 @@
+use crate::normalize::LEGACY;
+use crate::package::{Token, Tokens};
+use crate::with::{license_with, DEPRECATED_WITH};
+use crate::*;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The lowest similarity of a text to a license for [`identify_str`] to identify it.
+pub const MIN_TEXT_SIMILARITY: f32 = 0.5;
+
+/// The longest [`input_excerpt`](Identification::input_excerpt) of a text, in characters.
+const EXCERPT_LEN: usize = 80;
+
+/// How a license was identified, see [`Identification`].
+///
+/// Each method documents its [`confidence`](Identification::confidence). The scores are
+/// part of the API: they only change in a major release, so that thresholds built on
+/// them, such as sending everything below 0.9 to review, keep their meaning.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Method {
+    /// The input is an SPDX id, or an id with a trailing `+`, as written. Confidence 1.0.
+    ExactId,
+    /// The input is a legacy name that isn't an SPDX id, such as `GPLv3+`, or an id in
+    /// another ASCII case, such as `mit`. Confidence 0.9, or 0.8 for the names that
+    /// don't say if later versions are allowed, such as `GPLv3`, see
+    /// [`Rewrite::lossy`].
+    Alias,
+    /// The input is a deprecated SPDX id, such as `GPL-2.0` or `GPL-2.0+`, resolved to
+    /// the id that replaces it if there is one. Confidence 0.95.
+    DeprecatedId,
+    /// The input is the text of a license, apart from copyright lines, placeholders and
+    /// whitespace, see [`TextDiff::is_trivial`](text::TextDiff::is_trivial). The text of
+    /// a deprecated id is identified as the id that replaces it, such as `GPL-3.0-only`
+    /// for `GPL-3.0`. Confidence 1.0.
+    TextExact,
+    /// The input is similar to the text of a license, with a
+    /// [similarity](text::Match::similarity) of at least [`MIN_TEXT_SIMILARITY`].
+    /// Confidence 0.9 times the similarity, so that it is always below the other methods.
+    TextFuzzy,
+    /// The input is a license of an expression validated by [`PackageLicense::parse`].
+    /// Confidence 1.0.
+    ExpressionLeaf,
+}
+
+/// A license identified from a string, with how it was identified and how far the
+/// result can be trusted.
+///
+/// It is returned by [`identify_str`] and by [`PackageLicense::identify`], so that a
+/// pipeline that gets licenses from ids, legacy names, license files and expressions
+/// can treat them alike, such as by reviewing every result whose
+/// [`confidence`](Identification::confidence) is below a threshold.
+///
+/// Requires the `alloc` feature.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct Identification {
+    /// The license, with whether the input asked for later versions and used a
+    /// deprecated form.
+    pub license: ResolvedId,
+    /// How the license was identified.
+    pub method: Method,
+    /// How far the result can be trusted, from 0 to 1, as documented by each [`Method`].
+    pub confidence: f32,
+    /// The part of the input that was identified: the start of the first line of a
+    /// text, or the license as written in an expression. It is `None` for the ids and
+    /// the names, which are the whole input.
+    pub input_excerpt: Option<String>,
+}
+
+impl Identification {
+    fn new(license: ResolvedId, method: Method, confidence: f32) -> Self {
+        Identification {
+            license,
+            method,
+            confidence,
+            input_excerpt: None,
+        }
+    }
+}
+
+/// Identifies the license of a string, trying it as an id, then as an alias, then as the
+/// text of a license.
+///
+/// The id and the alias are trimmed, and an id may be combined with an exception, as in
+/// `GPL-2.0-only WITH Classpath-exception-2.0`. A text is compared to the licenses of
+/// [`LICENSES`] with [`text::best_match`], and it is identified if it is similar enough,
+/// see [`Method::TextFuzzy`].
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use license::Method;
+///
+/// let gpl = license::identify_str("GPLv3+").unwrap();
+/// assert_eq!(gpl.license.license.id(), "GPL-3.0-or-later");
+/// assert_eq!((gpl.method, gpl.confidence), (Method::Alias, 0.9));
+///
+/// let mit = license::from_id("MIT").unwrap().text();
+/// let file = mit.replace("<year> <copyright holders>", "2024 Example Corp");
+/// let identified = license::identify_str(&file).unwrap();
+/// assert_eq!(identified.license.license.id(), "MIT");
+/// assert_eq!(identified.method, Method::TextExact);
+/// assert_eq!(identified.input_excerpt.as_deref(), Some("MIT License"));
+///
+/// assert!(license::identify_str("All rights reserved.").is_none());
+/// ```
+pub fn identify_str(input: &str) -> Option<Identification> {
+    let trimmed = input.trim();
+    by_id(trimmed)
+        .or_else(|| by_alias(trimmed))
+        .or_else(|| by_text(input))
+}
+
+impl PackageLicense {
+    /// Identifies each license of the expression, in order, with
+    /// [`Method::ExpressionLeaf`].
+    ///
+    /// A license with an exception is identified as the combined license if there is
+    /// one, such as `GPL-2.0-only WITH Classpath-exception-2.0`, and as the license
+    /// alone otherwise.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// let identified = license::declared!("(MIT OR Apache-2.0) AND GPL-2.0+").identify();
+    /// let ids: Vec<_> = identified.iter().map(|i| i.license.license.id()).collect();
+    /// assert_eq!(ids, ["MIT", "Apache-2.0", "GPL-2.0-or-later"]);
+    /// assert_eq!(identified[2].input_excerpt.as_deref(), Some("GPL-2.0+"));
+    /// assert!(identified[2].license.deprecated_form);
+    /// ```
+    pub fn identify(&self) -> Vec<Identification> {
+        let mut tokens = Tokens::new(self.expression());
+        let mut identified = Vec::new();
+        loop {
+            let start = tokens.pos;
+            let license = match tokens.next() {
+                None => return identified,
+                Some((Token::Id, license)) => license,
+                Some(_) => continue,
+            };
+            if let Some(Token::With) = tokens.peek() {
+                tokens.next();
+                tokens.next();
+            }
+            let written = tokens.expression[start..tokens.pos].trim();
+            let found = by_id(written).or_else(|| by_id(license));
+            if let Some(mut found) = found {
+                found.method = Method::ExpressionLeaf;
+                found.confidence = 1.0;
+                found.input_excerpt = Some(written.into());
+                identified.push(found);
+            }
+        }
+    }
+}
+
+/// Identifies an SPDX id, as written.
+fn by_id(id: &str) -> Option<Identification> {
+    if !id.ends_with('+') {
+        let replacement = LEGACY
+            .iter()
+            .filter(|(legacy, _, _)| from_id(legacy).is_some())
+            .map(|&(legacy, replacement, _)| (legacy, replacement))
+            .chain(DEPRECATED_WITH.iter().copied())
+            .find(|&(deprecated, _)| deprecated == id);
+        if let Some((_, replacement)) = replacement {
+            let mut resolved = resolve(replacement)?;
+            resolved.deprecated_form = true;
+            return Some(Identification::new(resolved, Method::DeprecatedId, 0.95));
+        }
+    }
+    let resolved = resolve(id)?;
+    Some(if resolved.deprecated_form {
+        Identification::new(resolved, Method::DeprecatedId, 0.95)
+    } else {
+        Identification::new(resolved, Method::ExactId, 1.0)
+    })
+}
+
+/// Resolves an id with [`from_id_flexible`], or as a license combined with an exception.
+fn resolve(id: &str) -> Option<ResolvedId> {
+    from_id_flexible(id).or_else(|| {
+        license_with(id).map(|license| ResolvedId {
+            license,
+            or_later: false,
+            deprecated_form: false,
+        })
+    })
+}
+
+/// Identifies a legacy name or an id in another ASCII case.
+fn by_alias(name: &str) -> Option<Identification> {
+    if let Some(&(legacy, replacement, lossy)) = LEGACY
+        .iter()
+        .find(|(legacy, _, _)| legacy.eq_ignore_ascii_case(name))
+    {
+        if from_id(legacy).is_some() {
+            return by_id(legacy);
+        }
+        let confidence = if lossy { 0.8 } else { 0.9 };
+        return Some(Identification::new(
+            resolve(replacement)?,
+            Method::Alias,
+            confidence,
+        ));
+    }
+    let (base, plus) = match name.strip_suffix('+') {
+        Some(base) => (base, "+"),
+        None => (name, ""),
+    };
+    let id = LICENSES
+        .iter()
+        .map(|l| l.id())
+        .chain(LICENSES_EXT.iter().map(|l| l.id()))
+        .find(|id| id.eq_ignore_ascii_case(base))?;
+    let mut found = by_id(&[id, plus].concat())?;
+    if found.method == Method::ExactId {
+        found.method = Method::Alias;
+        found.confidence = 0.9;
+    }
+    Some(found)
+}
+
+/// Identifies the text of a license.
+fn by_text(text: &str) -> Option<Identification> {
+    let found = text::best_match(text).filter(|m| m.similarity() >= MIN_TEXT_SIMILARITY)?;
+    // The texts of deprecated ids, such as GPL-3.0, are those of the ids that replace them.
+    let license = ResolvedId {
+        license: by_id(found.license().id()).map_or(found.license(), |i| i.license.license),
+        or_later: false,
+        deprecated_form: false,
+    };
+    let mut identified = if text::diff(found.license().text(), text).is_trivial() {
+        Identification::new(license, Method::TextExact, 1.0)
+    } else {
+        Identification::new(license, Method::TextFuzzy, 0.9 * found.similarity())
+    };
+    let line = text.lines().map(str::trim).find(|line| !line.is_empty());
+    identified.input_excerpt = line.map(|line| line.chars().take(EXCERPT_LEN).collect());
+    Some(identified)
+}
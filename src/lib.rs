@@ -19,23 +19,182 @@
 //! let gcc = license::from_id_exception("GCC-exception-3.1").unwrap();
 //! assert_eq!(gcc.name(), "GCC Runtime Library exception 3.1");
 //! ```
+//!
+//! The crate contains no unsafe code and builds on Rust 1.57 or later. Raising the minimum
+//! supported Rust version is a breaking change. A future feature that needs unsafe code,
+//! such as FFI bindings, must be off by default, so that the default build keeps
+//! `#![forbid(unsafe_code)]`.
 
 #![no_std]
+#![forbid(unsafe_code)]
 #![doc(html_root_url = "https://docs.rs/license")]
 #![deny(missing_docs)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod apply;
+#[cfg(feature = "alloc")]
+pub mod attribution;
+mod badge;
+mod binary;
+mod busl;
+mod category;
+pub mod compat;
+mod composite;
+#[cfg(feature = "alloc")]
+mod data;
+mod diagnostics;
+mod display;
+pub mod error;
+/// All license exceptions from the [SPDX License List](https://spdx.org/licenses/exceptions-index.html).
+///
+/// Like the licenses, every exception is a unit struct that [`from_id_exception`]
+/// returns a reference to. The texts are the SPDX texts, and their checksums are
+/// recorded in `src/provenance.tsv`. The items of this module are also re-exported at
+/// the crate root.
+///
+/// # Examples
+/// ```
+/// use license::exceptions::LLVM_exception;
+/// use license::LicenseException;
+///
+/// assert_eq!(LLVM_exception.id(), "LLVM-exception");
+/// assert!(LLVM_exception.text().starts_with("---- LLVM Exceptions to the Apache 2.0 License ----"));
+///
+/// for id in ["Classpath-exception-2.0", "GCC-exception-3.1", "Bison-exception-2.2", "Autoconf-exception-3.0"] {
+///     assert_eq!(license::from_id_exception(id).unwrap().id(), id);
+/// }
+/// ```
 #[allow(bad_style)]
-mod exceptions;
+pub mod exceptions;
+pub mod export;
+#[cfg(feature = "alloc")]
+pub mod expression;
 mod ext;
+mod family;
+mod features;
+mod identifiers;
+#[cfg(feature = "alloc")]
+mod identify;
+/// All licenses from the [SPDX License List](https://spdx.org/licenses/).
+///
+/// Every license is a unit struct, and the lookup functions such as [`from_id`] and
+/// [`from_id_ext`] return references to these same types. A struct is named after the
+/// id, with `_` for the separators, such as `Apache_2_0`, and `ZERO_BSD` for `0BSD`,
+/// which can't start with a digit. Searching the docs for the id finds it too. The
+/// items of this module are also re-exported at the crate root.
+///
+/// Each struct is documented with a one line description, and the licenses of the
+/// larger families are also grouped in the [`gnu`], [`bsd`] and [`creative_commons`]
+/// modules.
+///
+/// # Examples
+/// ```
+/// use license::licenses::Apache_2_0;
+/// use license::License;
+///
+/// assert_eq!(Apache_2_0.id(), "Apache-2.0");
+/// assert!(Apache_2_0.is_osi_approved());
+///
+/// let apache = license::from_id_ext("Apache-2.0").unwrap();
+/// assert!(apache.as_any().is::<Apache_2_0>());
+///
+/// use license::licenses::gnu::GPL_3_0_only;
+/// assert_eq!(GPL_3_0_only.family(), license::Family::Gnu);
+/// ```
 #[allow(bad_style)]
-mod licenses;
+pub mod licenses;
+mod lineage;
+mod linking;
+#[cfg(feature = "alloc")]
+pub mod manifest;
+pub mod migration;
+mod normalize;
+mod offer;
+#[cfg(feature = "alloc")]
+pub mod overrides;
+mod package;
+pub mod params;
+mod patent;
+#[cfg(feature = "alloc")]
+pub mod policy;
+pub mod prelude;
+#[cfg(feature = "alloc")]
+mod registry;
+pub mod report;
+mod resolve;
+#[cfg(feature = "fs")]
+pub mod scan;
+mod sentinel;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "spdx-doc")]
+pub mod spdx_doc;
+#[cfg(feature = "alloc")]
+pub mod stats;
+#[cfg(feature = "alloc")]
+pub mod tags;
+pub mod text;
+#[allow(bad_style)]
+mod with;
+#[cfg(feature = "fs")]
+pub mod write;
 
+pub use apply::*;
+pub use badge::*;
+pub use binary::*;
+pub use busl::*;
+pub use category::*;
+pub use composite::*;
+#[cfg(feature = "alloc")]
+pub use data::*;
+pub use diagnostics::*;
+pub use display::*;
+pub use error::*;
 pub use exceptions::*;
 pub use ext::*;
+pub use family::*;
+pub use features::*;
+pub use identifiers::*;
+#[cfg(feature = "alloc")]
+pub use identify::*;
 pub use licenses::*;
+pub use linking::*;
+pub use normalize::*;
+pub use offer::*;
+pub use package::*;
+pub use patent::*;
+#[cfg(feature = "alloc")]
+pub use registry::*;
+pub use resolve::*;
+pub use sentinel::*;
+pub use with::*;
 
 /// Base functionality for all licenses.
-pub trait License {
+///
+/// Licenses are `Send + Sync`, and every reference returned by the lookup functions
+/// and by the methods of the trait is `'static`, so licenses can be shared freely
+/// between threads.
+///
+/// A `dyn License` is compared, ordered and hashed by its [`id`](License::id), so that
+/// licenses can be kept in a `BTreeSet` or a `HashSet`. The order is that of the ids as
+/// strings, which is case-sensitive and puts `MIT` before `MIT-CMU` before `MITNFA`.
+/// [`LICENSES`] and the outputs of the crate that are ordered by id follow it.
+///
+/// # Examples
+/// ```
+/// use std::collections::BTreeSet;
+///
+/// let ids = ["MIT", "Apache-2.0", "MIT", "0BSD"];
+/// let set: BTreeSet<_> = ids.iter().map(|id| license::from_id(id).unwrap()).collect();
+/// let sorted: Vec<_> = set.iter().map(|l| l.id()).collect();
+/// assert_eq!(sorted, ["0BSD", "Apache-2.0", "MIT"]);
+/// assert!(license::from_id("MIT").unwrap() < license::from_id("MIT-CMU").unwrap());
+/// ```
+pub trait License: Send + Sync {
     /// The name of the license.
     ///
     /// Corresponds to the *Full name* column from [spdx.org/licenses](https://spdx.org/licenses/).
@@ -47,11 +206,51 @@ pub trait License {
     fn id(&self) -> &'static str;
 
     /// The license text.
+    ///
+    /// It is the English text of the SPDX License List, verbatim, as recorded in
+    /// `src/provenance.tsv`. The list has no translations, so neither does the crate,
+    /// not even the official language versions of licenses such as EUPL-1.2, since a
+    /// text that can't be checked against its source isn't shipped.
     fn text(&self) -> &'static str;
 
     /// The standard license header.
     fn header(&self) -> Option<&'static str>;
 
+    /// The sections of the license text, in order.
+    ///
+    /// Their spans cover the whole [`text`](License::text): the text before the first
+    /// numbered section and after the last one, such as the appendix of the GPL, are
+    /// unnumbered sections. Free-form texts, such as MIT, have no sections.
+    ///
+    /// # Examples
+    /// ```
+    /// let gpl = license::from_id("GPL-3.0-only").unwrap();
+    /// let sections = gpl.sections();
+    /// assert_eq!(sections[1].number(), Some("0"));
+    /// assert_eq!(sections[1].title(), "Definitions");
+    ///
+    /// assert!(license::from_id("MIT").unwrap().sections().is_empty());
+    /// ```
+    fn sections(&self) -> &'static [text::Section] {
+        &[]
+    }
+
+    /// The text of a numbered section, heading included.
+    ///
+    /// # Examples
+    /// ```
+    /// let apache = license::from_id("Apache-2.0").unwrap();
+    /// assert!(apache.section("4").unwrap().starts_with("4. Redistribution."));
+    /// assert!(apache.section("10").is_none());
+    /// ```
+    fn section(&self, number: &str) -> Option<&'static str> {
+        let section = self
+            .sections()
+            .iter()
+            .find(|s| s.number() == Some(number))?;
+        self.text().get(section.span())
+    }
+
     /// Says if the license is OSI approved.
     ///
     /// Corresponds to the *OSI Approved?* column from [spdx.org/licenses](https://spdx.org/licenses/).
@@ -67,22 +266,644 @@ pub trait License {
 
     /// Relevant sources.
     fn see_also(&self) -> &'static [&'static str];
+
+    /// The license as [`Any`](core::any::Any), for downcasting to its concrete type.
+    ///
+    /// Licenses are zero-sized unit structs, so comparing references with
+    /// [`ptr::eq`](core::ptr::eq) does not reliably tell licenses apart.
+    /// Compare the types through this method, or compare the licenses with `==`, which
+    /// compares their [`id`](License::id)s.
+    ///
+    /// # Examples
+    /// ```
+    /// let mit = license::from_id_ext("MIT").unwrap();
+    /// assert!(mit.as_any().is::<license::MIT>());
+    /// assert!(mit.as_any().downcast_ref::<license::Apache_2_0>().is_none());
+    /// ```
+    fn as_any(&self) -> &dyn core::any::Any;
+
+    /// The exception the license is combined with, if any.
+    ///
+    /// Such a combination corresponds to the SPDX `WITH` operator. Its
+    /// [`text`](License::text) is the text of the license alone, and its
+    /// [`LicenseExt`] data describes the combined terms, so an exception that
+    /// relaxes a condition of the license clears the corresponding flag.
+    fn exception(&self) -> Option<&'static dyn LicenseException> {
+        None
+    }
+
+    /// The extension data of the license, if it has any.
+    ///
+    /// This is the entry of [`LICENSES_EXT`] with the same type, so it agrees with
+    /// [`from_id_ext`] for every license.
+    ///
+    /// # Examples
+    /// ```
+    /// let mit = license::from_id("MIT").unwrap();
+    /// assert!(mit.ext().unwrap().conditions().license_and_copyright_notice());
+    /// assert!(license::from_id("AAL").unwrap().ext().is_none());
+    /// ```
+    fn ext(&self) -> Option<&'static dyn LicenseExt> {
+        let ty = self.as_any().type_id();
+        LICENSES_EXT
+            .iter()
+            .copied()
+            .find(|l| l.as_any().type_id() == ty)
+    }
+
+    /// The family of the license, such as [`Family::Gnu`] for all GNU licenses.
+    ///
+    /// This is curated data that is not part of SPDX.
+    fn family(&self) -> Family {
+        family::family(self.id())
+    }
+
+    /// The terms of the license, if it is a Creative Commons license.
+    ///
+    /// This is curated data that is not part of SPDX.
+    fn cc_terms(&self) -> Option<creative_commons::CcTerms> {
+        creative_commons::terms(self.id())
+    }
+
+    /// The year the license was published, if it has several versions.
+    ///
+    /// This is curated data that is not part of SPDX.
+    fn published_year(&self) -> Option<u16> {
+        lineage::published_year(self.id())
+    }
+
+    /// The previous version of the license.
+    ///
+    /// This is curated data that is not part of SPDX, see also
+    /// [`successor`](License::successor).
+    fn predecessor(&self) -> Option<&'static dyn License> {
+        lineage::predecessor(self.id())
+    }
+
+    /// The next version of the license, for hints that a newer version is available.
+    ///
+    /// This is curated data that is not part of SPDX. The `-only` and `-or-later`
+    /// licenses of the GNU family are separate lineages.
+    ///
+    /// # Examples
+    /// ```
+    /// let mpl = license::from_id("MPL-1.1").unwrap();
+    /// let next = mpl.successor().unwrap();
+    /// assert_eq!(next.id(), "MPL-2.0");
+    /// assert_eq!(
+    ///     format!("{} ({}), successor to {} ({})", next.id(), next.published_year().unwrap(), mpl.id(), mpl.published_year().unwrap()),
+    ///     "MPL-2.0 (2012), successor to MPL-1.1 (1999)"
+    /// );
+    /// assert!(license::from_id("MIT").unwrap().successor().is_none());
+    /// ```
+    fn successor(&self) -> Option<&'static dyn License> {
+        lineage::successor(self.id())
+    }
+
+    /// The strings that the lookups of this crate resolve to the license, see [`Identifiers`].
+    ///
+    /// # Examples
+    /// ```
+    /// let mit = license::from_id("MIT").unwrap().identifiers();
+    /// assert_eq!(mit.name(), "MIT License");
+    /// assert_eq!(mit.aliases().collect::<Vec<_>>(), ["MIT/X11"]);
+    ///
+    /// let gpl = license::from_id("GPL-2.0-only").unwrap().identifiers();
+    /// assert_eq!(gpl.deprecated_forms().collect::<Vec<_>>(), ["GPL-2.0"]);
+    /// ```
+    fn identifiers(&self) -> Identifiers {
+        Identifiers::new(self.id(), self.name())
+    }
+
+    /// The fingerprint of the license text, see [`text::fingerprint`].
+    ///
+    /// It is generated for the licenses of this crate, and computed from the
+    /// [text](License::text) on each call by default.
+    fn fingerprint(&self) -> text::Fingerprint {
+        text::fingerprint(self.text())
+    }
+
+    /// The URL of a [shields.io](https://shields.io) static badge for the license.
+    fn badge_url(&self, style: BadgeStyle) -> BadgeUrl {
+        BadgeUrl {
+            id: self.id(),
+            style,
+        }
+    }
+
+    /// A Markdown badge for the license, linking to `link` if provided.
+    fn badge_markdown<'a>(&self, link: Option<&'a str>) -> BadgeMarkdown<'a> {
+        BadgeMarkdown {
+            url: self.badge_url(BadgeStyle::default()),
+            link,
+        }
+    }
 }
 
 /// Extension trait for supported licenses.
+///
+/// # Implementing
+///
+/// Implement [`metadata`](LicenseExt::metadata), from which every other method is
+/// derived. New accessors are only ever added as provided methods, and new data as
+/// fields of [`Metadata`] with a default, so implementations outside of this crate keep
+/// compiling and stay consistent. Overriding the other provided methods is possible but
+/// discouraged, since they would then disagree with the metadata.
+///
+/// Like a `dyn License`, a `dyn LicenseExt` is compared, ordered and hashed by its id.
 pub trait LicenseExt: License {
+    /// The permissions, conditions and limitations of the license.
+    fn metadata(&self) -> Metadata;
+
     /// The permissions of the license.
-    fn permissions(&self) -> Permissions;
+    fn permissions(&self) -> Permissions {
+        self.metadata().permissions()
+    }
 
     /// The conditions of the license.
-    fn conditions(&self) -> Conditions;
+    fn conditions(&self) -> Conditions {
+        self.metadata().conditions()
+    }
 
     /// The limitations of the license.
-    fn limitations(&self) -> Limitations;
+    fn limitations(&self) -> Limitations {
+        self.metadata().limitations()
+    }
+
+    /// A fixed-width summary of the flags on one line, such as
+    /// `✔✔✔–✔ | ––©–––– | ⚠–⚠–` for MIT, see [`CompactLegend`].
+    fn compact_legend(&self) -> CompactLegend {
+        CompactLegend::new(self.metadata())
+    }
+
+    /// A small number that identifies the license, for compact storage.
+    ///
+    /// Codes are permanently stable: a license keeps its code in every release, new
+    /// licenses get new codes, and codes are never reused. The code `0` is never assigned,
+    /// it is the default for licenses implemented outside of this crate.
+    /// See [`from_code`] for the reverse lookup.
+    ///
+    /// # Examples
+    /// ```
+    /// let mit = license::from_id_ext("MIT").unwrap();
+    /// assert_eq!(license::from_code(mit.code()).unwrap().id(), "MIT");
+    /// ```
+    fn code(&self) -> u16 {
+        0
+    }
+
+    /// A lowercase identifier of the license that is safe in file names and URLs.
+    ///
+    /// It is the id with ASCII letters lowercased, spaces replaced by `-` and a `+`
+    /// replaced by `-plus`, so that `MIT` becomes `mit`, `OFL-1.1` keeps its dot as
+    /// `ofl-1.1`, and an exception is joined as in
+    /// `gpl-2.0-only-with-classpath-exception-2.0`. No two licenses of this crate share
+    /// a slug. The empty slug is the default for licenses implemented outside of this
+    /// crate. See [`from_slug`] for the reverse lookup.
+    ///
+    /// # Examples
+    /// ```
+    /// let wtfpl = license::from_id_ext("WTFPL").unwrap();
+    /// assert_eq!(wtfpl.slug(), "wtfpl");
+    /// assert_eq!(license::from_slug("agpl-3.0-only").unwrap().id(), "AGPL-3.0-only");
+    /// ```
+    fn slug(&self) -> &'static str {
+        ""
+    }
+
+    /// Says if offering the software only as a network service imposes obligations.
+    ///
+    /// Defaults to [`network_use_is_distribution`](Conditions::network_use_is_distribution),
+    /// but licenses whose terms are triggered in other ways override it, such as the
+    /// external deployment clause of APSL-2.0.
+    ///
+    /// # Examples
+    /// ```
+    /// let agpl = license::from_id_ext("AGPL-3.0-only").unwrap();
+    /// let gpl = license::from_id_ext("GPL-3.0-only").unwrap();
+    /// let apsl = license::from_id_ext("APSL-2.0").unwrap();
+    /// assert!(agpl.triggers_on_network_use());
+    /// assert!(!gpl.triggers_on_network_use());
+    /// assert!(apsl.triggers_on_network_use());
+    /// ```
+    fn triggers_on_network_use(&self) -> bool {
+        self.conditions().network_use_is_distribution()
+    }
+
+    /// Says if the license makes the source available but restricts its use, so that it
+    /// isn't open source, such as the BUSL-1.1 and the Elastic-2.0.
+    ///
+    /// Such licenses are never [`permissive`], even if they allow commercial use.
+    ///
+    /// # Examples
+    /// ```
+    /// let elastic = license::from_id_ext("Elastic-2.0").unwrap();
+    /// assert!(elastic.is_source_available() && !elastic.is_osi_approved());
+    /// assert!(!license::from_id_ext("MIT").unwrap().is_source_available());
+    /// ```
+    fn is_source_available(&self) -> bool {
+        false
+    }
+
+    /// What the license says about the patents of its contributors, see
+    /// [`PatentPosture`].
+    ///
+    /// For licenses outside of this crate, it is derived from the
+    /// [`patent_rights`](Permissions::patent_rights) and
+    /// [`no_patent_rights`](Limitations::no_patent_rights) flags, which can't express a
+    /// [grant with termination](PatentPosture::GrantWithTermination).
+    ///
+    /// # Examples
+    /// ```
+    /// use license::PatentPosture;
+    ///
+    /// let ms_pl = license::from_id_ext("MS-PL").unwrap();
+    /// assert_eq!(ms_pl.patent_posture(), PatentPosture::GrantWithTermination);
+    /// assert!(ms_pl.permissions().patent_rights());
+    /// ```
+    fn patent_posture(&self) -> PatentPosture {
+        if self.limitations().no_patent_rights() {
+            PatentPosture::ExplicitNonGrant
+        } else if self.permissions().patent_rights() {
+            PatentPosture::ExpressGrant
+        } else {
+            PatentPosture::Silent
+        }
+    }
+
+    /// Says if the license is permissive: it has no [`same_license`](Conditions::same_license),
+    /// no [`disclose_sources`](Conditions::disclose_sources) and no network clause, see
+    /// [`triggers_on_network_use`](LicenseExt::triggers_on_network_use), it allows
+    /// commercial use, distribution and modification, and it isn't
+    /// [source available](LicenseExt::is_source_available).
+    ///
+    /// These are the licenses of [`permissive`]. The licenses that only restrict how the
+    /// software is used, such as CC-BY-NC-4.0 and BUSL-1.1, aren't permissive even though
+    /// they have no copyleft, and neither are the [sentinels](UNKNOWN).
+    ///
+    /// # Examples
+    /// ```
+    /// let permissive = |id| license::from_id_ext(id).unwrap().is_permissive();
+    /// assert!(permissive("MIT") && permissive("Apache-2.0"));
+    /// assert!(!permissive("MPL-2.0") && !permissive("CC-BY-NC-4.0"));
+    /// assert!(!license::UNKNOWN.is_permissive());
+    /// ```
+    fn is_permissive(&self) -> bool {
+        let conditions = self.conditions();
+        let permissions = self.permissions();
+        !sentinel::is_sentinel(self.id())
+            && !conditions.same_license()
+            && !conditions.disclose_sources()
+            && !self.triggers_on_network_use()
+            && !self.is_source_available()
+            && permissions.commercial_use()
+            && permissions.distribution()
+            && permissions.modification()
+    }
+
+    /// Says if the source must be made available when the software is `distributed`, or
+    /// offered as a `network_service`, or both.
+    ///
+    /// Distribution requires it if the license has
+    /// [`disclose_sources`](Conditions::disclose_sources). A network service requires it
+    /// if the license has [`network_use_is_distribution`](Conditions::network_use_is_distribution),
+    /// which implies the disclosure, or if the service counts as distribution, see
+    /// [`triggers_on_network_use`](LicenseExt::triggers_on_network_use), and the license
+    /// has `disclose_sources`. Software that is only used privately never requires it.
+    ///
+    /// For the [sentinels](UNKNOWN), nothing is known, so any distribution or network
+    /// service requires it.
+    ///
+    /// # Examples
+    /// ```
+    /// let gpl = license::from_id_ext("GPL-3.0-only").unwrap();
+    /// let agpl = license::from_id_ext("AGPL-3.0-only").unwrap();
+    /// assert!(gpl.requires_source_disclosure(true, false));
+    /// assert!(!gpl.requires_source_disclosure(false, true));
+    /// assert!(agpl.requires_source_disclosure(false, true));
+    /// assert!(!agpl.requires_source_disclosure(false, false));
+    /// ```
+    fn requires_source_disclosure(&self, distributed: bool, network_service: bool) -> bool {
+        if sentinel::is_sentinel(self.id()) {
+            return distributed || network_service;
+        }
+        let conditions = self.conditions();
+        let distributed = distributed || network_service && self.triggers_on_network_use();
+        distributed && conditions.disclose_sources()
+            || network_service && conditions.network_use_is_distribution()
+    }
+
+    /// Says if the software may be modified and the changes kept private, as long as it
+    /// is never distributed, when it is only used internally or, if
+    /// `deployed_as_network_service`, also offered to others over a network.
+    ///
+    /// The answer is conservative:
+    ///
+    /// - Internal use is use by the organization itself. Giving a copy to a contractor or
+    ///   to another company of a group is distribution under most licenses.
+    /// - It is `false` if the license doesn't grant
+    ///   [`modification`](Permissions::modification), such as CC-BY-ND-4.0, even where
+    ///   changes that are never shared might be allowed.
+    /// - A network service keeps the changes private only if it doesn't
+    ///   [`require the source`](LicenseExt::requires_source_disclosure), which it does
+    ///   for AGPL-3.0-only, and for the licenses for which it counts as distribution.
+    /// - A license whose terms are triggered by internal use overrides the method, such as
+    ///   RPL-1.5, which requires the source of any deployment and isn't part of this
+    ///   crate yet.
+    ///
+    /// For the [sentinels](UNKNOWN), nothing is known, so it is always `false`.
+    ///
+    /// # Examples
+    /// ```
+    /// let gpl = license::from_id_ext("GPL-3.0-only").unwrap();
+    /// let agpl = license::from_id_ext("AGPL-3.0-only").unwrap();
+    /// assert!(gpl.private_modifications_allowed(false));
+    /// assert!(gpl.private_modifications_allowed(true));
+    /// assert!(agpl.private_modifications_allowed(false));
+    /// assert!(!agpl.private_modifications_allowed(true));
+    /// assert!(!license::UNKNOWN.private_modifications_allowed(false));
+    /// ```
+    fn private_modifications_allowed(&self, deployed_as_network_service: bool) -> bool {
+        !sentinel::is_sentinel(self.id())
+            && self.permissions().modification()
+            && !self.requires_source_disclosure(false, deployed_as_network_service)
+    }
+
+    /// What the license requires to distribute an unmodified, statically linked binary
+    /// of the software.
+    ///
+    /// This is curated data that is not part of SPDX, derived from the
+    /// [`conditions`](LicenseExt::conditions) for licenses outside of this crate.
+    ///
+    /// # Examples
+    /// ```
+    /// let mit = license::from_id_ext("MIT").unwrap().binary_distribution_requirements();
+    /// assert!(mit.license_text() && !mit.source());
+    ///
+    /// let gpl = license::from_id_ext("GPL-3.0-only").unwrap().binary_distribution_requirements();
+    /// assert!(gpl.corresponding_source() && !gpl.needs_legal_review());
+    /// ```
+    fn binary_distribution_requirements(&self) -> BinaryRequirements {
+        BinaryRequirements::new(self.id(), self.conditions())
+    }
+
+    /// The template of the written offer that lets a binary be distributed without its
+    /// source, which is instead given on request, for the GNU licenses that allow one.
+    ///
+    /// The offer follows the license version: the GPL-2.0 offers the source to any third
+    /// party for the cost of physically performing the distribution, and the version 3
+    /// licenses offer it to anyone who possesses the object code, on a physical medium
+    /// or from a network server. Its placeholders are `<company>`, `<contact>`,
+    /// `<years>` and `<medium>`, which `render_source_offer` fills in with the `alloc`
+    /// feature.
+    ///
+    /// This is curated boilerplate that is not part of SPDX, and not legal advice: for
+    /// instance, the version 3 offer is only allowed for a physical product. It is `None`
+    /// for the other licenses.
+    ///
+    /// # Examples
+    /// ```
+    /// let gpl = license::from_id_ext("GPL-2.0-only").unwrap();
+    /// assert!(gpl.source_offer_template().unwrap().contains("For at least <years> years"));
+    /// assert!(license::from_id_ext("MPL-2.0").unwrap().source_offer_template().is_none());
+    /// ```
+    fn source_offer_template(&self) -> Option<&'static str> {
+        offer::offer(self.id()).map(|(template, _)| template)
+    }
+
+    /// The [written offer](LicenseExt::source_offer_template) of the license, with the
+    /// placeholders filled in from `params`.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::OfferParams;
+    ///
+    /// let params = OfferParams {
+    ///     company: "Example Corp",
+    ///     contact: "source@example.com",
+    ///     years: None,
+    ///     medium: None,
+    /// };
+    /// let gpl = license::from_id_ext("GPL-3.0-only").unwrap();
+    /// let offer = gpl.render_source_offer(&params).unwrap();
+    /// assert!(offer.contains("For at least 3 years, and for as long as Example Corp offers spare parts"));
+    /// assert!(offer.ends_with("To request a copy, contact source@example.com.\n"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn render_source_offer(&self, params: &OfferParams) -> Option<alloc::string::String> {
+        let (template, medium) = offer::offer(self.id())?;
+        Some(offer::render(template, medium, params))
+    }
+
+    /// What the license requires of a program that uses the software as a library, in
+    /// the way given by `link`.
+    ///
+    /// This is curated data that is not part of SPDX, see [`LinkObligations`].
+    ///
+    /// # Examples
+    /// ```
+    /// use license::{Coverage, Linkage};
+    ///
+    /// let lgpl = license::from_id_ext("LGPL-3.0-only").unwrap();
+    /// let dynamic = lgpl.obligations_for_linking(Linkage::Dynamic);
+    /// assert_eq!(dynamic.coverage(), Coverage::LibraryOnly);
+    /// assert!(dynamic.relinking() && dynamic.notice());
+    /// ```
+    fn obligations_for_linking(&self, link: Linkage) -> LinkObligations {
+        LinkObligations::new(self.id(), self.conditions(), link)
+    }
+
+    /// The ids of the licenses that the license itself allows derivatives to be
+    /// distributed under, such as the compatible licences listed in the appendix of
+    /// EUPL-1.2.
+    ///
+    /// This is the normative list of the license text, so it is empty for the many
+    /// licenses that don't have one, even when they are compatible with others. Every
+    /// id resolves through [`from_id_ext`].
+    ///
+    /// # Examples
+    /// ```
+    /// let eupl = license::from_id_ext("EUPL-1.2").unwrap();
+    /// assert!(eupl.compatible_outbound().contains(&"GPL-3.0-only"));
+    /// assert!(license::from_id_ext("MPL-2.0").unwrap().compatible_outbound().is_empty());
+    /// ```
+    fn compatible_outbound(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// The span of the [`text`](License::text) that supports a flag, such as the clause
+    /// that requires documenting changes, for an audit.
+    ///
+    /// This is curated data that is not part of SPDX, so far for Apache-2.0,
+    /// GPL-3.0-only, MPL-2.0 and MIT. It is `None` for the other licenses, and for the
+    /// flags that the license doesn't set. A span is the sentence or the paragraph that
+    /// grants or requires the flag, and several flags may share one.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::{Condition, Limitation};
+    ///
+    /// let apache = license::from_id_ext("Apache-2.0").unwrap();
+    /// let span = apache.flag_provenance(Condition::DocumentChanges.into()).unwrap();
+    /// assert!(apache.text()[span].ends_with("stating that You changed the files; and"));
+    /// assert_eq!(apache.flag_provenance(Condition::SameLicense.into()), None);
+    ///
+    /// let span = apache.flag_provenance(Limitation::NoTrademarkRights.into()).unwrap();
+    /// let section = apache.sections().iter().find(|s| s.span().contains(&span.start));
+    /// assert_eq!(section.unwrap().title(), "Trademarks");
+    /// ```
+    fn flag_provenance(&self, _flag: Flag) -> Option<text::TextSpan> {
+        None
+    }
+
+    /// The clause that restricts using the names or the trademarks of the licensor and
+    /// the contributors, verbatim from the [`text`](License::text), without its number
+    /// or heading.
+    ///
+    /// The [`no_trademark_rights`](Limitations::no_trademark_rights) flag doesn't tell
+    /// these clauses apart: the non-endorsement clause of BSD-3-Clause forbids promoting
+    /// products with the names of the contributors, and sets no flag, while section 6 of
+    /// Apache-2.0 withholds a license to the trademarks. This is curated data that is not
+    /// part of SPDX, so far for Apache-2.0, BSD-3-Clause, BSD-3-Clause-Clear and MS-PL.
+    /// It is `None` for the other licenses.
+    ///
+    /// # Examples
+    /// ```
+    /// let bsd = license::from_id_ext("BSD-3-Clause").unwrap();
+    /// let clause = bsd.endorsement_restriction().unwrap();
+    /// assert!(clause.starts_with("Neither the name of the copyright holder"));
+    /// assert!(!bsd.limitations().no_trademark_rights());
+    ///
+    /// let apache = license::from_id_ext("Apache-2.0").unwrap();
+    /// assert!(apache.endorsement_restriction().unwrap().starts_with("This License does not grant permission to use the trade names"));
+    ///
+    /// assert_eq!(license::from_id_ext("MIT").unwrap().endorsement_restriction(), None);
+    /// ```
+    fn endorsement_restriction(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Says if code under the MPL-2.0 may also be distributed under one of its
+    /// Secondary Licenses, the GPL-2.0 or later, the LGPL-2.1 or later and the AGPL-3.0
+    /// or later, when it is combined with code under them.
+    ///
+    /// It is `false` for `MPL-2.0-no-copyleft-exception`, the MPL-2.0 with the
+    /// "Incompatible With Secondary Licenses" notice of Exhibit B, which its
+    /// [`header`](License::header) includes. It is `None` for other licenses.
+    ///
+    /// # Examples
+    /// ```
+    /// let mpl = license::from_id_ext("MPL-2.0").unwrap();
+    /// assert_eq!(mpl.mpl_secondary_license_allowed(), Some(true));
+    /// let exhibit_b = license::from_id_ext("MPL-2.0-no-copyleft-exception").unwrap();
+    /// assert_eq!(exhibit_b.mpl_secondary_license_allowed(), Some(false));
+    /// assert_eq!(license::from_id_ext("MIT").unwrap().mpl_secondary_license_allowed(), None);
+    /// ```
+    fn mpl_secondary_license_allowed(&self) -> Option<bool> {
+        None
+    }
+
+    /// The license's instructions on how to apply it to a program, with the program
+    /// name, author and year filled in.
+    ///
+    /// This is the source header and, for the GPL, the notice of interactive
+    /// programs, as given by the license text. It is `None` for licenses without such
+    /// instructions.
+    ///
+    /// # Examples
+    /// ```
+    /// let gpl = license::from_id_ext("GPL-3.0-only").unwrap();
+    /// let instructions = gpl.apply_instructions("frobnicate", "Jane Doe", 2024).unwrap();
+    /// let header = instructions.header().to_string();
+    /// assert!(header.starts_with("frobnicate\nCopyright (C) 2024 Jane Doe\n"));
+    /// assert!(instructions
+    ///     .interactive_notice()
+    ///     .unwrap()
+    ///     .to_string()
+    ///     .starts_with("frobnicate  Copyright (C) 2024  Jane Doe\n"));
+    ///
+    /// assert!(license::from_id_ext("MIT").unwrap().apply_instructions("frobnicate", "Jane Doe", 2024).is_none());
+    /// ```
+    fn apply_instructions<'a>(
+        &self,
+        _program: &'a str,
+        _author: &'a str,
+        _year: u16,
+    ) -> Option<ApplyInstructions<'a>> {
+        None
+    }
+
+    /// The copyright and license notice that copies of the software must include, with
+    /// the copyright holder and year filled in.
+    ///
+    /// This is the whole text for licenses such as MIT and BSD-3-Clause, and the
+    /// boilerplate notice of the appendix for Apache-2.0. It is `None` for licenses
+    /// whose notice has not been curated.
+    ///
+    /// # Examples
+    /// ```
+    /// let bsd = license::from_id_ext("BSD-3-Clause").unwrap();
+    /// let notice = bsd.notice("Jane Doe", 2024).unwrap().to_string();
+    /// assert!(notice.starts_with("Copyright (c) 2024 Jane Doe. All rights reserved.\n"));
+    ///
+    /// assert!(license::from_id_ext("GPL-3.0-only").unwrap().notice("Jane Doe", 2024).is_none());
+    /// ```
+    fn notice<'a>(&self, _holder: &'a str, _year: u16) -> Option<Notice<'a>> {
+        None
+    }
+
+    /// The [`notice`](LicenseExt::notice) for [`Notice::EXAMPLE_HOLDER`] and
+    /// [`Notice::EXAMPLE_YEAR`], to show what the notice of a license looks like.
+    ///
+    /// # Examples
+    /// ```
+    /// let apache = license::from_id_ext("Apache-2.0").unwrap();
+    /// let example = apache.notice_example().unwrap().to_string();
+    /// assert!(example.starts_with("Copyright 2024 Example Corp\n\nLicensed under the Apache License"));
+    /// ```
+    fn notice_example(&self) -> Option<Notice<'static>> {
+        self.notice(Notice::EXAMPLE_HOLDER, Notice::EXAMPLE_YEAR)
+    }
+}
+
+macro_rules! impl_by_id {
+    ($($trait:ident),*) => {$(
+        impl PartialEq for dyn $trait + '_ {
+            fn eq(&self, other: &Self) -> bool {
+                self.id() == other.id()
+            }
+        }
+
+        impl Eq for dyn $trait + '_ {}
+
+        impl PartialOrd for dyn $trait + '_ {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for dyn $trait + '_ {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.id().cmp(other.id())
+            }
+        }
+
+        impl core::hash::Hash for dyn $trait + '_ {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.id().hash(state)
+            }
+        }
+    )*};
 }
 
+impl_by_id!(License, LicenseExt);
+
 /// Base functionality for all license exceptions.
-pub trait LicenseException {
+///
+/// Like licenses, exceptions are `Send + Sync` and are returned as `'static` references.
+pub trait LicenseException: Send + Sync {
     /// The name of the exception.
     fn name(&self) -> &'static str;
 
@@ -100,4 +921,22 @@ pub trait LicenseException {
 
     /// Relevant sources.
     fn see_also(&self) -> &'static [&'static str];
+
+    /// The ids of the licenses the exception is meant to be combined with.
+    ///
+    /// This is curated data that is not part of SPDX. It is empty
+    /// for exceptions whose pairings have not been curated.
+    ///
+    /// # Examples
+    /// ```
+    /// let llvm = license::from_id_exception("LLVM-exception").unwrap();
+    /// assert_eq!(llvm.applicable_to(), ["Apache-2.0"]);
+    ///
+    /// let classpath = license::from_id_exception("Classpath-exception-2.0").unwrap();
+    /// assert!(classpath.applicable_to().contains(&"GPL-2.0-only"));
+    /// assert!(!classpath.applicable_to().contains(&"MIT"));
+    /// ```
+    fn applicable_to(&self) -> &'static [&'static str] {
+        ext::applicable_to(self.id())
+    }
 }
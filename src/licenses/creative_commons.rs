@@ -0,0 +1,87 @@
+//! The Creative Commons licenses, with their terms.
+//!
+//! The structs of the complete CC 4.0 matrix are re-exported here. Like every
+//! license, they are also available from the parent module.
+//!
+//! # Examples
+//! ```
+//! use license::creative_commons::{CcTerms, CC_BY_NC_SA_4_0};
+//! use license::License;
+//!
+//! let terms = CC_BY_NC_SA_4_0.cc_terms().unwrap();
+//! assert!(terms.by() && terms.nc() && terms.sa() && !terms.nd());
+//! assert_eq!(license::from_id("MIT").unwrap().cc_terms(), None);
+//! ```
+
+pub use super::{
+    CC_BY_4_0, CC_BY_NC_4_0, CC_BY_NC_ND_4_0, CC_BY_NC_SA_4_0, CC_BY_ND_4_0, CC_BY_SA_4_0,
+};
+
+/// The terms of a Creative Commons license, see [`License::cc_terms`](crate::License::cc_terms).
+///
+/// The extension data of the CC licenses captures the same terms: NC clears
+/// [`commercial_use`](crate::Permissions::commercial_use) and ND clears
+/// [`modification`](crate::Permissions::modification).
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct CcTerms {
+    by: bool,
+    nc: bool,
+    nd: bool,
+    sa: bool,
+}
+
+impl CcTerms {
+    const fn new(by: bool, nc: bool, nd: bool, sa: bool) -> Self {
+        CcTerms { by, nc, nd, sa }
+    }
+
+    /// Attribution: credit must be given to the creator.
+    pub const fn by(self) -> bool {
+        self.by
+    }
+
+    /// NonCommercial: only noncommercial uses of the work are permitted.
+    pub const fn nc(self) -> bool {
+        self.nc
+    }
+
+    /// NoDerivatives: no derivatives or adaptations of the work are permitted.
+    pub const fn nd(self) -> bool {
+        self.nd
+    }
+
+    /// ShareAlike: adaptations must be shared under the same terms.
+    pub const fn sa(self) -> bool {
+        self.sa
+    }
+}
+
+/// The terms of a Creative Commons license, see [`License::cc_terms`](crate::License::cc_terms).
+///
+/// CC0 is a public domain dedication and has none of the terms.
+pub(crate) fn terms(id: &str) -> Option<CcTerms> {
+    match id {
+        "CC0-1.0" => Some(CcTerms::new(false, false, false, false)),
+        "CC-BY-1.0" | "CC-BY-2.0" | "CC-BY-2.5" | "CC-BY-3.0" | "CC-BY-3.0-AT" | "CC-BY-3.0-US"
+        | "CC-BY-4.0" => Some(CcTerms::new(true, false, false, false)),
+        "CC-BY-SA-1.0" | "CC-BY-SA-2.0" | "CC-BY-SA-2.0-UK" | "CC-BY-SA-2.1-JP"
+        | "CC-BY-SA-2.5" | "CC-BY-SA-3.0" | "CC-BY-SA-3.0-AT" | "CC-BY-SA-4.0" => {
+            Some(CcTerms::new(true, false, false, true))
+        }
+        "CC-BY-NC-1.0" | "CC-BY-NC-2.0" | "CC-BY-NC-2.5" | "CC-BY-NC-3.0" | "CC-BY-NC-4.0" => {
+            Some(CcTerms::new(true, true, false, false))
+        }
+        "CC-BY-NC-SA-1.0" | "CC-BY-NC-SA-2.0" | "CC-BY-NC-SA-2.5" | "CC-BY-NC-SA-3.0"
+        | "CC-BY-NC-SA-4.0" => Some(CcTerms::new(true, true, false, true)),
+        "CC-BY-ND-1.0" | "CC-BY-ND-2.0" | "CC-BY-ND-2.5" | "CC-BY-ND-3.0" | "CC-BY-ND-4.0" => {
+            Some(CcTerms::new(true, false, true, false))
+        }
+        "CC-BY-NC-ND-1.0"
+        | "CC-BY-NC-ND-2.0"
+        | "CC-BY-NC-ND-2.5"
+        | "CC-BY-NC-ND-3.0"
+        | "CC-BY-NC-ND-3.0-IGO"
+        | "CC-BY-NC-ND-4.0" => Some(CcTerms::new(true, true, true, false)),
+        _ => None,
+    }
+}
@@ -0,0 +1,158 @@
+use crate::*;
+
+/// The versions of licenses, as `(id, year of publication)` from the oldest to the newest.
+///
+/// This is curated data that is not part of SPDX.
+const LINEAGES: &[&[(&str, u16)]] = &[
+    // The GNU General Public License.
+    &[
+        ("GPL-1.0-only", 1989),
+        ("GPL-2.0-only", 1991),
+        ("GPL-3.0-only", 2007),
+    ],
+    &[
+        ("GPL-1.0-or-later", 1989),
+        ("GPL-2.0-or-later", 1991),
+        ("GPL-3.0-or-later", 2007),
+    ],
+    // The GNU Lesser General Public License, called the Library General Public License before 2.1.
+    &[
+        ("LGPL-2.0-only", 1991),
+        ("LGPL-2.1-only", 1999),
+        ("LGPL-3.0-only", 2007),
+    ],
+    &[
+        ("LGPL-2.0-or-later", 1991),
+        ("LGPL-2.1-or-later", 1999),
+        ("LGPL-3.0-or-later", 2007),
+    ],
+    // The Affero General Public License, whose version 2 was never published.
+    &[("AGPL-1.0-only", 2002), ("AGPL-3.0-only", 2007)],
+    &[("AGPL-1.0-or-later", 2002), ("AGPL-3.0-or-later", 2007)],
+    // The GNU Free Documentation License.
+    &[
+        ("GFDL-1.1-only", 2000),
+        ("GFDL-1.2-only", 2002),
+        ("GFDL-1.3-only", 2008),
+    ],
+    &[
+        ("GFDL-1.1-or-later", 2000),
+        ("GFDL-1.2-or-later", 2002),
+        ("GFDL-1.3-or-later", 2008),
+    ],
+    &[("MPL-1.0", 1998), ("MPL-1.1", 1999), ("MPL-2.0", 2012)],
+    &[("EUPL-1.0", 2007), ("EUPL-1.1", 2009), ("EUPL-1.2", 2017)],
+    &[
+        ("AFL-1.1", 2002),
+        ("AFL-1.2", 2002),
+        ("AFL-2.0", 2003),
+        ("AFL-2.1", 2004),
+        ("AFL-3.0", 2005),
+    ],
+    &[
+        ("OSL-1.0", 2002),
+        ("OSL-1.1", 2004),
+        ("OSL-2.0", 2004),
+        ("OSL-2.1", 2004),
+        ("OSL-3.0", 2005),
+    ],
+    &[
+        ("Apache-1.0", 1995),
+        ("Apache-1.1", 2000),
+        ("Apache-2.0", 2004),
+    ],
+    // The Eclipse Public License replaced the Common Public License.
+    &[("CPL-1.0", 2001), ("EPL-1.0", 2004), ("EPL-2.0", 2017)],
+    // The unported Creative Commons licenses, without the ports to a jurisdiction.
+    &[
+        ("CC-BY-1.0", 2002),
+        ("CC-BY-2.0", 2004),
+        ("CC-BY-2.5", 2005),
+        ("CC-BY-3.0", 2007),
+        ("CC-BY-4.0", 2013),
+    ],
+    &[
+        ("CC-BY-NC-1.0", 2002),
+        ("CC-BY-NC-2.0", 2004),
+        ("CC-BY-NC-2.5", 2005),
+        ("CC-BY-NC-3.0", 2007),
+        ("CC-BY-NC-4.0", 2013),
+    ],
+    &[
+        ("CC-BY-NC-ND-1.0", 2002),
+        ("CC-BY-NC-ND-2.0", 2004),
+        ("CC-BY-NC-ND-2.5", 2005),
+        ("CC-BY-NC-ND-3.0", 2007),
+        ("CC-BY-NC-ND-4.0", 2013),
+    ],
+    &[
+        ("CC-BY-NC-SA-1.0", 2002),
+        ("CC-BY-NC-SA-2.0", 2004),
+        ("CC-BY-NC-SA-2.5", 2005),
+        ("CC-BY-NC-SA-3.0", 2007),
+        ("CC-BY-NC-SA-4.0", 2013),
+    ],
+    &[
+        ("CC-BY-ND-1.0", 2002),
+        ("CC-BY-ND-2.0", 2004),
+        ("CC-BY-ND-2.5", 2005),
+        ("CC-BY-ND-3.0", 2007),
+        ("CC-BY-ND-4.0", 2013),
+    ],
+    &[
+        ("CC-BY-SA-1.0", 2002),
+        ("CC-BY-SA-2.0", 2004),
+        ("CC-BY-SA-2.5", 2005),
+        ("CC-BY-SA-3.0", 2007),
+        ("CC-BY-SA-4.0", 2013),
+    ],
+];
+
+/// Finds a license in its lineage, as the lineage and its index.
+fn find(id: &str) -> Option<(&'static [(&'static str, u16)], usize)> {
+    LINEAGES.iter().find_map(|lineage| {
+        let i = lineage.iter().position(|&(version, _)| version == id)?;
+        Some((*lineage, i))
+    })
+}
+
+/// The year a license was published, see [`License::published_year`].
+pub(crate) fn published_year(id: &str) -> Option<u16> {
+    let (lineage, i) = find(id)?;
+    Some(lineage[i].1)
+}
+
+/// The previous version of a license, see [`License::predecessor`].
+pub(crate) fn predecessor(id: &str) -> Option<&'static dyn License> {
+    let (lineage, i) = find(id)?;
+    from_id(lineage[i.checked_sub(1)?].0)
+}
+
+/// The next version of a license, see [`License::successor`].
+pub(crate) fn successor(id: &str) -> Option<&'static dyn License> {
+    let (lineage, i) = find(id)?;
+    from_id(lineage.get(i + 1)?.0)
+}
+
+/// The versions that a license offered under "or any later version" can be used under,
+/// from its own version to the newest, or nothing if it isn't offered so.
+///
+/// The `-or-later` licenses yield the `-only` licenses of their versions, and any other
+/// license yields its lineage if `or_later` is set, as by a trailing `+`.
+#[cfg(feature = "alloc")]
+pub(crate) fn or_later_versions(id: &str, or_later: bool) -> impl Iterator<Item = &'static str> {
+    let versions: &'static [(&'static str, u16)] = match find(id) {
+        Some((lineage, i)) if or_later || id.ends_with("-or-later") => &lineage[i..],
+        _ => &[],
+    };
+    versions
+        .iter()
+        .filter_map(|&(version, _)| match version.strip_suffix("-or-later") {
+            Some(base) => LINEAGES
+                .iter()
+                .flat_map(|lineage| lineage.iter())
+                .map(|&(only, _)| only)
+                .find(|only| only.strip_suffix("-only") == Some(base)),
+            None => Some(version),
+        })
+}
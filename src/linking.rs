@@ -0,0 +1,172 @@
+use crate::*;
+
+/// How a program uses the licensed library, see [`LicenseExt::obligations_for_linking`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum Linkage {
+    /// The library is linked into the executable of the program.
+    Static,
+    /// The library is a shared library that the program loads when it runs.
+    Dynamic,
+    /// The library is part of another program, which the program runs and talks to,
+    /// such as through pipes or command line arguments, and which is distributed with it.
+    SeparateProcess,
+    /// The library is part of a service that the program calls over a network, without
+    /// distributing it.
+    NetworkApi,
+}
+
+/// Which terms cover the program that uses the library, see [`LinkObligations`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum Coverage {
+    /// The license of the library covers the whole combined work.
+    CombinedWork,
+    /// Only the library itself stays under its license.
+    LibraryOnly,
+    /// The license doesn't say, and the combined work may be covered.
+    Unclear,
+}
+
+/// How settled the reading of a license is, see [`LinkObligations::confidence`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Confidence {
+    /// The answer is the conservative reading of a question that is disputed or
+    /// not curated, and it deserves a legal review.
+    Low,
+    /// The answer follows from the text of the license and the positions of its authors.
+    High,
+}
+
+/// Licenses whose copyleft covers the programs that are linked with them.
+const GPL: &[&str] = &["AGPL-3.0-only", "GPL-2.0-only", "GPL-3.0-only"];
+
+/// Licenses whose copyleft also covers offering the software over a network.
+const NETWORK: &[&str] = &["AGPL-3.0-only"];
+
+/// Licenses that let programs link with the library under terms of their choice, if
+/// the library can be replaced by a modified version.
+const LGPL: &[&str] = &["LGPL-2.1-only", "LGPL-3.0-only"];
+
+/// Licenses whose exception lets programs link with the library, with no conditions on
+/// the program.
+const LINKING_EXCEPTION: &[&str] = &["GPL-2.0-only WITH Classpath-exception-2.0"];
+
+/// Licenses whose copyleft only covers the licensed files.
+const FILE_LEVEL: &[&str] = &["MPL-2.0", "MPL-2.0-no-copyleft-exception"];
+
+/// What a license requires of a program that uses the licensed library in a given
+/// way, see [`LicenseExt::obligations_for_linking`].
+///
+/// This is curated data for GPL, AGPL, LGPL and MPL, and a uniform answer for the
+/// permissive licenses. Other copyleft licenses, and the [`UNKNOWN`] and [`NONE`]
+/// sentinels, get the conservative answer [`Unclear`](Coverage::Unclear) with a
+/// [`Low`](Confidence::Low) confidence.
+///
+/// # Examples
+/// ```
+/// use license::{Confidence, Coverage, Linkage};
+///
+/// let gpl = license::from_id_ext("GPL-3.0-only").unwrap();
+/// let dynamic = gpl.obligations_for_linking(Linkage::Dynamic);
+/// assert_eq!(dynamic.coverage(), Coverage::CombinedWork);
+/// assert_eq!(dynamic.confidence(), Confidence::Low);
+///
+/// let process = gpl.obligations_for_linking(Linkage::SeparateProcess);
+/// assert_eq!(process.coverage(), Coverage::LibraryOnly);
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct LinkObligations {
+    coverage: Coverage,
+    notice: bool,
+    library_source: bool,
+    relinking: bool,
+    confidence: Confidence,
+}
+
+impl LinkObligations {
+    /// The obligations of a license, from its conditions and the curated lists.
+    pub(crate) fn new(id: &str, conditions: Conditions, link: Linkage) -> Self {
+        let distributed = link != Linkage::NetworkApi;
+        let obligations = |coverage, relinking, confidence| LinkObligations {
+            coverage,
+            notice: distributed,
+            library_source: distributed,
+            relinking,
+            confidence,
+        };
+        if sentinel::is_sentinel(id) {
+            // Nothing is known, or nothing is permitted.
+            obligations(Coverage::Unclear, false, Confidence::Low)
+        } else if LINKING_EXCEPTION.contains(&id) {
+            obligations(Coverage::LibraryOnly, false, Confidence::High)
+        } else if GPL.contains(&id) {
+            let network = NETWORK.contains(&id);
+            let obligations = match link {
+                Linkage::Static => obligations(Coverage::CombinedWork, false, Confidence::High),
+                // The FSF considers it a combined work, which is disputed.
+                Linkage::Dynamic => obligations(Coverage::CombinedWork, false, Confidence::Low),
+                Linkage::SeparateProcess | Linkage::NetworkApi => {
+                    obligations(Coverage::LibraryOnly, false, Confidence::High)
+                }
+            };
+            LinkObligations {
+                library_source: obligations.library_source || network,
+                ..obligations
+            }
+        } else if LGPL.contains(&id) {
+            match link {
+                // Relinking then needs the object files of the program.
+                Linkage::Static => obligations(Coverage::LibraryOnly, true, Confidence::Low),
+                Linkage::Dynamic => obligations(Coverage::LibraryOnly, true, Confidence::High),
+                Linkage::SeparateProcess | Linkage::NetworkApi => {
+                    obligations(Coverage::LibraryOnly, false, Confidence::High)
+                }
+            }
+        } else if FILE_LEVEL.contains(&id) {
+            obligations(Coverage::LibraryOnly, false, Confidence::High)
+        } else if !conditions.same_license() && !conditions.disclose_sources() {
+            LinkObligations {
+                coverage: Coverage::LibraryOnly,
+                notice: distributed && conditions.license_and_copyright_notice(),
+                library_source: false,
+                relinking: false,
+                confidence: Confidence::High,
+            }
+        } else {
+            LinkObligations {
+                library_source: distributed && conditions.disclose_sources(),
+                ..obligations(Coverage::Unclear, false, Confidence::Low)
+            }
+        }
+    }
+
+    /// Which terms cover the program.
+    pub const fn coverage(self) -> Coverage {
+        self.coverage
+    }
+
+    /// The license and the copyright notices of the library must be included, and for
+    /// the LGPL, it must be said that the program uses the library.
+    pub const fn notice(self) -> bool {
+        self.notice
+    }
+
+    /// The source of the library, with any changes, must be made available.
+    ///
+    /// For AGPL, this is also required when the library is only offered over a network.
+    pub const fn library_source(self) -> bool {
+        self.library_source
+    }
+
+    /// The user must be able to run the program with a modified version of the library.
+    ///
+    /// Dynamic linking does this as long as the program accepts a compatible library,
+    /// while static linking needs the object files of the program.
+    pub const fn relinking(self) -> bool {
+        self.relinking
+    }
+
+    /// How settled the answer is.
+    pub const fn confidence(self) -> Confidence {
+        self.confidence
+    }
+}
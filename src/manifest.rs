@@ -0,0 +1,208 @@
+//! Interprets the license declarations found in the manifests of package ecosystems.
+//!
+//! The functions take the string that was already extracted from the manifest, they
+//! don't read files or parse TOML or JSON.
+//!
+//! Requires the `alloc` feature.
+
+use crate::expression::Expression;
+use crate::*;
+use alloc::string::ToString;
+
+/// Python trove classifiers and the SPDX ids they stand for.
+///
+/// Classifiers that don't name a single license, such as `BSD License` or
+/// `GNU General Public License (GPL)`, are left out. `Apache Software License` names no
+/// version, but Apache-2.0 is the only one in use.
+const CLASSIFIERS: &[(&str, &str)] = &[
+    ("License :: Aladdin Free Public License (AFPL)", "Aladdin"),
+    ("License :: CC0 1.0 Universal (CC0 1.0) Public Domain Dedication", "CC0-1.0"),
+    ("License :: OSI Approved :: Apache Software License", "Apache-2.0"),
+    ("License :: OSI Approved :: Attribution Assurance License", "AAL"),
+    ("License :: OSI Approved :: Boost Software License 1.0 (BSL-1.0)", "BSL-1.0"),
+    (
+        "License :: OSI Approved :: CEA CNRS Inria Logiciel Libre License, version 2.1 (CeCILL-2.1)",
+        "CECILL-2.1",
+    ),
+    (
+        "License :: OSI Approved :: Common Development and Distribution License 1.0 (CDDL-1.0)",
+        "CDDL-1.0",
+    ),
+    ("License :: OSI Approved :: Common Public License", "CPL-1.0"),
+    ("License :: OSI Approved :: Eclipse Public License 1.0 (EPL-1.0)", "EPL-1.0"),
+    ("License :: OSI Approved :: Eclipse Public License 2.0 (EPL-2.0)", "EPL-2.0"),
+    ("License :: OSI Approved :: European Union Public Licence 1.0 (EUPL 1.0)", "EUPL-1.0"),
+    ("License :: OSI Approved :: European Union Public Licence 1.1 (EUPL 1.1)", "EUPL-1.1"),
+    ("License :: OSI Approved :: European Union Public Licence 1.2 (EUPL 1.2)", "EUPL-1.2"),
+    ("License :: OSI Approved :: GNU Affero General Public License v3", "AGPL-3.0-only"),
+    (
+        "License :: OSI Approved :: GNU Affero General Public License v3 or later (AGPLv3+)",
+        "AGPL-3.0-or-later",
+    ),
+    ("License :: OSI Approved :: GNU General Public License v2 (GPLv2)", "GPL-2.0-only"),
+    (
+        "License :: OSI Approved :: GNU General Public License v2 or later (GPLv2+)",
+        "GPL-2.0-or-later",
+    ),
+    ("License :: OSI Approved :: GNU General Public License v3 (GPLv3)", "GPL-3.0-only"),
+    (
+        "License :: OSI Approved :: GNU General Public License v3 or later (GPLv3+)",
+        "GPL-3.0-or-later",
+    ),
+    ("License :: OSI Approved :: GNU Lesser General Public License v2 (LGPLv2)", "LGPL-2.0-only"),
+    (
+        "License :: OSI Approved :: GNU Lesser General Public License v2 or later (LGPLv2+)",
+        "LGPL-2.0-or-later",
+    ),
+    ("License :: OSI Approved :: GNU Lesser General Public License v3 (LGPLv3)", "LGPL-3.0-only"),
+    (
+        "License :: OSI Approved :: GNU Lesser General Public License v3 or later (LGPLv3+)",
+        "LGPL-3.0-or-later",
+    ),
+    (
+        "License :: OSI Approved :: Historical Permission Notice and Disclaimer (HPND)",
+        "HPND",
+    ),
+    ("License :: OSI Approved :: IBM Public License", "IPL-1.0"),
+    ("License :: OSI Approved :: ISC License (ISCL)", "ISC"),
+    ("License :: OSI Approved :: Intel Open Source License", "Intel"),
+    ("License :: OSI Approved :: MIT License", "MIT"),
+    ("License :: OSI Approved :: Motosoto License", "Motosoto"),
+    ("License :: OSI Approved :: Mozilla Public License 1.0 (MPL)", "MPL-1.0"),
+    ("License :: OSI Approved :: Mozilla Public License 1.1 (MPL 1.1)", "MPL-1.1"),
+    ("License :: OSI Approved :: Mozilla Public License 2.0 (MPL 2.0)", "MPL-2.0"),
+    ("License :: OSI Approved :: Nokia Open Source License", "Nokia"),
+    ("License :: OSI Approved :: Open Group Test Suite License", "OGTSL"),
+    ("License :: OSI Approved :: Open Software License 3.0 (OSL-3.0)", "OSL-3.0"),
+    ("License :: OSI Approved :: PostgreSQL License", "PostgreSQL"),
+    ("License :: OSI Approved :: Python License (CNRI Python License)", "CNRI-Python"),
+    ("License :: OSI Approved :: Python Software Foundation License", "PSF-2.0"),
+    ("License :: OSI Approved :: Qt Public License (QPL)", "QPL-1.0"),
+    ("License :: OSI Approved :: Ricoh Source Code Public License", "RSCPL"),
+    ("License :: OSI Approved :: SIL Open Font License 1.1 (OFL-1.1)", "OFL-1.1"),
+    ("License :: OSI Approved :: Sleepycat License", "Sleepycat"),
+    (
+        "License :: OSI Approved :: Sun Industry Standards Source License (SISSL)",
+        "SISSL",
+    ),
+    ("License :: OSI Approved :: Sun Public License", "SPL-1.0"),
+    ("License :: OSI Approved :: The Unlicense (Unlicense)", "Unlicense"),
+    ("License :: OSI Approved :: Universal Permissive License (UPL)", "UPL-1.0"),
+    (
+        "License :: OSI Approved :: University of Illinois/NCSA Open Source License",
+        "NCSA",
+    ),
+    ("License :: OSI Approved :: Vovida Software License 1.0", "VSL-1.0"),
+    ("License :: OSI Approved :: W3C License", "W3C"),
+    ("License :: OSI Approved :: X.Net License", "Xnet"),
+    ("License :: OSI Approved :: Zero-Clause BSD (0BSD)", "0BSD"),
+    ("License :: OSI Approved :: zlib/libpng License", "Zlib"),
+];
+
+/// The classifier of software that is not released under a license.
+const PROPRIETARY_CLASSIFIER: &str = "License :: Other/Proprietary License";
+
+/// The prefix of an npm `license` field that points to a file.
+const NPM_FILE_PREFIX: &str = "SEE LICENSE IN";
+
+/// A license declaration read from a manifest.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum Declared<'a> {
+    /// The declaration is an SPDX expression once normalized with [`normalize_legacy`],
+    /// as [`Expression::parse`] accepts it.
+    Expression(Normalized<'a>),
+    /// The license is in a file of the package, at the given path.
+    File(&'a str),
+    /// The software is explicitly not released under any license, such as with the npm
+    /// `UNLICENSED` value.
+    Proprietary,
+    /// The declaration could not be interpreted, such as a license name that isn't an
+    /// SPDX id.
+    Unknown(&'a str),
+}
+
+fn expression(field: &str) -> Declared<'_> {
+    match normalize_legacy(field) {
+        Ok(normalized) if Expression::parse(&normalized.to_string()).is_ok() => {
+            Declared::Expression(normalized)
+        }
+        _ => Declared::Unknown(field),
+    }
+}
+
+/// Interprets the `license` field of a `Cargo.toml`.
+///
+/// # Examples
+/// ```
+/// use license::manifest::{self, Declared};
+///
+/// match manifest::from_cargo_field("MIT/Apache-2.0") {
+///     Declared::Expression(e) => assert_eq!(e.to_string(), "MIT OR Apache-2.0"),
+///     other => panic!("{:?}", other),
+/// }
+/// assert_eq!(manifest::from_cargo_field("Apache 2"), Declared::Unknown("Apache 2"));
+/// ```
+pub fn from_cargo_field(field: &str) -> Declared<'_> {
+    expression(field)
+}
+
+/// Interprets the `license` field of a `package.json`.
+///
+/// Besides SPDX expressions, npm accepts `SEE LICENSE IN <file>` and `UNLICENSED`. The
+/// deprecated `licenses` array lists alternatives, interpret the `type` of each entry with
+/// this function.
+///
+/// # Examples
+/// ```
+/// use license::manifest::{self, Declared};
+///
+/// assert_eq!(manifest::from_npm_field("SEE LICENSE IN LICENSE.md"), Declared::File("LICENSE.md"));
+/// assert_eq!(manifest::from_npm_field("UNLICENSED"), Declared::Proprietary);
+/// ```
+pub fn from_npm_field(field: &str) -> Declared<'_> {
+    let trimmed = field.trim();
+    if let Some(file) = trimmed.strip_prefix(NPM_FILE_PREFIX) {
+        return match file.trim_start() {
+            "" => Declared::Unknown(field),
+            path if path.len() < file.len() => Declared::File(path),
+            _ => expression(field),
+        };
+    }
+    if trimmed == "UNLICENSED" {
+        return Declared::Proprietary;
+    }
+    expression(field)
+}
+
+/// Interprets a Python trove classifier, such as `License :: OSI Approved :: MIT License`.
+///
+/// The parts of the classifier may be separated by any amount of spaces. Classifiers
+/// that don't name a single license, like `License :: OSI Approved :: BSD License`, are
+/// [`Unknown`](Declared::Unknown).
+///
+/// # Examples
+/// ```
+/// use license::manifest::{self, Declared};
+///
+/// let classifier = "License :: OSI Approved :: Apache Software License";
+/// match manifest::from_python_classifier(classifier) {
+///     Declared::Expression(e) => assert_eq!(e.to_string(), "Apache-2.0"),
+///     other => panic!("{:?}", other),
+/// }
+///
+/// let bsd = "License :: OSI Approved :: BSD License";
+/// assert_eq!(manifest::from_python_classifier(bsd), Declared::Unknown(bsd));
+/// ```
+pub fn from_python_classifier(classifier: &str) -> Declared<'_> {
+    let matches = |known: &str| {
+        let mut parts = classifier.split("::").map(str::trim);
+        known.split(" :: ").all(|part| parts.next() == Some(part)) && parts.next().is_none()
+    };
+    if matches(PROPRIETARY_CLASSIFIER) {
+        return Declared::Proprietary;
+    }
+    CLASSIFIERS
+        .iter()
+        .find(|(known, _)| matches(known))
+        .map_or(Declared::Unknown(classifier), |&(_, id)| expression(id))
+}
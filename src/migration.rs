@@ -0,0 +1,235 @@
+//! Analyzes moving a project from one license to another.
+//!
+//! A [`MigrationReport`] says what the users of the project gain and lose with the new
+//! license, whether code under either license can be incorporated into a work under
+//! the other, and whether the move needs the consent of every copyright holder.
+//!
+//! # Examples
+//! ```
+//! use license::migration;
+//!
+//! let mit = license::from_id_ext("MIT").unwrap();
+//! let apache = license::from_id_ext("Apache-2.0").unwrap();
+//! let report = migration::analyze(mit, apache);
+//! assert!(report.gained_permissions().patent_rights());
+//! assert!(!report.requires_consent_of_all_holders());
+//! ```
+
+use crate::compat::{self, Compatibility};
+use crate::report::Copyleft;
+use crate::*;
+use core::fmt::{self, Display, Formatter};
+
+/// The analysis of moving a project from one license to another, see [`analyze`].
+///
+/// Its [`Display`] impl renders the report as Markdown, for sharing with the people who
+/// decide on the move.
+#[derive(Copy, Clone)]
+pub struct MigrationReport<'a> {
+    from: &'a dyn LicenseExt,
+    to: &'a dyn LicenseExt,
+}
+
+/// Analyzes moving a project from the license `from` to the license `to`.
+///
+/// The differences of the flags are from `from` to `to`, so that a permission gained is
+/// one that `to` grants and `from` doesn't.
+///
+/// # Examples
+/// ```
+/// use license::compat::Compatibility;
+/// use license::migration;
+/// use license::report::Copyleft;
+///
+/// let gpl = license::from_id_ext("GPL-3.0-only").unwrap();
+/// let mpl = license::from_id_ext("MPL-2.0").unwrap();
+/// let report = migration::analyze(gpl, mpl);
+/// assert_eq!((report.from_copyleft(), report.to_copyleft()), (Copyleft::Strong, Copyleft::Weak));
+/// assert!(matches!(report.to_incorporates_from(), Compatibility::Incompatible(_)));
+/// assert_eq!(report.from_incorporates_to(), Compatibility::Compatible);
+/// assert!(report.requires_consent_of_all_holders());
+/// ```
+pub fn analyze<'a>(from: &'a dyn LicenseExt, to: &'a dyn LicenseExt) -> MigrationReport<'a> {
+    MigrationReport { from, to }
+}
+
+impl<'a> MigrationReport<'a> {
+    /// The license that the project moves from.
+    pub fn from(&self) -> &'a dyn LicenseExt {
+        self.from
+    }
+
+    /// The license that the project moves to.
+    pub fn to(&self) -> &'a dyn LicenseExt {
+        self.to
+    }
+
+    /// The permissions that `to` grants and `from` doesn't.
+    pub fn gained_permissions(&self) -> Permissions {
+        self.to.permissions().difference(self.from.permissions())
+    }
+
+    /// The permissions that `from` grants and `to` doesn't.
+    pub fn lost_permissions(&self) -> Permissions {
+        self.from.permissions().difference(self.to.permissions())
+    }
+
+    /// The conditions of `to` that `from` doesn't have.
+    pub fn added_conditions(&self) -> Conditions {
+        self.to.conditions().difference(self.from.conditions())
+    }
+
+    /// The conditions of `from` that `to` doesn't have.
+    pub fn removed_conditions(&self) -> Conditions {
+        self.from.conditions().difference(self.to.conditions())
+    }
+
+    /// The limitations of `to` that `from` doesn't have.
+    pub fn added_limitations(&self) -> Limitations {
+        self.to.limitations().difference(self.from.limitations())
+    }
+
+    /// The limitations of `from` that `to` doesn't have.
+    pub fn removed_limitations(&self) -> Limitations {
+        self.from.limitations().difference(self.to.limitations())
+    }
+
+    /// The copyleft class of `from`.
+    pub fn from_copyleft(&self) -> Copyleft {
+        Copyleft::of(self.from)
+    }
+
+    /// The copyleft class of `to`.
+    pub fn to_copyleft(&self) -> Copyleft {
+        Copyleft::of(self.to)
+    }
+
+    /// Says if a work under `to` can incorporate code under `from`, see [`compat::check`].
+    pub fn to_incorporates_from(&self) -> Compatibility {
+        compat::check_ids(self.to.id(), self.from.id(), self.from.ext())
+    }
+
+    /// Says if a work under `from` can incorporate code under `to`, see [`compat::check`].
+    pub fn from_incorporates_to(&self) -> Compatibility {
+        compat::check_ids(self.from.id(), self.to.id(), self.to.ext())
+    }
+
+    /// Says if the code that others contributed under `from` can only move to `to` with
+    /// the consent of all its copyright holders.
+    ///
+    /// This is the case when the copyleft of `to` is weaker, and whenever a work under
+    /// `to` can't be shown to incorporate code under `from`. Code that the project owns
+    /// outright can always be relicensed by its owner.
+    pub fn requires_consent_of_all_holders(&self) -> bool {
+        self.to_copyleft() < self.from_copyleft()
+            || self.to_incorporates_from() != Compatibility::Compatible
+    }
+}
+
+impl fmt::Debug for MigrationReport<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MigrationReport")
+            .field("from", &self.from.id())
+            .field("to", &self.to.id())
+            .finish()
+    }
+}
+
+/// Writes the labels of flags separated by commas, or `none`.
+struct Labels<I>(I);
+
+impl<I: Iterator<Item = &'static str> + Clone> Display for Labels<I> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut labels = self.0.clone();
+        match labels.next() {
+            Some(first) => f.write_str(first)?,
+            None => return f.write_str("none"),
+        }
+        for label in labels {
+            write!(f, ", {}", label)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a [`Compatibility`] as the answer to a question.
+struct Answer(Compatibility);
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0 {
+            Compatibility::Compatible => f.write_str("yes"),
+            Compatibility::Incompatible(i) => write!(f, "no, because {}", i.reason()),
+            Compatibility::Unknown => f.write_str("unknown"),
+        }
+    }
+}
+
+impl Display for MigrationReport<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (from, to) = (self.from.id(), self.to.id());
+        writeln!(f, "# Migration from {} to {}\n", from, to)?;
+        writeln!(
+            f,
+            "- Copyleft: {} to {}",
+            self.from_copyleft(),
+            self.to_copyleft()
+        )?;
+        writeln!(
+            f,
+            "- Consent of all copyright holders: {}",
+            if self.requires_consent_of_all_holders() {
+                "required"
+            } else {
+                "not required"
+            }
+        )?;
+        writeln!(
+            f,
+            "- {} can incorporate {} code: {}",
+            to,
+            from,
+            Answer(self.to_incorporates_from())
+        )?;
+        writeln!(
+            f,
+            "- {} can incorporate {} code: {}",
+            from,
+            to,
+            Answer(self.from_incorporates_to())
+        )?;
+        writeln!(f, "\n## Permissions\n")?;
+        writeln!(
+            f,
+            "- Gained: {}",
+            Labels(self.gained_permissions().iter().map(|p| p.label()))
+        )?;
+        writeln!(
+            f,
+            "- Lost: {}",
+            Labels(self.lost_permissions().iter().map(|p| p.label()))
+        )?;
+        writeln!(f, "\n## Conditions\n")?;
+        writeln!(
+            f,
+            "- Added: {}",
+            Labels(self.added_conditions().iter().map(|c| c.label()))
+        )?;
+        writeln!(
+            f,
+            "- Removed: {}",
+            Labels(self.removed_conditions().iter().map(|c| c.label()))
+        )?;
+        writeln!(f, "\n## Limitations\n")?;
+        writeln!(
+            f,
+            "- Added: {}",
+            Labels(self.added_limitations().iter().map(|l| l.label()))
+        )?;
+        writeln!(
+            f,
+            "- Removed: {}",
+            Labels(self.removed_limitations().iter().map(|l| l.label()))
+        )
+    }
+}
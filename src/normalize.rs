@@ -0,0 +1,364 @@
+use crate::*;
+use core::fmt::{self, Display, Formatter};
+
+/// Legacy license names found in crates.io metadata, and their SPDX replacements.
+///
+/// The names are matched case-insensitively. Forms that contain a `/` are listed before
+/// the ones they start with, since a `/` otherwise separates alternatives.
+pub(crate) const LEGACY: &[(&str, &str, bool)] = &[
+    // (legacy, replacement, lossy)
+    ("MIT/X11", "MIT", false),
+    ("AGPL-3.0", "AGPL-3.0-only", false),
+    ("AGPL-3.0+", "AGPL-3.0-or-later", false),
+    ("AGPLv3", "AGPL-3.0-only", true),
+    ("AGPLv3+", "AGPL-3.0-or-later", false),
+    ("Apache2", "Apache-2.0", false),
+    ("Apache-2", "Apache-2.0", false),
+    ("ASL-2.0", "Apache-2.0", false),
+    ("BSD-2", "BSD-2-Clause", false),
+    ("BSD-3", "BSD-3-Clause", false),
+    ("Boost", "BSL-1.0", false),
+    ("GPL-2.0", "GPL-2.0-only", false),
+    ("GPL-2.0+", "GPL-2.0-or-later", false),
+    ("GPL-3.0", "GPL-3.0-only", false),
+    ("GPL-3.0+", "GPL-3.0-or-later", false),
+    ("GPLv2", "GPL-2.0-only", true),
+    ("GPLv2+", "GPL-2.0-or-later", false),
+    ("GPLv3", "GPL-3.0-only", true),
+    ("GPLv3+", "GPL-3.0-or-later", false),
+    ("LGPL-2.1", "LGPL-2.1-only", false),
+    ("LGPL-2.1+", "LGPL-2.1-or-later", false),
+    ("LGPL-3.0", "LGPL-3.0-only", false),
+    ("LGPL-3.0+", "LGPL-3.0-or-later", false),
+    ("LGPLv2.1", "LGPL-2.1-only", true),
+    ("LGPLv2.1+", "LGPL-2.1-or-later", false),
+    ("LGPLv3", "LGPL-3.0-only", true),
+    ("LGPLv3+", "LGPL-3.0-or-later", false),
+    ("MPL2", "MPL-2.0", false),
+    ("MPLv2", "MPL-2.0", false),
+    ("MPL-2", "MPL-2.0", false),
+];
+
+const OPERATORS: &[&str] = &["AND", "OR", "WITH"];
+
+/// A rewrite applied by [`normalize_legacy`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Rewrite<'a> {
+    /// The rewritten part of the field.
+    pub from: &'a str,
+    /// Its replacement.
+    pub to: &'static str,
+    /// Says if the replacement may not mean the same, such as `GPLv2`,
+    /// which doesn't say if later versions are allowed, or a `/` next to an `AND`.
+    pub lossy: bool,
+}
+
+/// A license field normalized by [`normalize_legacy`].
+///
+/// Its [`Display`] impl writes the normalized expression.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Normalized<'a> {
+    field: &'a str,
+}
+
+impl<'a> Normalized<'a> {
+    /// The rewrites that were applied, in the order of the field.
+    pub fn rewrites(&self) -> impl Iterator<Item = Rewrite<'a>> + 'a {
+        Pieces::new(self.field).filter_map(|piece| match piece {
+            Piece::Text(_) => None,
+            Piece::Rewrite(rewrite) => Some(rewrite),
+        })
+    }
+
+    /// Says if any rewrite is [`lossy`](Rewrite::lossy).
+    pub fn is_lossy(&self) -> bool {
+        self.rewrites().any(|rewrite| rewrite.lossy)
+    }
+}
+
+impl Display for Normalized<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for piece in Pieces::new(self.field) {
+            match piece {
+                Piece::Text(text) => f.write_str(text)?,
+                Piece::Rewrite(rewrite) => f.write_str(rewrite.to)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Rewrites the legacy forms of a license field into an SPDX expression.
+///
+/// The rewrites are:
+///
+/// - Known legacy names, such as `GPLv2` or `MIT/X11`, become SPDX ids. Names that don't
+///   say if later versions are allowed are rewritten to the `-only` license and marked as
+///   [`lossy`](Rewrite::lossy). Deprecated SPDX ids, such as `GPL-2.0`, become the ids
+///   that replace them.
+/// - A `/` between two licenses, with any surrounding spaces, becomes ` OR `. Since `OR`
+///   binds less tightly than `AND` and `WITH`, the rewrite is [`lossy`](Rewrite::lossy)
+///   if the same parentheses also have an `AND` or a `WITH`: `MIT/Apache-2.0 AND Zlib`
+///   was probably not meant as `MIT OR (Apache-2.0 AND Zlib)`.
+/// - Operators and the ids of the extension licenses are fixed to their SPDX casing.
+///
+/// Everything else is left unchanged, so unknown names pass through as they are and the
+/// result is not guaranteed to be a valid expression.
+///
+/// # Examples
+/// ```
+/// let normalized = license::normalize_legacy("mit and (Apache-2.0/GPLv2)").unwrap();
+/// assert_eq!(normalized.to_string(), "MIT AND (Apache-2.0 OR GPL-2.0-only)");
+/// assert!(normalized.is_lossy());
+///
+/// let from: Vec<_> = normalized.rewrites().map(|r| (r.from, r.lossy)).collect();
+/// assert_eq!(from, [("mit", false), ("and", false), ("/", false), ("GPLv2", true)]);
+///
+/// let ambiguous = license::normalize_legacy("MIT AND Apache-2.0/Zlib").unwrap();
+/// assert_eq!(ambiguous.to_string(), "MIT AND Apache-2.0 OR Zlib");
+/// assert!(ambiguous.rewrites().any(|r| r.from == "/" && r.lossy));
+///
+/// let unknown = license::normalize_legacy("Custom License v1").unwrap();
+/// assert_eq!(unknown.to_string(), "Custom License v1");
+/// assert_eq!(unknown.rewrites().count(), 0);
+///
+/// assert!(license::normalize_legacy("MIT/").is_err());
+/// ```
+pub fn normalize_legacy(field: &str) -> Result<Normalized<'_>, NormalizeError<'_>> {
+    if field.trim().is_empty() {
+        return Err(NormalizeError::Empty);
+    }
+    let empty_operand = |position| NormalizeError::EmptyOperand { field, position };
+    let mut seen_license = false;
+    let mut pending_or = None;
+    let mut pieces = Pieces::new(field);
+    loop {
+        let position = field.len() - pieces.rest.len();
+        match pieces.next() {
+            None => break,
+            Some(Piece::Rewrite(Rewrite {
+                from, to: " OR ", ..
+            })) => {
+                let position = position + from.find('/').unwrap_or(0);
+                if !seen_license || pending_or.is_some() {
+                    return Err(empty_operand(position));
+                }
+                pending_or = Some(position);
+            }
+            Some(Piece::Text(text)) if text.contains('/') => {
+                // The second `/` of a run is the one without a license before it.
+                let second = text.match_indices('/').nth(1).map_or(0, |(i, _)| i);
+                return Err(empty_operand(position + second));
+            }
+            Some(Piece::Text(text)) if text.trim().is_empty() => {}
+            Some(_) => {
+                seen_license = true;
+                pending_or = None;
+            }
+        }
+    }
+    match pending_or {
+        Some(position) => Err(empty_operand(position)),
+        None => Ok(Normalized { field }),
+    }
+}
+
+/// Like [`normalize_legacy`], and reports each rewrite to `diagnostics` as a
+/// [`Diagnostic`], in the order of the field.
+///
+/// A deprecated SPDX id, as written or with a trailing `+`, is reported as
+/// [`DeprecatedId`](Diagnostic::DeprecatedId), and any other legacy name or casing of an
+/// id as [`AliasUsed`](Diagnostic::AliasUsed). Nothing is reported for a field that
+/// can't be normalized, and the names that pass through unchanged, such as
+/// `Apache 2.0`, aren't reported either.
+///
+/// # Examples
+/// ```
+/// use license::Diagnostic;
+///
+/// let mut diagnostics = Vec::new();
+/// let mut report = |diagnostic| diagnostics.push(diagnostic);
+/// let normalized = license::normalize_legacy_with_diagnostics("MIT/GPLv3", &mut report).unwrap();
+/// assert_eq!(normalized.to_string(), "MIT OR GPL-3.0-only");
+/// assert!(matches!(diagnostics[0], Diagnostic::LegacySeparator { position: 3, .. }));
+/// assert_eq!(diagnostics[1].to_string(), "license alias `GPLv3`, use `GPL-3.0-only`");
+/// ```
+pub fn normalize_legacy_with_diagnostics<'a>(
+    field: &'a str,
+    diagnostics: &mut dyn Diagnostics<'a>,
+) -> Result<Normalized<'a>, NormalizeError<'a>> {
+    let normalized = normalize_legacy(field)?;
+    let mut pieces = Pieces::new(field);
+    loop {
+        let position = field.len() - pieces.rest.len();
+        match pieces.next() {
+            None => return Ok(normalized),
+            Some(Piece::Text(_)) => {}
+            Some(Piece::Rewrite(rewrite)) => diagnostics.report(diagnostic(rewrite, position)),
+        }
+    }
+}
+
+/// The diagnostic of a rewrite at `position`.
+fn diagnostic(rewrite: Rewrite<'_>, position: usize) -> Diagnostic<'_> {
+    let Rewrite { from, to, .. } = rewrite;
+    let base = from.strip_suffix('+').unwrap_or(from);
+    if to == " OR " {
+        Diagnostic::LegacySeparator {
+            position: position + from.find('/').unwrap_or(0),
+        }
+    } else if OPERATORS.contains(&to) {
+        Diagnostic::OperatorCase {
+            given: from,
+            canonical: to,
+        }
+    } else if from_id(base).map_or(false, |license| license.is_deprecated()) {
+        Diagnostic::DeprecatedId {
+            given: from,
+            canonical: to,
+        }
+    } else {
+        Diagnostic::AliasUsed {
+            given: from,
+            canonical: to,
+        }
+    }
+}
+
+enum Piece<'a> {
+    Text(&'a str),
+    Rewrite(Rewrite<'a>),
+}
+
+/// Splits a field into the parts that are kept and the parts that are rewritten.
+struct Pieces<'a> {
+    field: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Pieces<'a> {
+    fn new(field: &'a str) -> Self {
+        Pieces { field, rest: field }
+    }
+
+    fn take(&mut self, len: usize) -> &'a str {
+        let (taken, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        taken
+    }
+}
+
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || c == '/' || c == '(' || c == ')'
+}
+
+/// Says if the parentheses around `field[at]` also have an `AND` or a `WITH`, outside of
+/// nested parentheses, in any casing.
+fn mixes_with_and(field: &str, at: usize) -> bool {
+    let (mut start, mut depth) = (0, 0);
+    for (i, c) in field[..at].char_indices().rev() {
+        match c {
+            ')' => depth += 1,
+            '(' if depth == 0 => {
+                start = i + 1;
+                break;
+            }
+            '(' => depth -= 1,
+            _ => {}
+        }
+    }
+    let (mut end, mut depth) = (field.len(), 0);
+    for (i, c) in field[at..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => {
+                end = at + i;
+                break;
+            }
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    let mut depth = 0;
+    for word in field[start..end].split_inclusive(is_separator) {
+        let operator = word.trim_end_matches(is_separator);
+        if depth == 0
+            && (operator.eq_ignore_ascii_case("AND") || operator.eq_ignore_ascii_case("WITH"))
+        {
+            return true;
+        }
+        if word.ends_with('(') {
+            depth += 1;
+        } else if word.ends_with(')') {
+            depth -= 1;
+        }
+    }
+    false
+}
+
+/// Says if `text` starts with `prefix`, ignoring ASCII case, followed by a separator.
+fn starts_with_word(text: &str, prefix: &str) -> bool {
+    match text.get(..prefix.len()) {
+        Some(start) if start.eq_ignore_ascii_case(prefix) => {
+            let rest = &text[prefix.len()..];
+            rest.is_empty() || rest.starts_with(is_separator)
+        }
+        _ => false,
+    }
+}
+
+impl<'a> Iterator for Pieces<'a> {
+    type Item = Piece<'a>;
+
+    fn next(&mut self) -> Option<Piece<'a>> {
+        let first = self.rest.chars().next()?;
+
+        if first.is_whitespace() || first == '/' {
+            let len = self
+                .rest
+                .find(|c: char| !c.is_whitespace() && c != '/')
+                .unwrap_or(self.rest.len());
+            let at = self.field.len() - self.rest.len();
+            let run = self.take(len);
+            return Some(match run.matches('/').count() {
+                0 => Piece::Text(run),
+                1 => Piece::Rewrite(Rewrite {
+                    from: run,
+                    to: " OR ",
+                    lossy: mixes_with_and(self.field, at),
+                }),
+                // Rewriting would hide an empty operand, keep it for the validation.
+                _ => Piece::Text(run),
+            });
+        }
+        if first == '(' || first == ')' {
+            return Some(Piece::Text(self.take(1)));
+        }
+
+        for &(legacy, replacement, lossy) in LEGACY {
+            if starts_with_word(self.rest, legacy) {
+                return Some(Piece::Rewrite(Rewrite {
+                    from: self.take(legacy.len()),
+                    to: replacement,
+                    lossy,
+                }));
+            }
+        }
+
+        let len = self.rest.find(is_separator).unwrap_or(self.rest.len());
+        let word = self.take(len);
+        let canonical = OPERATORS
+            .iter()
+            .copied()
+            .chain(LICENSES_EXT.iter().map(|l| l.id()))
+            .find(|id| id.eq_ignore_ascii_case(word));
+        Some(match canonical {
+            Some(canonical) if canonical != word => Piece::Rewrite(Rewrite {
+                from: word,
+                to: canonical,
+                lossy: false,
+            }),
+            _ => Piece::Text(word),
+        })
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+/// The medium of the offers of the version 2 licenses, in their words.
+const MEDIUM_V2: &str = "a medium customarily used for software interchange";
+
+/// The medium of the offers of the version 3 licenses, in their words.
+const MEDIUM_V3: &str = "a durable physical medium customarily used for software interchange";
+
+/// The offer of section 3(b) of the GPL-2.0.
+const GPL_2_0: &str = "\
+Written Offer for Source Code
+
+<company> distributes this product with software licensed under the GNU General Public License, version 2. For at least <years> years from the date of distribution, <company> offers to give any third party, for a charge no more than its cost of physically performing source distribution, a complete machine-readable copy of the corresponding source code of that software, to be distributed under the terms of Sections 1 and 2 of the license on <medium>.
+
+To request a copy, contact <contact>.
+";
+
+/// The offer of section 6(c) of the LGPL-2.1.
+const LGPL_2_1: &str = "\
+Written Offer for Source Code
+
+<company> distributes this product with libraries licensed under the GNU Lesser General Public License, version 2.1. For at least <years> years from the date of distribution, <company> offers to give any user of this product, for a charge no more than its cost of performing this distribution, the materials specified in Subsection 6a of the license: the complete corresponding machine-readable source code of the libraries, including any changes, and the work that uses them, on <medium>.
+
+To request a copy, contact <contact>.
+";
+
+/// The offer of section 6(b) of the GPL-3.0, which the AGPL-3.0 shares and section 4(d)
+/// of the LGPL-3.0 refers to, for the license `$name` and the source `$source`.
+macro_rules! offer_v3 {
+    ($name:literal, $source:literal) => {
+        concat!(
+            "\
+Written Offer for Source Code
+
+<company> distributes this product with software licensed under the ",
+            $name,
+            ", version 3. For at least <years> years, and for as long as <company> offers spare parts or customer support for this product model, <company> offers to give anyone who possesses the object code either (1) a copy of ",
+            $source,
+            " for all the software in the product that is covered by the license, on <medium>, for a price no more than its reasonable cost of physically performing this conveying of source, or (2) access to copy ",
+            $source,
+            " from a network server at no charge.
+
+To request a copy, contact <contact>.
+"
+        )
+    };
+}
+
+const GPL_3_0: &str = offer_v3!("GNU General Public License", "the Corresponding Source");
+const AGPL_3_0: &str = offer_v3!(
+    "GNU Affero General Public License",
+    "the Corresponding Source"
+);
+const LGPL_3_0: &str = offer_v3!(
+    "GNU Lesser General Public License",
+    "the Minimal Corresponding Source and the Corresponding Application Code"
+);
+
+/// The template of the offer of the license with the given id, and the medium of the
+/// license, if it allows an offer instead of the source itself.
+pub(crate) fn offer(id: &str) -> Option<(&'static str, &'static str)> {
+    match id {
+        "GPL-2.0-only" | "GPL-2.0-only WITH Classpath-exception-2.0" => Some((GPL_2_0, MEDIUM_V2)),
+        "LGPL-2.1-only" => Some((LGPL_2_1, MEDIUM_V2)),
+        "GPL-3.0-only" => Some((GPL_3_0, MEDIUM_V3)),
+        "AGPL-3.0-only" => Some((AGPL_3_0, MEDIUM_V3)),
+        "LGPL-3.0-only" => Some((LGPL_3_0, MEDIUM_V3)),
+        _ => None,
+    }
+}
+
+/// Replaces the placeholders of the template of an offer.
+#[cfg(feature = "alloc")]
+pub(crate) fn render(template: &str, medium: &str, params: &OfferParams) -> String {
+    let years = params.years.map_or(OfferParams::MIN_YEARS, |years| {
+        years.max(OfferParams::MIN_YEARS)
+    });
+    let mut offer = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('<') {
+        let len = rest[start..].find('>').map_or(1, |end| end + 1);
+        offer.push_str(&rest[..start]);
+        match &rest[start..start + len] {
+            "<company>" => offer.push_str(params.company),
+            "<contact>" => offer.push_str(params.contact),
+            "<medium>" => offer.push_str(params.medium.unwrap_or(medium)),
+            "<years>" => offer.push_str(&years.to_string()),
+            other => offer.push_str(other),
+        }
+        rest = &rest[start + len..];
+    }
+    offer.push_str(rest);
+    offer
+}
+
+/// What fills in a written offer for source code, see
+/// `LicenseExt::render_source_offer`, which requires the `alloc` feature.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct OfferParams<'a> {
+    /// The company that distributes the software and makes the offer.
+    pub company: &'a str,
+    /// Where to request the source, such as a postal or email address.
+    pub contact: &'a str,
+    /// How many years the offer is valid for, or `None` for
+    /// [`MIN_YEARS`](OfferParams::MIN_YEARS). A shorter duration is raised to it.
+    pub years: Option<u8>,
+    /// The medium the source is given on, or `None` for the words of the license, such as
+    /// "a durable physical medium customarily used for software interchange" for the
+    /// GPL-3.0.
+    pub medium: Option<&'a str>,
+}
+
+impl OfferParams<'_> {
+    /// The shortest duration of an offer, three years for every license that allows one.
+    pub const MIN_YEARS: u8 = 3;
+}
@@ -0,0 +1,591 @@
+//! Flags that an organization assigns to licenses in place of those of the crate, when
+//! its legal team reads a license differently.
+//!
+//! [`Overrides`] maps license ids to flags that are set or cleared, and checks that
+//! every id is the id of a license with flags, suggesting the nearest id on a typo. A
+//! lookup through it returns a [`View`], whose [`metadata`](LicenseExt::metadata) and
+//! the predicates derived from it use the overridden flags, while its
+//! [`id`](License::id), its [`text`](License::text) and its other data are those of the
+//! crate. The licenses of [`from_id_ext`] and [`LICENSES_EXT`] never change.
+//!
+//! An override is applied flag by flag: a flag that is set or cleared replaces the flag
+//! of the crate, and the other flags keep theirs. A later override of the same flag
+//! replaces the earlier one.
+//!
+//! With the `serde` feature, the overrides are deserialized from a document that maps
+//! ids to `permissions`, `conditions` and `limitations`, such as this TOML:
+//!
+//! ```toml
+//! # A partial override: the flags that are given are set or cleared, the others keep
+//! # the flags of the crate.
+//! [BSD-3-Clause.limitations]
+//! no_trademark_rights = true
+//!
+//! # A full override: the flags that are listed are set, the others are cleared.
+//! [MIT]
+//! conditions = ["license_and_copyright_notice", "notice_file"]
+//! ```
+//!
+//! The names of the flags are those of [`Flag::name`], and a group that isn't given
+//! keeps the flags of the crate.
+//!
+//! Requires the `alloc` feature.
+//!
+//! # Examples
+//! ```
+//! use license::overrides::Overrides;
+//! use license::{Condition, LicenseExt};
+//!
+//! let mut overrides = Overrides::new();
+//! overrides.set("MIT", Condition::NoticeFile, true).unwrap();
+//! let mit = overrides.get("MIT").unwrap();
+//! assert!(mit.conditions().notice_file() && mit.conditions().license_and_copyright_notice());
+//! assert!(!license::from_id_ext("MIT").unwrap().conditions().notice_file());
+//!
+//! let err = overrides.set("Apache-20", Condition::NoticeFile, true).unwrap_err();
+//! assert_eq!(err.to_string(), "unknown license id `Apache-20`, did you mean `Apache-2.0`?");
+//! ```
+
+use crate::*;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+
+/// Flags that replace those of the crate, see the [module](self).
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq)]
+pub struct Overrides {
+    /// Sorted by id.
+    overrides: Vec<Override>,
+}
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+struct Override {
+    id: &'static str,
+    set: Metadata,
+    cleared: Metadata,
+}
+
+impl Overrides {
+    /// Creates overrides that change nothing.
+    pub fn new() -> Self {
+        Overrides::default()
+    }
+
+    /// Sets or clears a flag of a license, which is looked up with [`from_id_ext`].
+    ///
+    /// Fails if the id is unknown.
+    pub fn set(
+        &mut self,
+        id: &str,
+        flag: impl Into<Flag>,
+        value: bool,
+    ) -> Result<&mut Self, OverrideError> {
+        let license = resolve(id)?;
+        self.push(license, flag.into(), value);
+        Ok(self)
+    }
+
+    /// Replaces every flag of a license, which is looked up with [`from_id_ext`].
+    ///
+    /// Fails if the id is unknown.
+    pub fn replace(&mut self, id: &str, metadata: Metadata) -> Result<&mut Self, OverrideError> {
+        let license = resolve(id)?;
+        for flag in all_flags() {
+            self.push(license, flag, metadata.contains(flag));
+        }
+        Ok(self)
+    }
+
+    fn push(&mut self, license: &'static dyn LicenseExt, flag: Flag, value: bool) {
+        let id = license.id();
+        let i = match self.overrides.binary_search_by(|o| o.id.cmp(id)) {
+            Ok(i) => i,
+            Err(i) => {
+                self.overrides.insert(
+                    i,
+                    Override {
+                        id,
+                        set: Metadata::default(),
+                        cleared: Metadata::default(),
+                    },
+                );
+                i
+            }
+        };
+        let o = &mut self.overrides[i];
+        if value {
+            o.set = with_flag(o.set, flag, true);
+            o.cleared = with_flag(o.cleared, flag, false);
+        } else {
+            o.set = with_flag(o.set, flag, false);
+            o.cleared = with_flag(o.cleared, flag, true);
+        }
+    }
+
+    /// The flags of a license with the overrides applied.
+    pub fn metadata(&self, license: &dyn LicenseExt) -> Metadata {
+        let metadata = license.metadata();
+        let o = match self.overrides.binary_search_by(|o| o.id.cmp(license.id())) {
+            Ok(i) => self.overrides[i],
+            Err(_) => return metadata,
+        };
+        Metadata::new(
+            metadata
+                .permissions()
+                .union(o.set.permissions())
+                .difference(o.cleared.permissions()),
+            metadata
+                .conditions()
+                .union(o.set.conditions())
+                .difference(o.cleared.conditions()),
+            metadata
+                .limitations()
+                .union(o.set.limitations())
+                .difference(o.cleared.limitations()),
+        )
+    }
+
+    /// A license with the overrides applied.
+    pub fn view(&self, license: &'static dyn LicenseExt) -> View {
+        View {
+            license,
+            metadata: self.metadata(license),
+        }
+    }
+
+    /// Returns a license based on the provided id, as [`from_id_ext`] does, with the
+    /// overrides applied.
+    pub fn get(&self, id: &str) -> Option<View> {
+        from_id_ext(id).map(|license| self.view(license))
+    }
+
+    /// The ids of the overridden licenses, ordered by id.
+    pub fn ids(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.overrides.iter().map(|o| o.id)
+    }
+}
+
+/// The license that the lookups find for `id`.
+fn resolve(id: &str) -> Result<&'static dyn LicenseExt, OverrideError> {
+    from_id_ext(id).ok_or_else(|| OverrideError::UnknownLicense {
+        id: id.into(),
+        suggestion: nearest(id, LICENSES_EXT.iter().map(|license| license.id())),
+    })
+}
+
+fn all_flags() -> impl Iterator<Item = Flag> {
+    Permission::ALL
+        .iter()
+        .map(|&flag| Flag::from(flag))
+        .chain(Condition::ALL.iter().map(|&flag| Flag::from(flag)))
+        .chain(Limitation::ALL.iter().map(|&flag| Flag::from(flag)))
+}
+
+/// `metadata` with `flag` set or cleared.
+fn with_flag(metadata: Metadata, flag: Flag, value: bool) -> Metadata {
+    let toggle = |bits: u32, bit: u32| if value { bits | bit } else { bits & !bit };
+    let (permissions, conditions, limitations) = (
+        metadata.permissions(),
+        metadata.conditions(),
+        metadata.limitations(),
+    );
+    match flag {
+        Flag::Permission(flag) => Metadata::new(
+            Permissions::from_bits_truncate(toggle(permissions.bits(), flag.bit())),
+            conditions,
+            limitations,
+        ),
+        Flag::Condition(flag) => Metadata::new(
+            permissions,
+            Conditions::from_bits_truncate(toggle(conditions.bits(), flag.bit())),
+            limitations,
+        ),
+        Flag::Limitation(flag) => Metadata::new(
+            permissions,
+            conditions,
+            Limitations::from_bits_truncate(toggle(limitations.bits(), flag.bit())),
+        ),
+    }
+}
+
+/// A license with overridden flags, see [`Overrides::view`].
+///
+/// Its [`metadata`](LicenseExt::metadata) is overridden, and so are the methods derived
+/// from it, such as [`is_permissive`](LicenseExt::is_permissive). Everything else is
+/// the data of the license of the crate, such as its id, its text and its
+/// [`notice`](LicenseExt::notice). The [`patent_posture`](LicenseExt::patent_posture)
+/// is derived from the flags if an override changes the patent flags.
+#[derive(Copy, Clone)]
+pub struct View {
+    license: &'static dyn LicenseExt,
+    metadata: Metadata,
+}
+
+impl View {
+    /// The license of the crate, without the overrides.
+    pub fn license(&self) -> &'static dyn LicenseExt {
+        self.license
+    }
+
+    /// Says if an override changes a flag of the license.
+    pub fn is_overridden(&self) -> bool {
+        self.metadata != self.license.metadata()
+    }
+}
+
+impl Debug for View {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("View")
+            .field("license", &self.license.id())
+            .field("metadata", &self.metadata)
+            .finish()
+    }
+}
+
+impl License for View {
+    fn name(&self) -> &'static str {
+        self.license.name()
+    }
+
+    fn id(&self) -> &'static str {
+        self.license.id()
+    }
+
+    fn text(&self) -> &'static str {
+        self.license.text()
+    }
+
+    fn header(&self) -> Option<&'static str> {
+        self.license.header()
+    }
+
+    fn sections(&self) -> &'static [text::Section] {
+        self.license.sections()
+    }
+
+    fn is_osi_approved(&self) -> bool {
+        self.license.is_osi_approved()
+    }
+
+    fn is_fsf_libre(&self) -> bool {
+        self.license.is_fsf_libre()
+    }
+
+    fn is_deprecated(&self) -> bool {
+        self.license.is_deprecated()
+    }
+
+    fn see_also(&self) -> &'static [&'static str] {
+        self.license.see_also()
+    }
+
+    /// The license of the crate, so that it downcasts to the type of the license.
+    fn as_any(&self) -> &dyn core::any::Any {
+        self.license.as_any()
+    }
+
+    fn exception(&self) -> Option<&'static dyn LicenseException> {
+        self.license.exception()
+    }
+
+    /// The license of the crate, without the overrides.
+    fn ext(&self) -> Option<&'static dyn LicenseExt> {
+        Some(self.license)
+    }
+
+    fn family(&self) -> Family {
+        self.license.family()
+    }
+}
+
+impl LicenseExt for View {
+    fn metadata(&self) -> Metadata {
+        self.metadata
+    }
+
+    fn code(&self) -> u16 {
+        self.license.code()
+    }
+
+    fn slug(&self) -> &'static str {
+        self.license.slug()
+    }
+
+    fn triggers_on_network_use(&self) -> bool {
+        self.license.triggers_on_network_use()
+    }
+
+    fn is_source_available(&self) -> bool {
+        self.license.is_source_available()
+    }
+
+    fn patent_posture(&self) -> PatentPosture {
+        let patents = |metadata: Metadata| {
+            (
+                metadata.permissions().patent_rights(),
+                metadata.limitations().no_patent_rights(),
+            )
+        };
+        if patents(self.metadata) == patents(self.license.metadata()) {
+            self.license.patent_posture()
+        } else if self.metadata.limitations().no_patent_rights() {
+            PatentPosture::ExplicitNonGrant
+        } else if self.metadata.permissions().patent_rights() {
+            PatentPosture::ExpressGrant
+        } else {
+            PatentPosture::Silent
+        }
+    }
+
+    fn compatible_outbound(&self) -> &'static [&'static str] {
+        self.license.compatible_outbound()
+    }
+
+    /// The span of the license of the crate, for the flags that are still set.
+    fn flag_provenance(&self, flag: Flag) -> Option<text::TextSpan> {
+        if !self.metadata.contains(flag) {
+            return None;
+        }
+        self.license.flag_provenance(flag)
+    }
+
+    fn endorsement_restriction(&self) -> Option<&'static str> {
+        self.license.endorsement_restriction()
+    }
+
+    fn mpl_secondary_license_allowed(&self) -> Option<bool> {
+        self.license.mpl_secondary_license_allowed()
+    }
+
+    fn apply_instructions<'a>(
+        &self,
+        program: &'a str,
+        author: &'a str,
+        year: u16,
+    ) -> Option<ApplyInstructions<'a>> {
+        self.license.apply_instructions(program, author, year)
+    }
+
+    fn notice<'a>(&self, holder: &'a str, year: u16) -> Option<Notice<'a>> {
+        self.license.notice(holder, year)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod de {
+    use super::*;
+    use ::serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+
+    impl<'de> ::serde::Deserialize<'de> for Overrides {
+        /// Deserializes a map of license ids to groups of flags, failing on the first
+        /// unknown id or flag.
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_map(OverridesVisitor)
+        }
+    }
+
+    struct OverridesVisitor;
+
+    impl<'de> Visitor<'de> for OverridesVisitor {
+        type Value = Overrides;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a map of SPDX license ids to overrides")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Overrides, A::Error> {
+            let mut overrides = Overrides::new();
+            while let Some(license) = map.next_key_seed(Id)? {
+                map.next_value_seed(Groups {
+                    overrides: &mut overrides,
+                    license,
+                })?;
+            }
+            Ok(overrides)
+        }
+    }
+
+    /// Deserializes a license id into the license.
+    struct Id;
+
+    impl<'de> DeserializeSeed<'de> for Id {
+        type Value = &'static dyn LicenseExt;
+
+        fn deserialize<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for Id {
+        type Value = &'static dyn LicenseExt;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("an SPDX license id")
+        }
+
+        fn visit_str<E: de::Error>(self, id: &str) -> Result<Self::Value, E> {
+            resolve(id).map_err(E::custom)
+        }
+    }
+
+    const GROUPS: &[&str] = &["permissions", "conditions", "limitations"];
+
+    /// The flags of one of the groups.
+    #[derive(Copy, Clone)]
+    enum Group {
+        Permissions,
+        Conditions,
+        Limitations,
+    }
+
+    impl Group {
+        fn flags(self) -> impl Iterator<Item = Flag> {
+            all_flags().filter(move |flag| {
+                matches!(
+                    (self, flag),
+                    (Group::Permissions, Flag::Permission(_))
+                        | (Group::Conditions, Flag::Condition(_))
+                        | (Group::Limitations, Flag::Limitation(_))
+                )
+            })
+        }
+    }
+
+    /// Deserializes the name of a group.
+    struct GroupName;
+
+    impl<'de> DeserializeSeed<'de> for GroupName {
+        type Value = Group;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Group, D::Error> {
+            deserializer.deserialize_identifier(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for GroupName {
+        type Value = Group;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("`permissions`, `conditions` or `limitations`")
+        }
+
+        fn visit_str<E: de::Error>(self, group: &str) -> Result<Group, E> {
+            match group {
+                "permissions" => Ok(Group::Permissions),
+                "conditions" => Ok(Group::Conditions),
+                "limitations" => Ok(Group::Limitations),
+                _ => Err(E::unknown_field(group, GROUPS)),
+            }
+        }
+    }
+
+    /// Deserializes the groups of a license into the overrides.
+    struct Groups<'a> {
+        overrides: &'a mut Overrides,
+        license: &'static dyn LicenseExt,
+    }
+
+    impl<'de> DeserializeSeed<'de> for Groups<'_> {
+        type Value = ();
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+            deserializer.deserialize_map(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for Groups<'_> {
+        type Value = ();
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a map of `permissions`, `conditions` or `limitations` to flags")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+            while let Some(group) = map.next_key_seed(GroupName)? {
+                map.next_value_seed(Flags {
+                    overrides: &mut *self.overrides,
+                    license: self.license,
+                    group,
+                })?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Deserializes the flags of a group into the overrides, from a map of flags to
+    /// booleans or from a list of the flags that are set.
+    struct Flags<'a> {
+        overrides: &'a mut Overrides,
+        license: &'static dyn LicenseExt,
+        group: Group,
+    }
+
+    impl<'de> DeserializeSeed<'de> for Flags<'_> {
+        type Value = ();
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+            deserializer.deserialize_any(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for Flags<'_> {
+        type Value = ();
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a map of flags to booleans, or a list of flags")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+            while let Some(flag) = map.next_key_seed(Name(self.group))? {
+                let value = map.next_value()?;
+                self.overrides.push(self.license, flag, value);
+            }
+            Ok(())
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+            let mut set = Vec::new();
+            while let Some(flag) = seq.next_element_seed(Name(self.group))? {
+                set.push(flag);
+            }
+            for flag in self.group.flags() {
+                self.overrides.push(self.license, flag, set.contains(&flag));
+            }
+            Ok(())
+        }
+    }
+
+    /// Deserializes the name of a flag of a group.
+    struct Name(Group);
+
+    impl<'de> DeserializeSeed<'de> for Name {
+        type Value = Flag;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Flag, D::Error> {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for Name {
+        type Value = Flag;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("the name of a flag")
+        }
+
+        fn visit_str<E: de::Error>(self, name: &str) -> Result<Flag, E> {
+            let group = self.0;
+            group
+                .flags()
+                .find(|flag| flag.name() == name)
+                .ok_or_else(|| {
+                    E::custom(format_args!(
+                        "unknown flag `{}` of the {}",
+                        name, GROUPS[group as usize]
+                    ))
+                })
+        }
+    }
+}
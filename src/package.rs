@@ -0,0 +1,376 @@
+use crate::*;
+use core::fmt::{self, Display, Formatter};
+
+/// The license expression of a package, validated when it is compiled.
+///
+/// It is usually created with [`from_env!`], or with [`declared!`] for a literal.
+///
+/// # Examples
+/// ```
+/// let license = license::declared!("MIT");
+/// assert_eq!(license.to_string(), "MIT");
+/// assert!(license.ext().unwrap().conditions().license_and_copyright_notice());
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct PackageLicense {
+    expression: &'static str,
+}
+
+impl PackageLicense {
+    /// Validates a license expression, in a constant expression if needed.
+    ///
+    /// The expression is made of SPDX license ids, optionally followed by `+` and by
+    /// `WITH` and an exception id, combined with `AND`, `OR` and parentheses. A `/`
+    /// is accepted as `OR`, since Cargo still accepts it. `LicenseRef-` ids are rejected.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::{PackageLicense, PackageLicenseError};
+    ///
+    /// assert!(PackageLicense::parse("(MIT OR Apache-2.0) AND GPL-2.0-only WITH Classpath-exception-2.0").is_ok());
+    /// assert!(matches!(PackageLicense::parse("MIT OR"), Err(PackageLicenseError::Syntax { position: 6, .. })));
+    /// assert!(matches!(PackageLicense::parse("MIT-2.0"), Err(PackageLicenseError::UnknownLicense { .. })));
+    /// ```
+    pub const fn parse(expression: &'static str) -> Result<Self, PackageLicenseError> {
+        match validate(expression.as_bytes(), false) {
+            Ok(()) => Ok(PackageLicense { expression }),
+            Err(Invalid::Empty) => Err(PackageLicenseError::Empty),
+            Err(Invalid::UnknownLicense(position)) => Err(PackageLicenseError::UnknownLicense {
+                expression,
+                position,
+            }),
+            Err(Invalid::UnknownException(position)) => {
+                Err(PackageLicenseError::UnknownException {
+                    expression,
+                    position,
+                })
+            }
+            Err(Invalid::Syntax(position)) => Err(PackageLicenseError::Syntax {
+                expression,
+                position,
+            }),
+        }
+    }
+
+    /// The license expression, as written.
+    pub const fn expression(&self) -> &'static str {
+        self.expression
+    }
+
+    /// The extension license, if the expression is a single license that has one.
+    pub fn ext(&self) -> Option<&'static dyn LicenseExt> {
+        from_id_ext(self.expression.trim())
+    }
+}
+
+impl Display for PackageLicense {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.expression)
+    }
+}
+
+const fn is_id_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'-' || b == b'.' || b == b':'
+}
+
+const fn skip_spaces(bytes: &[u8], mut pos: usize) -> usize {
+    while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+        pos += 1;
+    }
+    pos
+}
+
+const fn word_end(bytes: &[u8], mut pos: usize) -> usize {
+    while pos < bytes.len() && is_id_byte(bytes[pos]) {
+        pos += 1;
+    }
+    pos
+}
+
+/// Says if `bytes[start..end]` is `word`.
+const fn is_word(bytes: &[u8], start: usize, end: usize, word: &str) -> bool {
+    end - start == word.len() && starts_with(bytes, start, end, word)
+}
+
+/// Says if `bytes[start..end]` starts with `prefix`.
+const fn starts_with(bytes: &[u8], start: usize, end: usize, prefix: &str) -> bool {
+    let prefix = prefix.as_bytes();
+    if end - start < prefix.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if bytes[start + i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn is_one_of(bytes: &[u8], start: usize, end: usize, ids: &[&str]) -> bool {
+    let mut i = 0;
+    while i < ids.len() {
+        if is_word(bytes, start, end, ids[i]) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Says if `bytes[start..end]` is a `LicenseRef-` id, optionally prefixed with a
+/// `DocumentRef-` id and a `:`.
+const fn is_license_ref(bytes: &[u8], start: usize, end: usize) -> bool {
+    let mut start = start;
+    if starts_with(bytes, start, end, "DocumentRef-") {
+        while start < end && bytes[start] != b':' {
+            start += 1;
+        }
+        if start == end {
+            return false;
+        }
+        start += 1;
+    }
+    let prefix = "LicenseRef-".len();
+    end - start > prefix && starts_with(bytes, start, end, "LicenseRef-")
+}
+
+/// A token of a license expression, see [`token`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Token {
+    /// `(`.
+    Open,
+    /// `)`.
+    Close,
+    /// `AND`.
+    And,
+    /// `OR`, or `/`.
+    Or,
+    /// `WITH`.
+    With,
+    /// A license or exception id, with a `+` that directly follows it.
+    Id,
+    /// Any other character.
+    Other,
+}
+
+/// The token after the spaces at `pos`, and where it starts and ends, or `None` at the
+/// end of the expression.
+///
+/// This is the tokenizer of every expression of the crate, so that they agree on where
+/// an id ends.
+pub(crate) const fn token(bytes: &[u8], pos: usize) -> Option<(Token, usize, usize)> {
+    let start = skip_spaces(bytes, pos);
+    if start == bytes.len() {
+        return None;
+    }
+    let end = word_end(bytes, start);
+    let token = if end > start {
+        if is_word(bytes, start, end, "AND") {
+            Token::And
+        } else if is_word(bytes, start, end, "OR") {
+            Token::Or
+        } else if is_word(bytes, start, end, "WITH") {
+            Token::With
+        } else {
+            Token::Id
+        }
+    } else {
+        match bytes[start] {
+            b'(' => Token::Open,
+            b')' => Token::Close,
+            b'/' => Token::Or,
+            _ => Token::Other,
+        }
+    };
+    let end = match token {
+        Token::Id if end < bytes.len() && bytes[end] == b'+' => end + 1,
+        Token::Open | Token::Close | Token::Or if end == start => start + 1,
+        // The whole character, so that the token is a `str`.
+        Token::Other => start + utf8_len(bytes[start]),
+        _ => end,
+    };
+    Some((token, start, end))
+}
+
+const fn utf8_len(first: u8) -> usize {
+    match first {
+        0..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        _ => 4,
+    }
+}
+
+/// The end of an id token without its `+`.
+pub(crate) const fn id_end(bytes: &[u8], end: usize) -> usize {
+    if bytes[end - 1] == b'+' {
+        end - 1
+    } else {
+        end
+    }
+}
+
+/// The tokens of an expression, with their text.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub(crate) struct Tokens<'a> {
+    pub(crate) expression: &'a str,
+    pub(crate) pos: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Tokens<'a> {
+    pub(crate) fn new(expression: &'a str) -> Self {
+        Tokens { expression, pos: 0 }
+    }
+
+    pub(crate) fn peek(&self) -> Option<Token> {
+        token(self.expression.as_bytes(), self.pos).map(|(token, _, _)| token)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Iterator for Tokens<'a> {
+    type Item = (Token, &'a str);
+
+    fn next(&mut self) -> Option<(Token, &'a str)> {
+        let (token, start, end) = token(self.expression.as_bytes(), self.pos)?;
+        self.pos = end;
+        Some((token, &self.expression[start..end]))
+    }
+}
+
+/// Why an expression is invalid, and where, see [`validate`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Invalid {
+    Empty,
+    UnknownLicense(usize),
+    UnknownException(usize),
+    Syntax(usize),
+}
+
+/// Validates an expression, accepting `LicenseRef-` ids if `refs` is set.
+pub(crate) const fn validate(bytes: &[u8], refs: bool) -> Result<(), Invalid> {
+    if skip_spaces(bytes, 0) == bytes.len() {
+        return Err(Invalid::Empty);
+    }
+    match parse_expression(bytes, 0, refs) {
+        Ok(end) if end == bytes.len() => Ok(()),
+        Ok(end) => Err(Invalid::Syntax(end)),
+        Err(invalid) => Err(invalid),
+    }
+}
+
+/// Parses terms combined with `AND`, `OR` and `/`, and returns where they end.
+const fn parse_expression(bytes: &[u8], pos: usize, refs: bool) -> Result<usize, Invalid> {
+    let mut pos = match parse_term(bytes, pos, refs) {
+        Ok(pos) => pos,
+        Err(invalid) => return Err(invalid),
+    };
+    loop {
+        pos = match token(bytes, pos) {
+            None => return Ok(bytes.len()),
+            Some((Token::Close, start, _)) => return Ok(start),
+            Some((Token::And, _, end)) | Some((Token::Or, _, end)) => end,
+            Some((_, start, _)) => return Err(Invalid::Syntax(start)),
+        };
+        pos = match parse_term(bytes, pos, refs) {
+            Ok(pos) => pos,
+            Err(invalid) => return Err(invalid),
+        };
+    }
+}
+
+/// Parses a parenthesized expression or a license, and returns where it ends.
+const fn parse_term(bytes: &[u8], pos: usize, refs: bool) -> Result<usize, Invalid> {
+    let (start, end) = match token(bytes, pos) {
+        None => return Err(Invalid::Syntax(bytes.len())),
+        Some((Token::Open, _, end)) => {
+            return match parse_expression(bytes, end, refs) {
+                Ok(end) if end < bytes.len() && bytes[end] == b')' => Ok(end + 1),
+                Ok(end) => Err(Invalid::Syntax(end)),
+                Err(invalid) => Err(invalid),
+            };
+        }
+        // An operator where a license is expected is an unknown license.
+        Some((_, start, end)) if is_id_byte(bytes[start]) => (start, end),
+        Some((_, start, _)) => return Err(Invalid::Syntax(start)),
+    };
+    let id = id_end(bytes, end);
+    let known = is_one_of(bytes, start, id, LICENSE_IDS)
+        || (refs && id == end && is_license_ref(bytes, start, end));
+    if !known {
+        return Err(Invalid::UnknownLicense(start));
+    }
+
+    let with_end = match token(bytes, end) {
+        Some((Token::With, _, with_end)) => with_end,
+        _ => return Ok(end),
+    };
+    let (exception, exception_end) = match token(bytes, with_end) {
+        Some((_, start, end)) if is_id_byte(bytes[start]) => (start, id_end(bytes, end)),
+        Some((_, start, _)) => return Err(Invalid::Syntax(start)),
+        None => return Err(Invalid::Syntax(bytes.len())),
+    };
+    if !is_one_of(bytes, exception, exception_end, EXCEPTION_IDS) {
+        return Err(Invalid::UnknownException(exception));
+    }
+    Ok(exception_end)
+}
+
+/// Validates a license expression at compile time and expands to a
+/// `&'static` [`PackageLicense`].
+///
+/// An invalid expression is a compile error that includes the expression, see
+/// [`PackageLicense::parse`] for the accepted syntax.
+///
+/// # Examples
+/// ```
+/// let license = license::declared!("MIT OR Apache-2.0");
+/// assert_eq!(license.expression(), "MIT OR Apache-2.0");
+/// assert!(license.ext().is_none());
+/// ```
+///
+/// ```compile_fail
+/// let license = license::declared!("MIT OR Apache");
+/// ```
+#[macro_export]
+macro_rules! declared {
+    ($($expression:tt)+) => {{
+        const LICENSE: &$crate::PackageLicense = &match $crate::PackageLicense::parse($($expression)+) {
+            Ok(license) => license,
+            Err($crate::PackageLicenseError::Empty) => {
+                panic!("the license expression is empty, is `license` set in Cargo.toml?")
+            }
+            Err($crate::PackageLicenseError::UnknownLicense { .. }) => {
+                panic!(concat!("unknown license id in `", $($expression)+, "`"))
+            }
+            Err($crate::PackageLicenseError::UnknownException { .. }) => {
+                panic!(concat!("unknown exception id in `", $($expression)+, "`"))
+            }
+            Err(_) => {
+                panic!(concat!("invalid license expression `", $($expression)+, "`"))
+            }
+        };
+        LICENSE
+    }};
+}
+
+/// The license of the package being compiled, read from `CARGO_PKG_LICENSE` and
+/// validated at compile time, see [`declared!`].
+///
+/// # Examples
+/// ```
+/// fn version() -> String {
+///     format!("{} {} ({})", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"), license::from_env!())
+/// }
+/// # assert!(version().ends_with("(MIT OR Apache-2.0)"));
+/// ```
+#[macro_export]
+macro_rules! from_env {
+    () => {
+        $crate::declared!(env!("CARGO_PKG_LICENSE"))
+    };
+}
@@ -0,0 +1,145 @@
+//! Values that fill in the placeholders of license texts.
+//!
+//! # Examples
+//! ```
+//! use license::params::CopyrightYears;
+//!
+//! let years = CopyrightYears::range(2019, 2025).unwrap();
+//! let mit = license::from_id_ext("MIT").unwrap();
+//! let notice = mit.notice("Jane Doe", years.start()).unwrap().with_years(years);
+//! assert!(notice.to_string().starts_with("MIT License\n\nCopyright (c) 2019-2025 Jane Doe\n"));
+//! ```
+
+use crate::error::CopyrightYearsError;
+use core::fmt::{self, Display, Formatter};
+
+/// The years of a copyright, a single year or a range of years.
+///
+/// Its [`Display`] impl writes the year, such as `2021`, or the first and last years,
+/// such as `2019-2025`.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct CopyrightYears {
+    start: u16,
+    end: u16,
+}
+
+impl CopyrightYears {
+    /// A single year.
+    pub const fn single(year: u16) -> Self {
+        CopyrightYears {
+            start: year,
+            end: year,
+        }
+    }
+
+    /// The years from `start` to `end`, both included, which is a single year if they
+    /// are the same.
+    ///
+    /// # Errors
+    /// [`CopyrightYearsError::Reversed`] if `start` is after `end`.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::params::CopyrightYears;
+    ///
+    /// assert_eq!(CopyrightYears::range(2019, 2025).unwrap().to_string(), "2019-2025");
+    /// assert_eq!(CopyrightYears::range(2021, 2021).unwrap().to_string(), "2021");
+    /// assert!(CopyrightYears::range(2025, 2019).is_err());
+    /// ```
+    pub const fn range(start: u16, end: u16) -> Result<Self, CopyrightYearsError> {
+        if start > end {
+            return Err(CopyrightYearsError::Reversed { start, end });
+        }
+        Ok(CopyrightYears { start, end })
+    }
+
+    /// The years from `start` to the current year of the system clock.
+    ///
+    /// Requires the `clock` feature.
+    ///
+    /// # Errors
+    /// [`CopyrightYearsError::FutureStart`] if `start` is after the current year.
+    #[cfg(feature = "clock")]
+    pub fn through_current(start: u16) -> Result<Self, CopyrightYearsError> {
+        let current = current_year();
+        if start > current {
+            return Err(CopyrightYearsError::FutureStart { start, current });
+        }
+        Ok(CopyrightYears {
+            start,
+            end: current,
+        })
+    }
+
+    /// The first year.
+    pub const fn start(&self) -> u16 {
+        self.start
+    }
+
+    /// The last year, which is the first year if it is a single year.
+    pub const fn end(&self) -> u16 {
+        self.end
+    }
+
+    /// Says if it is a single year.
+    pub const fn is_single(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+impl From<u16> for CopyrightYears {
+    fn from(year: u16) -> Self {
+        CopyrightYears::single(year)
+    }
+}
+
+impl Display for CopyrightYears {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.is_single() {
+            write!(f, "{}", self.start)
+        } else {
+            write!(f, "{}-{}", self.start, self.end)
+        }
+    }
+}
+
+/// The year of the system clock in UTC, or 1970 if the clock is before it.
+#[cfg(feature = "clock")]
+fn current_year() -> u16 {
+    use core::convert::TryFrom;
+
+    let (year, _, _) = civil_date(now());
+    u16::try_from(year).unwrap_or(u16::MAX)
+}
+
+/// The seconds since 1970-01-01 of the system clock, or 0 if the clock is before it.
+#[cfg(any(feature = "clock", feature = "spdx-doc"))]
+pub(crate) fn now() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// The year, the month and the day in UTC of a time in seconds since 1970-01-01.
+#[cfg(any(feature = "clock", feature = "spdx-doc"))]
+pub(crate) fn civil_date(secs: u64) -> (u64, u64, u64) {
+    // The civil calendar from days since 1970-01-01, counted in eras of 400 years that
+    // start on March 1, so that the leap day is the last day of a year.
+    let days = secs / 86_400 + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let march_based_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * march_based_month + 2) / 5 + 1;
+    let month = if march_based_month < 10 {
+        march_based_month + 3
+    } else {
+        march_based_month - 9
+    };
+    let year = era * 400 + year_of_era + u64::from(march_based_month >= 10);
+    (year, month, day)
+}
@@ -0,0 +1,56 @@
+/// What a license says about the patents of its contributors, see
+/// [`LicenseExt::patent_posture`](crate::LicenseExt::patent_posture).
+///
+/// This is curated data that is not part of SPDX. It is what the
+/// [`patent_rights`](crate::Permissions::patent_rights) and
+/// [`no_patent_rights`](crate::Limitations::no_patent_rights) flags of the licenses of
+/// this crate are derived from, so they always agree, and it tells apart the grants that
+/// end for a licensee who sues over patents, which the flags can't.
+///
+/// # Examples
+/// ```
+/// use license::PatentPosture;
+///
+/// let posture = |id| license::from_id_ext(id).unwrap().patent_posture();
+/// assert_eq!(posture("GPL-3.0-only"), PatentPosture::ExpressGrant);
+/// assert_eq!(posture("Apache-2.0"), PatentPosture::GrantWithTermination);
+/// assert_eq!(posture("BSD-3-Clause-Clear"), PatentPosture::ExplicitNonGrant);
+/// assert_eq!(posture("MIT"), PatentPosture::Silent);
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PatentPosture {
+    /// Grants the rights in the patents of contributors, such as the GPL-3.0.
+    ///
+    /// The grant may still end with the rest of the license, such as when the licensee
+    /// violates it, but not for suing over patents alone.
+    ExpressGrant,
+    /// Grants the rights in the patents of contributors, and the grant ends for a
+    /// licensee who claims that the software infringes a patent, such as the
+    /// litigation clause of Apache-2.0.
+    GrantWithTermination,
+    /// Says that no rights in patents are granted, such as BSD-3-Clause-Clear and CC0.
+    ExplicitNonGrant,
+    /// Doesn't mention patents, such as MIT, which may or may not imply a grant.
+    ///
+    /// It is also the posture of the [`UNKNOWN`](crate::UNKNOWN) and
+    /// [`NONE`](crate::NONE) sentinels, whose flags are empty.
+    Silent,
+}
+
+impl PatentPosture {
+    /// Says if rights in patents are granted, as the
+    /// [`patent_rights`](crate::Permissions::patent_rights) flag does.
+    pub const fn grants_patent_rights(self) -> bool {
+        matches!(
+            self,
+            PatentPosture::ExpressGrant | PatentPosture::GrantWithTermination
+        )
+    }
+
+    /// Says if rights in patents are explicitly not granted, as the
+    /// [`no_patent_rights`](crate::Limitations::no_patent_rights) flag does.
+    pub const fn excludes_patent_rights(self) -> bool {
+        matches!(self, PatentPosture::ExplicitNonGrant)
+    }
+}
@@ -0,0 +1,758 @@
+//! Allow and deny lists evaluated against license expressions.
+//!
+//! With the `serde` feature, a [`Policy`] is serialized to and deserialized from a
+//! document of its lists, any of which may be left out, such as this TOML:
+//!
+//! ```toml
+//! allow = ["MIT", "Apache-2.0"]
+//! deny = ["GPL-2.0-or-later"]
+//! allow_copyleft = ["none", "weak"]
+//! deny_copyleft = ["strong", "network"]
+//! deny_conditions = ["network_use_is_distribution"]
+//! ```
+//!
+//! Requires the `alloc` feature.
+
+use crate::expression::{Expression, Leaf};
+use crate::report::Copyleft;
+use crate::resolve::nearest;
+use crate::tags::TagSet;
+use crate::*;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+/// Decides which license expressions are acceptable, such as for the dependencies
+/// of a project.
+///
+/// A license of an expression is judged by the first rule that applies:
+///
+/// 1. It is denied if its id is on the deny list.
+/// 2. It is allowed if its id is on the allow list.
+/// 3. It is denied if it imposes a denied condition, or its [`Copyleft`] class is denied.
+/// 4. It is allowed if its copyleft class is allowed.
+/// 5. Otherwise it needs review, which is also the case for a license without
+///    extension data that isn't on a list.
+///
+/// An id on a list matches a license as written, such as `GPL-2.0+`, as resolved,
+/// such as `GPL-2.0-or-later`, and with any exception, unless the list has the
+/// whole `WITH` expression. An exception only adds permissions, so a license with
+/// an exception is otherwise judged by the data of its license.
+///
+/// A license offered under "or any later version", such as `GPL-2.0-or-later` or
+/// `MPL-1.1+`, that isn't allowed as written is allowed if one of its versions is, from
+/// its own to the newest, such as `GPL-3.0-only`, unless its id is on the deny list.
+/// [`PackageLicense::effective_licenses`] tells which version is exercised. An `-only`
+/// license is never upgraded.
+///
+/// # Examples
+/// ```
+/// use license::expression::Expression;
+/// use license::policy::{Decision, Policy};
+/// use license::report::Copyleft;
+///
+/// let mut policy = Policy::new();
+/// policy.allow_copyleft(Copyleft::None).deny_copyleft(Copyleft::Strong);
+///
+/// let expression = Expression::parse("MIT OR GPL-3.0-only").unwrap();
+/// match policy.evaluate(&expression) {
+///     Decision::Allow { chosen } => assert_eq!(chosen[0].to_string(), "MIT"),
+///     other => panic!("{:?}", other),
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Policy {
+    allowed: Vec<String>,
+    denied: Vec<String>,
+    allowed_copyleft: Vec<Copyleft>,
+    denied_copyleft: Vec<Copyleft>,
+    denied_conditions: Conditions,
+}
+
+/// The result of [`Policy::evaluate`], whose licenses are leaves of the expression.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub enum Decision {
+    /// The expression is acceptable.
+    Allow {
+        /// The licenses whose terms are accepted, in the order of the expression.
+        ///
+        /// For `OR`, the allowed branch with the weakest copyleft is chosen, and the
+        /// leftmost of equally permissive ones. A license without extension data
+        /// counts as the strongest copyleft.
+        chosen: Vec<Leaf>,
+    },
+    /// The expression is not acceptable.
+    Deny {
+        /// Why, for every denied license that made the expression unacceptable.
+        reasons: Vec<Denial>,
+    },
+    /// The expression is acceptable if the licenses that need review are.
+    NeedsReview {
+        /// The licenses that need review, without duplicates.
+        unknowns: Vec<Leaf>,
+    },
+}
+
+/// Why a license is denied, see [`Decision::Deny`].
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Denial {
+    license: Leaf,
+    reason: DenyReason,
+}
+
+impl Denial {
+    /// The denied license.
+    pub fn license(&self) -> &Leaf {
+        &self.license
+    }
+
+    /// Why it is denied.
+    pub fn reason(&self) -> DenyReason {
+        self.reason
+    }
+}
+
+impl Display for Denial {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.reason {
+            DenyReason::Id => write!(f, "{} is denied", self.license),
+            DenyReason::Copyleft(class) => {
+                write!(f, "{} has a denied {} copyleft", self.license, class)
+            }
+            DenyReason::Conditions(_) => write!(f, "{} has a denied condition", self.license),
+        }
+    }
+}
+
+/// The rule that denies a license, see [`Denial`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum DenyReason {
+    /// Its id is on the deny list.
+    Id,
+    /// Its copyleft class is denied.
+    Copyleft(Copyleft),
+    /// It imposes these denied conditions.
+    Conditions(Conditions),
+}
+
+/// A license chosen by a policy, and the version that is exercised if it is offered under
+/// "or any later version", see [`PackageLicense::effective_licenses`].
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct EffectiveLicense {
+    written: Leaf,
+    exercised: Option<&'static str>,
+}
+
+impl EffectiveLicense {
+    /// The license, as in the expression.
+    pub fn written(&self) -> &Leaf {
+        &self.written
+    }
+
+    /// The version that is exercised, or `None` if the license is accepted as written.
+    ///
+    /// An exception of the license still applies to the version.
+    pub fn exercised(&self) -> Option<&'static str> {
+        self.exercised
+    }
+}
+
+impl Display for EffectiveLicense {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.exercised {
+            Some(version) => write!(f, "{} exercised as {}", self.written, version),
+            None => self.written.fmt(f),
+        }
+    }
+}
+
+impl PackageLicense {
+    /// The licenses that `policy` chooses for the expression, with the version that is
+    /// exercised of those that are only allowed through "or any later version", or
+    /// `None` if the expression isn't [allowed](Decision::Allow).
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::policy::Policy;
+    ///
+    /// let mut policy = Policy::new();
+    /// policy.allow("GPL-3.0-only")?.allow("MIT")?;
+    ///
+    /// let effective = license::declared!("GPL-2.0-or-later AND MIT").effective_licenses(&policy).unwrap();
+    /// let effective: Vec<_> = effective.iter().map(|l| l.to_string()).collect();
+    /// assert_eq!(effective, ["GPL-2.0-or-later exercised as GPL-3.0-only", "MIT"]);
+    ///
+    /// assert!(license::declared!("GPL-2.0-only").effective_licenses(&policy).is_none());
+    /// # Ok::<(), license::PolicyError>(())
+    /// ```
+    pub fn effective_licenses(&self, policy: &Policy) -> Option<Vec<EffectiveLicense>> {
+        let chosen = match policy.evaluate(&Expression::from(*self)) {
+            Decision::Allow { chosen } => chosen,
+            _ => return None,
+        };
+        let effective = chosen.into_iter().map(|written| {
+            let (id, as_written) = names(&written);
+            let exercised = match policy.judge_as_written(&id, &as_written) {
+                Judgement::Allow => None,
+                _ => policy.exercise(&id),
+            };
+            EffectiveLicense { written, exercised }
+        });
+        Some(effective.collect())
+    }
+}
+
+/// The judgement of one license in an expression.
+enum Judgement {
+    Allow,
+    Deny(DenyReason),
+    Review,
+}
+
+impl Policy {
+    /// Creates a policy that allows and denies nothing, so that every license needs review.
+    pub fn new() -> Self {
+        Policy::default()
+    }
+
+    /// Allows a license.
+    ///
+    /// The id is a single license of an [`Expression`], such as `MIT`, `GPL-2.0+`,
+    /// `GPL-2.0-only WITH Classpath-exception-2.0` or `LicenseRef-Vendor`.
+    ///
+    /// # Errors
+    /// [`PolicyError::UnknownLicense`] if it isn't, such as for the typo `Apache2.0`,
+    /// which would never match a license.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::policy::Policy;
+    /// use license::PolicyError;
+    ///
+    /// let mut policy = Policy::new();
+    /// policy.allow("MIT")?.allow("LicenseRef-Vendor")?;
+    ///
+    /// let err = policy.allow("Apache2.0").unwrap_err();
+    /// assert_eq!(err.to_string(), "unknown license id `Apache2.0`, did you mean `Apache-2.0`?");
+    /// # Ok::<(), PolicyError>(())
+    /// ```
+    pub fn allow(&mut self, id: &str) -> Result<&mut Self, PolicyError> {
+        self.allowed.push(checked(id)?);
+        Ok(self)
+    }
+
+    /// Denies a license, which is checked as by [`allow`](Policy::allow).
+    ///
+    /// # Errors
+    /// [`PolicyError::UnknownLicense`] if the id isn't a single license.
+    pub fn deny(&mut self, id: &str) -> Result<&mut Self, PolicyError> {
+        self.denied.push(checked(id)?);
+        Ok(self)
+    }
+
+    /// Allows the licenses that have a tag, as if each was [allowed](Policy::allow).
+    ///
+    /// # Examples
+    /// ```
+    /// use license::expression::Expression;
+    /// use license::policy::{Decision, Policy};
+    /// use license::tags::TagSet;
+    ///
+    /// let mut tags = TagSet::new();
+    /// tags.insert("MIT", "approved").unwrap().insert("AGPL-3.0-only", "banned-for-saas").unwrap();
+    ///
+    /// let mut policy = Policy::new();
+    /// policy.allow_tagged(&tags, "approved").deny_tagged(&tags, "banned-for-saas");
+    /// assert!(matches!(
+    ///     policy.evaluate(&Expression::parse("MIT").unwrap()),
+    ///     Decision::Allow { .. }
+    /// ));
+    /// assert!(matches!(
+    ///     policy.evaluate(&Expression::parse("AGPL-3.0-only").unwrap()),
+    ///     Decision::Deny { .. }
+    /// ));
+    /// ```
+    pub fn allow_tagged(&mut self, tags: &TagSet, tag: &str) -> &mut Self {
+        // The ids of a tag set are known.
+        self.allowed
+            .extend(tags.licenses_with(tag).map(String::from));
+        self
+    }
+
+    /// Denies the licenses that have a tag, as if each was [denied](Policy::deny).
+    pub fn deny_tagged(&mut self, tags: &TagSet, tag: &str) -> &mut Self {
+        self.denied
+            .extend(tags.licenses_with(tag).map(String::from));
+        self
+    }
+
+    /// Allows the licenses of a copyleft class.
+    pub fn allow_copyleft(&mut self, class: Copyleft) -> &mut Self {
+        self.allowed_copyleft.push(class);
+        self
+    }
+
+    /// Denies the licenses of a copyleft class.
+    pub fn deny_copyleft(&mut self, class: Copyleft) -> &mut Self {
+        self.denied_copyleft.push(class);
+        self
+    }
+
+    /// Denies the licenses that impose any of the conditions.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::expression::Expression;
+    /// use license::policy::{Decision, Policy};
+    /// use license::report::Copyleft;
+    /// use license::{Condition, Conditions};
+    ///
+    /// let network_use_is_distribution =
+    ///     Conditions::from_bits_truncate(Condition::NetworkUseIsDistribution.bit());
+    /// let mut policy = Policy::new();
+    /// policy
+    ///     .allow_copyleft(Copyleft::Strong)
+    ///     .allow_copyleft(Copyleft::Network)
+    ///     .deny_conditions(network_use_is_distribution);
+    ///
+    /// assert!(matches!(
+    ///     policy.evaluate(&Expression::parse("AGPL-3.0-only").unwrap()),
+    ///     Decision::Deny { .. }
+    /// ));
+    /// ```
+    pub fn deny_conditions(&mut self, conditions: Conditions) -> &mut Self {
+        self.denied_conditions = self.denied_conditions.union(conditions);
+        self
+    }
+
+    /// Evaluates an expression.
+    ///
+    /// Every part of an `AND` must be allowed, while one part of an `OR` suffices.
+    /// A denied part decides an `AND`, and an allowed part decides an `OR`, before
+    /// any part that needs review. A `LicenseRef-` id needs review unless it is on a
+    /// list.
+    pub fn evaluate(&self, expression: &Expression) -> Decision {
+        let parts = match expression {
+            Expression::Leaf(leaf) => return self.leaf(leaf),
+            Expression::And(parts) | Expression::Or(parts) => parts,
+        };
+        let combine = match expression {
+            Expression::Or(_) => either,
+            _ => both,
+        };
+        parts
+            .iter()
+            .map(|part| self.evaluate(part))
+            .reduce(combine)
+            // An expression has no empty nodes.
+            .unwrap_or(Decision::NeedsReview {
+                unknowns: Vec::new(),
+            })
+    }
+
+    /// Evaluates an expression read at runtime, such as the license field of a manifest,
+    /// parsed by [`Expression::parse`].
+    ///
+    /// A field in a legacy form, such as `Apache-2.0/GPLv2`, can be normalized first with
+    /// [`normalize_legacy`] or [`manifest::from_cargo_field`].
+    ///
+    /// # Errors
+    /// The [`ExpressionError`] of an invalid expression.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::manifest::{self, Declared};
+    /// use license::policy::{Decision, Policy};
+    ///
+    /// let mut policy = Policy::new();
+    /// policy.allow("Apache-2.0")?.allow("LicenseRef-Vendor")?;
+    ///
+    /// let field = String::from("GPLv2/Apache-2.0");
+    /// let expression = match manifest::from_cargo_field(&field) {
+    ///     Declared::Expression(normalized) => normalized.to_string(),
+    ///     other => panic!("{:?}", other),
+    /// };
+    /// match policy.evaluate_str(&expression).unwrap() {
+    ///     Decision::Allow { chosen } => assert_eq!(chosen[0].to_string(), "Apache-2.0"),
+    ///     other => panic!("{:?}", other),
+    /// }
+    /// assert!(matches!(
+    ///     policy.evaluate_str("LicenseRef-Vendor").unwrap(),
+    ///     Decision::Allow { .. }
+    /// ));
+    /// assert!(policy.evaluate_str("GPLv2").is_err());
+    /// # Ok::<(), license::PolicyError>(())
+    /// ```
+    pub fn evaluate_str<'a>(&self, expression: &'a str) -> Result<Decision, ExpressionError<'a>> {
+        Ok(self.evaluate(&Expression::parse(expression)?))
+    }
+
+    fn leaf(&self, leaf: &Leaf) -> Decision {
+        let (id, written) = names(leaf);
+        match self.judge(&id, &written) {
+            Judgement::Allow => Decision::Allow {
+                chosen: vec![leaf.clone()],
+            },
+            Judgement::Deny(reason) => Decision::Deny {
+                reasons: vec![Denial {
+                    license: leaf.clone(),
+                    reason,
+                }],
+            },
+            Judgement::Review => Decision::NeedsReview {
+                unknowns: vec![leaf.clone()],
+            },
+        }
+    }
+
+    /// Judges a license, `id` being its license and `written` the whole leaf with
+    /// any exception, upgrading it if it is offered under "or any later version".
+    fn judge(&self, id: &str, written: &str) -> Judgement {
+        match self.judge_as_written(id, written) {
+            judgement @ Judgement::Allow | judgement @ Judgement::Deny(DenyReason::Id) => judgement,
+            judgement => match self.exercise(id) {
+                Some(_) => Judgement::Allow,
+                None => judgement,
+            },
+        }
+    }
+
+    /// The first allowed version of a license offered under "or any later version".
+    fn exercise(&self, id: &str) -> Option<&'static str> {
+        let resolved = from_id_flexible(id)?;
+        lineage::or_later_versions(resolved.license.id(), resolved.or_later)
+            .find(|version| matches!(self.judge_as_written(version, version), Judgement::Allow))
+    }
+
+    /// Judges a license as written, see [`judge`](Policy::judge).
+    fn judge_as_written(&self, id: &str, written: &str) -> Judgement {
+        let resolved = from_id_flexible(id).map(|resolved| resolved.license);
+        let on = |list: &[String]| {
+            list.iter().any(|entry| {
+                entry == written || entry == id || Some(&**entry) == resolved.map(|l| l.id())
+            })
+        };
+        if on(&self.denied) {
+            return Judgement::Deny(DenyReason::Id);
+        }
+        if on(&self.allowed) {
+            return Judgement::Allow;
+        }
+
+        let ext = match from_id_ext(written).or_else(|| resolved.and_then(|l| l.ext())) {
+            Some(ext) => ext,
+            None => return Judgement::Review,
+        };
+        let conditions = ext.conditions().intersection(self.denied_conditions);
+        if !conditions.is_empty() {
+            return Judgement::Deny(DenyReason::Conditions(conditions));
+        }
+        let class = Copyleft::of(ext);
+        if self.denied_copyleft.contains(&class) {
+            Judgement::Deny(DenyReason::Copyleft(class))
+        } else if self.allowed_copyleft.contains(&class) {
+            Judgement::Allow
+        } else {
+            Judgement::Review
+        }
+    }
+}
+
+/// The id of an entry of a list, if it is a single license.
+fn checked(id: &str) -> Result<String, PolicyError> {
+    match Expression::parse(id) {
+        Ok(Expression::Leaf(_)) => Ok(id.to_string()),
+        _ => Err(PolicyError::UnknownLicense {
+            id: id.into(),
+            suggestion: nearest(id, LICENSES.iter().map(|license| license.id())),
+        }),
+    }
+}
+
+/// The license of a leaf without its exception, and the whole leaf, as written.
+fn names(leaf: &Leaf) -> (String, String) {
+    let written = leaf.to_string();
+    let id = match written.find(" WITH ") {
+        Some(end) => written[..end].to_string(),
+        None => written.clone(),
+    };
+    (id, written)
+}
+
+/// The decision of an `OR` of two parts.
+fn either(a: Decision, b: Decision) -> Decision {
+    match (a, b) {
+        (Decision::Allow { chosen: a }, Decision::Allow { chosen: b }) => {
+            if rank(&b) < rank(&a) {
+                Decision::Allow { chosen: b }
+            } else {
+                Decision::Allow { chosen: a }
+            }
+        }
+        (allow @ Decision::Allow { .. }, _) | (_, allow @ Decision::Allow { .. }) => allow,
+        (Decision::NeedsReview { unknowns: a }, Decision::NeedsReview { unknowns: b }) => {
+            Decision::NeedsReview {
+                unknowns: merge(a, b),
+            }
+        }
+        (review @ Decision::NeedsReview { .. }, _) | (_, review @ Decision::NeedsReview { .. }) => {
+            review
+        }
+        (Decision::Deny { reasons: a }, Decision::Deny { reasons: b }) => Decision::Deny {
+            reasons: merge(a, b),
+        },
+    }
+}
+
+/// The decision of an `AND` of two parts.
+fn both(a: Decision, b: Decision) -> Decision {
+    match (a, b) {
+        (Decision::Deny { reasons: a }, Decision::Deny { reasons: b }) => Decision::Deny {
+            reasons: merge(a, b),
+        },
+        (deny @ Decision::Deny { .. }, _) | (_, deny @ Decision::Deny { .. }) => deny,
+        (Decision::NeedsReview { unknowns: a }, Decision::NeedsReview { unknowns: b }) => {
+            Decision::NeedsReview {
+                unknowns: merge(a, b),
+            }
+        }
+        (review @ Decision::NeedsReview { .. }, _) | (_, review @ Decision::NeedsReview { .. }) => {
+            review
+        }
+        (Decision::Allow { chosen: a }, Decision::Allow { chosen: b }) => Decision::Allow {
+            chosen: merge(a, b),
+        },
+    }
+}
+
+/// The strongest copyleft of the chosen licenses of a branch.
+fn rank(chosen: &[Leaf]) -> Copyleft {
+    chosen
+        .iter()
+        .map(|leaf| {
+            let (id, written) = names(leaf);
+            let ext = from_id_ext(&written)
+                .or_else(|| from_id_flexible(&id).and_then(|resolved| resolved.license.ext()));
+            ext.map_or(Copyleft::Network, Copyleft::of)
+        })
+        .max()
+        .unwrap_or(Copyleft::None)
+}
+
+/// Appends the items of `b` that aren't in `a`.
+fn merge<T: PartialEq>(mut a: Vec<T>, b: Vec<T>) -> Vec<T> {
+    for item in b {
+        if !a.contains(&item) {
+            a.push(item);
+        }
+    }
+    a
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+    use ::serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+    use ::serde::ser::{SerializeStruct, Serializer};
+
+    const FIELDS: &[&str] = &[
+        "allow",
+        "deny",
+        "allow_copyleft",
+        "deny_copyleft",
+        "deny_conditions",
+    ];
+
+    const COPYLEFT: [Copyleft; 4] = [
+        Copyleft::None,
+        Copyleft::Weak,
+        Copyleft::Strong,
+        Copyleft::Network,
+    ];
+
+    const COPYLEFT_NAMES: &[&str] = &["none", "weak", "strong", "network"];
+
+    impl ::serde::Serialize for Policy {
+        /// Serializes the lists by the names of [`Deserialize`](::serde::Deserialize).
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut policy = serializer.serialize_struct("Policy", FIELDS.len())?;
+            policy.serialize_field("allow", &Seq(self.allowed.iter().map(|id| &**id)))?;
+            policy.serialize_field("deny", &Seq(self.denied.iter().map(|id| &**id)))?;
+            policy.serialize_field(
+                "allow_copyleft",
+                &Seq(self.allowed_copyleft.iter().map(|class| class.as_str())),
+            )?;
+            policy.serialize_field(
+                "deny_copyleft",
+                &Seq(self.denied_copyleft.iter().map(|class| class.as_str())),
+            )?;
+            policy.serialize_field(
+                "deny_conditions",
+                &Seq(self.denied_conditions.iter().map(Condition::name)),
+            )?;
+            policy.end()
+        }
+    }
+
+    /// Serializes strings as a sequence, without the `alloc` feature of serde.
+    struct Seq<I>(I);
+
+    impl<'a, I: Iterator<Item = &'a str> + Clone> ::serde::Serialize for Seq<I> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.0.clone())
+        }
+    }
+
+    impl<'de> ::serde::Deserialize<'de> for Policy {
+        /// Deserializes a map of the lists of [`Policy`], which may be left out:
+        /// `allow` and `deny` of ids, `allow_copyleft` and `deny_copyleft` of the
+        /// [`Copyleft`] classes in lowercase, and `deny_conditions` of
+        /// [condition names](Condition::name).
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_struct("Policy", FIELDS, PolicyVisitor)
+        }
+    }
+
+    struct PolicyVisitor;
+
+    impl<'de> Visitor<'de> for PolicyVisitor {
+        type Value = Policy;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a policy")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Policy, A::Error> {
+            let mut policy = Policy::new();
+            while let Some(field) = map.next_key_seed(FieldName)? {
+                map.next_value_seed(Entries {
+                    policy: &mut policy,
+                    field,
+                })?;
+            }
+            Ok(policy)
+        }
+    }
+
+    /// A list of a policy.
+    #[derive(Copy, Clone)]
+    enum Field {
+        Allow,
+        Deny,
+        AllowCopyleft,
+        DenyCopyleft,
+        DenyConditions,
+    }
+
+    /// Deserializes the name of a list.
+    struct FieldName;
+
+    impl<'de> DeserializeSeed<'de> for FieldName {
+        type Value = Field;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Field, D::Error> {
+            deserializer.deserialize_identifier(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for FieldName {
+        type Value = Field;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("the name of a list of a policy")
+        }
+
+        fn visit_str<E: de::Error>(self, field: &str) -> Result<Field, E> {
+            match field {
+                "allow" => Ok(Field::Allow),
+                "deny" => Ok(Field::Deny),
+                "allow_copyleft" => Ok(Field::AllowCopyleft),
+                "deny_copyleft" => Ok(Field::DenyCopyleft),
+                "deny_conditions" => Ok(Field::DenyConditions),
+                _ => Err(E::unknown_field(field, FIELDS)),
+            }
+        }
+    }
+
+    /// Deserializes a list into the policy.
+    struct Entries<'a> {
+        policy: &'a mut Policy,
+        field: Field,
+    }
+
+    impl<'de> DeserializeSeed<'de> for Entries<'_> {
+        type Value = ();
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+            deserializer.deserialize_seq(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for Entries<'_> {
+        type Value = ();
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a list of strings")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+            while let Some(()) = seq.next_element_seed(Entry {
+                policy: &mut *self.policy,
+                field: self.field,
+            })? {}
+            Ok(())
+        }
+    }
+
+    /// Deserializes an entry of a list into the policy.
+    struct Entry<'a> {
+        policy: &'a mut Policy,
+        field: Field,
+    }
+
+    impl<'de> DeserializeSeed<'de> for Entry<'_> {
+        type Value = ();
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for Entry<'_> {
+        type Value = ();
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a string")
+        }
+
+        fn visit_str<E: de::Error>(self, entry: &str) -> Result<(), E> {
+            let copyleft = || {
+                COPYLEFT
+                    .iter()
+                    .copied()
+                    .find(|class| class.as_str() == entry)
+                    .ok_or_else(|| E::unknown_variant(entry, COPYLEFT_NAMES))
+            };
+            match self.field {
+                Field::Allow => self.policy.allow(entry).map_err(E::custom)?,
+                Field::Deny => self.policy.deny(entry).map_err(E::custom)?,
+                Field::AllowCopyleft => self.policy.allow_copyleft(copyleft()?),
+                Field::DenyCopyleft => self.policy.deny_copyleft(copyleft()?),
+                Field::DenyConditions => {
+                    let condition = Condition::ALL
+                        .iter()
+                        .find(|condition| condition.name() == entry)
+                        .ok_or_else(|| {
+                            E::custom(format_args!("unknown flag `{}` of the conditions", entry))
+                        })?;
+                    self.policy
+                        .deny_conditions(Conditions::from_bits_truncate(condition.bit()))
+                }
+            };
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! The traits and the most used items of the crate, for a glob import.
+//!
+//! The traits are needed to call the methods of the licenses, and the lookup functions
+//! return them. Everything here is also at the crate root.
+//!
+//! # Examples
+//! ```
+//! use license::prelude::*;
+//!
+//! let apache = from_id_ext("Apache-2.0").unwrap();
+//! assert_eq!(apache.name(), "Apache License 2.0");
+//! assert!(apache.permissions().commercial_use());
+//!
+//! let expression = PackageLicense::parse("MIT OR Apache-2.0").unwrap();
+//! assert_eq!(expression.to_string(), "MIT OR Apache-2.0");
+//! ```
+
+pub use crate::{
+    from_id, from_id_exception, from_id_ext, from_id_flexible, Conditions, Flag, License,
+    LicenseException, LicenseExt, Limitations, PackageLicense, Permissions, NONE, UNKNOWN,
+};
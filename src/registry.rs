@@ -0,0 +1,109 @@
+use crate::*;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::{self, Formatter};
+
+/// A lookup of the extension licenses of this crate together with licenses registered
+/// at runtime, such as the internal licenses of an organization.
+///
+/// The registry is an owned value, there is no global registration: the free
+/// functions such as [`from_id_ext`] only ever know the licenses of this crate. A
+/// registry can be shared between threads, for example behind an `Arc`.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use license::{License, LicenseExt, Metadata, Registry};
+///
+/// struct Internal;
+///
+/// impl License for Internal {
+///     fn name(&self) -> &'static str { "Example Corp Internal License" }
+///     fn id(&self) -> &'static str { "LicenseRef-Example-Internal" }
+///     fn text(&self) -> &'static str { "For internal use only." }
+///     fn header(&self) -> Option<&'static str> { None }
+///     fn is_osi_approved(&self) -> bool { false }
+///     fn is_fsf_libre(&self) -> bool { false }
+///     fn is_deprecated(&self) -> bool { false }
+///     fn see_also(&self) -> &'static [&'static str] { &[] }
+///     fn as_any(&self) -> &dyn core::any::Any { self }
+/// }
+///
+/// impl LicenseExt for Internal {
+///     fn metadata(&self) -> Metadata { Metadata::default() }
+/// }
+///
+/// let mut registry = Registry::with_builtin();
+/// registry.register(Box::new(Internal)).unwrap();
+/// assert_eq!(registry.get("LicenseRef-Example-Internal").unwrap().name(), "Example Corp Internal License");
+/// assert_eq!(registry.get("MIT").unwrap().id(), "MIT");
+/// ```
+pub struct Registry {
+    /// Sorted by id.
+    registered: Vec<Box<dyn LicenseExt + Send + Sync>>,
+}
+
+impl Registry {
+    /// Creates a registry of the licenses that [`from_id_ext`] finds.
+    pub fn with_builtin() -> Self {
+        Registry {
+            registered: Vec::new(),
+        }
+    }
+
+    /// Registers a license.
+    ///
+    /// Fails if a builtin or registered license already has its id, so that a lookup
+    /// never depends on the order of the registrations.
+    pub fn register(
+        &mut self,
+        license: Box<dyn LicenseExt + Send + Sync>,
+    ) -> Result<(), RegisterError> {
+        if from_id_ext(license.id()).is_some() {
+            return Err(RegisterError::Builtin { id: license.id() });
+        }
+        match self
+            .registered
+            .binary_search_by(|l| l.id().cmp(license.id()))
+        {
+            Ok(_) => Err(RegisterError::Registered { id: license.id() }),
+            Err(i) => {
+                self.registered.insert(i, license);
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns a builtin or registered license based on the provided id.
+    pub fn get(&self, id: &str) -> Option<&dyn LicenseExt> {
+        match from_id_ext(id) {
+            Some(license) => Some(license),
+            None => self
+                .registered
+                .binary_search_by(|l| l.id().cmp(id))
+                .ok()
+                .map(|i| &*self.registered[i] as &dyn LicenseExt),
+        }
+    }
+
+    /// The builtin licenses, in the order of [`LICENSES_EXT`], followed by the registered
+    /// ones, ordered by id whatever the order they were registered in.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn LicenseExt> + '_ {
+        LICENSES_EXT
+            .iter()
+            .copied()
+            .chain(self.registered.iter().map(|l| &**l as &dyn LicenseExt))
+    }
+}
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Registry")
+            .field(
+                "registered",
+                &self.registered.iter().map(|l| l.id()).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
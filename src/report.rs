@@ -0,0 +1,230 @@
+//! Reports over the licenses of a whole project.
+
+use crate::*;
+use core::fmt::{self, Display, Formatter};
+
+/// Licenses whose copyleft only covers the files or the library itself.
+///
+/// This is curated data, the flags can't tell file-level copyleft from GPL-style copyleft.
+const WEAK_COPYLEFT: &[&str] = &[
+    "APSL-2.0",
+    "CERN-OHL-W-2.0",
+    "CPL-1.0",
+    "EPL-1.0",
+    "EPL-2.0",
+    "GPL-2.0-only WITH Classpath-exception-2.0",
+    "LGPL-2.1-only",
+    "LGPL-3.0-only",
+    "LiLiQ-R-1.1",
+    "MPL-2.0",
+    "MPL-2.0-no-copyleft-exception",
+];
+
+/// How far the copyleft of a license reaches, from weakest to strongest.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Copyleft {
+    /// The license is permissive.
+    None,
+    /// Only the licensed files or library stay under the license, such as with MPL-2.0 or LGPL.
+    Weak,
+    /// The whole combined work must be released under the license, such as with the GPL.
+    Strong,
+    /// Like [`Strong`](Copyleft::Strong), but also triggered by offering the software
+    /// over a network, such as with AGPL.
+    Network,
+}
+
+impl Copyleft {
+    /// The copyleft class of a license.
+    ///
+    /// It is [`Network`](Copyleft::Network), the conservative answer, for the
+    /// [`UNKNOWN`] and [`NONE`] sentinels.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::report::Copyleft;
+    ///
+    /// let class = |id| Copyleft::of(license::from_id_ext(id).unwrap());
+    /// assert_eq!(class("MIT"), Copyleft::None);
+    /// assert_eq!(class("MPL-2.0"), Copyleft::Weak);
+    /// assert_eq!(class("GPL-3.0-only"), Copyleft::Strong);
+    /// assert_eq!(class("AGPL-3.0-only"), Copyleft::Network);
+    /// ```
+    pub fn of(license: &dyn LicenseExt) -> Self {
+        let conditions = license.conditions();
+        if sentinel::is_sentinel(license.id()) {
+            // Nothing is known, or nothing is permitted.
+            Copyleft::Network
+        } else if WEAK_COPYLEFT.contains(&license.id()) {
+            Copyleft::Weak
+        } else if conditions.same_license() && conditions.network_use_is_distribution() {
+            Copyleft::Network
+        } else if conditions.same_license() {
+            Copyleft::Strong
+        } else if conditions.disclose_sources() {
+            Copyleft::Weak
+        } else {
+            Copyleft::None
+        }
+    }
+
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Copyleft::None => "none",
+            Copyleft::Weak => "weak",
+            Copyleft::Strong => "strong",
+            Copyleft::Network => "network",
+        }
+    }
+}
+
+impl Display for Copyleft {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The combined obligations of a set of licenses, see [`combine`].
+///
+/// Its [`Display`] impl renders the report as Markdown, with the licenses deduplicated
+/// and ordered by id, so the order in which they are given doesn't matter. Without
+/// conditions, the section of the combined conditions is left out and the list at the
+/// top says `No conditions.` The [`UNKNOWN`] and [`NONE`] sentinels are each noted on a
+/// line of the list, such as `NOASSERTION: License could not be determined.`
+#[derive(Copy, Clone)]
+pub struct CombinedReport<'a> {
+    licenses: &'a [&'static dyn LicenseExt],
+}
+
+/// Combines the licenses present in a project into a report.
+///
+/// # Examples
+/// ```
+/// use license::report::{self, Copyleft};
+///
+/// let licenses = ["MIT", "Apache-2.0", "MPL-2.0", "GPL-3.0-only"]
+///     .iter()
+///     .map(|id| license::from_id_ext(id).unwrap())
+///     .collect::<Vec<_>>();
+/// let report = report::combine(&licenses);
+/// assert_eq!(report.copyleft(), Copyleft::Strong);
+/// assert!(report.conditions().notice_file());
+/// assert_eq!(report.floor().map(|l| l.id()).collect::<Vec<_>>(), ["GPL-3.0-only"]);
+/// assert_eq!(report.network_triggering().count(), 0);
+/// ```
+pub fn combine<'a>(licenses: &'a [&'static dyn LicenseExt]) -> CombinedReport<'a> {
+    CombinedReport { licenses }
+}
+
+impl<'a> CombinedReport<'a> {
+    /// The licenses of the report, as given.
+    pub fn licenses(&self) -> &'a [&'static dyn LicenseExt] {
+        self.licenses
+    }
+
+    /// The union of the conditions of all licenses.
+    pub fn conditions(&self) -> Conditions {
+        self.licenses
+            .iter()
+            .fold(Conditions::default(), |c, l| c.union(l.conditions()))
+    }
+
+    /// The strongest copyleft class present.
+    pub fn copyleft(&self) -> Copyleft {
+        self.licenses
+            .iter()
+            .map(|l| Copyleft::of(*l))
+            .max()
+            .unwrap_or(Copyleft::None)
+    }
+
+    /// The licenses that set the outbound license floor of the combined work.
+    ///
+    /// These are the licenses of the strongest copyleft class present, if it is at least
+    /// [`Strong`](Copyleft::Strong): the combined work must at least be released under one of
+    /// them. Nothing is yielded when no license reaches that far.
+    ///
+    /// The licenses are deduplicated and ordered by id.
+    pub fn floor(&self) -> impl Iterator<Item = &'static dyn LicenseExt> + Clone + 'a {
+        let copyleft = self.copyleft();
+        Sorted::new(
+            self.licenses
+                .iter()
+                .copied()
+                .filter(move |l| copyleft >= Copyleft::Strong && Copyleft::of(*l) == copyleft),
+        )
+    }
+
+    /// The licenses that impose obligations when the software is only offered over a network,
+    /// see [`LicenseExt::triggers_on_network_use`], deduplicated and ordered by id.
+    pub fn network_triggering(&self) -> impl Iterator<Item = &'static dyn LicenseExt> + Clone + 'a {
+        Sorted::new(
+            self.licenses
+                .iter()
+                .copied()
+                .filter(|l| l.triggers_on_network_use()),
+        )
+    }
+}
+
+impl fmt::Debug for CombinedReport<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("CombinedReport")
+            .field("licenses", &Ids(self.licenses.iter().copied()))
+            .finish()
+    }
+}
+
+/// Writes license ids separated by commas, or `none`.
+struct Ids<I>(I);
+
+impl<I: Iterator<Item = &'static dyn LicenseExt> + Clone> Display for Ids<I> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut ids = self.0.clone().map(|l| l.id());
+        match ids.next() {
+            Some(first) => f.write_str(first)?,
+            None => return f.write_str("none"),
+        }
+        for id in ids {
+            write!(f, ", {}", id)?;
+        }
+        Ok(())
+    }
+}
+
+impl<I: Iterator<Item = &'static dyn LicenseExt> + Clone> fmt::Debug for Ids<I> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.clone().map(|l| l.id()))
+            .finish()
+    }
+}
+
+impl Display for CombinedReport<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("# License report\n\n")?;
+        writeln!(
+            f,
+            "- Licenses: {}",
+            Ids(Sorted::new(self.licenses.iter().copied()))
+        )?;
+        for license in Sorted::new(self.licenses.iter().copied()) {
+            if let Some(note) = sentinel::note(license.id()) {
+                writeln!(f, "- {}: {}", license.id(), note)?;
+            }
+        }
+        writeln!(f, "- Strongest copyleft: {}", self.copyleft())?;
+        writeln!(f, "- Outbound license floor: {}", Ids(self.floor()))?;
+        writeln!(
+            f,
+            "- Triggered by network use: {}",
+            Ids(self.network_triggering())
+        )?;
+        let conditions = self.conditions();
+        if conditions.is_empty() {
+            writeln!(f, "- {}", Conditions::NOTE)
+        } else {
+            write!(f, "\n## Combined conditions\n\n{}", conditions)
+        }
+    }
+}
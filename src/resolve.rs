@@ -0,0 +1,232 @@
+use crate::normalize::LEGACY;
+use crate::*;
+use core::fmt::{self, Debug, Formatter};
+
+/// A license resolved by [`from_id_flexible`].
+#[derive(Copy, Clone)]
+pub struct ResolvedId {
+    /// The resolved license.
+    pub license: &'static dyn License,
+    /// Says if the id asked for the license or any later version.
+    pub or_later: bool,
+    /// Says if the id used a deprecated form, such as `GPL-2.0+`.
+    pub deprecated_form: bool,
+}
+
+impl Debug for ResolvedId {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ResolvedId")
+            .field("license", &self.license.id())
+            .field("or_later", &self.or_later)
+            .field("deprecated_form", &self.deprecated_form)
+            .finish()
+    }
+}
+
+/// Returns a license based on the provided id, also accepting a trailing `+`.
+///
+/// A trailing `+` asks for the license or any later version. When an `-or-later`
+/// license exists for the base id, as for the GNU licenses, it is returned instead and
+/// the id is reported as a deprecated form. Otherwise the base license is returned with
+/// [`or_later`](ResolvedId::or_later) set, and callers decide what to make of it.
+///
+/// The strict lookups, such as [`from_id_ext`], do not accept a trailing `+`.
+///
+/// # Examples
+/// ```
+/// let gpl = license::from_id_flexible("GPL-2.0+").unwrap();
+/// assert_eq!(gpl.license.id(), "GPL-2.0-or-later");
+/// assert!(gpl.or_later && gpl.deprecated_form);
+///
+/// let apache = license::from_id_flexible("Apache-2.0+").unwrap();
+/// assert_eq!(apache.license.id(), "Apache-2.0");
+/// assert!(apache.or_later && !apache.deprecated_form);
+///
+/// let mit = license::from_id_flexible("MIT").unwrap();
+/// assert!(!mit.or_later && !mit.deprecated_form);
+///
+/// assert!(license::from_id_ext("GPL-2.0+").is_none());
+/// ```
+pub fn from_id_flexible(id: &str) -> Option<ResolvedId> {
+    let base = match id.strip_suffix('+') {
+        Some(base) => base,
+        None => {
+            return from_id(id).map(|license| ResolvedId {
+                license,
+                or_later: false,
+                deprecated_form: license.is_deprecated(),
+            })
+        }
+    };
+    let stem = base.strip_suffix("-only").unwrap_or(base);
+    let mut buf = [0; 64];
+    if let Some(license) = with_suffix(&mut buf, stem, "-or-later").and_then(from_id) {
+        return Some(ResolvedId {
+            license,
+            or_later: true,
+            deprecated_form: true,
+        });
+    }
+    from_id(base).map(|license| ResolvedId {
+        license,
+        or_later: true,
+        deprecated_form: license.is_deprecated(),
+    })
+}
+
+/// Like [`from_id_flexible`], and reports a deprecated form to `diagnostics` as a
+/// [`Diagnostic::DeprecatedId`], with the id that replaces it.
+///
+/// # Examples
+/// ```
+/// use license::Diagnostic;
+///
+/// let mut diagnostics = Vec::new();
+/// let mut report = |diagnostic: Diagnostic| diagnostics.push(diagnostic.to_string());
+/// let gpl = license::from_id_flexible_with_diagnostics("GPL-2.0", &mut report).unwrap();
+/// assert_eq!(gpl.license.id(), "GPL-2.0");
+/// license::from_id_flexible_with_diagnostics("Apache-2.0+", &mut report).unwrap();
+/// assert_eq!(diagnostics, ["deprecated license id `GPL-2.0`, use `GPL-2.0-only`"]);
+/// ```
+pub fn from_id_flexible_with_diagnostics<'a>(
+    id: &'a str,
+    diagnostics: &mut dyn Diagnostics<'a>,
+) -> Option<ResolvedId> {
+    let resolved = from_id_flexible(id)?;
+    if resolved.deprecated_form {
+        let canonical = LEGACY
+            .iter()
+            .find(|(legacy, _, _)| *legacy == id)
+            .map_or(resolved.license.id(), |(_, replacement, _)| replacement);
+        diagnostics.report(Diagnostic::DeprecatedId {
+            given: id,
+            canonical,
+        });
+    }
+    Some(resolved)
+}
+
+/// Concatenates `base` and `suffix` into `buf` without allocating.
+fn with_suffix<'a>(buf: &'a mut [u8], base: &str, suffix: &str) -> Option<&'a str> {
+    let len = base.len() + suffix.len();
+    let buf = buf.get_mut(..len)?;
+    buf[..base.len()].copy_from_slice(base.as_bytes());
+    buf[base.len()..].copy_from_slice(suffix.as_bytes());
+    core::str::from_utf8(buf).ok()
+}
+
+/// The licenses with extension data that `prefix` completes to, at most `limit` of them,
+/// for completion as the user types.
+///
+/// The matches ignore ASCII case and are ranked in tiers: the ids that start with
+/// `prefix`, then the names that start with it, then the ids and names with a word that
+/// starts with it, and last the ids that contain it anywhere, so that `gpl` also finds
+/// `AGPL-3.0-only`. Within a tier the licenses are in the order of [`LICENSES_EXT`], and
+/// a license is listed once, in its best tier. An empty `prefix` completes to every
+/// license.
+///
+/// Only the licenses of [`LICENSES_EXT`] are completed, so `GPL-2.0-or-later` isn't,
+/// and the [sentinels](UNKNOWN) aren't either.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// let ids = |prefix, limit| {
+///     license::complete(prefix, limit).iter().map(|l| l.id()).collect::<Vec<_>>()
+/// };
+/// assert_eq!(ids("apa", 5), ["Apache-2.0", "Apache-2.0 WITH SHL-2.1"]);
+/// assert_eq!(ids("zero", 5), ["0BSD", "CC0-1.0"]);
+/// assert_eq!(ids("lgpl", 1), ["LGPL-2.1-only"]);
+/// assert!(ids("xyz", 5).is_empty());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn complete(prefix: &str, limit: usize) -> alloc::vec::Vec<&'static dyn LicenseExt> {
+    let tiers: [fn(&dyn LicenseExt, &str) -> bool; 4] = [
+        |license, prefix| starts_with(license.id(), prefix),
+        |license, prefix| starts_with(license.name(), prefix),
+        |license, prefix| has_word(license.id(), prefix) || has_word(license.name(), prefix),
+        |license, prefix| contains(license.id(), prefix),
+    ];
+    let mut found = alloc::vec::Vec::new();
+    for matches in tiers.iter() {
+        for &license in LICENSES_EXT {
+            if found.len() == limit {
+                return found;
+            }
+            if matches(license, prefix)
+                && !found
+                    .iter()
+                    .any(|other: &&dyn LicenseExt| other.id() == license.id())
+            {
+                found.push(license);
+            }
+        }
+    }
+    found
+}
+
+/// Says if `s` starts with `prefix`, ignoring ASCII case.
+#[cfg(feature = "alloc")]
+fn starts_with(s: &str, prefix: &str) -> bool {
+    s.as_bytes()
+        .get(..prefix.len())
+        .map_or(false, |start| start.eq_ignore_ascii_case(prefix.as_bytes()))
+}
+
+/// Says if a word of `s`, which starts after a character that isn't alphanumeric,
+/// starts with `prefix`, ignoring ASCII case.
+#[cfg(feature = "alloc")]
+fn has_word(s: &str, prefix: &str) -> bool {
+    let mut after_word = false;
+    s.char_indices().any(|(i, c)| {
+        let starts = !after_word && starts_with(&s[i..], prefix);
+        after_word = c.is_alphanumeric();
+        starts
+    })
+}
+
+/// Says if `s` contains `part`, ignoring ASCII case.
+#[cfg(feature = "alloc")]
+fn contains(s: &str, part: &str) -> bool {
+    part.is_empty()
+        || s.as_bytes()
+            .windows(part.len())
+            .any(|window| window.eq_ignore_ascii_case(part.as_bytes()))
+}
+
+/// The longest id that distances are computed for, longer input is cut.
+#[cfg(any(feature = "serde", feature = "alloc"))]
+const MAX_LEN: usize = 64;
+
+/// The id nearest to `id` by edit distance, ignoring ASCII case, if it is close enough
+/// to be a likely typo, for the errors that suggest an id.
+#[cfg(any(feature = "serde", feature = "alloc"))]
+pub(crate) fn nearest(id: &str, ids: impl Iterator<Item = &'static str>) -> Option<&'static str> {
+    let id = &id.as_bytes()[..id.len().min(MAX_LEN)];
+    ids.map(|candidate| (candidate, distance(id, candidate.as_bytes())))
+        .filter(|(_, distance)| *distance <= 1 + id.len() / 4)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(id, _)| id)
+}
+
+/// The Levenshtein distance, ignoring ASCII case, without allocating.
+#[cfg(any(feature = "serde", feature = "alloc"))]
+fn distance(a: &[u8], b: &[u8]) -> usize {
+    let b = &b[..b.len().min(MAX_LEN)];
+    let mut row = [0; MAX_LEN + 1];
+    for (j, cell) in row.iter_mut().enumerate().take(b.len() + 1) {
+        *cell = j;
+    }
+    for (i, &x) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &y) in b.iter().enumerate() {
+            let cost = usize::from(!x.eq_ignore_ascii_case(&y));
+            let next = (diagonal + cost).min(row[j] + 1).min(row[j + 1] + 1);
+            diagonal = row[j + 1];
+            row[j + 1] = next;
+        }
+    }
+    row[b.len()]
+}
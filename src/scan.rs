@@ -0,0 +1,194 @@
+//! Finds the license files of a directory tree, such as the crates of `vendor/`, and
+//! tells their licenses.
+//!
+//! Requires the `fs` feature.
+
+use crate::text::{self, Matcher, RegionMatch};
+use crate::MIN_TEXT_SIMILARITY;
+use alloc::vec::Vec;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The prefixes of the names of license files, compared in any ASCII case.
+const PREFIXES: &[&str] = &["LICENSE", "COPYING", "NOTICE"];
+
+/// How many texts the matcher of a scan remembers, since vendored crates often carry
+/// the same files.
+const CACHE_CAPACITY: usize = 64;
+
+/// A license file found by [`scan_dir`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ScanHit {
+    /// The path of the file, under the scanned directory.
+    pub path: PathBuf,
+    /// What the file contains.
+    pub outcome: ScanOutcome,
+}
+
+impl ScanHit {
+    /// The license of the file, if it is the text of a single license.
+    pub fn license(&self) -> Option<&'static dyn crate::License> {
+        match &self.outcome {
+            ScanOutcome::Exact(found) | ScanOutcome::Fuzzy(found) => Some(found.license()),
+            _ => None,
+        }
+    }
+}
+
+/// What a license file contains, see [`ScanHit`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum ScanOutcome {
+    /// The text of a license, apart from copyright lines, placeholders and whitespace,
+    /// see [`TextDiff::is_trivial`](text::TextDiff::is_trivial).
+    Exact(text::Match),
+    /// A text similar to that of a license, with a
+    /// [similarity](text::Match::similarity) of at least [`MIN_TEXT_SIMILARITY`].
+    Fuzzy(text::Match),
+    /// The texts of several licenses one after the other, see [`text::match_all`].
+    Several(Vec<RegionMatch>),
+    /// A text that isn't similar enough to any license, with the similarity of the
+    /// closest one, or `0.0` if it has nothing in common with any.
+    Unmatched {
+        /// The similarity of the closest license.
+        similarity: f32,
+    },
+    /// A file with nothing but whitespace.
+    Empty,
+    /// A file that isn't UTF-8, see [`text::from_utf8`].
+    NotUtf8,
+    /// A file or a directory that couldn't be read.
+    Error(io::ErrorKind),
+}
+
+/// Finds the files whose names start with `LICENSE`, `COPYING` or `NOTICE`, in any
+/// ASCII case, in a directory and its subdirectories, and matches their texts with the
+/// licenses of [`LICENSES`](crate::LICENSES).
+///
+/// The files are read as they are reached, one directory at a time: first the files of
+/// a directory by name, then its subdirectories by name. Symbolic links are followed,
+/// and a directory that was already scanned, such as through a link to one of its
+/// parents, is skipped. A file or a directory that can't be read is reported as
+/// [`ScanOutcome::Error`] and the scan goes on.
+///
+/// # Examples
+/// ```no_run
+/// use license::scan::{self, ScanOutcome};
+/// use std::path::Path;
+///
+/// for hit in scan::scan_dir(Path::new("vendor")) {
+///     match hit.outcome {
+///         ScanOutcome::Exact(found) => println!("{}: {}", hit.path.display(), found.license().id()),
+///         outcome => println!("{}: {:?}", hit.path.display(), outcome),
+///     }
+/// }
+/// ```
+pub fn scan_dir(path: &Path) -> impl Iterator<Item = ScanHit> {
+    Scan {
+        matcher: Matcher::new().with_cache(CACHE_CAPACITY),
+        dirs: alloc::vec![path.to_path_buf()],
+        files: Vec::new(),
+        visited: HashSet::new(),
+    }
+}
+
+struct Scan {
+    matcher: Matcher,
+    /// The directories left to scan, the next one last.
+    dirs: Vec<PathBuf>,
+    /// The license files of the current directory left to read, the next one last.
+    files: Vec<PathBuf>,
+    /// The canonical paths of the directories that were scanned.
+    visited: HashSet<PathBuf>,
+}
+
+impl Scan {
+    /// Lists the license files and the subdirectories of a directory, unless it was
+    /// already scanned.
+    fn enter(&mut self, dir: &Path) -> io::Result<()> {
+        if !self.visited.insert(dir.canonicalize()?) {
+            return Ok(());
+        }
+        let mut files = Vec::new();
+        let mut dirs = Vec::new();
+        for entry in fs::read_dir(dir)?.filter_map(Result::ok) {
+            let path = entry.path();
+            if fs::metadata(&path).map_or(false, |metadata| metadata.is_dir()) {
+                dirs.push(path);
+            } else if is_license_file(&path) {
+                files.push(path);
+            }
+        }
+        files.sort_unstable_by(|a, b| b.cmp(a));
+        dirs.sort_unstable_by(|a, b| b.cmp(a));
+        self.files = files;
+        self.dirs.extend(dirs);
+        Ok(())
+    }
+
+    fn read(&self, path: &Path) -> ScanOutcome {
+        let bytes = match fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(err) => return ScanOutcome::Error(err.kind()),
+        };
+        match text::from_utf8(&bytes) {
+            Ok(text) if text.trim().is_empty() => ScanOutcome::Empty,
+            Ok(text) => self.classify(text),
+            Err(_) => ScanOutcome::NotUtf8,
+        }
+    }
+
+    fn classify(&self, text: &str) -> ScanOutcome {
+        let best = self.matcher.match_one(text);
+        let close = best.filter(|found| found.similarity() >= MIN_TEXT_SIMILARITY);
+        if let Some(found) = close {
+            if text::diff(found.license().text(), text).is_trivial() {
+                return ScanOutcome::Exact(found);
+            }
+        }
+        let regions = text::match_all(text);
+        if regions.len() > 1 {
+            return ScanOutcome::Several(regions);
+        }
+        match close {
+            Some(found) => ScanOutcome::Fuzzy(found),
+            None => ScanOutcome::Unmatched {
+                similarity: best.map_or(0.0, |found| found.similarity()),
+            },
+        }
+    }
+}
+
+impl Iterator for Scan {
+    type Item = ScanHit;
+
+    fn next(&mut self) -> Option<ScanHit> {
+        loop {
+            if let Some(path) = self.files.pop() {
+                let outcome = self.read(&path);
+                return Some(ScanHit { path, outcome });
+            }
+            let dir = self.dirs.pop()?;
+            if let Err(err) = self.enter(&dir) {
+                return Some(ScanHit {
+                    path: dir,
+                    outcome: ScanOutcome::Error(err.kind()),
+                });
+            }
+        }
+    }
+}
+
+fn is_license_file(path: &Path) -> bool {
+    let name = match path.file_name() {
+        Some(name) => name.to_string_lossy(),
+        None => return false,
+    };
+    PREFIXES.iter().any(|prefix| {
+        name.get(..prefix.len())
+            .map_or(false, |start| start.eq_ignore_ascii_case(prefix))
+    })
+}
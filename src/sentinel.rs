@@ -0,0 +1,144 @@
+use crate::*;
+
+/// The SPDX special values, with how the renderers word them.
+const SENTINELS: &[(&str, &str)] = &[
+    ("NOASSERTION", "License could not be determined."),
+    ("NONE", "No license, all rights reserved."),
+];
+
+/// The sentinel for a license that could not be determined, the SPDX `NOASSERTION`.
+///
+/// It lets code typed as `&dyn LicenseExt` carry the lack of an answer without
+/// inventing a license. Its flags are all unset, but it is never treated as permissive:
+/// its [`Copyleft`](crate::report::Copyleft) class is the strongest, linking with it is
+/// [`Unclear`](Coverage::Unclear), and distributing it
+/// [needs a legal review](BinaryRequirements::needs_legal_review). The renderers, such
+/// as [`SummaryDisplay`], say that the license could not be determined.
+///
+/// [`from_id`] and [`from_id_ext`] resolve `NOASSERTION` to it. It isn't in
+/// [`LICENSES`] or [`LICENSES_EXT`].
+///
+/// # Examples
+/// ```
+/// use license::{ListStyle, SummaryDisplay};
+///
+/// let unknown = license::from_id_ext("NOASSERTION").unwrap();
+/// assert_eq!(unknown.id(), license::UNKNOWN.id());
+/// assert!(unknown.permissions().is_empty() && !unknown.is_osi_approved());
+/// assert_eq!(
+///     SummaryDisplay::new(unknown, ListStyle::default()).to_string(),
+///     "License could not be determined.\n"
+/// );
+/// ```
+pub const UNKNOWN: &dyn LicenseExt = &NoAssertion;
+
+/// The sentinel for explicitly no license, so that all rights are reserved, the SPDX
+/// `NONE`.
+///
+/// It is handled like [`UNKNOWN`], which is conservative since nothing is permitted,
+/// and the renderers say that all rights are reserved.
+///
+/// [`from_id`] and [`from_id_ext`] resolve `NONE` to it.
+///
+/// # Examples
+/// ```
+/// use license::{ListStyle, SummaryDisplay};
+///
+/// assert_eq!(license::from_id_ext("NONE").unwrap().id(), "NONE");
+/// assert_eq!(
+///     SummaryDisplay::new(license::NONE, ListStyle::default()).to_string(),
+///     "No license, all rights reserved.\n"
+/// );
+/// ```
+pub const NONE: &dyn LicenseExt = &NoLicense;
+
+/// The type of [`UNKNOWN`].
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct NoAssertion;
+
+/// The type of [`NONE`].
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct NoLicense;
+
+macro_rules! impl_sentinel {
+    ($($struct:ident: $id:literal, $slug:literal, $name:literal;)*) => {$(
+        impl License for $struct {
+            fn name(&self) -> &'static str {
+                $name
+            }
+
+            fn id(&self) -> &'static str {
+                $id
+            }
+
+            fn text(&self) -> &'static str {
+                ""
+            }
+
+            fn header(&self) -> Option<&'static str> {
+                None
+            }
+
+            fn is_osi_approved(&self) -> bool {
+                false
+            }
+
+            fn is_fsf_libre(&self) -> bool {
+                false
+            }
+
+            fn is_deprecated(&self) -> bool {
+                false
+            }
+
+            fn see_also(&self) -> &'static [&'static str] {
+                &[]
+            }
+
+            fn as_any(&self) -> &dyn core::any::Any {
+                self
+            }
+
+            fn ext(&self) -> Option<&'static dyn LicenseExt> {
+                Some(&$struct)
+            }
+        }
+
+        impl LicenseExt for $struct {
+            fn metadata(&self) -> Metadata {
+                Metadata::default()
+            }
+
+            fn slug(&self) -> &'static str {
+                $slug
+            }
+        }
+    )*};
+}
+
+impl_sentinel! {
+    NoAssertion: "NOASSERTION", "noassertion", "No assertion";
+    NoLicense: "NONE", "none", "No license";
+}
+
+/// Looks up a sentinel by its id, for [`from_id`].
+pub(crate) fn by_id(id: &str) -> Option<&'static dyn License> {
+    match id {
+        "NOASSERTION" => Some(&NoAssertion),
+        "NONE" => Some(&NoLicense),
+        _ => None,
+    }
+}
+
+/// How the renderers word a sentinel, or `None` if the id isn't one.
+pub(crate) fn note(id: &str) -> Option<&'static str> {
+    SENTINELS
+        .iter()
+        .find(|&&(sentinel, _)| sentinel == id)
+        .map(|&(_, note)| note)
+}
+
+/// Says if the id is the id of a sentinel.
+pub(crate) fn is_sentinel(id: &str) -> bool {
+    note(id).is_some()
+}
@@ -0,0 +1,110 @@
+//! Serde helpers for fields that hold a license, for use with `#[serde(with = "...")]`.
+//!
+//! A license is serialized as its SPDX id, and deserialized by looking the id up. An
+//! unknown id is an error that names the id and the nearest known one, if any.
+//!
+//! Requires the `serde` feature.
+//!
+//! # Examples
+//! ```
+//! use license::LicenseExt;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Dependency {
+//!     name: String,
+//!     #[serde(with = "license::serde::license_ref")]
+//!     license: &'static dyn LicenseExt,
+//! }
+//!
+//! let dependency: Dependency = serde_json::from_str(r#"{"name":"serde","license":"MIT"}"#).unwrap();
+//! assert_eq!(dependency.license.id(), "MIT");
+//! assert_eq!(serde_json::to_string(&dependency).unwrap(), r#"{"name":"serde","license":"MIT"}"#);
+//!
+//! let err = serde_json::from_str::<Dependency>(r#"{"name":"serde","license":"Apache 2.0"}"#).err().unwrap();
+//! assert!(err.to_string().starts_with("unknown license id `Apache 2.0`, did you mean `Apache-2.0`?"));
+//! ```
+
+use crate::*;
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::Serializer;
+use core::fmt::{self, Formatter};
+
+/// Serializes a license as its id, and deserializes a license with [`from_id_ext`].
+pub mod license_ref {
+    use super::*;
+
+    /// Serializes a license as its id.
+    pub fn serialize<S: Serializer>(
+        license: &&'static dyn LicenseExt,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(license.id())
+    }
+
+    /// Deserializes a license from its id.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<&'static dyn LicenseExt, D::Error> {
+        deserializer.deserialize_str(LicenseVisitor { lenient: false })
+    }
+}
+
+/// Like [`license_ref`], but also deserializes the ids in any ASCII case, the
+/// [aliases](Identifiers::aliases) and the [deprecated forms](Identifiers::deprecated_forms).
+///
+/// The id of the license is always serialized, so `GPLv3` becomes `GPL-3.0-only`.
+pub mod license_ref_lenient {
+    use super::*;
+
+    /// Serializes a license as its id.
+    pub fn serialize<S: Serializer>(
+        license: &&'static dyn LicenseExt,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(license.id())
+    }
+
+    /// Deserializes a license from its id, an alias or a deprecated form.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<&'static dyn LicenseExt, D::Error> {
+        deserializer.deserialize_str(LicenseVisitor { lenient: true })
+    }
+}
+
+struct LicenseVisitor {
+    lenient: bool,
+}
+
+impl<'de> Visitor<'de> for LicenseVisitor {
+    type Value = &'static dyn LicenseExt;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("an SPDX license id")
+    }
+
+    fn visit_str<E: de::Error>(self, id: &str) -> Result<Self::Value, E> {
+        if let Some(license) = from_id_ext(id) {
+            return Ok(license);
+        }
+        if self.lenient {
+            let found = LICENSES_EXT.iter().copied().find(|license| {
+                license
+                    .identifiers()
+                    .all()
+                    .any(|identifier| identifier.eq_ignore_ascii_case(id))
+            });
+            if let Some(license) = found {
+                return Ok(license);
+            }
+        }
+        Err(match nearest(id, LICENSES_EXT.iter().map(|l| l.id())) {
+            Some(nearest) => E::custom(format_args!(
+                "unknown license id `{}`, did you mean `{}`?",
+                id, nearest
+            )),
+            None => E::custom(format_args!("unknown license id `{}`", id)),
+        })
+    }
+}
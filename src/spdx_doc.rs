@@ -0,0 +1,254 @@
+//! SPDX 2.3 documents of the licenses of packages, such as the dependencies of a
+//! release.
+//!
+//! Only the fields about licenses are filled in from the crate: the version of the
+//! SPDX License List that the licenses come from, the declared and concluded license
+//! expressions with the ids as on the list, and the `LicenseRef-` ids that the
+//! expressions use. The other fields that SPDX requires are written as `NOASSERTION`,
+//! and a tool that knows more, such as the download locations, can add it to the JSON.
+//!
+//! Requires the `spdx-doc` feature.
+//!
+//! # Examples
+//! ```
+//! use license::expression::Expression;
+//! use license::spdx_doc::SpdxDocument;
+//!
+//! let declared = Expression::parse("MIT OR Apache-2.0").unwrap();
+//! let vendored = Expression::parse("MIT AND LicenseRef-Vendor").unwrap();
+//! let json = SpdxDocument::new("example-1.0.0", "https://example.com/spdx/example-1.0.0")
+//!     .with_created(1_700_000_000)
+//!     .add_package("example", "1.0.0", &declared, None)
+//!     .add_package("vendored", "0.1.0", &vendored, Some(&vendored))
+//!     .to_string();
+//! assert!(json.contains("\"created\": \"2023-11-14T22:13:20Z\""));
+//! assert!(json.contains("\"licenseDeclared\": \"MIT OR Apache-2.0\""));
+//! assert!(json.contains("\"licenseId\": \"LicenseRef-Vendor\""));
+//! ```
+
+use crate::export::Json;
+use crate::expression::{Expression, Leaf};
+use crate::params::{civil_date, now};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+/// The value of the fields that aren't known.
+const NOASSERTION: &str = "NOASSERTION";
+
+/// The provenance of the texts, whose header names the version of the license list.
+const PROVENANCE: &str = include_str!("provenance.tsv");
+
+/// An SPDX 2.3 document of packages and their licenses, built with
+/// [`add_package`](SpdxDocument::add_package).
+///
+/// Its [`Display`] impl writes the document as JSON. The packages are described by the
+/// document, in the order that they were added, with the ids `SPDXRef-Package-1`,
+/// `SPDXRef-Package-2` and so on. Every `LicenseRef-` id of the expressions that isn't
+/// prefixed with a `DocumentRef-` id is listed once in `hasExtractedLicensingInfos`,
+/// with its text if it was given with
+/// [`with_extracted_text`](SpdxDocument::with_extracted_text).
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct SpdxDocument {
+    name: String,
+    namespace: String,
+    created: u64,
+    creators: Vec<String>,
+    packages: Vec<Package>,
+    license_refs: Vec<(String, Option<String>)>,
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+struct Package {
+    name: String,
+    version: String,
+    declared: String,
+    concluded: Option<String>,
+}
+
+impl SpdxDocument {
+    /// A document without packages, created now by this crate as the tool.
+    ///
+    /// The `namespace` is the unique URI of the document, such as
+    /// `https://example.com/spdx/example-1.0.0`.
+    pub fn new(name: &str, namespace: &str) -> Self {
+        SpdxDocument {
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+            created: now(),
+            creators: alloc::vec![format!("Tool: license-{}", env!("CARGO_PKG_VERSION"))],
+            packages: Vec::new(),
+            license_refs: Vec::new(),
+        }
+    }
+
+    /// The document created at a time in seconds since 1970-01-01, such as the
+    /// `SOURCE_DATE_EPOCH` of a reproducible build.
+    pub fn with_created(self, secs: u64) -> Self {
+        SpdxDocument {
+            created: secs,
+            ..self
+        }
+    }
+
+    /// The document with another creator, such as `Organization: Example Corp`.
+    pub fn with_creator(mut self, creator: &str) -> Self {
+        self.creators.push(creator.to_string());
+        self
+    }
+
+    /// The document with the text of a `LicenseRef-` id, which is otherwise
+    /// `NOASSERTION`.
+    pub fn with_extracted_text(mut self, license_ref: &str, text: &str) -> Self {
+        let text = Some(text.to_string());
+        match self
+            .license_refs
+            .iter_mut()
+            .find(|(id, _)| id == license_ref)
+        {
+            Some(license_ref) => license_ref.1 = text,
+            None => self.license_refs.push((license_ref.to_string(), text)),
+        }
+        self
+    }
+
+    /// The document with a package, whose declared license is `declared`, and whose
+    /// concluded license is `concluded`, or `NOASSERTION` if it is `None`.
+    pub fn add_package(
+        mut self,
+        name: &str,
+        version: &str,
+        declared: &Expression,
+        concluded: Option<&Expression>,
+    ) -> Self {
+        for leaf in declared
+            .leaves()
+            .chain(concluded.into_iter().flat_map(Expression::leaves))
+        {
+            if let Leaf::LicenseRef {
+                document: None,
+                license,
+                ..
+            } = leaf
+            {
+                if !self.license_refs.iter().any(|(id, _)| id == license) {
+                    self.license_refs.push((license.clone(), None));
+                }
+            }
+        }
+        self.packages.push(Package {
+            name: name.to_string(),
+            version: version.to_string(),
+            declared: declared.to_string(),
+            concluded: concluded.map(ToString::to_string),
+        });
+        self
+    }
+}
+
+impl Display for SpdxDocument {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("{\n  \"spdxVersion\": \"SPDX-2.3\",\n")?;
+        f.write_str("  \"dataLicense\": \"CC0-1.0\",\n")?;
+        f.write_str("  \"SPDXID\": \"SPDXRef-DOCUMENT\",\n")?;
+        writeln!(f, "  \"name\": {},", Json(&self.name))?;
+        writeln!(f, "  \"documentNamespace\": {},", Json(&self.namespace))?;
+        f.write_str("  \"creationInfo\": {\n")?;
+        writeln!(f, "    \"created\": \"{}\",", Timestamp(self.created))?;
+        f.write_str("    \"creators\": [")?;
+        for (i, creator) in self.creators.iter().enumerate() {
+            f.write_str(if i == 0 { "" } else { ", " })?;
+            Json(creator).fmt(f)?;
+        }
+        f.write_str("]")?;
+        if let Some(version) = license_list_version() {
+            write!(f, ",\n    \"licenseListVersion\": {}", Json(version))?;
+        }
+        f.write_str("\n  },\n  \"packages\": [")?;
+        for (i, package) in self.packages.iter().enumerate() {
+            f.write_str(if i == 0 { "\n" } else { ",\n" })?;
+            writeln!(
+                f,
+                "    {{\n      \"SPDXID\": \"SPDXRef-Package-{}\",",
+                i + 1
+            )?;
+            writeln!(f, "      \"name\": {},", Json(&package.name))?;
+            writeln!(f, "      \"versionInfo\": {},", Json(&package.version))?;
+            writeln!(f, "      \"downloadLocation\": \"{}\",", NOASSERTION)?;
+            f.write_str("      \"filesAnalyzed\": false,\n")?;
+            let concluded = package.concluded.as_deref().unwrap_or(NOASSERTION);
+            writeln!(f, "      \"licenseConcluded\": {},", Json(concluded))?;
+            write!(
+                f,
+                "      \"licenseDeclared\": {}\n    }}",
+                Json(&package.declared)
+            )?;
+        }
+        f.write_str(if self.packages.is_empty() {
+            "]"
+        } else {
+            "\n  ]"
+        })?;
+        if !self.license_refs.is_empty() {
+            f.write_str(",\n  \"hasExtractedLicensingInfos\": [")?;
+            for (i, (id, text)) in self.license_refs.iter().enumerate() {
+                f.write_str(if i == 0 { "\n" } else { ",\n" })?;
+                let text = text.as_deref().unwrap_or(NOASSERTION);
+                write!(
+                    f,
+                    "    {{\n      \"licenseId\": {},\n      \"extractedText\": {}\n    }}",
+                    Json(id),
+                    Json(text)
+                )?;
+            }
+            f.write_str("\n  ]")?;
+        }
+        f.write_str(",\n  \"relationships\": [")?;
+        for i in 0..self.packages.len() {
+            f.write_str(if i == 0 { "\n" } else { ",\n" })?;
+            write!(
+                f,
+                "    {{\n      \"spdxElementId\": \"SPDXRef-DOCUMENT\",\n      \
+                 \"relationshipType\": \"DESCRIBES\",\n      \
+                 \"relatedSpdxElement\": \"SPDXRef-Package-{}\"\n    }}",
+                i + 1
+            )?;
+        }
+        f.write_str(if self.packages.is_empty() {
+            "]"
+        } else {
+            "\n  ]"
+        })?;
+        f.write_str("\n}\n")
+    }
+}
+
+/// The version of the SPDX License List of the licenses, or `None` if it wasn't recorded
+/// when they were generated.
+fn license_list_version() -> Option<&'static str> {
+    let version = PROVENANCE
+        .lines()
+        .find_map(|line| line.strip_prefix("# license-list-version: "))?;
+    Some(version).filter(|&version| version != "unknown")
+}
+
+/// Writes a time in seconds since 1970-01-01 in UTC, as in `2024-01-31T12:00:00Z`.
+struct Timestamp(u64);
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (year, month, day) = civil_date(self.0);
+        let secs = self.0 % 86_400;
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            year,
+            month,
+            day,
+            secs / 3_600,
+            secs / 60 % 60,
+            secs % 60
+        )
+    }
+}
@@ -0,0 +1,214 @@
+//! Statistics over the licenses of many packages.
+//!
+//! Requires the `alloc` feature.
+
+use crate::report::Copyleft;
+use crate::*;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::iter::FromIterator;
+
+/// Licenses whose patent grant terminates for a licensee who brings a patent lawsuit
+/// over the software.
+///
+/// This is curated data, the flags only say if a license grants patent rights.
+const PATENT_TERMINATION: &[&str] = &[
+    "AFL-3.0",
+    "AGPL-3.0-only",
+    "APSL-2.0",
+    "Apache-2.0",
+    "CPL-1.0",
+    "ECL-2.0",
+    "EPL-1.0",
+    "EPL-2.0",
+    "GPL-3.0-only",
+    "LGPL-3.0-only",
+    "MPL-2.0",
+    "MPL-2.0-no-copyleft-exception",
+    "MS-PL",
+    "OSL-2.1",
+    "OSL-3.0",
+];
+
+const COPYLEFT: [Copyleft; 4] = [
+    Copyleft::None,
+    Copyleft::Weak,
+    Copyleft::Strong,
+    Copyleft::Network,
+];
+
+/// Counts licenses, such as the license of every package of a dependency tree.
+///
+/// Licenses are told apart by their [`code`](LicenseExt::code), so that inserting one
+/// takes constant time, or by their id if they have no code.
+///
+/// Its [`Display`] impl renders the statistics as Markdown tables.
+///
+/// # Examples
+/// ```
+/// use license::report::Copyleft;
+/// use license::stats::Tally;
+///
+/// let tally: Tally = ["MIT", "Apache-2.0", "MIT", "GPL-3.0-only"]
+///     .iter()
+///     .map(|id| license::from_id_ext(id).unwrap())
+///     .collect();
+/// assert_eq!(tally.count("MIT"), 2);
+/// assert_eq!(tally.total(), 4);
+/// assert_eq!(tally.by_category()[0], (Copyleft::None, 3));
+/// assert!(tally.patent_mismatch());
+/// ```
+#[derive(Clone, Default)]
+pub struct Tally {
+    coded: Vec<Option<(&'static dyn LicenseExt, u64)>>,
+    uncoded: Vec<(&'static dyn LicenseExt, u64)>,
+}
+
+impl Tally {
+    /// Creates an empty tally.
+    pub fn new() -> Self {
+        Tally::default()
+    }
+
+    /// Counts a license once.
+    pub fn insert(&mut self, license: &'static dyn LicenseExt) {
+        self.insert_many(license, 1);
+    }
+
+    /// Counts a license `n` times.
+    pub fn insert_many(&mut self, license: &'static dyn LicenseExt, n: u64) {
+        let code = usize::from(license.code());
+        let count = if code == 0 {
+            match self
+                .uncoded
+                .iter()
+                .position(|(l, _)| l.id() == license.id())
+            {
+                Some(i) => &mut self.uncoded[i].1,
+                None => {
+                    self.uncoded.push((license, 0));
+                    &mut self.uncoded.last_mut().unwrap().1
+                }
+            }
+        } else {
+            if code >= self.coded.len() {
+                self.coded.resize(code + 1, None);
+            }
+            &mut self.coded[code].get_or_insert((license, 0)).1
+        };
+        *count += n;
+    }
+
+    /// How many times a license was counted.
+    pub fn count(&self, id: &str) -> u64 {
+        self.entries()
+            .find(|(l, _)| l.id() == id)
+            .map_or(0, |(_, count)| count)
+    }
+
+    /// How many licenses were counted, with multiplicity.
+    pub fn total(&self) -> u64 {
+        self.entries().map(|(_, count)| count).sum()
+    }
+
+    /// The counted licenses with their counts, the most counted first, then by id.
+    pub fn licenses(&self) -> impl Iterator<Item = (&'static dyn LicenseExt, u64)> {
+        let mut licenses: Vec<_> = self.entries().collect();
+        licenses.sort_by(|(a, a_count), (b, b_count)| {
+            b_count.cmp(a_count).then_with(|| a.id().cmp(b.id()))
+        });
+        licenses.into_iter()
+    }
+
+    /// The counted licenses with their counts, unordered.
+    fn entries(&self) -> impl Iterator<Item = (&'static dyn LicenseExt, u64)> + Clone + '_ {
+        self.coded.iter().flatten().chain(&self.uncoded).copied()
+    }
+
+    /// The counts per copyleft class, from [`None`](Copyleft::None) to
+    /// [`Network`](Copyleft::Network).
+    pub fn by_category(&self) -> [(Copyleft, u64); 4] {
+        let mut counts = COPYLEFT.map(|class| (class, 0));
+        for (license, count) in self.entries() {
+            let class = Copyleft::of(license);
+            if let Some((_, n)) = counts.iter_mut().find(|(c, _)| *c == class) {
+                *n += count;
+            }
+        }
+        counts
+    }
+
+    /// How many of the counted licenses impose each condition, in the bit order of
+    /// [`Conditions`].
+    pub fn condition_counts(&self) -> [(&'static str, u64); 7] {
+        Condition::ALL.map(|condition| {
+            let count = self
+                .entries()
+                .filter(|(l, _)| l.conditions().contains(condition))
+                .map(|(_, count)| count)
+                .sum();
+            (condition.name(), count)
+        })
+    }
+
+    /// Says if a counted license grants no patent rights while another has a patent
+    /// grant that terminates on patent litigation.
+    ///
+    /// Code under the second license then can't be defended with patents from code
+    /// under the first, which a patent review usually wants to know about.
+    pub fn patent_mismatch(&self) -> bool {
+        let mut licenses = self.entries().map(|(l, _)| l);
+        licenses.clone().any(|l| !l.permissions().patent_rights())
+            && licenses.any(|l| PATENT_TERMINATION.contains(&l.id()))
+    }
+}
+
+impl Extend<&'static dyn LicenseExt> for Tally {
+    fn extend<I: IntoIterator<Item = &'static dyn LicenseExt>>(&mut self, iter: I) {
+        for license in iter {
+            self.insert(license);
+        }
+    }
+}
+
+impl FromIterator<&'static dyn LicenseExt> for Tally {
+    fn from_iter<I: IntoIterator<Item = &'static dyn LicenseExt>>(iter: I) -> Self {
+        let mut tally = Tally::new();
+        tally.extend(iter);
+        tally
+    }
+}
+
+impl fmt::Debug for Tally {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_map()
+            .entries(self.licenses().map(|(l, count)| (l.id(), count)))
+            .finish()
+    }
+}
+
+impl Display for Tally {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("# License statistics\n\n")?;
+        writeln!(f, "- Total: {}", self.total())?;
+        writeln!(
+            f,
+            "- Patent mismatch: {}",
+            if self.patent_mismatch() { "yes" } else { "no" }
+        )?;
+
+        f.write_str("\n| License | Count |\n| --- | ---: |\n")?;
+        for (license, count) in self.licenses() {
+            writeln!(f, "| {} | {} |", license.id(), count)?;
+        }
+        f.write_str("\n| Copyleft | Count |\n| --- | ---: |\n")?;
+        for (class, count) in self.by_category().iter() {
+            writeln!(f, "| {} | {} |", class, count)?;
+        }
+        f.write_str("\n| Condition | Count |\n| --- | ---: |\n")?;
+        for (condition, count) in self.condition_counts().iter() {
+            writeln!(f, "| {} | {} |", condition, count)?;
+        }
+        Ok(())
+    }
+}
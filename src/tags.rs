@@ -0,0 +1,194 @@
+//! Tags that an organization attaches to licenses, such as `approved` or
+//! `banned-for-saas`.
+//!
+//! The crate ships no tags, only the mechanism: a [`TagSet`] maps license ids to tags,
+//! which are free strings, and checks that every id is known to the crate, suggesting
+//! the nearest id on a typo. A [policy](crate::policy::Policy) can allow or deny the
+//! licenses with a tag.
+//!
+//! With the `serde` feature, a tag set is deserialized from a document that maps ids to
+//! lists of tags, such as this TOML:
+//!
+//! ```toml
+//! MIT = ["approved"]
+//! "AGPL-3.0-only" = ["banned-for-saas", "needs-legal-review"]
+//! ```
+//!
+//! Requires the `alloc` feature.
+//!
+//! # Examples
+//! ```
+//! use license::tags::TagSet;
+//!
+//! let mut tags = TagSet::new();
+//! tags.insert("MIT", "approved").unwrap().insert("Apache-2.0", "approved").unwrap();
+//! assert_eq!(tags.tags_for(license::from_id("MIT").unwrap()), ["approved"]);
+//! assert!(tags.tags_for(license::from_id("ISC").unwrap()).is_empty());
+//! assert_eq!(tags.licenses_with("approved").collect::<Vec<_>>(), ["Apache-2.0", "MIT"]);
+//!
+//! let err = tags.insert("Apache 2.0", "approved").unwrap_err();
+//! assert_eq!(err.to_string(), "unknown license id `Apache 2.0`, did you mean `Apache-2.0`?");
+//! ```
+
+use crate::*;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Tags of licenses, see the [module](self).
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq)]
+pub struct TagSet {
+    /// Sorted by id, and the tags of a license in the order they were inserted.
+    tags: Vec<(&'static str, Vec<String>)>,
+}
+
+impl TagSet {
+    /// Creates a tag set without tags.
+    pub fn new() -> Self {
+        TagSet::default()
+    }
+
+    /// Tags a license, which is looked up with [`from_id`], or with [`from_id_ext`] for a
+    /// license combined with an exception.
+    ///
+    /// Fails if the id is unknown. Tagging a license twice with the same tag does
+    /// nothing.
+    pub fn insert(&mut self, id: &str, tag: &str) -> Result<&mut Self, TagError> {
+        self.push(resolve(id)?, tag.into());
+        Ok(self)
+    }
+
+    fn push(&mut self, id: &'static str, tag: String) {
+        let i = match self.tags.binary_search_by(|&(other, _)| other.cmp(id)) {
+            Ok(i) => i,
+            Err(i) => {
+                self.tags.insert(i, (id, Vec::new()));
+                i
+            }
+        };
+        let tags = &mut self.tags[i].1;
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+
+    /// The tags of a license, in the order they were inserted.
+    pub fn tags_for(&self, license: &dyn License) -> &[String] {
+        match self.tags.binary_search_by(|&(id, _)| id.cmp(license.id())) {
+            Ok(i) => &self.tags[i].1,
+            Err(_) => &[],
+        }
+    }
+
+    /// The ids of the licenses with a tag, ordered by id.
+    pub fn licenses_with<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'static str> + 'a {
+        self.tags
+            .iter()
+            .filter(move |(_, tags)| tags.iter().any(|other| other == tag))
+            .map(|&(id, _)| id)
+    }
+
+    /// The ids of the tagged licenses with their tags, ordered by id.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &[String])> + '_ {
+        self.tags.iter().map(|(id, tags)| (*id, &tags[..]))
+    }
+}
+
+/// The id of the license that the lookups find for `id`.
+fn resolve(id: &str) -> Result<&'static str, TagError> {
+    from_id(id)
+        .map(|license| license.id())
+        .or_else(|| from_id_ext(id).map(|license| license.id()))
+        .ok_or_else(|| TagError::UnknownLicense {
+            id: id.into(),
+            suggestion: nearest(id, LICENSES.iter().map(|license| license.id())),
+        })
+}
+
+#[cfg(feature = "serde")]
+mod de {
+    use super::*;
+    use ::serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+    use core::fmt::{self, Formatter};
+
+    impl<'de> ::serde::Deserialize<'de> for TagSet {
+        /// Deserializes a map of license ids to lists of tags, failing on the first
+        /// unknown id.
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_map(TagSetVisitor)
+        }
+    }
+
+    struct TagSetVisitor;
+
+    impl<'de> Visitor<'de> for TagSetVisitor {
+        type Value = TagSet;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a map of SPDX license ids to lists of tags")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<TagSet, A::Error> {
+            let mut tags = TagSet::new();
+            while let Some(id) = map.next_key_seed(Owned)? {
+                let id = resolve(&id).map_err(de::Error::custom)?;
+                for tag in map.next_value_seed(Tags)? {
+                    tags.push(id, tag);
+                }
+            }
+            Ok(tags)
+        }
+    }
+
+    /// Deserializes a string, without the `alloc` feature of serde.
+    struct Owned;
+
+    impl<'de> DeserializeSeed<'de> for Owned {
+        type Value = String;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<String, D::Error> {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for Owned {
+        type Value = String;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a string")
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<String, E> {
+            Ok(s.into())
+        }
+    }
+
+    /// Deserializes a list of tags.
+    struct Tags;
+
+    impl<'de> DeserializeSeed<'de> for Tags {
+        type Value = Vec<String>;
+
+        fn deserialize<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Vec<String>, D::Error> {
+            deserializer.deserialize_seq(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for Tags {
+        type Value = Vec<String>;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a list of tags")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<String>, A::Error> {
+            let mut tags = Vec::new();
+            while let Some(tag) = seq.next_element_seed(Owned)? {
+                tags.push(tag);
+            }
+            Ok(tags)
+        }
+    }
+}
@@ -0,0 +1,1970 @@
+//! Functions that work on license texts.
+
+use crate::License;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Formatter, Write};
+use core::ops::Range;
+
+#[cfg(feature = "alloc")]
+mod copyright;
+#[cfg(feature = "alloc")]
+pub use copyright::*;
+
+/// The version of the [`fingerprint`] algorithm and normalization.
+///
+/// It is incremented whenever a change would produce different fingerprints
+/// for the same text, so that stored fingerprints can be invalidated.
+pub const FINGERPRINT_VERSION: u32 = 3;
+
+/// The number of hashes in a [`Fingerprint`].
+pub const FINGERPRINT_LEN: usize = 64;
+
+const SHINGLE_LEN: usize = 5;
+
+/// A MinHash signature of a text, see [`fingerprint`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Fingerprint([u32; FINGERPRINT_LEN]);
+
+impl Fingerprint {
+    /// The generated fingerprint of a license text, or the fingerprint computed from the
+    /// text if it was generated by another [`FINGERPRINT_VERSION`].
+    pub(crate) fn generated(hashes: [u32; FINGERPRINT_LEN], text: &str) -> Self {
+        if crate::licenses::GENERATED_FINGERPRINT_VERSION == FINGERPRINT_VERSION {
+            Fingerprint(hashes)
+        } else {
+            fingerprint(text)
+        }
+    }
+
+    /// The hashes of the fingerprint.
+    pub const fn hashes(&self) -> &[u32; FINGERPRINT_LEN] {
+        &self.0
+    }
+
+    /// Estimates the Jaccard similarity of the shingles of the two texts, from `0.0` to `1.0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::text;
+    ///
+    /// let a = text::fingerprint("Permission is hereby granted, free of charge, to any person");
+    /// let b = text::fingerprint("PERMISSION IS HEREBY GRANTED, free of charge, to any person!");
+    /// let c = text::fingerprint("All rights reserved. Redistribution and use in source and binary");
+    /// assert_eq!(a.similarity(&b), 1.0);
+    /// assert!(a.similarity(&c) < 0.1);
+    /// ```
+    pub fn similarity(&self, other: &Fingerprint) -> f32 {
+        let equal = self.0.iter().zip(&other.0).filter(|(a, b)| a == b).count();
+        equal as f32 / FINGERPRINT_LEN as f32
+    }
+}
+
+/// Computes the fingerprint of a text.
+///
+/// The text is split into tokens by [`tokenize`], which leaves out the copyright lines
+/// that differ between copies of a license. Every window of five consecutive tokens
+/// forms a shingle, or the whole text forms one shingle if it has fewer than five tokens.
+///
+/// Tokens are hashed with 64-bit FNV-1a over the UTF-8 bytes of their normalized
+/// [characters](Token::chars), and shingles by folding
+/// the hashes of their tokens with FNV-1a. The fingerprint keeps, for each of
+/// [`FINGERPRINT_LEN`] seeds, the minimum over all shingles of the upper 32 bits of
+/// `splitmix64(shingle ^ seed)`, where seed `i` is `i * 0x9E37_79B9_7F4A_7C15`.
+///
+/// Any change to this procedure increments [`FINGERPRINT_VERSION`].
+///
+/// # Examples
+/// ```
+/// use license::{text, License};
+///
+/// let mit = license::from_id("MIT").unwrap();
+/// assert_eq!(text::fingerprint(mit.text()), mit.fingerprint());
+/// assert_eq!(mit.fingerprint().hashes()[..4], [31501173, 26529784, 41914365, 25730839]);
+///
+/// let copy = mit.text().replace("<year> <copyright holders>", "2024 Example Corp");
+/// let apache = license::from_id("Apache-2.0").unwrap();
+/// assert!(text::fingerprint(&copy).similarity(&mit.fingerprint()) > 0.8);
+/// assert!(apache.fingerprint().similarity(&mit.fingerprint()) < 0.1);
+/// ```
+pub fn fingerprint(text: &str) -> Fingerprint {
+    fingerprint_tokens(tokenize(text).map(|token| token.hash))
+}
+
+fn fingerprint_tokens(tokens: impl Iterator<Item = u64>) -> Fingerprint {
+    let mut hashes = [u32::MAX; FINGERPRINT_LEN];
+    let mut window = [0; SHINGLE_LEN];
+    let mut count = 0;
+    for token in tokens {
+        window[count % SHINGLE_LEN] = token;
+        count += 1;
+        if count >= SHINGLE_LEN {
+            let start = count % SHINGLE_LEN;
+            let shingle = (0..SHINGLE_LEN)
+                .map(|i| window[(start + i) % SHINGLE_LEN])
+                .fold(FNV_OFFSET, fnv_u64);
+            update(&mut hashes, shingle);
+        }
+    }
+    if count > 0 && count < SHINGLE_LEN {
+        update(
+            &mut hashes,
+            window[..count].iter().copied().fold(FNV_OFFSET, fnv_u64),
+        );
+    }
+    Fingerprint(hashes)
+}
+
+fn update(hashes: &mut [u32; FINGERPRINT_LEN], shingle: u64) {
+    for (i, hash) in hashes.iter_mut().enumerate() {
+        let seed = (i as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        let value = (splitmix64(shingle ^ seed) >> 32) as u32;
+        *hash = (*hash).min(value);
+    }
+}
+
+/// Words that the SPDX matching guidelines consider equivalent, as `(variant, word)`.
+///
+/// The variants are mostly British spellings, and a variant of two words matches them
+/// separated by any punctuation or whitespace, such as `sub-license` or `sub license`.
+pub const EQUIVALENT_WORDS: &[(&str, &str)] = &[
+    ("acknowledgement", "acknowledgment"),
+    ("analogue", "analog"),
+    ("analyse", "analyze"),
+    ("artefact", "artifact"),
+    ("authorisation", "authorization"),
+    ("authorised", "authorized"),
+    ("calibre", "caliber"),
+    ("cancelled", "canceled"),
+    ("capitalisations", "capitalizations"),
+    ("catalogue", "catalog"),
+    ("categorise", "categorize"),
+    ("centre", "center"),
+    ("emphasised", "emphasized"),
+    ("favour", "favor"),
+    ("favourite", "favorite"),
+    ("fulfil", "fulfill"),
+    ("fulfilment", "fulfillment"),
+    ("initialise", "initialize"),
+    ("judgement", "judgment"),
+    ("labelling", "labeling"),
+    ("labour", "labor"),
+    ("licence", "license"),
+    ("maximise", "maximize"),
+    ("modelled", "modeled"),
+    ("modelling", "modeling"),
+    ("non commercial", "noncommercial"),
+    ("offence", "offense"),
+    ("optimise", "optimize"),
+    ("organisation", "organization"),
+    ("organise", "organize"),
+    ("per cent", "percent"),
+    ("practise", "practice"),
+    ("programme", "program"),
+    ("realise", "realize"),
+    ("recognise", "recognize"),
+    ("signalling", "signaling"),
+    ("sub license", "sublicense"),
+    ("utilisation", "utilization"),
+    ("whilst", "while"),
+    ("wilful", "willful"),
+];
+
+/// A token of a license text, see [`tokenize`].
+///
+/// Tokens are equal if their normalized [characters](Token::chars) are.
+#[derive(Copy, Clone)]
+pub struct Token<'a> {
+    raw: &'a str,
+    word: Option<&'static str>,
+    hash: u64,
+}
+
+impl<'a> Token<'a> {
+    /// The token as it is written in the text, such as `Licence` or `sub-\nlicense`.
+    pub const fn raw(&self) -> &'a str {
+        self.raw
+    }
+
+    /// The normalized characters of the token, such as `license` or `sublicense`.
+    pub fn chars(&self) -> impl Iterator<Item = char> + Clone + 'a {
+        let raw = if self.word.is_some() { "" } else { self.raw };
+        self.word.unwrap_or("").chars().chain(letters(raw))
+    }
+}
+
+impl PartialEq for Token<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.chars().eq(other.chars())
+    }
+}
+
+impl Eq for Token<'_> {}
+
+impl core::hash::Hash for Token<'_> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+impl fmt::Debug for Token<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("Token")
+            .field(&format_args!("{}", self))
+            .finish()
+    }
+}
+
+impl Display for Token<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.chars().try_for_each(|c| f.write_char(c))
+    }
+}
+
+/// Splits a license text into tokens as the SPDX matching guidelines compare them.
+///
+/// The text is normalized by [`normalize`], and a token is a maximal run of alphanumeric
+/// characters, lowercased. Everything else, including punctuation and whitespace, only
+/// separates tokens, except that a hyphen joins the words it is between, also when it
+/// ends a line, so that `non-exclusive` is wrapped as `non-` and `exclusive`. The variants of [`EQUIVALENT_WORDS`] become their equivalent word,
+/// and the copyright lines that start a paragraph, which start with `Copyright`, `©` or
+/// `(c)`, are left out.
+///
+/// This is the tokenization that [`fingerprint`], [`best_match`] and [`diff`] compare
+/// texts with, and it doesn't allocate.
+///
+/// # Examples
+/// ```
+/// use license::text;
+///
+/// let tokens = |text| text::tokenize(text).map(|token| token.to_string()).collect::<Vec<_>>();
+/// assert_eq!(
+///     tokens("Copyright (c) 2024 Example Corp\nThe Licence may be sub-\nlicensed, whilst..."),
+///     ["the", "license", "may", "be", "sublicensed", "while"]
+/// );
+/// assert!(text::tokenize("Sub-License").eq(text::tokenize("sublicense")));
+/// assert!(text::tokenize("non-\n  exclusive").eq(text::tokenize("non-exclusive")));
+/// ```
+pub fn tokenize(text: &str) -> impl Iterator<Item = Token<'_>> + Clone + '_ {
+    Tokens::new(text, true)
+}
+
+/// Yields the FNV-1a hash of every token in the text, copyright lines included.
+fn tokens(text: &str) -> impl Iterator<Item = u64> + Clone + '_ {
+    Tokens::new(text, false).map(|token| token.hash)
+}
+
+/// The normalized, lowercased alphanumeric characters of a text.
+fn letters(text: &str) -> impl Iterator<Item = char> + Clone + '_ {
+    Normalize::new(text)
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+}
+
+fn fnv_char(hash: u64, c: char) -> u64 {
+    let mut buf = [0; 4];
+    c.encode_utf8(&mut buf).bytes().fold(hash, fnv_u8)
+}
+
+#[derive(Clone)]
+struct Tokens<'a> {
+    text: &'a str,
+    chars: Normalize<'a>,
+    /// Says if the copyright lines at the start of a paragraph are left out.
+    copyright: bool,
+    line_start: bool,
+    /// Says if the lines since the last blank line were left out.
+    paragraph_start: bool,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(text: &'a str, copyright: bool) -> Self {
+        Tokens {
+            text,
+            chars: Normalize::new(text),
+            copyright,
+            line_start: true,
+            paragraph_start: true,
+        }
+    }
+
+    /// The byte offset of the next character.
+    fn offset(&self) -> usize {
+        self.text.len() - self.chars.chars.as_str().len()
+    }
+
+    /// The span and the hash of the next word, before equivalent words are folded.
+    fn word(&mut self) -> Option<(usize, usize, u64)> {
+        let mut word = None;
+        loop {
+            if self.line_start && self.copyright {
+                self.line_start = false;
+                let rest = &self.text[self.offset()..];
+                let line = rest.split('\n').next().unwrap_or("");
+                if line.trim().is_empty() {
+                    self.paragraph_start = true;
+                } else if self.paragraph_start && is_copyright(line) {
+                    self.chars = Normalize::new(&rest[line.len()..]);
+                } else {
+                    self.paragraph_start = false;
+                }
+            }
+            let offset = self.offset();
+            let c = match self.chars.next() {
+                Some(c) => c,
+                None => return word,
+            };
+            if c.is_alphanumeric() {
+                let (start, _, hash) = word.unwrap_or((offset, offset, FNV_OFFSET));
+                let hash = c.to_lowercase().fold(hash, fnv_char);
+                word = Some((start, self.offset(), hash));
+                continue;
+            }
+            if c == '\n' {
+                self.line_start = true;
+            } else if c == '-' && word.is_some() && self.hyphenation() {
+                continue;
+            }
+            if word.is_some() {
+                return word;
+            }
+        }
+    }
+
+    /// Skips a hyphen that joins two words, either directly or at the end of a line.
+    fn hyphenation(&mut self) -> bool {
+        let mut ahead = self.chars.clone();
+        let mut newline = false;
+        let mut space = false;
+        loop {
+            let mut next = ahead.clone();
+            match next.next() {
+                Some('\n') if !newline => newline = true,
+                Some(' ') | Some('\t') | Some('\r') => space = true,
+                Some(c) if c.is_alphanumeric() && (newline || !space) => {
+                    self.chars = ahead;
+                    return true;
+                }
+                _ => return false,
+            }
+            ahead = next;
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let (start, end, hash) = self.word()?;
+        let text = self.text;
+        let raw = &text[start..end];
+        // Most words are ASCII, and are compared without normalizing them.
+        let ascii = raw.bytes().all(|b| b.is_ascii_alphanumeric());
+        let is = |start, end, word: &str| letters(&text[start..end]).eq(word.chars());
+        for (variant, word) in EQUIVALENT_WORDS {
+            let matches = if ascii {
+                let bytes = variant.as_bytes();
+                bytes.len() >= raw.len()
+                    && bytes[..raw.len()].eq_ignore_ascii_case(raw.as_bytes())
+                    && bytes.get(raw.len()).map_or(true, |&b| b == b' ')
+            } else {
+                variant
+                    .split(' ')
+                    .next()
+                    .map_or(false, |first| is(start, end, first))
+            };
+            if !matches {
+                continue;
+            }
+            let end = match variant.split(' ').nth(1) {
+                None => end,
+                Some(second) => {
+                    let mut ahead = self.clone();
+                    match ahead.word() {
+                        Some((next, end, _)) if is(next, end, second) => {
+                            *self = ahead;
+                            end
+                        }
+                        _ => continue,
+                    }
+                }
+            };
+            return Some(Token {
+                raw: &text[start..end],
+                word: Some(word),
+                hash: word.bytes().fold(FNV_OFFSET, fnv_u8),
+            });
+        }
+        Some(Token {
+            raw: &text[start..end],
+            word: None,
+            hash,
+        })
+    }
+}
+
+/// Normalizes the Unicode quirks of texts copied from websites and word processors.
+///
+/// The transformations are, character by character:
+///
+/// - The byte order mark U+FEFF, the zero-width characters U+200B, U+200C, U+200D and
+///   U+2060, and the soft hyphen U+00AD are removed.
+/// - The quotation marks U+2018, U+2019, U+201A, U+201B and the prime U+2032 become `'`.
+/// - The quotation marks U+201C, U+201D, U+201E, U+201F, U+00AB, U+00BB and the double
+///   prime U+2033 become `"`.
+/// - The hyphens and dashes U+2010 to U+2015 and the minus sign U+2212 become `-`.
+/// - The spaces U+00A0, U+2000 to U+200A, U+202F, U+205F and U+3000 become ` `.
+/// - The ellipsis U+2026 becomes `...`.
+/// - As in NFKC, the Latin ligatures U+FB00 to U+FB06 become their letters, and the
+///   fullwidth forms U+FF01 to U+FF5E become their ASCII equivalents.
+///
+/// Every other character is kept. Full NFKC needs the Unicode tables, which this crate
+/// doesn't embed, so for example decomposed accents aren't composed. Texts that aren't
+/// UTF-8 are rejected by [`from_utf8`] rather than converted lossily.
+///
+/// # Examples
+/// ```
+/// use license::text;
+///
+/// let copied = "\u{feff}the \u{201c}Software\u{201d}\u{a0}\u{2014} it\u{2019}s \u{fb01}ne";
+/// assert_eq!(text::normalize(copied).collect::<String>(), "the \"Software\" - it's fine");
+/// ```
+pub fn normalize(text: &str) -> impl Iterator<Item = char> + Clone + '_ {
+    Normalize::new(text)
+}
+
+#[derive(Clone)]
+struct Normalize<'a> {
+    chars: core::str::Chars<'a>,
+    pending: core::str::Chars<'static>,
+}
+
+impl Iterator for Normalize<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if let Some(c) = self.pending.next() {
+            return Some(c);
+        }
+        loop {
+            let c = self.chars.next()?;
+            let folded = match c {
+                '\u{feff}' | '\u{200b}' | '\u{200c}' | '\u{200d}' | '\u{2060}' | '\u{ad}' => {
+                    continue
+                }
+                '\u{2018}' | '\u{2019}' | '\u{201a}' | '\u{201b}' | '\u{2032}' => '\'',
+                '\u{201c}' | '\u{201d}' | '\u{201e}' | '\u{201f}' | '\u{ab}' | '\u{bb}'
+                | '\u{2033}' => '"',
+                '\u{2010}'..='\u{2015}' | '\u{2212}' => '-',
+                '\u{a0}' | '\u{2000}'..='\u{200a}' | '\u{202f}' | '\u{205f}' | '\u{3000}' => ' ',
+                '\u{2026}' => return self.expand("..."),
+                '\u{fb00}' => return self.expand("ff"),
+                '\u{fb01}' => return self.expand("fi"),
+                '\u{fb02}' => return self.expand("fl"),
+                '\u{fb03}' => return self.expand("ffi"),
+                '\u{fb04}' => return self.expand("ffl"),
+                '\u{fb05}' | '\u{fb06}' => return self.expand("st"),
+                '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+                c => c,
+            };
+            return Some(folded);
+        }
+    }
+}
+
+impl<'a> Normalize<'a> {
+    fn new(text: &'a str) -> Self {
+        Normalize {
+            chars: text.chars(),
+            pending: "".chars(),
+        }
+    }
+
+    fn expand(&mut self, s: &'static str) -> Option<char> {
+        self.pending = s.chars();
+        self.pending.next()
+    }
+}
+
+/// Converts the bytes of a license file to a text, without the byte order mark if it
+/// starts with one.
+///
+/// Bytes that aren't UTF-8 are an error rather than replaced, so that a file in another
+/// encoding is never compared, and possibly matched, with replacement characters in
+/// place of its text.
+///
+/// # Examples
+/// ```
+/// use license::text;
+///
+/// assert_eq!(text::from_utf8(b"\xef\xbb\xbfMIT License"), Ok("MIT License"));
+/// assert!(text::from_utf8(b"Copyright (c) 2024 J\xf6rg").is_err());
+/// ```
+pub fn from_utf8(bytes: &[u8]) -> Result<&str, core::str::Utf8Error> {
+    let text = core::str::from_utf8(bytes)?;
+    Ok(text.strip_prefix('\u{feff}').unwrap_or(text))
+}
+
+const FNV_OFFSET: u64 = 0xCBF2_9CE4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+fn fnv_u8(hash: u64, byte: u8) -> u64 {
+    (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+}
+
+fn fnv_u64(hash: u64, value: u64) -> u64 {
+    value.to_le_bytes().iter().copied().fold(hash, fnv_u8)
+}
+
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Variants of MIT that add a clause with obligations, as `(variant, clause)`.
+///
+/// Once the placeholders are filled in, the fingerprints of such a text can be as
+/// similar to MIT, or to other variants, as to the right one.
+const VARIANTS: &[(&str, &str)] = &[
+    (
+        "X11",
+        "shall not be used in advertising or otherwise to promote the sale, use or other \
+         dealings in this Software without prior written authorization",
+    ),
+    (
+        "MIT-advertising",
+        "acknowledgment shall be given in the documentation, materials and software \
+         packages that this Software was used",
+    ),
+    (
+        "MITNFA",
+        "bug reporting email addresses and urls are either replaced with the contact \
+         information of the parties responsible for the changes",
+    ),
+];
+
+/// How much less similar than the best match, if it isn't MIT, a variant of
+/// [`VARIANTS`] can be and still be preferred.
+const VARIANT_MARGIN: f32 = 0.125;
+
+/// A license whose text is similar to a candidate text, see [`best_match`].
+#[derive(Copy, Clone)]
+pub struct Match {
+    license: &'static dyn License,
+    similarity: f32,
+}
+
+impl Match {
+    /// The license.
+    pub fn license(&self) -> &'static dyn License {
+        self.license
+    }
+
+    /// The similarity of the fingerprints of the texts, see [`Fingerprint::similarity`].
+    pub fn similarity(&self) -> f32 {
+        self.similarity
+    }
+}
+
+impl fmt::Debug for Match {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Match")
+            .field("license", &self.license.id())
+            .field("similarity", &self.similarity)
+            .finish()
+    }
+}
+
+/// Finds the license whose text is the most similar to a candidate text.
+///
+/// The candidate is compared with every license of [`LICENSES`](crate::LICENSES) by
+/// [`fingerprint`], which leaves out the copyright lines, and whose values for the
+/// licenses are generated with the crate, so only the candidate is fingerprinted. Ties go to the first license by id. A variant of MIT that adds a clause with obligations, such as
+/// X11, is preferred whenever the candidate contains the clause, and either matches MIT
+/// best or is about as similar to the variant as to the best license. MPL-2.0 is
+/// reported as MPL-2.0-no-copyleft-exception if the candidate carries the notice of
+/// Exhibit B, see [`is_incompatible_with_secondary_licenses`].
+///
+/// Returns `None` if the candidate has nothing in common with any license.
+///
+/// # Examples
+/// ```
+/// use license::text;
+///
+/// let mit = license::from_id("MIT").unwrap().text();
+/// assert_eq!(text::best_match(mit).unwrap().license().id(), "MIT");
+///
+/// let x11 = format!(
+///     "{}\n\nExcept as contained in this notice, the name of Example Corp shall not be \
+///      used in advertising or otherwise to promote the sale, use or other dealings in \
+///      this Software without prior written authorization from Example Corp.",
+///     mit
+/// );
+/// assert_eq!(text::best_match(&x11).unwrap().license().id(), "X11");
+/// ```
+pub fn best_match(text: &str) -> Option<Match> {
+    best_of(
+        text,
+        crate::LICENSES
+            .iter()
+            .map(|&license| (license, license.fingerprint())),
+        |id| crate::from_id(id).map(|license| (license, license.fingerprint())),
+    )
+}
+
+/// The match of [`best_match`] among the licenses with their fingerprints, `variant`
+/// looking up one of [`VARIANTS`].
+fn best_of(
+    text: &str,
+    licenses: impl Iterator<Item = (&'static dyn License, Fingerprint)>,
+    variant: impl Fn(&str) -> Option<(&'static dyn License, Fingerprint)>,
+) -> Option<Match> {
+    let candidate = fingerprint(text);
+    let best = licenses
+        .map(|(license, fingerprint)| Match {
+            license,
+            similarity: fingerprint.similarity(&candidate),
+        })
+        .fold(None, |best: Option<Match>, m| match best {
+            Some(best) if best.similarity >= m.similarity => Some(best),
+            _ => Some(m),
+        })
+        .filter(|best| best.similarity > 0.0)?;
+    let variant = VARIANTS
+        .iter()
+        .filter(|(_, clause)| contains(text, clause))
+        .filter_map(|(id, _)| variant(id))
+        .map(|(license, fingerprint)| Match {
+            license,
+            similarity: fingerprint.similarity(&candidate),
+        })
+        .find(|variant| {
+            best.license.id() == "MIT" || variant.similarity + VARIANT_MARGIN >= best.similarity
+        });
+    let best = variant.unwrap_or(best);
+    if best.license.id() == "MPL-2.0" && is_incompatible_with_secondary_licenses(text) {
+        // The text is the same, only the notice tells the two apart.
+        return Some(Match {
+            license: &crate::MPL_2_0_no_copyleft_exception,
+            ..best
+        });
+    }
+    Some(best)
+}
+
+/// Matches many texts against the same licenses, whose fingerprints are computed once.
+///
+/// The fingerprints of the licenses of the crate are generated, while a license
+/// implemented elsewhere computes its fingerprint from its text each time, which
+/// dominates when matching many small files. A matcher takes the fingerprints when it
+/// is built, and is `Send + Sync`, so one matcher can be shared by threads. With the
+/// `rayon` feature, [`match_many`](Matcher::match_many) matches texts in parallel.
+///
+/// With the `std` feature, [`with_cache`](Matcher::with_cache) keeps the matches of
+/// recent texts, for scans that meet the same license file many times.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use license::text::Matcher;
+///
+/// let matcher = Matcher::new();
+/// let mit = license::from_id("MIT").unwrap().text();
+/// assert_eq!(matcher.match_one(mit).unwrap().license().id(), "MIT");
+///
+/// let bsd = [license::from_id("BSD-2-Clause").unwrap(), license::from_id("BSD-3-Clause").unwrap()];
+/// let matcher = Matcher::with_licenses(&bsd);
+/// assert_eq!(matcher.match_one(mit).unwrap().license().id(), "BSD-2-Clause");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct Matcher {
+    licenses: Vec<(&'static dyn License, Fingerprint)>,
+    #[cfg(feature = "std")]
+    cache: Option<Cache>,
+}
+
+#[cfg(feature = "alloc")]
+impl Matcher {
+    /// A matcher for every license of [`LICENSES`](crate::LICENSES), which agrees with
+    /// [`best_match`].
+    pub fn new() -> Self {
+        Matcher::with_licenses(crate::LICENSES)
+    }
+
+    /// A matcher for the given licenses.
+    ///
+    /// Ties go to the first license given. The variants of MIT, such as X11, are only
+    /// preferred as in [`best_match`] if they are given.
+    pub fn with_licenses(licenses: &[&'static dyn License]) -> Self {
+        Matcher {
+            licenses: licenses
+                .iter()
+                .map(|&license| (license, license.fingerprint()))
+                .collect(),
+            #[cfg(feature = "std")]
+            cache: None,
+        }
+    }
+
+    /// Keeps the matches of the last `capacity` distinct texts, so that
+    /// [`match_one`](Matcher::match_one) returns them without matching again.
+    ///
+    /// Texts are looked up by their length and a 64-bit FNV-1a hash of their bytes, so
+    /// only byte-identical texts hit the cache, and the least recently used one is
+    /// dropped when it is full. A capacity of `0` removes the cache, which is the
+    /// default. The cache is behind a lock, so the matcher can still be shared by
+    /// threads.
+    ///
+    /// Requires the `std` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use license::text::Matcher;
+    ///
+    /// let matcher = Matcher::new().with_cache(1024);
+    /// let mit = license::from_id("MIT").unwrap().text();
+    /// for _ in 0..3 {
+    ///     assert_eq!(matcher.match_one(mit).unwrap().license().id(), "MIT");
+    /// }
+    /// let stats = matcher.cache_stats().unwrap();
+    /// assert_eq!((stats.hits(), stats.misses(), stats.len()), (2, 1, 1));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn with_cache(self, capacity: usize) -> Self {
+        Matcher {
+            cache: if capacity == 0 {
+                None
+            } else {
+                Some(Cache::new(capacity))
+            },
+            ..self
+        }
+    }
+
+    /// The counters of the cache, or `None` if the matcher has none, see
+    /// [`with_cache`](Matcher::with_cache).
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(Cache::stats)
+    }
+
+    /// The licenses of the matcher, in the order given.
+    pub fn licenses(&self) -> impl Iterator<Item = &'static dyn License> + Clone + '_ {
+        self.licenses.iter().map(|&(license, _)| license)
+    }
+
+    /// Finds the license whose text is the most similar to a candidate text, as
+    /// [`best_match`] does among the licenses of the matcher.
+    pub fn match_one(&self, text: &str) -> Option<Match> {
+        #[cfg(feature = "std")]
+        if let Some(cache) = &self.cache {
+            return cache.get_or_insert_with(text, || self.best_of(text));
+        }
+        self.best_of(text)
+    }
+
+    fn best_of(&self, text: &str) -> Option<Match> {
+        best_of(text, self.licenses.iter().copied(), |id| {
+            self.licenses
+                .iter()
+                .copied()
+                .find(|(license, _)| license.id() == id)
+        })
+    }
+
+    /// Matches every text with [`match_one`](Matcher::match_one), in parallel, and
+    /// returns the matches in the order of the texts.
+    ///
+    /// Requires the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    pub fn match_many(&self, texts: &[&str]) -> Vec<Option<Match>> {
+        use rayon::prelude::*;
+
+        texts.par_iter().map(|text| self.match_one(text)).collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for Matcher {
+    fn default() -> Self {
+        Matcher::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for Matcher {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Matcher")
+            .field("len", &self.licenses.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The counters of the cache of a [`Matcher`], see [`Matcher::cache_stats`].
+///
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
+pub struct CacheStats {
+    hits: u64,
+    misses: u64,
+    len: usize,
+    capacity: usize,
+}
+
+#[cfg(feature = "std")]
+impl CacheStats {
+    /// The number of texts that were found in the cache.
+    pub const fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// The number of texts that were matched and then cached.
+    pub const fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// The number of texts in the cache.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Says if the cache is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of texts that the cache keeps.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+/// The cache of a [`Matcher`], see [`Matcher::with_cache`].
+#[cfg(feature = "std")]
+struct Cache {
+    capacity: usize,
+    lru: std::sync::Mutex<Lru>,
+}
+
+/// The length of a text and the FNV-1a hash of its bytes.
+#[cfg(feature = "std")]
+type CacheKey = (usize, u64);
+
+#[cfg(feature = "std")]
+#[derive(Clone, Default)]
+struct Lru {
+    /// The match of each text, with when it was last used.
+    matches: std::collections::HashMap<CacheKey, (Option<Match>, u64)>,
+    /// The texts by when they were last used.
+    order: alloc::collections::BTreeMap<u64, CacheKey>,
+    tick: u64,
+    hits: u64,
+    misses: u64,
+}
+
+#[cfg(feature = "std")]
+impl Cache {
+    fn new(capacity: usize) -> Self {
+        Cache {
+            capacity,
+            lru: Default::default(),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Lru> {
+        // The lock is never held while matching, so a panic can't leave it inconsistent.
+        self.lru
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// The cached match of a text, or the match of `f`, which is then cached.
+    ///
+    /// `f` runs without the lock, so threads that match the same new text at once each
+    /// run it.
+    fn get_or_insert_with(&self, text: &str, f: impl FnOnce() -> Option<Match>) -> Option<Match> {
+        let key = (text.len(), text.bytes().fold(FNV_OFFSET, fnv_u8));
+        {
+            let mut lru = self.lock();
+            let lru = &mut *lru;
+            lru.tick += 1;
+            if let Some((m, used)) = lru.matches.get_mut(&key) {
+                lru.order.remove(used);
+                *used = lru.tick;
+                lru.order.insert(lru.tick, key);
+                lru.hits += 1;
+                return *m;
+            }
+        }
+        let m = f();
+        let mut lru = self.lock();
+        let lru = &mut *lru;
+        lru.misses += 1;
+        lru.tick += 1;
+        if let Some((_, used)) = lru.matches.insert(key, (m, lru.tick)) {
+            // Another thread cached the text meanwhile.
+            lru.order.remove(&used);
+        }
+        lru.order.insert(lru.tick, key);
+        if lru.matches.len() > self.capacity {
+            let (&oldest, _) = lru.order.iter().next().expect("the cache is not empty");
+            let key = lru.order.remove(&oldest).expect("the entry exists");
+            lru.matches.remove(&key);
+        }
+        m
+    }
+
+    fn stats(&self) -> CacheStats {
+        let lru = self.lock();
+        CacheStats {
+            hits: lru.hits,
+            misses: lru.misses,
+            len: lru.matches.len(),
+            capacity: self.capacity,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clone for Cache {
+    fn clone(&self) -> Self {
+        Cache {
+            capacity: self.capacity,
+            lru: std::sync::Mutex::new(self.lock().clone()),
+        }
+    }
+}
+
+/// The lowest share of the shingles of a license that a text must have for the license
+/// to be looked for in its regions, see [`match_all`].
+#[cfg(feature = "alloc")]
+const MIN_CONTAINMENT: f32 = 0.5;
+
+/// The lowest [confidence](RegionMatch::confidence) of a region, see [`match_all`].
+#[cfg(feature = "alloc")]
+const MIN_CONFIDENCE: f32 = 0.5;
+
+/// How many shingles, of the text or of the license, a region may skip between two
+/// that match.
+#[cfg(feature = "alloc")]
+const MAX_GAP: usize = 64;
+
+/// A license found in a region of a text, see [`match_all`].
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct RegionMatch {
+    license: &'static dyn License,
+    range: Range<usize>,
+    confidence: f32,
+}
+
+#[cfg(feature = "alloc")]
+impl RegionMatch {
+    /// The license.
+    pub fn license(&self) -> &'static dyn License {
+        self.license
+    }
+
+    /// The byte range of the region in the text.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// The Jaccard similarity of the shingles of the region and of the license, from
+    /// `0.0` to `1.0`.
+    pub fn confidence(&self) -> f32 {
+        self.confidence
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for RegionMatch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("RegionMatch")
+            .field("license", &self.license.id())
+            .field("range", &self.range)
+            .field("confidence", &self.confidence)
+            .finish()
+    }
+}
+
+/// Finds every license in a text that concatenates several, such as a `LICENSE` file
+/// with the license of a project followed by those of vendored code.
+///
+/// The shingles of the text, as in [`fingerprint`], are anchored in the texts of the
+/// licenses of [`LICENSES`](crate::LICENSES): a region is a run of shingles that are
+/// found in a license in about the same order, and it is reported if its
+/// [confidence](RegionMatch::confidence) is at least one half. A region is widened to
+/// whole lines, and to the copyright lines right above it, which are left out of the
+/// tokens. Where regions overlap, the longest is kept, then the one with the highest
+/// confidence, then the one of a license that isn't deprecated, then the first by id.
+/// MPL-2.0 is reported as MPL-2.0-no-copyleft-exception as in [`best_match`].
+///
+/// The matches are ordered by position. Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use license::text;
+///
+/// let mit = license::from_id("MIT").unwrap().text();
+/// let zlib = license::from_id("Zlib").unwrap().text();
+/// let file = format!("{}\n\nThe following applies to vendor/zlib:\n\n{}", mit, zlib);
+///
+/// let matches = text::match_all(&file);
+/// let ids: Vec<_> = matches.iter().map(|m| m.license().id()).collect();
+/// assert_eq!(ids, ["MIT", "Zlib"]);
+/// assert!(file[matches[1].range()].starts_with("zlib License"));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn match_all(text: &str) -> Vec<RegionMatch> {
+    let tokens: Vec<Token> = tokenize(text).collect();
+    let shingles = shingle_hashes(tokens.iter().map(|token| token.hash));
+    let mut distinct = shingles.clone();
+    distinct.sort_unstable();
+    distinct.dedup();
+
+    let mut candidates = Vec::new();
+    for &license in crate::LICENSES {
+        let license_shingles = shingle_hashes(tokenize(license.text()).map(|t| t.hash));
+        let shared = license_shingles
+            .iter()
+            .filter(|shingle| distinct.binary_search(shingle).is_ok())
+            .count();
+        if license_shingles.is_empty()
+            || (shared as f32) < MIN_CONTAINMENT * license_shingles.len() as f32
+        {
+            continue;
+        }
+        // The shingles of the license with their positions, sorted by hash.
+        let mut canonical: Vec<(u64, usize)> = license_shingles
+            .into_iter()
+            .enumerate()
+            .map(|(i, shingle)| (shingle, i))
+            .collect();
+        canonical.sort_unstable();
+
+        let mut runs = Vec::new();
+        let mut run: Option<Run> = None;
+        for (i, &shingle) in shingles.iter().enumerate() {
+            let from = canonical.partition_point(|&(s, _)| s < shingle);
+            let to = canonical.partition_point(|&(s, _)| s <= shingle);
+            if from == to {
+                continue;
+            }
+            // A phrase that the license repeats continues the run where it is nearest.
+            let near = |position: usize| {
+                canonical[from..to]
+                    .iter()
+                    .map(|&(_, p)| p)
+                    .min_by_key(|&p| (p < position, p.max(position) - p.min(position)))
+                    .unwrap_or(position)
+            };
+            match &mut run {
+                Some(run) if i - run.last <= MAX_GAP => {
+                    let position = near(run.position);
+                    if position + MAX_GAP >= run.position && position <= run.position + MAX_GAP {
+                        run.last = i;
+                        run.position = position;
+                        run.hits += 1;
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            runs.extend(run.take());
+            run = Some(Run {
+                first: i,
+                last: i,
+                position: near(0),
+                hits: 1,
+            });
+        }
+        runs.extend(run);
+
+        for run in runs {
+            let len = run.last - run.first + 1;
+            let confidence = run.hits as f32 / (canonical.len() + len - run.hits) as f32;
+            if confidence < MIN_CONFIDENCE {
+                continue;
+            }
+            let start = offset(text, tokens[run.first].raw);
+            let last = tokens[run.last + SHINGLE_LEN - 1].raw;
+            let end = offset(text, last) + last.len();
+            let before = run.first.checked_sub(1).map(|i| {
+                let raw = tokens[i].raw;
+                offset(text, raw) + raw.len()
+            });
+            candidates.push(RegionMatch {
+                license,
+                range: widen(text, before, start..end),
+                confidence,
+            });
+        }
+    }
+
+    candidates.sort_by(|a, b| {
+        let len = |m: &RegionMatch| m.range.end - m.range.start;
+        len(b)
+            .cmp(&len(a))
+            .then(
+                b.confidence
+                    .partial_cmp(&a.confidence)
+                    .unwrap_or(Ordering::Equal),
+            )
+            .then(a.license.is_deprecated().cmp(&b.license.is_deprecated()))
+            .then(a.license.id().cmp(b.license.id()))
+    });
+    let mut matches: Vec<RegionMatch> = Vec::new();
+    for candidate in candidates {
+        let overlaps = matches
+            .iter()
+            .any(|m| m.range.start < candidate.range.end && candidate.range.start < m.range.end);
+        if !overlaps {
+            matches.push(candidate);
+        }
+    }
+    matches.sort_by_key(|m| m.range.start);
+    for m in &mut matches {
+        if m.license.id() == "MPL-2.0" && is_incompatible_with_secondary_licenses(&text[m.range()])
+        {
+            m.license = &crate::MPL_2_0_no_copyleft_exception;
+        }
+    }
+    matches
+}
+
+/// Shingles of a text that match a license in about the same order, see [`match_all`].
+#[cfg(feature = "alloc")]
+struct Run {
+    first: usize,
+    last: usize,
+    /// The position in the license of the last shingle.
+    position: usize,
+    hits: usize,
+}
+
+/// The hash of every window of [`SHINGLE_LEN`] tokens, as in [`fingerprint`].
+#[cfg(feature = "alloc")]
+fn shingle_hashes(tokens: impl Iterator<Item = u64>) -> Vec<u64> {
+    let tokens: Vec<u64> = tokens.collect();
+    tokens
+        .windows(SHINGLE_LEN)
+        .map(|window| window.iter().copied().fold(FNV_OFFSET, fnv_u64))
+        .collect()
+}
+
+/// The byte offset of a token in the text that it was taken from.
+#[cfg(feature = "alloc")]
+fn offset(text: &str, raw: &str) -> usize {
+    raw.as_ptr() as usize - text.as_ptr() as usize
+}
+
+/// Widens a region to whole lines, and to the copyright lines between the previous token,
+/// which ends at `before`, or the start of the text, and the region.
+#[cfg(feature = "alloc")]
+fn widen(text: &str, before: Option<usize>, range: Range<usize>) -> Range<usize> {
+    let is_blank = |s: &str| !s.chars().any(char::is_alphanumeric);
+    let line_start = text[..range.start].rfind('\n').map_or(0, |i| i + 1);
+    let mut start = if is_blank(&text[line_start..range.start]) {
+        line_start
+    } else {
+        range.start
+    };
+    // The lines after the previous token that hold words were left out as copyright lines.
+    let lines = match before {
+        Some(before) => text[before..start].find('\n').map(|i| before + i + 1),
+        None => Some(0),
+    };
+    if let Some(mut offset) = lines {
+        for line in text[offset..start].split_inclusive('\n') {
+            if !is_blank(line) {
+                start = offset;
+                break;
+            }
+            offset += line.len();
+        }
+    }
+    let line_end = text[range.end..]
+        .find('\n')
+        .map_or(text.len(), |i| range.end + i);
+    let end = if is_blank(&text[range.end..line_end]) {
+        range.end + text[range.end..line_end].trim_end().len()
+    } else {
+        range.end
+    };
+    start..end
+}
+
+/// The lowest share of the shingles of a section that a text must have for the section
+/// to be found, see [`match_report`].
+#[cfg(feature = "alloc")]
+const MIN_SECTION_COVERAGE: f32 = 0.5;
+
+/// The number of words of a paragraph that a [`MatchReport`] shows for it.
+#[cfg(feature = "alloc")]
+const PARAGRAPH_WORDS: usize = 6;
+
+/// A license that a text may be a copy of, see [`match_report`].
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct Candidate {
+    license: &'static dyn License,
+    similarity: f32,
+    coverage: f32,
+    missing: Vec<Section>,
+}
+
+#[cfg(feature = "alloc")]
+impl Candidate {
+    /// The license.
+    pub fn license(&self) -> &'static dyn License {
+        self.license
+    }
+
+    /// The similarity of the fingerprints of the texts, as in [`Match::similarity`].
+    pub fn similarity(&self) -> f32 {
+        self.similarity
+    }
+
+    /// The share of the shingles of the license that are in the text, from `0.0` to
+    /// `1.0`.
+    pub fn coverage(&self) -> f32 {
+        self.coverage
+    }
+
+    /// The sections of the license that are not in the text, in order.
+    ///
+    /// For a license without [sections](License::sections), its paragraphs stand for
+    /// them, with no number and no title.
+    pub fn missing(&self) -> &[Section] {
+        &self.missing
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for Candidate {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Candidate")
+            .field("license", &self.license.id())
+            .field("similarity", &self.similarity)
+            .field("coverage", &self.coverage)
+            .field("missing", &self.missing)
+            .finish()
+    }
+}
+
+/// The licenses that a text is the most similar to, see [`match_report`].
+///
+/// It is displayed with a line per candidate, with its coverage as a percentage, and a
+/// line listing its missing sections. A paragraph is shown by its first words.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct MatchReport {
+    candidates: Vec<Candidate>,
+}
+
+#[cfg(feature = "alloc")]
+impl MatchReport {
+    /// The candidates, the most similar first.
+    pub fn candidates(&self) -> &[Candidate] {
+        &self.candidates
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Display for MatchReport {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.candidates.is_empty() {
+            return f.write_str("No similar license.\n");
+        }
+        for candidate in &self.candidates {
+            writeln!(
+                f,
+                "{}: {:.0}% found",
+                candidate.license.id(),
+                candidate.coverage * 100.0
+            )?;
+            if candidate.missing.is_empty() {
+                continue;
+            }
+            f.write_str("  missing: ")?;
+            for (i, section) in candidate.missing.iter().enumerate() {
+                if i > 0 {
+                    f.write_str("; ")?;
+                }
+                match (section.number, section.title) {
+                    (Some(number), "") => write!(f, "section {}", number)?,
+                    (Some(number), title) => write!(f, "{}. {}", number, title)?,
+                    (None, "") => {
+                        let text = &candidate.license.text()[section.span()];
+                        let mut words = text.split_whitespace();
+                        f.write_char('“')?;
+                        for (i, word) in words.by_ref().take(PARAGRAPH_WORDS).enumerate() {
+                            if i > 0 {
+                                f.write_char(' ')?;
+                            }
+                            f.write_str(word)?;
+                        }
+                        if words.next().is_some() {
+                            f.write_str(" …")?;
+                        }
+                        f.write_char('”')?;
+                    }
+                    (None, title) => f.write_str(title)?,
+                }
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+/// Reports the licenses whose texts are the most similar to a candidate text, and what
+/// of them is missing, such as to explain to a human why [`best_match`] found none.
+///
+/// The licenses are compared by [`fingerprint`] as in [`best_match`], and the `top_n`
+/// most similar are reported, ties going to those that aren't deprecated, then to the
+/// first by id. A license that has nothing in common with the text isn't reported. A
+/// section of a license is missing if less than half of its shingles are in the text.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use license::text;
+///
+/// let mit = license::from_id("MIT").unwrap().text();
+/// let truncated = &mit[..mit.find("THE SOFTWARE IS PROVIDED").unwrap()];
+///
+/// let report = text::match_report(truncated, 2);
+/// let best = &report.candidates()[0];
+/// assert_eq!(best.license().id(), "MIT");
+/// assert!(best.coverage() < 0.9);
+/// assert_eq!(best.missing().len(), 1);
+/// assert!(report.to_string().starts_with(
+///     "MIT: 53% found\n  missing: “THE SOFTWARE IS PROVIDED \"AS IS\", …”\n"
+/// ));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn match_report(text: &str, top_n: usize) -> MatchReport {
+    let fingerprint = fingerprint(text);
+    let mut similar: Vec<(&'static dyn License, f32)> = crate::LICENSES
+        .iter()
+        .map(|&license| (license, license.fingerprint().similarity(&fingerprint)))
+        .filter(|&(_, similarity)| similarity > 0.0)
+        .collect();
+    similar.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(Ordering::Equal)
+            .then(a.0.is_deprecated().cmp(&b.0.is_deprecated()))
+            .then(a.0.id().cmp(b.0.id()))
+    });
+    similar.truncate(top_n);
+
+    let mut shingles = shingle_hashes(tokenize(text).map(|token| token.hash));
+    shingles.sort_unstable();
+    shingles.dedup();
+    let found = |part: &str| {
+        let part = shingle_hashes(tokenize(part).map(|token| token.hash));
+        let hits = part
+            .iter()
+            .filter(|shingle| shingles.binary_search(shingle).is_ok())
+            .count();
+        (hits, part.len())
+    };
+    let candidates = similar
+        .into_iter()
+        .map(|(license, similarity)| {
+            let canonical = license.text();
+            let (hits, len) = found(canonical);
+            let sections = license.sections();
+            let parts: Vec<Section> = if sections.is_empty() {
+                Paragraphs { rest: canonical }
+                    .map(|paragraph| {
+                        let start = offset(canonical, paragraph);
+                        Section::new(None, "", start..start + paragraph.len())
+                    })
+                    .collect()
+            } else {
+                sections.to_vec()
+            };
+            let missing = parts
+                .into_iter()
+                .filter(|section| {
+                    let (hits, len) = found(&canonical[section.span()]);
+                    (hits as f32) < MIN_SECTION_COVERAGE * len as f32
+                })
+                .collect();
+            Candidate {
+                license,
+                similarity,
+                coverage: if len == 0 {
+                    1.0
+                } else {
+                    hits as f32 / len as f32
+                },
+                missing,
+            }
+        })
+        .collect();
+    MatchReport { candidates }
+}
+
+/// The notice of Exhibit B of the MPL-2.0.
+const EXHIBIT_B: &str = "This Source Code Form is \"Incompatible With Secondary Licenses\", as \
+                         defined by the Mozilla Public License, v. 2.0.";
+
+/// Says if a text carries the "Incompatible With Secondary Licenses" notice of
+/// Exhibit B of the MPL-2.0, such as the header of a source file.
+///
+/// The template of the notice at the end of the MPL-2.0 text itself doesn't count.
+///
+/// # Examples
+/// ```
+/// use license::text;
+///
+/// let header = "This Source Code Form is subject to the terms of the Mozilla Public \
+///               License, v. 2.0.\n\nThis Source Code Form is \"Incompatible With Secondary \
+///               Licenses\", as defined by the Mozilla Public License, v. 2.0.";
+/// assert!(text::is_incompatible_with_secondary_licenses(header));
+///
+/// let mpl = license::from_id("MPL-2.0").unwrap().text();
+/// assert!(!text::is_incompatible_with_secondary_licenses(mpl));
+/// ```
+pub fn is_incompatible_with_secondary_licenses(text: &str) -> bool {
+    let exhibit = "Exhibit B - \"Incompatible With Secondary Licenses\" Notice";
+    occurrences(text, EXHIBIT_B) > occurrences(text, exhibit)
+}
+
+/// How many times the normalized tokens of the phrase occur in those of the text.
+fn occurrences(text: &str, phrase: &str) -> usize {
+    let mut text = tokens(text);
+    let mut count = 0;
+    loop {
+        let mut rest = text.clone();
+        if tokens(phrase).all(|token| rest.next() == Some(token)) {
+            count += 1;
+        }
+        if text.next().is_none() {
+            return count;
+        }
+    }
+}
+
+/// Says if the normalized tokens of the text contain those of the phrase, in order.
+fn contains(text: &str, phrase: &str) -> bool {
+    let mut text = tokens(text);
+    loop {
+        let mut rest = text.clone();
+        if tokens(phrase).all(|token| rest.next() == Some(token)) {
+            return true;
+        }
+        if text.next().is_none() {
+            return false;
+        }
+    }
+}
+
+/// The number of paragraphs either side of a change searched for the texts to agree again.
+const DIFF_WINDOW: usize = 32;
+
+/// The number of unchanged paragraphs shown around the changes of a [`TextDiff`].
+const DIFF_CONTEXT: usize = 1;
+
+/// A paragraph of a [`TextDiff`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum Change<'a> {
+    /// A paragraph of the candidate that is also in the canonical text.
+    Equal(&'a str),
+    /// A paragraph of the canonical text that is not in the candidate.
+    Removed(&'a str),
+    /// A paragraph of the candidate that is not in the canonical text.
+    Added(&'a str),
+}
+
+impl<'a> Change<'a> {
+    /// The text of the paragraph.
+    pub const fn text(self) -> &'a str {
+        match self {
+            Change::Equal(text) | Change::Removed(text) | Change::Added(text) => text,
+        }
+    }
+
+    /// Says if the change is only a copyright line, or a placeholder of the canonical text.
+    fn is_trivial(self) -> bool {
+        match self {
+            Change::Equal(_) => true,
+            Change::Removed(text) => has_placeholder(text) || is_copyright(text),
+            Change::Added(text) => is_copyright(text),
+        }
+    }
+}
+
+/// Says if every line of the paragraph is a copyright line.
+///
+/// A line that starts with `(c)` is only one if a year or a placeholder follows, since
+/// it is otherwise the third item of a list, as in the Apache-2.0.
+fn is_copyright(paragraph: &str) -> bool {
+    paragraph
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .all(|line| {
+            let line = line.trim_start();
+            let word = line.split(|c: char| !c.is_alphanumeric()).next();
+            let year = |rest: &str| {
+                rest.trim_start()
+                    .starts_with(|c: char| c.is_ascii_digit() || c == '<' || c == '[')
+            };
+            matches!(word, Some(word) if word.eq_ignore_ascii_case("copyright"))
+                || line.starts_with('©')
+                || matches!(line.get(..3), Some(c) if c.eq_ignore_ascii_case("(c)") && year(&line[3..]))
+        })
+}
+
+/// Says if the paragraph contains a placeholder such as `<year>` or `[name]`.
+fn has_placeholder(paragraph: &str) -> bool {
+    [('<', '>'), ('[', ']')].iter().any(|&(open, close)| {
+        matches!(paragraph.find(open), Some(start) if paragraph[start..].contains(close))
+    })
+}
+
+/// The paragraphs of a text that contain at least one token.
+#[derive(Clone)]
+struct Paragraphs<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Paragraphs<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        loop {
+            let mut start = None;
+            let mut end = self.rest.len();
+            let mut offset = 0;
+            for line in self.rest.split_inclusive('\n') {
+                let blank = line.trim().is_empty();
+                match start {
+                    None if !blank => start = Some(offset),
+                    Some(_) if blank => {
+                        end = offset;
+                        break;
+                    }
+                    _ => {}
+                }
+                offset += line.len();
+            }
+            let paragraph = self.rest[start?..end].trim_end();
+            self.rest = &self.rest[end..];
+            if tokens(paragraph).next().is_some() {
+                return Some(paragraph);
+            }
+        }
+    }
+}
+
+/// Says if two paragraphs have the same normalized tokens.
+fn same(a: &str, b: &str) -> bool {
+    tokens(a).eq(tokens(b))
+}
+
+/// Yields the changes between two texts, see [`TextDiff::changes`].
+#[derive(Clone)]
+struct Changes<'a> {
+    canonical: Paragraphs<'a>,
+    candidate: Paragraphs<'a>,
+    removed: usize,
+    added: usize,
+}
+
+impl<'a> Iterator for Changes<'a> {
+    type Item = Change<'a>;
+
+    fn next(&mut self) -> Option<Change<'a>> {
+        if self.removed > 0 {
+            self.removed -= 1;
+            return self.canonical.next().map(Change::Removed);
+        }
+        if self.added > 0 {
+            self.added -= 1;
+            return self.candidate.next().map(Change::Added);
+        }
+        match (self.canonical.clone().next(), self.candidate.clone().next()) {
+            (None, None) => None,
+            (Some(_), None) => self.canonical.next().map(Change::Removed),
+            (None, Some(_)) => self.candidate.next().map(Change::Added),
+            (Some(a), Some(b)) if same(a, b) => {
+                self.canonical.next();
+                self.candidate.next()
+            }
+            .map(Change::Equal),
+            (Some(_), Some(_)) => {
+                let (removed, added) = self.resync().unwrap_or((1, 1));
+                self.removed = removed;
+                self.added = added;
+                self.next()
+            }
+        }
+    }
+}
+
+impl Changes<'_> {
+    /// Finds the fewest paragraphs to remove and add until the texts agree again.
+    fn resync(&self) -> Option<(usize, usize)> {
+        (1..=2 * DIFF_WINDOW).find_map(|skipped| {
+            (skipped.saturating_sub(DIFF_WINDOW)..=skipped.min(DIFF_WINDOW)).find_map(|removed| {
+                let added = skipped - removed;
+                let a = self.canonical.clone().nth(removed)?;
+                let b = self.candidate.clone().nth(added)?;
+                if same(a, b) {
+                    Some((removed, added))
+                } else {
+                    None
+                }
+            })
+        })
+    }
+}
+
+/// The differences between a canonical license text and a candidate, see [`diff`].
+///
+/// Its [`Display`] impl renders the differences in the style of a unified
+/// diff, where the line numbers count paragraphs.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct TextDiff<'a> {
+    canonical: &'a str,
+    candidate: &'a str,
+}
+
+/// Compares a candidate text with a canonical license text.
+///
+/// The texts are compared paragraph by paragraph, where paragraphs are separated by
+/// blank lines. Paragraphs are equal if they have the same tokens, as normalized by
+/// [`fingerprint`], so differences in case, punctuation and line wrapping are ignored.
+///
+/// # Examples
+/// ```
+/// use license::{text, License};
+///
+/// let mit = license::from_id("MIT").unwrap();
+/// let candidate = mit.text().replace("<year> <copyright holders>", "2024 Example Corp");
+/// let diff = text::diff(mit.text(), &candidate);
+/// assert!(diff.is_trivial() && !diff.is_identical());
+/// assert_eq!(
+///     diff.to_string(),
+///     "--- canonical\n\
+///      +++ candidate\n\
+///      @@ -1,3 +1,3 @@\n \
+///      MIT License\n\
+///      -Copyright (c) <year> <copyright holders>\n\
+///      +Copyright (c) 2024 Example Corp\n \
+///      Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:\n"
+/// );
+/// ```
+pub fn diff<'a>(canonical: &'a str, candidate: &'a str) -> TextDiff<'a> {
+    TextDiff {
+        canonical,
+        candidate,
+    }
+}
+
+impl<'a> TextDiff<'a> {
+    /// The paragraphs of both texts, in order.
+    ///
+    /// Removed paragraphs come before the added paragraphs that replace them.
+    pub fn changes(&self) -> impl Iterator<Item = Change<'a>> + Clone + 'a {
+        Changes {
+            canonical: Paragraphs {
+                rest: self.canonical,
+            },
+            candidate: Paragraphs {
+                rest: self.candidate,
+            },
+            removed: 0,
+            added: 0,
+        }
+    }
+
+    /// Says if the texts have no differences after normalization.
+    pub fn is_identical(&self) -> bool {
+        self.changes()
+            .all(|change| matches!(change, Change::Equal(_)))
+    }
+
+    /// Says if the texts only differ in copyright lines and in the placeholders of the
+    /// canonical text, such as `<year>`.
+    ///
+    /// A scanner can accept such a candidate as the canonical license.
+    pub fn is_trivial(&self) -> bool {
+        self.changes().all(Change::is_trivial)
+    }
+}
+
+impl Display for TextDiff<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("--- canonical\n+++ candidate\n")?;
+        let total = self.changes().count();
+        let mut next = 0;
+        while let Some(first) = self
+            .changes()
+            .enumerate()
+            .skip(next)
+            .find(|(_, change)| !matches!(change, Change::Equal(_)))
+            .map(|(i, _)| i)
+        {
+            let mut last = first;
+            for (i, change) in self.changes().enumerate().skip(first + 1) {
+                if i - last - 1 > 2 * DIFF_CONTEXT {
+                    break;
+                }
+                if !matches!(change, Change::Equal(_)) {
+                    last = i;
+                }
+            }
+            let start = first.saturating_sub(DIFF_CONTEXT).max(next);
+            let end = (last + 1 + DIFF_CONTEXT).min(total);
+
+            let count = |changes: &mut dyn Iterator<Item = Change>| {
+                changes.fold((0, 0), |(a, b), change| match change {
+                    Change::Equal(_) => (a + 1, b + 1),
+                    Change::Removed(_) => (a + 1, b),
+                    Change::Added(_) => (a, b + 1),
+                })
+            };
+            let (a, b) = count(&mut self.changes().take(start));
+            let (len_a, len_b) = count(&mut self.changes().skip(start).take(end - start));
+            writeln!(f, "@@ -{},{} +{},{} @@", a + 1, len_a, b + 1, len_b)?;
+            for change in self.changes().skip(start).take(end - start) {
+                let prefix = match change {
+                    Change::Equal(_) => ' ',
+                    Change::Removed(_) => '-',
+                    Change::Added(_) => '+',
+                };
+                for line in change.text().lines() {
+                    writeln!(f, "{}{}", prefix, line)?;
+                }
+            }
+            next = end;
+        }
+        Ok(())
+    }
+}
+
+/// The lowest similarity of two paragraphs for [`align`] to pair them.
+#[cfg(feature = "alloc")]
+const MIN_PARAGRAPH_SIMILARITY: f32 = 0.3;
+
+/// A paragraph of a license text, see [`paragraphs`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Paragraph {
+    span: TextSpan,
+    hash: u64,
+}
+
+#[cfg(feature = "alloc")]
+impl Paragraph {
+    /// The byte range of the paragraph in the license text.
+    pub fn span(&self) -> TextSpan {
+        self.span.clone()
+    }
+
+    /// The hash of the normalized tokens of the paragraph, which is the same for
+    /// paragraphs that only differ in case, punctuation and line wrapping, as in [`diff`].
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+}
+
+/// The paragraphs of a license text, separated by blank lines, in order.
+///
+/// The paragraphs are those of [`diff`], so a paragraph without any token, such as a
+/// line of dashes, is left out.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use license::{text, License};
+///
+/// let mit = license::from_id("MIT").unwrap();
+/// let paragraphs = text::paragraphs(mit);
+/// assert_eq!(paragraphs.len(), 5);
+/// assert_eq!(&mit.text()[paragraphs[0].span()], "MIT License");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn paragraphs(license: &dyn License) -> Vec<Paragraph> {
+    let text = license.text();
+    Paragraphs { rest: text }
+        .map(|paragraph| {
+            let start = offset(text, paragraph);
+            Paragraph {
+                span: start..start + paragraph.len(),
+                hash: tokens(paragraph).fold(FNV_OFFSET, fnv_u64),
+            }
+        })
+        .collect()
+}
+
+/// Pairs the [`paragraphs`] of two license texts, for showing them side by side.
+///
+/// Each row is the index of a paragraph of `a`, of `b`, or of both when they are paired.
+/// The rows are in the order of both texts, and every paragraph is in exactly one row.
+/// Paragraphs are paired by the similarity of their normalized word pairs, when it
+/// reaches 0.3, so that the pairs have the highest total similarity; an unpaired
+/// paragraph of `a` comes before those of `b` at the same place.
+///
+/// The pairing is a best effort, but it is deterministic, and it takes a few
+/// milliseconds for the longest texts.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use license::text;
+///
+/// let mit = license::from_id("MIT").unwrap();
+/// let x11 = license::from_id("X11").unwrap();
+/// let rows = text::align(mit, x11);
+/// // The permission, the notice and the disclaimer are paired, the trademark clauses of
+/// // X11 are not.
+/// assert_eq!(rows[4..7], [(Some(2), Some(2)), (Some(3), Some(3)), (Some(4), Some(4))]);
+/// assert_eq!(rows[7..], [(None, Some(5)), (None, Some(6))]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn align(a: &dyn License, b: &dyn License) -> Vec<(Option<usize>, Option<usize>)> {
+    let word_pairs = |license: &dyn License| -> Vec<Vec<u64>> {
+        Paragraphs {
+            rest: license.text(),
+        }
+        .map(|paragraph| {
+            let tokens: Vec<u64> = tokens(paragraph).collect();
+            let mut pairs: Vec<u64> = if tokens.len() < 2 {
+                tokens
+            } else {
+                tokens
+                    .windows(2)
+                    .map(|pair| fnv_u64(fnv_u64(FNV_OFFSET, pair[0]), pair[1]))
+                    .collect()
+            };
+            pairs.sort_unstable();
+            pairs.dedup();
+            pairs
+        })
+        .collect()
+    };
+    let a = word_pairs(a);
+    let b = word_pairs(b);
+
+    // The best total similarity of the first `i` paragraphs of `a` and `j` of `b`, in a
+    // row of `b.len() + 1` cells per paragraph of `a`.
+    let width = b.len() + 1;
+    let mut best = alloc::vec![0.0f32; (a.len() + 1) * width];
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let skip = best[(i - 1) * width + j].max(best[i * width + j - 1]);
+            let similarity = jaccard(&a[i - 1], &b[j - 1]);
+            best[i * width + j] = if similarity >= MIN_PARAGRAPH_SIMILARITY {
+                skip.max(best[(i - 1) * width + j - 1] + similarity)
+            } else {
+                skip
+            };
+        }
+    }
+
+    let mut rows = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 || j > 0 {
+        let cell = best[i * width + j];
+        if j > 0 && (i == 0 || cell == best[i * width + j - 1]) {
+            j -= 1;
+            rows.push((None, Some(j)));
+        } else if i > 0 && (j == 0 || cell == best[(i - 1) * width + j]) {
+            i -= 1;
+            rows.push((Some(i), None));
+        } else {
+            i -= 1;
+            j -= 1;
+            rows.push((Some(i), Some(j)));
+        }
+    }
+    rows.reverse();
+    rows
+}
+
+/// The share of the elements of two sorted sets that are in both.
+#[cfg(feature = "alloc")]
+fn jaccard(a: &[u64], b: &[u64]) -> f32 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let (mut i, mut j, mut both) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                both += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    both as f32 / (a.len() + b.len() - both) as f32
+}
+
+/// A byte range of a license text, such as the clause that supports a flag, see
+/// [`LicenseExt::flag_provenance`](crate::LicenseExt::flag_provenance).
+///
+/// The offsets are those of [`Section::span`], so a span is in the section whose span
+/// contains its start.
+pub type TextSpan = Range<usize>;
+
+/// A numbered section of a license text, see [`License::sections`](crate::License::sections).
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Section {
+    number: Option<&'static str>,
+    title: &'static str,
+    span: Range<usize>,
+}
+
+impl Section {
+    pub(crate) const fn new(
+        number: Option<&'static str>,
+        title: &'static str,
+        span: Range<usize>,
+    ) -> Self {
+        Section {
+            number,
+            title,
+            span,
+        }
+    }
+
+    /// The number of the section, such as `"4"`, or `None` for the text before the
+    /// first section and after the last one.
+    pub fn number(&self) -> Option<&'static str> {
+        self.number
+    }
+
+    /// The title of the section, such as `"Redistribution"`, or an empty string if the
+    /// section starts with its text, as in the GPL-2.0.
+    pub fn title(&self) -> &'static str {
+        self.title
+    }
+
+    /// The byte range of the section in the license text, heading included.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
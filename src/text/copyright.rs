@@ -0,0 +1,334 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+/// A copyright statement found by [`extract_copyrights`].
+///
+/// # Examples
+/// ```
+/// use license::text;
+///
+/// let statements = text::extract_copyrights("Copyright (c) 2019-2021, 2023 Example Corp. All rights reserved.");
+/// assert_eq!(statements[0].years, Some(2019..=2023));
+/// assert_eq!(statements[0].holder, "Example Corp.");
+/// ```
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct CopyrightStatement {
+    /// The first and the last year, or `None` if the statement has no year.
+    pub years: Option<RangeInclusive<u16>>,
+    /// The holder, on one line and without a trailing `All rights reserved.`
+    pub holder: String,
+    /// The lines of the statement as they are in the text, trimmed, continuation lines
+    /// included.
+    pub raw: String,
+}
+
+/// Extracts the copyright statements of a text, in order, for attribution.
+///
+/// A statement is a line that starts with `Copyright`, `©` or `(C)`, in any mix, such as
+/// `Copyright (c) 2024 Example Corp`, after the markers of a comment such as `//`, `#`
+/// or ` * `. A line that only starts with `(c)` needs a year, since it is otherwise an
+/// item of a list, and `Copyright` must be followed by a marker, a year, a placeholder or
+/// a capitalized word, so that `copyright notice` isn't taken as one.
+///
+/// The years are the four-digit years and ranges before the holder, such as
+/// `2019-2021, 2023` or `1991-92`, reduced to the first and the last, and they may also
+/// end the statement. A statement continues on the next line when the line ends with
+/// `,`, `&` or `and`, unless the next line starts with a digit as an address does, and
+/// when the next line is `All rights reserved.` The holder ends before
+/// `All rights reserved`. A line with several holders, as in `2019 Alice, 2021 Bob`,
+/// or with several statements gives a statement for each of them, sharing the raw line.
+/// Statements without a holder are left out.
+///
+/// These are the lines that [`tokenize`](super::tokenize) leaves out at the start of a
+/// paragraph, so a pass over a license file can get both the license, with
+/// [`best_match`](super::best_match), and its attribution.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use license::text;
+///
+/// let notice = "Copyright (c) 2016 Alex Example\n\
+///               Copyright 2018, 2020 The Example Project Developers\n\
+///               \n\
+///               Permission is hereby granted, free of charge, ...";
+/// let statements = text::extract_copyrights(notice);
+/// let holders: Vec<_> = statements.iter().map(|s| s.holder.as_str()).collect();
+/// assert_eq!(holders, ["Alex Example", "The Example Project Developers"]);
+/// assert_eq!(statements[1].years, Some(2018..=2020));
+/// assert_eq!(statements[1].raw, "Copyright 2018, 2020 The Example Project Developers");
+/// ```
+pub fn extract_copyrights(text: &str) -> Vec<CopyrightStatement> {
+    let mut statements = Vec::new();
+    let mut lines = text.lines().peekable();
+    while let Some(line) = lines.next() {
+        let mut body = match statement(line) {
+            Some(body) => String::from(body),
+            None => continue,
+        };
+        let mut raw = String::from(line.trim());
+        while let Some(&next) = lines.peek() {
+            if !continues(&body, next) {
+                break;
+            }
+            raw.push('\n');
+            raw.push_str(next.trim());
+            body.push(' ');
+            body.push_str(uncomment(next));
+            lines.next();
+        }
+        parse(&body, &raw, &mut statements);
+    }
+    statements
+}
+
+/// The words after `Copyright` that make it a word of the license rather than the
+/// start of a statement, as in `COPYRIGHT HOLDERS AND CONTRIBUTORS`.
+const NOT_HOLDERS: &[&str] = &[
+    "and",
+    "disclaimer",
+    "disclaimers",
+    "holder",
+    "holders",
+    "law",
+    "laws",
+    "license",
+    "notice",
+    "notices",
+    "notification",
+    "or",
+    "owner",
+    "owners",
+    "protection",
+    "statement",
+    "status",
+];
+
+/// The abbreviations whose dot is kept at the end of a holder.
+const ABBREVIATIONS: &[&str] = &["al", "co", "corp", "etc", "inc", "jr", "ltd", "sr"];
+
+/// The line without the markers of a comment at its start.
+fn uncomment(line: &str) -> &str {
+    line.trim_start_matches(|c: char| c.is_whitespace() || matches!(c, '/' | '*' | '#' | ';'))
+        .trim_end()
+}
+
+/// The text after the copyright markers if the line starts a statement.
+fn statement(line: &str) -> Option<&str> {
+    let mut rest = uncomment(line);
+    let (mut word, mut symbol, mut paren) = (None, false, false);
+    loop {
+        if let Some(after) = strip_word(rest, "copyright") {
+            word = word.or(Some(&rest[..9]));
+            rest = after.strip_prefix(':').unwrap_or(after);
+        } else if let Some(after) = rest.strip_prefix('©') {
+            symbol = true;
+            rest = after;
+        } else if matches!(rest.get(..3), Some(c) if c.eq_ignore_ascii_case("(c)")) {
+            paren = true;
+            rest = &rest[3..];
+        } else {
+            break;
+        }
+        rest = rest.trim_start();
+    }
+    let first = rest.chars().next()?;
+    let dated = first.is_ascii_digit() || first == '<' || first == '[';
+    let ok = match word {
+        None if symbol => dated || first.is_uppercase(),
+        None => paren && dated,
+        Some(word) => {
+            let next = rest
+                .split(|c: char| !c.is_alphanumeric())
+                .next()
+                .unwrap_or("");
+            let licensed = NOT_HOLDERS.iter().any(|w| w.eq_ignore_ascii_case(next));
+            dated || (symbol || paren || word != "copyright") && first.is_uppercase() && !licensed
+        }
+    };
+    if ok {
+        Some(rest)
+    } else {
+        None
+    }
+}
+
+/// The text after `word` if `s` starts with it, ignoring ASCII case.
+fn strip_word<'a>(s: &'a str, word: &str) -> Option<&'a str> {
+    let after = s.get(word.len()..)?;
+    let matches = s.as_bytes()[..word.len()].eq_ignore_ascii_case(word.as_bytes());
+    (matches && !after.starts_with(char::is_alphanumeric)).then(|| after)
+}
+
+/// Says if `next` continues the statement whose text so far is `body`.
+///
+/// A line that starts with a digit isn't a holder but an address, as in the GPL.
+fn continues(body: &str, next: &str) -> bool {
+    let content = uncomment(next);
+    if content.is_empty() || statement(next).is_some() {
+        return false;
+    }
+    is_rights_reserved(content)
+        || !content.starts_with(|c: char| c.is_ascii_digit())
+            && (body.ends_with(',') || body.ends_with('&') || strip_word_end(body, "and").is_some())
+}
+
+/// Says if the line is only `All rights reserved`.
+fn is_rights_reserved(line: &str) -> bool {
+    line.trim_end_matches('.')
+        .eq_ignore_ascii_case("all rights reserved")
+}
+
+/// The text before `word` if `s` ends with it as a word, ignoring ASCII case.
+fn strip_word_end<'a>(s: &'a str, word: &str) -> Option<&'a str> {
+    let start = s.len().checked_sub(word.len())?;
+    let before = s.get(..start)?;
+    let matches = s.as_bytes()[start..].eq_ignore_ascii_case(word.as_bytes());
+    (matches && !before.ends_with(char::is_alphanumeric)).then(|| before)
+}
+
+/// Parses the years and holders of a statement.
+fn parse(body: &str, raw: &str, statements: &mut Vec<CopyrightStatement>) {
+    let mut rest = body;
+    loop {
+        let (mut years, after) = years(rest);
+        let (holder, next) = next_holder(after);
+        let mut holder = clean(holder);
+        if years.is_none() {
+            if let Some((before, trailing)) = trailing_years(&holder) {
+                years = Some(trailing);
+                holder = clean(before);
+            }
+        }
+        if !holder.is_empty() {
+            statements.push(CopyrightStatement {
+                years,
+                holder,
+                raw: raw.into(),
+            });
+        }
+        match next {
+            Some(next) => rest = next,
+            None => return,
+        }
+    }
+}
+
+/// A four-digit year at the start of `s`, and the text after it.
+fn year(s: &str) -> Option<(u16, &str)> {
+    let digits = s.get(..4)?;
+    let after = &s[4..];
+    if !digits.bytes().all(|b| b.is_ascii_digit()) || after.starts_with(char::is_alphanumeric) {
+        return None;
+    }
+    Some((digits.parse().ok()?, after))
+}
+
+/// The year that ends a range from `start`, in full or as its last two digits, as in
+/// `1991-92`.
+fn end_year(s: &str, start: u16) -> Option<(u16, &str)> {
+    if let Some(year) = year(s) {
+        return Some(year);
+    }
+    let len = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    if !(1..=2).contains(&len) || s[len..].starts_with(char::is_alphanumeric) {
+        return None;
+    }
+    let short: u16 = s[..len].parse().ok()?;
+    let end = start - start % 100 + short;
+    Some((if end < start { end + 100 } else { end }, &s[len..]))
+}
+
+/// The years and ranges of years at the start of `s`, and the text after them.
+fn years(s: &str) -> (Option<RangeInclusive<u16>>, &str) {
+    let mut range: Option<RangeInclusive<u16>> = None;
+    let mut rest = s;
+    while let Some((start, after)) = year(rest.trim_start()) {
+        let mut end = start;
+        rest = after;
+        let dash = after
+            .trim_start()
+            .strip_prefix(|c| matches!(c, '-' | '–' | '—'));
+        if let Some((last, after)) = dash.and_then(|dash| end_year(dash.trim_start(), start)) {
+            end = last;
+            rest = after;
+        }
+        let (low, high) = (start.min(end), start.max(end));
+        range = Some(match range {
+            Some(range) => *range.start().min(&low)..=*range.end().max(&high),
+            None => low..=high,
+        });
+        let trimmed = rest.trim_start();
+        rest = match trimmed.strip_prefix(',') {
+            Some(after) if year(after.trim_start()).is_some() => after,
+            _ => rest,
+        };
+    }
+    (range, rest)
+}
+
+/// The holder before the years that end it, as in `Example Corp 2001-2002`.
+fn trailing_years(holder: &str) -> Option<(&str, RangeInclusive<u16>)> {
+    holder
+        .char_indices()
+        .filter(|&(_, c)| c == ' ' || c == ',')
+        .find_map(|(i, _)| match years(&holder[i + 1..]) {
+            (Some(years), rest) if rest.trim().is_empty() => Some((&holder[..i], years)),
+            _ => None,
+        })
+}
+
+/// Splits `s` before the years of a second holder, as in `Alice, 2021 Bob`, or before
+/// a second statement on the same line.
+fn next_holder(s: &str) -> (&str, Option<&str>) {
+    let split = s.char_indices().find_map(|(i, c)| {
+        let after = match c {
+            ' ' => match statement(&s[i + 1..]) {
+                Some(body) => return Some((&s[..i], body)),
+                None if strip_word(&s[i + 1..], "and").is_some() => &s[i + 4..],
+                None => return None,
+            },
+            ',' | ';' => &s[i + 1..],
+            _ => return None,
+        };
+        year(after.trim_start()).map(|_| (&s[..i], after))
+    });
+    match split {
+        Some((holder, rest)) => (holder, Some(rest)),
+        None => (s, None),
+    }
+}
+
+/// The holder on one line, without `by`, what follows `All rights reserved` and the
+/// punctuation around it.
+fn clean(holder: &str) -> String {
+    let holder = holder
+        .trim_start_matches(|c: char| matches!(c, ',' | '.' | ':' | ';') || c.is_whitespace());
+    let lowercase = holder.to_ascii_lowercase();
+    let holder = match lowercase.find("all rights reserved") {
+        Some(i) => &holder[..i],
+        None => holder,
+    };
+    let mut words: Vec<&str> = holder.split_whitespace().collect();
+    if matches!(words.first(), Some(word) if word.eq_ignore_ascii_case("by")) {
+        words.remove(0);
+    }
+    let mut holder = words.join(" ");
+    loop {
+        let trimmed = holder.trim_end_matches(|c: char| c == ',' || c == ';' || c == ' ');
+        let stripped = trimmed
+            .strip_suffix('.')
+            .filter(|before| {
+                let word = before.rsplit(|c: char| !c.is_alphanumeric()).next();
+                !matches!(word, Some(word) if ABBREVIATIONS.iter().any(|a| a.eq_ignore_ascii_case(word)))
+            })
+            .unwrap_or(trimmed);
+        if stripped.len() == holder.len() {
+            return holder;
+        }
+        holder = String::from(stripped);
+    }
+}
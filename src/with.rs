@@ -0,0 +1,197 @@
+use crate::*;
+
+/// The `WITH` ids that use a deprecated license id, and the ids that replace them.
+pub(crate) const DEPRECATED_WITH: &[(&str, &str)] = &[(
+    "GPL-2.0 WITH Classpath-exception-2.0",
+    "GPL-2.0-only WITH Classpath-exception-2.0",
+)];
+
+/// Returns a license combined with an exception based on the provided `WITH` id.
+pub(crate) fn from_id_with(id: &str) -> Option<&'static dyn LicenseExt> {
+    lookup_with(id).map(|(_, license)| license)
+}
+
+/// Like [`from_id_with`], as a [`License`].
+#[cfg(feature = "alloc")]
+pub(crate) fn license_with(id: &str) -> Option<&'static dyn License> {
+    lookup_with(id).map(|(license, _)| license)
+}
+
+fn lookup_with(id: &str) -> Option<(&'static dyn License, &'static dyn LicenseExt)> {
+    let id = DEPRECATED_WITH
+        .iter()
+        .find(|(deprecated, _)| *deprecated == id)
+        .map_or(id, |(_, replacement)| replacement);
+    match id {
+        "Apache-2.0 WITH SHL-2.1" => Some((&Apache_2_0_WITH_SHL_2_1, &Apache_2_0_WITH_SHL_2_1)),
+        "GPL-2.0-only WITH Classpath-exception-2.0" => Some((
+            &GPL_2_0_only_WITH_Classpath_exception_2_0,
+            &GPL_2_0_only_WITH_Classpath_exception_2_0,
+        )),
+        _ => None,
+    }
+}
+
+/// The Apache License 2.0 with the Solderpad Hardware License v2.1.
+///
+/// SPDX lists the Solderpad Hardware License as an exception, since it is a wraparound
+/// license that adapts Apache-2.0 to hardware designs, and it is written
+/// `Apache-2.0 WITH SHL-2.1`. As for every combination, its [text](License::text) and
+/// its sections are those of Apache-2.0, while the published Solderpad text, which
+/// incorporates Apache-2.0 by reference rather than repeating it, is the text of the
+/// exception. The [header](License::header) is the notice of the Solderpad appendix. The
+/// flags are those of Apache-2.0, patent rights included, and so are the OSI and FSF
+/// flags, since the licensee may treat the work as licensed under Apache-2.0.
+///
+/// # Examples
+/// ```
+/// let shl = license::from_id_ext("Apache-2.0 WITH SHL-2.1").unwrap();
+/// let apache = license::from_id_ext("Apache-2.0").unwrap();
+/// assert_eq!(shl.text(), apache.text());
+/// assert!(shl.exception().unwrap().text().starts_with("SOLDERPAD HARDWARE LICENSE VERSION 2.1"));
+/// assert_eq!(shl.permissions(), apache.permissions());
+/// assert_eq!(shl.section("2"), apache.section("2"));
+/// assert!(shl.header().unwrap().contains("Solderpad Hardware License v 2.1"));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Apache_2_0_WITH_SHL_2_1;
+
+impl License for Apache_2_0_WITH_SHL_2_1 {
+    fn name(&self) -> &'static str {
+        "Apache License 2.0 WITH Solderpad Hardware License v2.1"
+    }
+
+    fn id(&self) -> &'static str {
+        "Apache-2.0 WITH SHL-2.1"
+    }
+
+    fn text(&self) -> &'static str {
+        Apache_2_0.text()
+    }
+
+    fn sections(&self) -> &'static [crate::text::Section] {
+        Apache_2_0.sections()
+    }
+
+    fn fingerprint(&self) -> crate::text::Fingerprint {
+        Apache_2_0.fingerprint()
+    }
+
+    fn header(&self) -> Option<&'static str> {
+        let text = SHL_2_1.text();
+        let start = text.find("Copyright [yyyy]")?;
+        Some(text[start..].trim_end())
+    }
+
+    fn is_osi_approved(&self) -> bool {
+        Apache_2_0.is_osi_approved()
+    }
+
+    fn is_fsf_libre(&self) -> bool {
+        Apache_2_0.is_fsf_libre()
+    }
+
+    fn is_deprecated(&self) -> bool {
+        false
+    }
+
+    fn see_also(&self) -> &'static [&'static str] {
+        SHL_2_1.see_also()
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+
+    fn family(&self) -> Family {
+        Apache_2_0.family()
+    }
+
+    fn exception(&self) -> Option<&'static dyn LicenseException> {
+        Some(&SHL_2_1)
+    }
+}
+
+/// The GNU General Public License v2.0 only with the Classpath exception 2.0.
+///
+/// The exception allows linking independent modules with the library and
+/// distributing the result under terms of your choice, while modifications of the
+/// library itself remain covered by the GPL. So the flags are those of GPL-2.0-only,
+/// [`same_license`](Conditions::same_license) included, and the exception is modelled
+/// by [`obligations_for_linking`](LicenseExt::obligations_for_linking), which only
+/// covers the library.
+///
+/// # Examples
+/// ```
+/// use license::{Coverage, Linkage};
+///
+/// let openjdk = license::from_id_ext("GPL-2.0-only WITH Classpath-exception-2.0").unwrap();
+/// let gpl = license::from_id_ext("GPL-2.0-only").unwrap();
+/// assert_eq!(openjdk.text(), gpl.text());
+/// assert_eq!(openjdk.exception().unwrap().id(), "Classpath-exception-2.0");
+/// assert_eq!(openjdk.conditions(), gpl.conditions());
+///
+/// let linked = openjdk.obligations_for_linking(Linkage::Static);
+/// assert_eq!(linked.coverage(), Coverage::LibraryOnly);
+/// assert_eq!(gpl.obligations_for_linking(Linkage::Static).coverage(), Coverage::CombinedWork);
+///
+/// // The deprecated `GPL-2.0` id is still common in converted Maven metadata.
+/// let maven = license::from_id_ext("GPL-2.0 WITH Classpath-exception-2.0").unwrap();
+/// assert_eq!(maven.id(), openjdk.id());
+/// ```
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct GPL_2_0_only_WITH_Classpath_exception_2_0;
+
+impl License for GPL_2_0_only_WITH_Classpath_exception_2_0 {
+    fn name(&self) -> &'static str {
+        "GNU General Public License v2.0 only WITH Classpath exception 2.0"
+    }
+
+    fn id(&self) -> &'static str {
+        "GPL-2.0-only WITH Classpath-exception-2.0"
+    }
+
+    fn text(&self) -> &'static str {
+        GPL_2_0_only.text()
+    }
+
+    fn sections(&self) -> &'static [crate::text::Section] {
+        GPL_2_0_only.sections()
+    }
+
+    fn fingerprint(&self) -> crate::text::Fingerprint {
+        GPL_2_0_only.fingerprint()
+    }
+
+    fn header(&self) -> Option<&'static str> {
+        GPL_2_0_only.header()
+    }
+
+    fn is_osi_approved(&self) -> bool {
+        GPL_2_0_only.is_osi_approved()
+    }
+
+    fn is_fsf_libre(&self) -> bool {
+        GPL_2_0_only.is_fsf_libre()
+    }
+
+    fn is_deprecated(&self) -> bool {
+        false
+    }
+
+    fn see_also(&self) -> &'static [&'static str] {
+        Classpath_exception_2_0.see_also()
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+
+    fn family(&self) -> Family {
+        GPL_2_0_only.family()
+    }
+
+    fn exception(&self) -> Option<&'static dyn LicenseException> {
+        Some(&Classpath_exception_2_0)
+    }
+}
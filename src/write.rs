@@ -0,0 +1,167 @@
+//! Writes the license files of a project, such as `LICENSE-MIT` and `LICENSE-APACHE`.
+//!
+//! Requires the `fs` feature.
+
+use crate::params::CopyrightYears;
+use crate::*;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// The licenses to write files for, see [`write_license_files`].
+#[derive(Copy, Clone)]
+pub enum LicenseChoice {
+    /// A single license, written to `LICENSE`.
+    Single(&'static dyn License),
+    /// `MIT OR Apache-2.0`, written to `LICENSE-MIT` and `LICENSE-APACHE` as is usual
+    /// for Rust projects.
+    MitOrApache,
+    /// The licenses of an expression, except its exceptions. A single license is written
+    /// to `LICENSE`, and several are written to `LICENSE-MIT`, `LICENSE-APACHE` for
+    /// Apache-2.0, and `LICENSE-<id>` for the others.
+    Expression(PackageLicense),
+}
+
+impl Debug for LicenseChoice {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            LicenseChoice::Single(license) => f.debug_tuple("Single").field(&license.id()).finish(),
+            LicenseChoice::MitOrApache => f.write_str("MitOrApache"),
+            LicenseChoice::Expression(expression) => {
+                f.debug_tuple("Expression").field(expression).finish()
+            }
+        }
+    }
+}
+
+/// How to write the license files, see [`write_license_files`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct LicenseParams<'a> {
+    /// The copyright holder, for licenses with a [`notice`](LicenseExt::notice) that is
+    /// their whole text, such as MIT.
+    pub holder: &'a str,
+    /// The years of the copyright, such as `2024.into()` for a single year.
+    pub years: CopyrightYears,
+    /// Says if existing files are replaced.
+    pub overwrite: bool,
+}
+
+/// Writes a file for each license of `choice` into `dir`, and returns the paths of the
+/// files, ordered by name.
+///
+/// A license whose [`notice`](LicenseExt::notice) is its whole text, such as MIT or
+/// BSD-3-Clause, is written with the placeholders replaced. Any other license is written
+/// as its text, such as Apache-2.0, whose notice belongs in the source files. Every file
+/// ends with a single newline.
+///
+/// Unless [`overwrite`](LicenseParams::overwrite) is set, nothing is written if one of
+/// the files exists, and the error is [`io::ErrorKind::AlreadyExists`].
+///
+/// # Examples
+/// ```no_run
+/// use license::params::CopyrightYears;
+/// use license::write::{self, LicenseChoice, LicenseParams};
+/// use std::path::Path;
+///
+/// let years = CopyrightYears::range(2019, 2024).unwrap();
+/// let params = LicenseParams { holder: "Jane Doe", years, overwrite: false };
+/// let paths = write::write_license_files(Path::new("."), &LicenseChoice::MitOrApache, &params)?;
+/// assert_eq!(paths, [Path::new("./LICENSE-APACHE"), Path::new("./LICENSE-MIT")]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn write_license_files(
+    dir: &Path,
+    choice: &LicenseChoice,
+    params: &LicenseParams,
+) -> io::Result<Vec<PathBuf>> {
+    let licenses = licenses(choice);
+    let mut files: Vec<_> = licenses
+        .iter()
+        .map(|license| {
+            let name = if licenses.len() == 1 {
+                "LICENSE".to_string()
+            } else {
+                file_name(license.id())
+            };
+            (dir.join(name), *license)
+        })
+        .collect();
+    files.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    if !params.overwrite {
+        if let Some((path, _)) = files.iter().find(|(path, _)| path.exists()) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", path.display()),
+            ));
+        }
+    }
+    for (path, license) in &files {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(params.overwrite)
+            .create_new(!params.overwrite)
+            .open(path)?;
+        file.write_all(contents(*license, params).as_bytes())?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// The licenses of a choice, deduplicated and ordered by id.
+fn licenses(choice: &LicenseChoice) -> Vec<&'static dyn License> {
+    let mut licenses: Vec<&'static dyn License> = match choice {
+        LicenseChoice::Single(license) => return alloc::vec![*license],
+        LicenseChoice::MitOrApache => alloc::vec![&Apache_2_0, &MIT],
+        LicenseChoice::Expression(expression) => {
+            let mut words = expression
+                .expression()
+                .split(|c: char| c.is_whitespace() || c == '(' || c == ')' || c == '/')
+                .filter(|word| !word.is_empty());
+            let mut licenses = Vec::new();
+            while let Some(word) = words.next() {
+                match word {
+                    "AND" | "OR" => {}
+                    "WITH" => {
+                        words.next();
+                    }
+                    // A validated expression only has known ids.
+                    id => licenses.extend(from_id_flexible(id).map(|resolved| resolved.license)),
+                }
+            }
+            licenses
+        }
+    };
+    licenses.sort_by_key(|license| license.id());
+    licenses.dedup_by_key(|license| license.id());
+    licenses
+}
+
+/// The conventional name of the file of a license among several.
+fn file_name(id: &str) -> String {
+    match id {
+        "MIT" => "LICENSE-MIT".to_string(),
+        "Apache-2.0" => "LICENSE-APACHE".to_string(),
+        id => format!("LICENSE-{}", id),
+    }
+}
+
+fn contents(license: &dyn License, params: &LicenseParams) -> String {
+    let text = license.text();
+    let notice = license
+        .ext()
+        .and_then(|ext| ext.notice(params.holder, params.years.start()))
+        .map(|notice| notice.with_years(params.years))
+        .filter(|notice| notice.template() == text);
+    let mut contents = match notice {
+        Some(notice) => notice.to_string(),
+        None => text.to_string(),
+    };
+    contents.truncate(contents.trim_end().len());
+    contents.push('\n');
+    contents
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "alloc")]
+
+use license::text::{self, Paragraph};
+use license::License;
+
+fn gpl() -> (&'static dyn License, &'static dyn License) {
+    (
+        license::from_id("GPL-2.0-only").unwrap(),
+        license::from_id("GPL-3.0-only").unwrap(),
+    )
+}
+
+/// The index of the paragraph that starts with `prefix`.
+fn find(license: &dyn License, paragraphs: &[Paragraph], prefix: &str) -> usize {
+    paragraphs
+        .iter()
+        .position(|p| license.text()[p.span()].starts_with(prefix))
+        .unwrap_or_else(|| panic!("{}: no paragraph {:?}", license.id(), prefix))
+}
+
+#[test]
+fn paragraphs_are_trimmed_and_ordered() {
+    for license in license::LICENSES.iter().take(100) {
+        let paragraphs = text::paragraphs(*license);
+        for p in &paragraphs {
+            let paragraph = &license.text()[p.span()];
+            assert!(!paragraph.trim().is_empty(), "{}", license.id());
+            assert_eq!(paragraph.trim_end(), paragraph, "{}", license.id());
+        }
+        for w in paragraphs.windows(2) {
+            assert!(w[0].span().end < w[1].span().start, "{}", license.id());
+        }
+    }
+}
+
+#[test]
+fn hashes_ignore_formatting() {
+    let (v2, v3) = gpl();
+    let (a, b) = (text::paragraphs(v2), text::paragraphs(v3));
+    let end = |license, paragraphs: &[Paragraph]| {
+        paragraphs[find(license, paragraphs, "END OF TERMS AND CONDITIONS")].hash()
+    };
+    assert_eq!(end(v2, &a), end(v3, &b));
+    assert_ne!(a[0].hash(), b[0].hash());
+}
+
+#[test]
+fn gpl_2_and_3() {
+    let (v2, v3) = gpl();
+    let (a, b) = (text::paragraphs(v2), text::paragraphs(v3));
+    let rows = text::align(v2, v3);
+
+    let warranty = (
+        Some(find(
+            v2,
+            &a,
+            "11. BECAUSE THE PROGRAM IS LICENSED FREE OF CHARGE",
+        )),
+        Some(find(v3, &b, "15. Disclaimer of Warranty.")),
+    );
+    let liability = (
+        Some(find(v2, &a, "12. IN NO EVENT")),
+        Some(find(v3, &b, "16. Limitation of Liability.")),
+    );
+    assert!(rows.contains(&warranty), "{:?}", rows);
+    assert!(rows.contains(&liability), "{:?}", rows);
+    assert!(rows.contains(&(Some(0), Some(0))));
+
+    for prefix in [
+        "0. Definitions.",
+        "3. Protecting Users' Legal Rights From Anti-Circumvention Law.",
+        "7. Additional Terms.",
+        "11. Patents.",
+        "17. Interpretation of Sections 15 and 16.",
+    ] {
+        let row = (None, Some(find(v3, &b, prefix)));
+        assert!(rows.contains(&row), "{} is paired", prefix);
+    }
+}
+
+#[test]
+fn rows_cover_both_texts_in_order() {
+    let (v2, v3) = gpl();
+    let rows = text::align(v2, v3);
+    let left: Vec<_> = rows.iter().filter_map(|&(a, _)| a).collect();
+    let right: Vec<_> = rows.iter().filter_map(|&(_, b)| b).collect();
+    assert_eq!(left, (0..text::paragraphs(v2).len()).collect::<Vec<_>>());
+    assert_eq!(right, (0..text::paragraphs(v3).len()).collect::<Vec<_>>());
+    assert!(!rows.contains(&(None, None)));
+    assert_eq!(text::align(v2, v3), rows);
+}
+
+#[test]
+fn a_text_aligns_with_itself() {
+    let apache = license::from_id("Apache-2.0").unwrap();
+    let rows = text::align(apache, apache);
+    assert_eq!(rows.len(), text::paragraphs(apache).len());
+    assert!(rows
+        .iter()
+        .enumerate()
+        .all(|(i, &row)| row == (Some(i), Some(i))));
+}
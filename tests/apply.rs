@@ -0,0 +1,96 @@
+use license::LICENSES_EXT;
+
+/// Placeholders used by the `How to apply` sections and headers of GNU licenses.
+const PLACEHOLDERS: &[&str] = &[
+    "<year>",
+    "[year]",
+    "yyyy",
+    "name of author",
+    "<program>",
+    "one line to give",
+];
+
+#[test]
+fn no_placeholders_remain() {
+    for l in LICENSES_EXT {
+        let instructions = match l.apply_instructions("frobnicate", "Jane Doe", 2024) {
+            Some(instructions) => instructions.to_string(),
+            None => continue,
+        };
+        for placeholder in PLACEHOLDERS {
+            assert!(
+                !instructions.contains(placeholder),
+                "{}: {:?} remains in\n{}",
+                l.id(),
+                placeholder,
+                instructions
+            );
+        }
+        assert!(instructions.contains("frobnicate"), "{}", l.id());
+        assert!(instructions.contains("Copyright (C) 2024"), "{}", l.id());
+        assert!(instructions.contains("Jane Doe"), "{}", l.id());
+    }
+}
+
+#[test]
+fn implemented_for_gnu_3_licenses() {
+    let ids: Vec<_> = LICENSES_EXT
+        .iter()
+        .filter(|l| l.apply_instructions("p", "a", 2000).is_some())
+        .map(|l| l.id())
+        .collect();
+    assert_eq!(ids, ["AGPL-3.0-only", "GPL-3.0-only", "LGPL-3.0-only"]);
+}
+
+#[test]
+fn gpl() {
+    let gpl = license::from_id_ext("GPL-3.0-only").unwrap();
+    let instructions = gpl
+        .apply_instructions("frobnicate", "Jane Doe", 2024)
+        .unwrap();
+    assert_eq!(
+        instructions.to_string(),
+        "frobnicate\n\
+         Copyright (C) 2024 Jane Doe\n\
+         \n\
+         This program is free software: you can redistribute it and/or modify it under the terms \
+         of the GNU General Public License as published by the Free Software Foundation, version 3.\n\
+         \n\
+         This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; \
+         without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. \
+         See the GNU General Public License for more details.\n\
+         \n\
+         You should have received a copy of the GNU General Public License along with this \
+         program. If not, see <https://www.gnu.org/licenses/>.\n\
+         \n\
+         frobnicate  Copyright (C) 2024  Jane Doe\n\
+         This program comes with ABSOLUTELY NO WARRANTY; for details type `show w'.\n\
+         This is free software, and you are welcome to redistribute it under certain conditions; \
+         type `show c' for details."
+    );
+}
+
+#[test]
+fn agpl_has_no_interactive_notice() {
+    let agpl = license::from_id_ext("AGPL-3.0-only").unwrap();
+    let instructions = agpl
+        .apply_instructions("frobnicate", "Jane Doe", 2024)
+        .unwrap();
+    assert!(instructions.interactive_notice().is_none());
+    assert!(instructions
+        .header()
+        .to_string()
+        .contains("GNU Affero General Public License"));
+}
+
+#[test]
+fn lgpl_says_lesser() {
+    let lgpl = license::from_id_ext("LGPL-3.0-only").unwrap();
+    let header = lgpl
+        .apply_instructions("libfrob", "Jane Doe", 2024)
+        .unwrap()
+        .header()
+        .to_string();
+    assert!(header.contains("GNU Lesser General Public License"));
+    assert!(!header.contains("GNU General Public License"));
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "alloc")]
+
+use license::attribution::{self, Strictness};
+use license::text::Matcher;
+
+fn mit(holder: &str) -> String {
+    license::from_id("MIT")
+        .unwrap()
+        .text()
+        .replace("<year> <copyright holders>", holder)
+}
+
+/// Three copies of MIT, two with the same holder, and an MIT variant with a changed
+/// notice sentence.
+fn texts() -> Vec<String> {
+    let variant = mit("2022 Carol").replace(
+        "The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.",
+        "The above copyright notice shall be included in all copies of the Software,\nand the Software shall not be used for evil.",
+    );
+    vec![
+        mit("2020 Alice"),
+        mit("2021 Bob"),
+        variant,
+        mit("2019-2020 Alice"),
+    ]
+}
+
+#[test]
+fn canonical_keeps_variants_verbatim() {
+    let texts = texts();
+    assert!(texts[2].contains("evil"));
+    let texts: Vec<&str> = texts.iter().map(String::as_str).collect();
+    let groups = attribution::dedupe(&Matcher::new(), &texts, Strictness::Canonical);
+    assert_eq!(groups.len(), 2);
+
+    assert_eq!(groups[0].license().unwrap().id(), "MIT");
+    assert!(groups[0].is_canonical());
+    assert_eq!(groups[0].holders(), ["Alice", "Bob"]);
+    assert_eq!(groups[0].entries(), [0, 1, 3]);
+    assert_eq!(groups[0].text(), texts[0]);
+
+    assert_eq!(groups[1].license().unwrap().id(), "MIT");
+    assert!(!groups[1].is_canonical());
+    assert_eq!(groups[1].holders(), ["Carol"]);
+    assert_eq!(groups[1].entries(), [2]);
+    assert_eq!(groups[1].text(), texts[2]);
+}
+
+#[test]
+fn similar_merges_variants() {
+    let texts = texts();
+    let texts: Vec<&str> = texts.iter().map(String::as_str).collect();
+    let groups = attribution::dedupe(&Matcher::new(), &texts, Strictness::Similar);
+    assert_eq!(groups.len(), 1);
+    assert!(groups[0].is_canonical());
+    assert_eq!(groups[0].holders(), ["Alice", "Bob", "Carol"]);
+    assert_eq!(groups[0].entries(), [0, 1, 2, 3]);
+    assert_eq!(groups[0].text(), texts[0]);
+}
+
+#[test]
+fn variant_first_gives_way_to_canonical_text() {
+    let texts = texts();
+    let texts = [texts[2].as_str(), texts[0].as_str()];
+    let groups = attribution::dedupe(&Matcher::new(), &texts, Strictness::Similar);
+    assert_eq!(groups.len(), 1);
+    assert!(groups[0].is_canonical());
+    assert_eq!(groups[0].text(), texts[1]);
+}
+
+#[test]
+fn whitespace_is_canonical() {
+    let bsd = license::from_id("BSD-3-Clause").unwrap().text();
+    let indented: String = bsd.lines().map(|line| format!("    {}\n", line)).collect();
+    let groups = attribution::dedupe(&Matcher::new(), &[bsd, &indented], Strictness::Canonical);
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].license().unwrap().id(), "BSD-3-Clause");
+    assert_eq!(groups[0].entries(), [0, 1]);
+}
+
+#[test]
+fn identical_variants_are_grouped() {
+    let texts = texts();
+    let again = texts[2].replace("Carol", "Dave");
+    let texts = [texts[2].as_str(), again.as_str()];
+    let groups = attribution::dedupe(&Matcher::new(), &texts, Strictness::Canonical);
+    assert_eq!(groups.len(), 1);
+    assert!(!groups[0].is_canonical());
+    assert_eq!(groups[0].holders(), ["Carol", "Dave"]);
+}
+
+#[test]
+fn unmatched_texts() {
+    let texts = [
+        "Do what you want.",
+        "Copyright 2020 Eve\n\nDo what you want.",
+        "Be nice.",
+    ];
+    let groups = attribution::dedupe(&Matcher::new(), &texts, Strictness::Similar);
+    assert_eq!(groups.len(), 2);
+    assert!(groups[0].license().is_none());
+    assert_eq!(groups[0].holders(), ["Eve"]);
+    assert_eq!(groups[0].entries(), [0, 1]);
+    assert_eq!(groups[1].entries(), [2]);
+}
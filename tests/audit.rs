@@ -0,0 +1,300 @@
+//! Consistency checks over the extension metadata of every license.
+
+use license::LICENSES_EXT;
+use std::collections::HashSet;
+
+/// Titles used by canonical texts that differ from the SPDX full name.
+const SYNONYMS: &[(&str, &[&str])] = &[
+    ("AFL-3.0", &["Academic Free License"]),
+    ("AGPL-3.0-only", &["GNU Affero General Public License"]),
+    ("APSL-2.0", &["Apple Public Source License"]),
+    ("Apache-2.0", &["Apache License"]),
+    ("Apache-2.0 WITH SHL-2.1", &["Apache License"]),
+    ("BSD-3-Clause-Clear", &["The Clear BSD License"]),
+    ("BSL-1.0", &["Boost Software License"]),
+    (
+        "CC-BY-NC-4.0",
+        &["Creative Commons Attribution-NonCommercial 4.0 International"],
+    ),
+    (
+        "CC-BY-NC-ND-4.0",
+        &["Creative Commons Attribution-NonCommercial-NoDerivatives 4.0 International"],
+    ),
+    (
+        "CC-BY-NC-SA-4.0",
+        &["Creative Commons Attribution-NonCommercial-ShareAlike 4.0 International"],
+    ),
+    (
+        "CC-BY-ND-4.0",
+        &["Creative Commons Attribution-NoDerivatives 4.0 International"],
+    ),
+    (
+        "CC-BY-SA-3.0",
+        &["Creative Commons Attribution-ShareAlike 3.0 Unported"],
+    ),
+    (
+        "CC-BY-SA-4.0",
+        &["Creative Commons Attribution-ShareAlike 4.0 International"],
+    ),
+    ("CC0-1.0", &["CC0 1.0 Universal"]),
+    ("CECILL-2.0", &["CeCILL Free Software License Agreement"]),
+    ("CECILL-2.1", &["CeCILL Free Software License Agreement"]),
+    ("CPL-1.0", &["Common Public License Version 1.0"]),
+    ("ECL-2.0", &["Educational Community License"]),
+    ("EPL-1.0", &["Eclipse Public License - v 1.0"]),
+    ("EPL-2.0", &["Eclipse Public License - v 2.0"]),
+    ("EUPL-1.1", &["European Union Public Licence"]),
+    ("EUPL-1.2", &["European Union Public Licence"]),
+    ("GFDL-1.3-only", &["GNU Free Documentation License"]),
+    ("GFDL-1.3-or-later", &["GNU Free Documentation License"]),
+    ("GPL-2.0-only", &["GNU General Public License"]),
+    (
+        "GPL-2.0-only WITH Classpath-exception-2.0",
+        &["GNU General Public License"],
+    ),
+    ("GPL-3.0-only", &["GNU General Public License"]),
+    ("LGPL-2.1-only", &["GNU Lesser General Public License"]),
+    ("LGPL-3.0-only", &["GNU Lesser General Public License"]),
+    ("LiLiQ-R-1.1", &["Licence Libre du Québec – Réciprocité"]),
+    (
+        "LiLiQ-Rplus-1.1",
+        &["Licence Libre du Québec – Réciprocité forte"],
+    ),
+    ("MPL-2.0", &["Mozilla Public License"]),
+    ("MPL-2.0-no-copyleft-exception", &["Mozilla Public License"]),
+    ("OSL-2.1", &["Open Software License"]),
+    ("OSL-3.0", &["Open Software License"]),
+    ("TAPR-OHL-1.0", &["The TAPR Open Hardware License"]),
+    ("Unlicense", &["unlicense.org"]),
+    ("WTFPL", &["Do What The Fuck You Want To Public License"]),
+];
+
+/// Canonical texts that carry no title at all.
+const UNTITLED: &[&str] = &[
+    "0BSD",
+    "BSD-2-Clause",
+    "BSD-3-Clause",
+    "BSD-4-Clause",
+    "MIT-CMU",
+    "MIT-advertising",
+    "MITNFA",
+];
+
+/// Licenses that require sources to be disclosed without requiring the same license.
+const DISCLOSE_WITHOUT_SAME_LICENSE: &[&str] = &[];
+
+fn check(violations: Vec<String>) {
+    assert!(violations.is_empty(), "\n{}", violations.join("\n"));
+}
+
+#[test]
+fn patent_rights_are_not_contradictory() {
+    let violations = LICENSES_EXT
+        .iter()
+        .filter(|l| l.permissions().patent_rights() && l.limitations().no_patent_rights())
+        .map(|l| format!("{}: sets both patent_rights and no_patent_rights", l.id()))
+        .collect();
+    check(violations);
+}
+
+#[test]
+fn disclose_sources_implies_same_license() {
+    let violations = LICENSES_EXT
+        .iter()
+        .filter(|l| l.conditions().disclose_sources() && !l.conditions().same_license())
+        .filter(|l| !DISCLOSE_WITHOUT_SAME_LICENSE.contains(&l.id()))
+        .map(|l| format!("{}: sets disclose_sources without same_license", l.id()))
+        .collect();
+    check(violations);
+}
+
+#[test]
+fn notice_file_implies_license_and_copyright_notice() {
+    let violations = LICENSES_EXT
+        .iter()
+        .filter(|l| l.conditions().notice_file() && !l.conditions().license_and_copyright_notice())
+        .map(|l| {
+            format!(
+                "{}: sets notice_file without license_and_copyright_notice",
+                l.id()
+            )
+        })
+        .collect();
+    check(violations);
+}
+
+#[test]
+fn no_derivatives_implies_no_same_license() {
+    let violations = LICENSES_EXT
+        .iter()
+        .filter(|l| matches!(l.cc_terms(), Some(terms) if terms.nd()))
+        .filter(|l| l.conditions().same_license() || l.conditions().document_changes())
+        .map(|l| format!("{}: is ND but sets conditions on modifications", l.id()))
+        .collect();
+    check(violations);
+}
+
+#[test]
+fn cc_terms_match_the_permissions() {
+    let mut violations = Vec::new();
+    for l in LICENSES_EXT {
+        let terms = match l.cc_terms() {
+            Some(terms) => terms,
+            None => continue,
+        };
+        if terms.nc() == l.permissions().commercial_use() {
+            violations.push(format!("{}: NC disagrees with commercial_use", l.id()));
+        }
+        if terms.nd() == l.permissions().modification() {
+            violations.push(format!("{}: ND disagrees with modification", l.id()));
+        }
+        if terms.sa() != l.conditions().same_license() {
+            violations.push(format!("{}: SA disagrees with same_license", l.id()));
+        }
+        if terms.by() != l.conditions().license_and_copyright_notice() {
+            violations.push(format!(
+                "{}: BY disagrees with license_and_copyright_notice",
+                l.id()
+            ));
+        }
+    }
+    check(violations);
+}
+
+#[test]
+fn ids_round_trip_through_lookup() {
+    let mut violations = Vec::new();
+    for l in LICENSES_EXT {
+        match license::from_id_ext(l.id()) {
+            Some(ext) if ext.as_any().type_id() == l.as_any().type_id() => {}
+            _ => violations.push(format!(
+                "{}: does not round-trip through from_id_ext",
+                l.id()
+            )),
+        }
+        if l.exception().is_some() {
+            continue;
+        }
+        match license::from_id(l.id()) {
+            Some(base) if base.as_any().type_id() == l.as_any().type_id() => {}
+            _ => violations.push(format!("{}: does not round-trip through from_id", l.id())),
+        }
+    }
+    check(violations);
+}
+
+#[test]
+fn lookups_agree() {
+    let ids = license::LICENSES
+        .iter()
+        .map(|l| l.id())
+        .chain(LICENSES_EXT.iter().map(|l| l.id()));
+    let mut violations = Vec::new();
+    for id in ids {
+        let base = license::from_id(id).map(|l| l.as_any().type_id());
+        let ext = license::from_id_ext(id).map(|l| l.as_any().type_id());
+        let upcast = license::from_id(id)
+            .and_then(|l| l.ext())
+            .map(|l| l.as_any().type_id());
+        if ext.is_some() && base.is_some() && ext != base {
+            violations.push(format!("{}: from_id and from_id_ext disagree", id));
+        }
+        if base.is_some() && upcast != ext {
+            violations.push(format!("{}: ext() and from_id_ext disagree", id));
+        }
+    }
+    check(violations);
+}
+
+#[test]
+fn compatible_outbound_licenses_resolve() {
+    let mut violations = Vec::new();
+    for l in LICENSES_EXT {
+        for id in l.compatible_outbound() {
+            if license::from_id_ext(id).is_none() {
+                violations.push(format!(
+                    "{}: compatible license {} does not resolve",
+                    l.id(),
+                    id
+                ));
+            }
+        }
+    }
+    check(violations);
+}
+
+#[test]
+fn licenses_are_sorted() {
+    let violations = license::LICENSES
+        .windows(2)
+        .filter(|w| w[0].id() >= w[1].id())
+        .map(|w| format!("{}: is not sorted before {}", w[0].id(), w[1].id()))
+        .collect();
+    check(violations);
+}
+
+#[test]
+fn texts_contain_their_name() {
+    let mut violations = Vec::new();
+    for l in LICENSES_EXT {
+        let text = l.text().to_lowercase();
+        if text.trim().is_empty() {
+            violations.push(format!("{}: has an empty text", l.id()));
+            continue;
+        }
+        if UNTITLED.contains(&l.id()) {
+            continue;
+        }
+        let synonyms = SYNONYMS
+            .iter()
+            .find(|(id, _)| *id == l.id())
+            .map_or(&[][..], |(_, synonyms)| synonyms);
+        let found = core::iter::once(&l.name())
+            .chain(synonyms)
+            .any(|name| text.contains(&name.to_lowercase()));
+        if !found {
+            violations.push(format!("{}: text does not contain {:?}", l.id(), l.name()));
+        }
+    }
+    check(violations);
+}
+
+#[test]
+fn ids_are_unique() {
+    let mut seen = HashSet::new();
+    let violations = LICENSES_EXT
+        .iter()
+        .filter(|l| !seen.insert(l.id()))
+        .map(|l| format!("{}: appears more than once", l.id()))
+        .collect();
+    check(violations);
+}
+
+#[test]
+fn ids_are_sorted() {
+    let violations = LICENSES_EXT
+        .windows(2)
+        .filter(|w| w[0].id() >= w[1].id())
+        .map(|w| format!("{}: is not sorted before {}", w[0].id(), w[1].id()))
+        .collect();
+    check(violations);
+}
+
+fn assert_send_sync<T: Send + Sync + ?Sized>() {}
+
+#[test]
+fn licenses_are_thread_safe() {
+    assert_send_sync::<dyn license::License>();
+    assert_send_sync::<dyn license::LicenseExt>();
+    assert_send_sync::<dyn license::LicenseException>();
+    assert_send_sync::<&'static [&'static dyn license::LicenseExt]>();
+
+    let handles: Vec<_> = LICENSES_EXT
+        .chunks(8)
+        .map(|chunk| {
+            std::thread::spawn(move || chunk.iter().filter(|l| !l.id().is_empty()).count())
+        })
+        .collect();
+    let count: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+    assert_eq!(count, LICENSES_EXT.len());
+}
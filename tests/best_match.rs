@@ -0,0 +1,77 @@
+use license::text;
+
+/// An X11-licensed file as found in the wild: the MIT text with its placeholders filled
+/// in, and the clause against using the name of the copyright holder in advertising.
+const X11_FILE: &str = "Copyright (C) 2019 Example Corp
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the \"Software\"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+Except as contained in this notice, the name of Example Corp shall not be used
+in advertising or otherwise to promote the sale, use or other dealings in this
+Software without prior written authorization from Example Corp.
+";
+
+fn best(text: &str) -> &'static str {
+    text::best_match(text).unwrap().license().id()
+}
+
+#[test]
+fn x11_file_matches_x11_and_not_mit() {
+    assert_eq!(best(X11_FILE), "X11");
+
+    let end = X11_FILE.find("Except as contained").unwrap();
+    assert_eq!(best(&X11_FILE[..end]), "MIT");
+}
+
+#[test]
+fn clause_alone_does_not_make_a_variant() {
+    // The ICU license has the clause of X11, but a different text.
+    let icu = license::from_id("ICU").unwrap();
+    assert_eq!(best(icu.text()), "ICU");
+}
+
+#[test]
+fn variants_match_themselves() {
+    for id in ["MIT", "MIT-CMU", "MIT-advertising", "MITNFA", "X11"] {
+        let license = license::from_id(id).unwrap();
+        let m = text::best_match(license.text()).unwrap();
+        assert_eq!(m.license().id(), id);
+        assert_eq!(m.similarity(), 1.0, "{}", id);
+    }
+}
+
+#[test]
+fn unrelated_text_has_no_match() {
+    assert!(text::best_match("").is_none());
+}
+
+#[test]
+fn exhibit_b_tells_the_mpl_forms_apart() {
+    let mpl = license::from_id("MPL-2.0").unwrap().text();
+    assert_eq!(best(mpl), "MPL-2.0");
+
+    let notice = "This Source Code Form is \"Incompatible With Secondary Licenses\", as \
+                  defined by the Mozilla Public License, v. 2.0.";
+    assert!(!text::is_incompatible_with_secondary_licenses(mpl));
+    assert!(text::is_incompatible_with_secondary_licenses(notice));
+    assert_eq!(
+        best(&format!("{}\n\n{}", notice, mpl)),
+        "MPL-2.0-no-copyleft-exception"
+    );
+}
@@ -0,0 +1,63 @@
+use license::{BinaryRequirements, LICENSES_EXT};
+
+fn requirements(id: &str) -> BinaryRequirements {
+    license::from_id_ext(id)
+        .unwrap()
+        .binary_distribution_requirements()
+}
+
+/// `[license_text, notice_file, source, corresponding_source, relinking, needs_legal_review]`
+fn flags(requirements: BinaryRequirements) -> [bool; 6] {
+    [
+        requirements.license_text(),
+        requirements.notice_file(),
+        requirements.source(),
+        requirements.corresponding_source(),
+        requirements.relinking(),
+        requirements.needs_legal_review(),
+    ]
+}
+
+#[test]
+fn pinned() {
+    let cases = [
+        ("MIT", [true, false, false, false, false, false]),
+        ("Apache-2.0", [true, true, false, false, false, false]),
+        ("MPL-2.0", [true, false, true, false, false, false]),
+        ("LGPL-3.0-only", [true, false, true, true, true, true]),
+        ("GPL-3.0-only", [true, false, true, true, false, false]),
+        ("CC0-1.0", [false; 6]),
+    ];
+    for (id, expected) in cases.iter() {
+        assert_eq!(flags(requirements(id)), *expected, "{}", id);
+    }
+}
+
+#[test]
+fn corresponding_source_implies_source() {
+    for license in LICENSES_EXT {
+        let requirements = license.binary_distribution_requirements();
+        if requirements.corresponding_source() {
+            assert!(requirements.source(), "{}", license.id());
+        }
+        if requirements.relinking() {
+            assert!(requirements.needs_legal_review(), "{}", license.id());
+        }
+    }
+}
+
+#[test]
+fn source_follows_the_conditions() {
+    for license in LICENSES_EXT {
+        let requirements = license.binary_distribution_requirements();
+        if license.conditions().disclose_sources() {
+            assert!(requirements.source(), "{}", license.id());
+        }
+        assert_eq!(
+            requirements.license_text(),
+            license.conditions().license_and_copyright_notice(),
+            "{}",
+            license.id()
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use license::{BuslParameters, Date};
+
+/// The parameters of MariaDB MaxScale 2.5.
+fn maxscale() -> BuslParameters<'static> {
+    BuslParameters {
+        change_date: Date::new(2024, 6, 2).unwrap(),
+        change_license_id: "GPL-2.0-or-later",
+        additional_use_grant: Some(
+            "You may use the Licensed Work when your application uses the Licensed Work with a \
+         total of less than three server instances for any purpose.",
+        ),
+    }
+}
+
+#[test]
+fn mariadb_parameters() {
+    let busl = license::busl_with_parameters(maxscale());
+    assert_eq!(busl.license().id(), "BUSL-1.1");
+    assert_eq!(busl.parameters(), maxscale());
+    assert_eq!(busl.change_date().to_string(), "2024-06-02");
+
+    assert!(!busl.permissions().commercial_use());
+    assert!(busl.permissions().distribution() && busl.permissions().modification());
+    assert!(busl.conditions().license_and_copyright_notice());
+    assert!(!busl.conditions().disclose_sources() && !busl.conditions().same_license());
+
+    assert_eq!(busl.change_license().unwrap().id(), "GPL-2.0-or-later");
+    let before = Date::parse("2024-06-01").unwrap();
+    let after = Date::parse("2024-06-02").unwrap();
+    assert_eq!(busl.license_on(before).unwrap().id(), "BUSL-1.1");
+    assert_eq!(busl.license_on(after).unwrap().id(), "GPL-2.0-or-later");
+}
+
+#[test]
+fn unknown_change_licenses() {
+    let busl = license::busl_with_parameters(BuslParameters {
+        change_license_id: "GPL v2 or later",
+        ..maxscale()
+    });
+    assert!(busl.change_license().is_none());
+    assert!(busl.license_on(Date::new(2030, 1, 1).unwrap()).is_none());
+    assert!(busl.license_on(Date::new(2020, 1, 1).unwrap()).is_some());
+}
+
+#[test]
+fn dates() {
+    assert!(Date::new(2024, 2, 29).is_some());
+    assert!(Date::new(2023, 2, 29).is_none());
+    assert!(Date::new(1900, 2, 29).is_none());
+    assert!(Date::new(2000, 2, 29).is_some());
+    assert!(Date::new(2024, 13, 1).is_none());
+    assert!(Date::new(2024, 4, 31).is_none());
+    assert!(Date::new(2024, 1, 0).is_none());
+    assert!(Date::new(2024, 1, 31) < Date::new(2024, 2, 1));
+    assert!(Date::new(2023, 12, 31) < Date::new(2024, 1, 1));
+
+    for invalid in ["2024-1-01", "2024-01-01-01", "2024-01", "+024-01-01", ""] {
+        assert_eq!(Date::parse(invalid), None, "{}", invalid);
+    }
+    let date = Date::parse("0999-03-04").unwrap();
+    assert_eq!((date.year(), date.month(), date.day()), (999, 3, 4));
+    assert_eq!(date.to_string(), "0999-03-04");
+}
+
+#[test]
+fn source_available_licenses_are_not_open_source() {
+    for id in ["BUSL-1.1", "Elastic-2.0"] {
+        let license = license::from_id_ext(id).unwrap();
+        assert!(license.is_source_available(), "{}", id);
+        assert!(
+            !license.is_osi_approved() && !license.is_fsf_libre(),
+            "{}",
+            id
+        );
+        assert!(license::permissive().all(|l| l.id() != id), "{}", id);
+    }
+    let elastic = license::from_id_ext("Elastic-2.0").unwrap();
+    assert!(elastic.text().contains("as a hosted or managed service"));
+    assert!(license::osi_approved().all(|l| !l.is_source_available()));
+}
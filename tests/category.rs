@@ -0,0 +1,42 @@
+use license::{LicenseExt, LICENSES_EXT};
+use std::collections::HashSet;
+
+fn ids(licenses: impl Iterator<Item = &'static dyn LicenseExt>) -> Vec<&'static str> {
+    licenses.map(|l| l.id()).collect()
+}
+
+#[test]
+fn osi_approved_and_the_rest_cover_every_license() {
+    let approved: HashSet<_> = ids(license::osi_approved()).into_iter().collect();
+    let rest = LICENSES_EXT
+        .iter()
+        .filter(|l| !l.is_osi_approved())
+        .map(|l| l.id());
+    let mut all: Vec<_> = approved.iter().copied().chain(rest).collect();
+    all.sort_unstable();
+    let mut expected = ids(LICENSES_EXT.iter().copied());
+    expected.sort_unstable();
+    assert_eq!(all, expected);
+}
+
+#[test]
+fn categories_are_sorted() {
+    for category in [
+        ids(license::osi_approved()),
+        ids(license::fsf_libre()),
+        ids(license::copyleft()),
+        ids(license::permissive()),
+    ] {
+        assert!(category.windows(2).all(|w| w[0] < w[1]), "{:?}", category);
+    }
+}
+
+#[test]
+fn copyleft_and_permissive_are_disjoint() {
+    let copyleft: HashSet<_> = ids(license::copyleft()).into_iter().collect();
+    for id in ids(license::permissive()) {
+        assert!(!copyleft.contains(id), "{}: is copyleft and permissive", id);
+    }
+    assert!(!copyleft.contains("CC-BY-NC-4.0"));
+    assert!(!ids(license::permissive()).contains(&"CC-BY-NC-4.0"));
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "cli")]
+
+use license::License;
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_license-info"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = run(args);
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn summary() {
+    let out = stdout(&["MIT"]);
+    assert!(out.starts_with("MIT License (MIT)\nOSI approved: yes\nFSF libre: yes\n"));
+    assert!(out.contains("Copyleft: none\n"));
+    assert!(out.contains("- May be distributed.\n"));
+}
+
+#[test]
+fn summary_accepts_a_trailing_plus() {
+    assert!(stdout(&["GPL-2.0+"]).contains("(GPL-2.0-or-later)\n"));
+}
+
+#[test]
+fn text() {
+    assert_eq!(
+        stdout(&["--text", "Apache-2.0"]),
+        license::licenses::Apache_2_0.text()
+    );
+}
+
+#[test]
+fn matches_a_file() {
+    let out = stdout(&["--match", "tests/fixtures/MIT-curly-quotes.txt"]);
+    assert!(out.starts_with("MIT (MIT License), similarity 1.00\n"));
+}
+
+#[test]
+fn expression() {
+    let out = stdout(&["--expr", "MIT OR GPL-3.0-only"]);
+    assert_eq!(
+        out,
+        "MIT OR GPL-3.0-only\n- MIT: copyleft none\n- GPL-3.0-only: copyleft strong\n"
+    );
+}
+
+#[test]
+fn expression_report() {
+    let out = stdout(&["--expr", "MIT AND Apache-2.0"]);
+    assert!(out.contains("# License report\n"));
+    assert!(out.contains("- Strongest copyleft: none\n"));
+}
+
+#[test]
+fn errors() {
+    for args in [
+        &["NOT-A-LICENSE"][..],
+        &["--expr", "MIT AND"],
+        &["--bogus"],
+        &[],
+    ] {
+        let output = run(args);
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(output.stdout.is_empty());
+        assert!(String::from_utf8(output.stderr)
+            .unwrap()
+            .starts_with("error: "));
+    }
+}
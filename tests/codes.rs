@@ -0,0 +1,49 @@
+use license::LICENSES_EXT;
+use std::collections::HashSet;
+
+/// Every code ever assigned, which must only ever grow.
+const GOLDEN: &str = include_str!("codes.txt");
+
+fn golden() -> impl Iterator<Item = (u16, &'static str)> {
+    GOLDEN
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| {
+            let (code, id) = line.split_once(' ').expect("a line is `<code> <id>`");
+            (code.parse().expect("a code is a u16"), id)
+        })
+}
+
+#[test]
+fn codes_are_stable() {
+    for (code, id) in golden() {
+        let license = license::from_id_ext(id).unwrap_or_else(|| panic!("{}: was removed", id));
+        assert_eq!(license.code(), code, "{}: changed its code", id);
+        assert_eq!(license::from_code(code).map(|l| l.id()), Some(id));
+    }
+}
+
+#[test]
+fn codes_are_never_reused() {
+    let mut codes = HashSet::new();
+    let mut ids = HashSet::new();
+    for (code, id) in golden() {
+        assert_ne!(code, 0, "{}: 0 is never assigned", id);
+        assert!(codes.insert(code), "{}: reuses code {}", id, code);
+        assert!(ids.insert(id), "{}: is listed twice", id);
+    }
+}
+
+#[test]
+fn every_license_has_a_code() {
+    let golden: HashSet<_> = golden().map(|(_, id)| id).collect();
+    for l in LICENSES_EXT {
+        assert!(
+            golden.contains(l.id()),
+            "{}: append `{} {}` to tests/codes.txt",
+            l.id(),
+            l.code(),
+            l.id()
+        );
+    }
+}
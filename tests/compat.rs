@@ -0,0 +1,179 @@
+use license::compat::{self, Compatibility, Reason};
+
+fn check(work: &str, component: &str) -> Compatibility {
+    compat::check(
+        license::from_id(work).unwrap(),
+        license::from_id(component).unwrap(),
+    )
+}
+
+fn reason(work: &str, component: &str) -> Option<Reason> {
+    match check(work, component) {
+        Compatibility::Incompatible(i) => Some(i.reason()),
+        _ => None,
+    }
+}
+
+#[test]
+fn classic_pairs() {
+    let cases = [
+        (
+            "GPL-2.0-only",
+            "Apache-2.0",
+            Some(Reason::PatentClauseConflict),
+        ),
+        (
+            "LGPL-2.1-only",
+            "Apache-2.0",
+            Some(Reason::PatentClauseConflict),
+        ),
+        (
+            "GPL-2.0-only",
+            "BSD-4-Clause",
+            Some(Reason::AdvertisingClause),
+        ),
+        ("GPL-3.0-only", "OpenSSL", Some(Reason::AdvertisingClause)),
+        (
+            "GPL-3.0-only",
+            "AGPL-3.0-only",
+            Some(Reason::NetworkClauseOneWay),
+        ),
+        (
+            "GPL-2.0-only",
+            "GPL-3.0-only",
+            Some(Reason::CopyleftConflict {
+                required: "GPL-3.0-only",
+                actual: "GPL-2.0-only",
+            }),
+        ),
+        (
+            "GPL-3.0-only",
+            "GPL-2.0-only",
+            Some(Reason::CopyleftConflict {
+                required: "GPL-2.0-only",
+                actual: "GPL-3.0-only",
+            }),
+        ),
+        (
+            "GPL-2.0-only",
+            "CDDL-1.0",
+            Some(Reason::CopyleftConflict {
+                required: "CDDL-1.0",
+                actual: "GPL-2.0-only",
+            }),
+        ),
+        (
+            "GPL-3.0-only",
+            "EPL-1.0",
+            Some(Reason::CopyleftConflict {
+                required: "EPL-1.0",
+                actual: "GPL-3.0-only",
+            }),
+        ),
+        (
+            "MIT",
+            "GPL-3.0-only",
+            Some(Reason::CopyleftConflict {
+                required: "GPL-3.0-only",
+                actual: "MIT",
+            }),
+        ),
+        ("GPL-3.0-only", "Apache-2.0", None),
+        ("GPL-2.0-only", "MIT", None),
+        ("GPL-2.0-only", "MPL-2.0", None),
+        ("GPL-3.0-only", "LGPL-3.0-only", None),
+        ("AGPL-3.0-only", "GPL-3.0-only", None),
+        ("GPL-3.0-only", "EUPL-1.2", None),
+        ("Apache-2.0", "MIT", None),
+    ];
+    for &(work, component, expected) in &cases {
+        assert_eq!(
+            reason(work, component),
+            expected,
+            "{} <- {}",
+            work,
+            component
+        );
+    }
+}
+
+#[test]
+fn explanations() {
+    let cases = [
+        (
+            "GPL-2.0-only",
+            "Apache-2.0",
+            "GPL-2.0-only cannot incorporate Apache-2.0 code because of the patent \
+             termination clause; GPL-3.0-only resolves this",
+        ),
+        (
+            "GPL-2.0-only",
+            "BSD-4-Clause",
+            "GPL-2.0-only cannot incorporate BSD-4-Clause code because of the advertising clause",
+        ),
+        (
+            "GPL-3.0-only",
+            "AGPL-3.0-only",
+            "GPL-3.0-only cannot incorporate AGPL-3.0-only code because its network use clause \
+             would bind the combined work; AGPL-3.0-only resolves this",
+        ),
+        (
+            "MIT",
+            "GPL-3.0-only",
+            "MIT cannot incorporate GPL-3.0-only code because the combined work must be \
+             distributed under GPL-3.0-only, not MIT; GPL-3.0-only resolves this",
+        ),
+    ];
+    for &(work, component, sentence) in &cases {
+        match check(work, component) {
+            Compatibility::Incompatible(i) => {
+                assert_eq!(i.work(), work);
+                assert_eq!(i.component(), component);
+                assert_eq!(i.to_string(), sentence);
+            }
+            other => panic!("{} <- {}: {:?}", work, component, other),
+        }
+    }
+}
+
+#[test]
+fn unknown_without_data() {
+    assert_eq!(check("MIT", "AAL"), Compatibility::Unknown);
+    assert_eq!(check("AAL", "MIT"), Compatibility::Compatible);
+    assert_eq!(check("AAL", "AAL"), Compatibility::Compatible);
+}
+
+#[test]
+fn secondary_licenses() {
+    for work in [
+        "GPL-2.0-only",
+        "GPL-3.0-only",
+        "LGPL-2.1-only",
+        "AGPL-3.0-only",
+    ] {
+        assert_eq!(
+            check(work, "MPL-2.0"),
+            Compatibility::Compatible,
+            "{}",
+            work
+        );
+        assert_eq!(
+            reason(work, "MPL-2.0-no-copyleft-exception"),
+            Some(Reason::SecondaryLicensesExcluded),
+            "{}",
+            work
+        );
+    }
+    assert_eq!(
+        check("Apache-2.0", "MPL-2.0-no-copyleft-exception"),
+        Compatibility::Compatible
+    );
+    match check("GPL-3.0-only", "MPL-2.0-no-copyleft-exception") {
+        Compatibility::Incompatible(i) => assert_eq!(
+            i.to_string(),
+            "GPL-3.0-only cannot incorporate MPL-2.0-no-copyleft-exception code because it \
+             is Incompatible With Secondary Licenses; MPL-2.0 resolves this"
+        ),
+        other => panic!("{:?}", other),
+    }
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "alloc")]
+
+fn ids(prefix: &str, limit: usize) -> Vec<&'static str> {
+    license::complete(prefix, limit)
+        .iter()
+        .map(|license| license.id())
+        .collect()
+}
+
+#[test]
+fn bsd_ranks_the_id_prefixes_first() {
+    assert_eq!(
+        ids("bsd", 10),
+        [
+            "BSD-2-Clause",
+            "BSD-3-Clause",
+            "BSD-3-Clause-Clear",
+            "BSD-4-Clause",
+            "0BSD"
+        ]
+    );
+}
+
+#[test]
+fn gpl_ranks_the_gnu_variants_after_gpl() {
+    assert_eq!(
+        ids("gpl", 10),
+        [
+            "GPL-2.0-only",
+            "GPL-2.0-only WITH Classpath-exception-2.0",
+            "GPL-3.0-only",
+            "AGPL-3.0-only",
+            "LGPL-2.1-only",
+            "LGPL-3.0-only",
+        ]
+    );
+}
+
+#[test]
+fn cc_lists_the_creative_commons_licenses() {
+    assert_eq!(
+        ids("cc", 10),
+        [
+            "CC-BY-4.0",
+            "CC-BY-NC-4.0",
+            "CC-BY-NC-ND-4.0",
+            "CC-BY-NC-SA-4.0",
+            "CC-BY-ND-4.0",
+            "CC-BY-SA-3.0",
+            "CC-BY-SA-4.0",
+            "CC0-1.0",
+        ]
+    );
+}
+
+#[test]
+fn case_is_ignored() {
+    assert_eq!(ids("GpL", 10), ids("gpl", 10));
+    assert_eq!(ids("mit", 2), ["MIT", "MIT-CMU"]);
+}
+
+#[test]
+fn names_and_words_are_matched_after_ids() {
+    assert_eq!(
+        ids("mozilla", 10),
+        ["MPL-2.0", "MPL-2.0-no-copyleft-exception"]
+    );
+    assert_eq!(
+        ids("clause", 10),
+        [
+            "0BSD",
+            "BSD-2-Clause",
+            "BSD-3-Clause",
+            "BSD-3-Clause-Clear",
+            "BSD-4-Clause"
+        ]
+    );
+    assert!(ids("bec", 10).is_empty());
+}
+
+#[test]
+fn limit_cuts_the_ranking() {
+    assert_eq!(ids("gpl", 3), &ids("gpl", 10)[..3]);
+    assert!(ids("gpl", 0).is_empty());
+    assert_eq!(ids("", usize::MAX).len(), license::LICENSES_EXT.len());
+}
@@ -0,0 +1,115 @@
+use license::{CompositeLicense, LicenseExt, Metadata};
+
+fn licenses(ids: &[&str]) -> Vec<&'static dyn LicenseExt> {
+    ids.iter()
+        .map(|id| license::from_id_ext(id).unwrap())
+        .collect()
+}
+
+fn summary(composite: CompositeLicense) -> (String, Metadata, String) {
+    (
+        composite.id().to_string(),
+        composite.metadata(),
+        composite.text().to_string(),
+    )
+}
+
+#[test]
+fn order_does_not_matter() {
+    let orders = [
+        ["MIT", "Apache-2.0", "MPL-2.0"],
+        ["MIT", "MPL-2.0", "Apache-2.0"],
+        ["Apache-2.0", "MIT", "MPL-2.0"],
+        ["Apache-2.0", "MPL-2.0", "MIT"],
+        ["MPL-2.0", "MIT", "Apache-2.0"],
+        ["MPL-2.0", "Apache-2.0", "MIT"],
+    ];
+    let expected = summary(CompositeLicense::all_of(&licenses(&orders[0])));
+    assert_eq!(expected.0, "Apache-2.0 AND MIT AND MPL-2.0");
+    for order in &orders[1..] {
+        assert_eq!(
+            summary(CompositeLicense::all_of(&licenses(order))),
+            expected
+        );
+    }
+}
+
+#[test]
+fn duplicates_are_removed() {
+    let once = summary(CompositeLicense::all_of(&licenses(&["Zlib", "MIT"])));
+    let twice = summary(CompositeLicense::all_of(&licenses(&[
+        "MIT", "Zlib", "MIT", "Zlib",
+    ])));
+    assert_eq!(once, twice);
+    assert_eq!(
+        CompositeLicense::all_of(&licenses(&["MIT", "MIT"]))
+            .licenses()
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn flags_are_conservative() {
+    let all = licenses(&["Apache-2.0", "GPL-3.0-only", "MIT"]);
+    let composite = CompositeLicense::all_of(&all);
+    for l in &all {
+        assert!(composite.permissions().is_subset(l.permissions()));
+        assert!(l.conditions().is_subset(composite.conditions()));
+        assert!(l.limitations().is_subset(composite.limitations()));
+    }
+    assert!(composite.conditions().same_license());
+    assert!(composite.conditions().notice_file());
+}
+
+#[test]
+fn single_license() {
+    let mit = licenses(&["MIT"]);
+    let composite = CompositeLicense::all_of(&mit);
+    assert_eq!(composite.id().to_string(), "MIT");
+    assert_eq!(composite.metadata(), mit[0].metadata());
+    assert_eq!(composite.text().to_string(), mit[0].text().trim());
+}
+
+#[test]
+fn no_licenses() {
+    let composite = CompositeLicense::all_of(&[]);
+    assert_eq!(composite.id().to_string(), "");
+    assert_eq!(composite.metadata(), Metadata::default());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn data_is_like_the_data_of_a_license() {
+    use license::LicenseData;
+
+    let all = licenses(&["MIT", "Apache-2.0"]);
+    let composite = CompositeLicense::all_of(&all);
+    let data = LicenseData::from(composite);
+    assert_eq!(data.id, composite.id().to_string());
+    assert_eq!(data.text, composite.text().to_string());
+    assert_eq!(data.metadata, composite.metadata());
+    assert_eq!(
+        data.see_also.len(),
+        all.iter().map(|l| l.see_also().len()).sum()
+    );
+    assert!(data.osi_approved && !data.deprecated && data.header.is_none());
+
+    let mit = licenses(&["MIT"]);
+    let single = CompositeLicense::all_of(&mit).data();
+    let of = LicenseData::of(mit[0]);
+    assert_eq!(single.markdown().to_string(), of.markdown().to_string());
+
+    let mut policy = license::policy::Policy::new();
+    policy.allow("MIT").unwrap().allow("Apache-2.0").unwrap();
+    let leaf = |id| license::expression::Leaf::License {
+        id,
+        or_later: false,
+    };
+    assert_eq!(
+        policy.evaluate_str(&data.id).unwrap(),
+        license::policy::Decision::Allow {
+            chosen: vec![leaf("Apache-2.0"), leaf("MIT")]
+        }
+    );
+}
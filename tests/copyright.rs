@@ -0,0 +1,162 @@
+#![cfg(feature = "alloc")]
+
+use license::text::{self, CopyrightStatement};
+use std::ops::RangeInclusive;
+
+fn statements(text: &str) -> Vec<(Option<RangeInclusive<u16>>, String)> {
+    text::extract_copyrights(text)
+        .into_iter()
+        .map(|CopyrightStatement { years, holder, .. }| (years, holder))
+        .collect()
+}
+
+fn one(text: &str) -> (Option<RangeInclusive<u16>>, String) {
+    let mut statements = statements(text);
+    assert_eq!(statements.len(), 1, "{:?}", statements);
+    statements.remove(0)
+}
+
+#[test]
+fn real_world_styles() {
+    let cases: &[(&str, Option<RangeInclusive<u16>>, &str)] = &[
+        (
+            "Copyright (c) 2016 Alex Crichton",
+            Some(2016..=2016),
+            "Alex Crichton",
+        ),
+        (
+            "Copyright 2015-2023 The Rust Project Developers.",
+            Some(2015..=2023),
+            "The Rust Project Developers",
+        ),
+        (
+            "Copyright (C) 2007 Free Software Foundation, Inc. <https://fsf.org/>",
+            Some(2007..=2007),
+            "Free Software Foundation, Inc. <https://fsf.org/>",
+        ),
+        ("© 2021 Example GmbH", Some(2021..=2021), "Example GmbH"),
+        (
+            "(c) 2020 Jane Doe <jane@example.com>",
+            Some(2020..=2020),
+            "Jane Doe <jane@example.com>",
+        ),
+        (
+            "Copyright © 2012, 2014, 2019 Acme Corp.",
+            Some(2012..=2019),
+            "Acme Corp.",
+        ),
+        (
+            "COPYRIGHT (C) 1991-92 RSA Data Security, Inc.",
+            Some(1991..=1992),
+            "RSA Data Security, Inc.",
+        ),
+        (
+            "Copyright (c) 1998 by Henry Spencer. All rights reserved.",
+            Some(1998..=1998),
+            "Henry Spencer",
+        ),
+        (
+            "Copyright 2010–2012 Example Ltd. All Rights Reserved",
+            Some(2010..=2012),
+            "Example Ltd.",
+        ),
+        ("Copyright The Go Authors", None, "The Go Authors"),
+        ("Copyright: 2004 Foo Inc.", Some(2004..=2004), "Foo Inc."),
+        (
+            "Copyright (c) Caldera International Inc. 2001-2002",
+            Some(2001..=2002),
+            "Caldera International Inc.",
+        ),
+        (
+            "// Copyright 2018 Developers of the Rand project.",
+            Some(2018..=2018),
+            "Developers of the Rand project",
+        ),
+        (
+            " * Copyright (c) 2009, 2010 Example, Inc.",
+            Some(2009..=2010),
+            "Example, Inc.",
+        ),
+        (
+            "# Copyright (c) <year> <copyright holders>",
+            None,
+            "<year> <copyright holders>",
+        ),
+    ];
+    for (line, years, holder) in cases {
+        assert_eq!(
+            one(line),
+            (years.clone(), String::from(*holder)),
+            "{}",
+            line
+        );
+    }
+}
+
+#[test]
+fn several_holders() {
+    assert_eq!(
+        statements("Copyright (c) 2014 Alice Example, 2016-2017 Bob Example"),
+        [
+            (Some(2014..=2014), "Alice Example".into()),
+            (Some(2016..=2017), "Bob Example".into())
+        ]
+    );
+    assert_eq!(
+        statements("Copyright (c) 1989 The Fellowship Copyright (c) 1991 by Lucent Technologies"),
+        [
+            (Some(1989..=1989), "The Fellowship".into()),
+            (Some(1991..=1991), "Lucent Technologies".into())
+        ]
+    );
+    let notice = "Copyright (c) 2016 Alex Crichton\nCopyright (c) 2017 The Tokio Authors\n";
+    let found = text::extract_copyrights(notice);
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[1].raw, "Copyright (c) 2017 The Tokio Authors");
+}
+
+#[test]
+fn continuation_lines() {
+    let notice = "Copyright (c) 2005, 2006 Alice Example,\n\
+                  \x20                        Bob Example and\n\
+                  \x20                        Carol Example\n\
+                  All rights reserved.\n\
+                  \n\
+                  Redistribution and use in source and binary forms...";
+    let found = text::extract_copyrights(notice);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].years, Some(2005..=2006));
+    assert_eq!(
+        found[0].holder,
+        "Alice Example, Bob Example and Carol Example"
+    );
+    assert_eq!(
+        found[0].raw,
+        "Copyright (c) 2005, 2006 Alice Example,\nBob Example and\nCarol Example\nAll rights reserved."
+    );
+
+    let gpl = "Copyright (C) 1989, 1991 Free Software Foundation, Inc.,\n\
+               51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA";
+    assert_eq!(one(gpl).1, "Free Software Foundation, Inc.");
+}
+
+#[test]
+fn words_of_licenses_are_not_statements() {
+    let text = "The above copyright notice and this permission notice shall be included\n\
+                copyright notice, this list of conditions and the following disclaimer.\n\
+                COPYRIGHT HOLDERS AND CONTRIBUTORS \"AS IS\"\n\
+                (c) You must cause any modified files to carry prominent notices\n\
+                Copyright law protects the work.";
+    assert!(statements(text).is_empty());
+    assert!(statements("Copyright (c)\n").is_empty());
+}
+
+#[test]
+fn the_matcher_and_the_extraction_read_the_same_file() {
+    let mit = license::from_id("MIT").unwrap();
+    let file = mit
+        .text()
+        .replace("<year> <copyright holders>", "2024 Example Corp");
+    assert_eq!(text::best_match(&file).unwrap().license().id(), "MIT");
+    assert_eq!(one(&file), (Some(2024..=2024), "Example Corp".into()));
+}
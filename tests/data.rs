@@ -0,0 +1,89 @@
+#![cfg(feature = "alloc")]
+
+use license::{LicenseData, Metadata};
+use std::borrow::Cow;
+
+#[test]
+fn static_and_custom_licenses_render_alike() {
+    let mit = LicenseData::of(license::from_id_ext("MIT").unwrap());
+    let mut custom = LicenseData::new(
+        format!("LicenseRef-{}", "Example"),
+        String::from("Example License"),
+        mit.text.to_string(),
+    );
+    custom.osi_approved = mit.osi_approved;
+    custom.fsf_libre = mit.fsf_libre;
+    custom.see_also = mit
+        .see_also
+        .iter()
+        .map(|url| Cow::Owned(url.to_string()))
+        .collect();
+    custom.metadata = mit.metadata;
+    assert!(matches!(custom.id, Cow::Owned(_)));
+
+    let expected = mit
+        .markdown()
+        .to_string()
+        .replace("MIT License", "Example License")
+        .replace("`MIT`", "`LicenseRef-Example`");
+    assert_eq!(custom.markdown().to_string(), expected);
+    assert_eq!(
+        expected,
+        "# Example License\n\
+         \n\
+         - Id: `LicenseRef-Example`\n\
+         - OSI approved: yes\n\
+         - FSF libre: yes\n\
+         - See also: <https://opensource.org/licenses/MIT>\n\
+         \n\
+         ## Permissions\n\
+         \n\
+         - May be used for commercial purposes.\n\
+         - May be distributed.\n\
+         - May be modified.\n\
+         - May be used for private purposes.\n\
+         \n\
+         ## Conditions\n\
+         \n\
+         - The license and copyright notice must be included with the software.\n\
+         \n\
+         ## Limitations\n\
+         \n\
+         - Includes a limitation of liability.\n\
+         - Does not provide any warranty.\n"
+    );
+}
+
+#[test]
+fn data_borrows_the_static_strings() {
+    let gpl = license::from_id_ext("GPL-3.0-only").unwrap();
+    let data = LicenseData::from(gpl);
+    assert!(matches!(data.text, Cow::Borrowed(text) if std::ptr::eq(text, gpl.text())));
+    assert_eq!(data.header.as_deref(), gpl.header());
+    assert_eq!(data.see_also, gpl.see_also());
+    let owned = data.clone().into_owned();
+    assert!(matches!(owned.text, Cow::Owned(_)));
+    assert_eq!(owned, data);
+}
+
+#[test]
+fn empty_sections_and_sentinels_are_noted() {
+    let custom = LicenseData::new("LicenseRef-Nothing", "Nothing", "");
+    assert_eq!(custom.metadata, Metadata::default());
+    assert_eq!(
+        custom.markdown().to_string(),
+        "# Nothing\n\
+         \n\
+         - Id: `LicenseRef-Nothing`\n\
+         - OSI approved: no\n\
+         - FSF libre: no\n\
+         - No permissions.\n\
+         - No conditions.\n\
+         - No limitations.\n"
+    );
+    let unknown = LicenseData::of(license::UNKNOWN);
+    assert!(unknown
+        .markdown()
+        .to_string()
+        .ends_with("- License could not be determined.\n"));
+}
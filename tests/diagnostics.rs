@@ -0,0 +1,92 @@
+#![cfg(feature = "alloc")]
+
+use license::Diagnostic;
+
+fn normalize(field: &str) -> Vec<Diagnostic<'_>> {
+    let mut diagnostics = Vec::new();
+    license::normalize_legacy_with_diagnostics(field, &mut diagnostics).unwrap();
+    diagnostics
+}
+
+#[test]
+fn messy_field() {
+    let diagnostics = normalize("Apache 2.0/GPL-2.0+");
+    assert_eq!(diagnostics.len(), 2, "{:?}", diagnostics);
+    assert!(matches!(
+        diagnostics[0],
+        Diagnostic::LegacySeparator { position: 10, .. }
+    ));
+    assert!(matches!(
+        diagnostics[1],
+        Diagnostic::DeprecatedId {
+            given: "GPL-2.0+",
+            canonical: "GPL-2.0-or-later",
+            ..
+        }
+    ));
+}
+
+#[test]
+fn every_rewrite_is_reported() {
+    let field = "mit and GPLv2 / GPL-3.0 OR MIT/X11";
+    let messages: Vec<_> = normalize(field).iter().map(|d| d.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "license alias `mit`, use `MIT`",
+            "operator `and`, use `AND`",
+            "license alias `GPLv2`, use `GPL-2.0-only`",
+            "`/` at position 14, use `OR`",
+            "deprecated license id `GPL-3.0`, use `GPL-3.0-only`",
+            "license alias `MIT/X11`, use `MIT`",
+        ]
+    );
+    assert_eq!(&field[14..15], "/");
+}
+
+#[test]
+fn strict_input_reports_nothing() {
+    assert!(normalize("MIT OR Apache-2.0").is_empty());
+    assert!(normalize("GPL-2.0-or-later WITH Classpath-exception-2.0").is_empty());
+    assert!(normalize("Apache 2.0").is_empty());
+
+    let mut diagnostics = Vec::new();
+    license::from_id_flexible_with_diagnostics("MIT", &mut diagnostics).unwrap();
+    license::from_id_flexible_with_diagnostics("Apache-2.0+", &mut diagnostics).unwrap();
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn invalid_fields_report_nothing() {
+    let mut diagnostics = Vec::new();
+    assert!(license::normalize_legacy_with_diagnostics("GPLv2/", &mut diagnostics).is_err());
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn flexible_ids() {
+    let mut diagnostics = Vec::new();
+    for id in ["GPL-2.0", "GPL-2.0+", "LGPL-2.1-only+", "Nunit"] {
+        license::from_id_flexible_with_diagnostics(id, &mut diagnostics).unwrap();
+    }
+    let messages: Vec<_> = diagnostics.iter().map(|d| d.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "deprecated license id `GPL-2.0`, use `GPL-2.0-only`",
+            "deprecated license id `GPL-2.0+`, use `GPL-2.0-or-later`",
+            "deprecated license id `LGPL-2.1-only+`, use `LGPL-2.1-or-later`",
+            "deprecated license id `Nunit`",
+        ]
+    );
+    assert!(license::from_id_flexible_with_diagnostics("nope", &mut diagnostics).is_none());
+    assert_eq!(diagnostics.len(), 4);
+}
+
+#[test]
+fn closures_are_hooks() {
+    let mut count = 0;
+    let mut hook = |_: Diagnostic| count += 1;
+    license::normalize_legacy_with_diagnostics("GPLv3+/mit", &mut hook).unwrap();
+    assert_eq!(count, 3);
+}
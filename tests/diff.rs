@@ -0,0 +1,91 @@
+use license::text::{self, Change};
+
+fn mit() -> &'static str {
+    license::from_id("MIT").unwrap().text()
+}
+
+/// Wraps every line of a text at 80 columns, like a typical LICENSE file.
+fn wrap(text: &str) -> String {
+    let mut wrapped = String::new();
+    for line in text.lines() {
+        let mut width = 0;
+        for word in line.split_whitespace() {
+            if width > 0 && width + word.len() >= 80 {
+                wrapped.push('\n');
+                width = 0;
+            } else if width > 0 {
+                wrapped.push(' ');
+                width += 1;
+            }
+            wrapped.push_str(word);
+            width += word.len();
+        }
+        wrapped.push('\n');
+    }
+    wrapped
+}
+
+#[test]
+fn different_copyright_holder_is_trivial() {
+    let candidate = wrap(&mit().replace(
+        "Copyright (c) <year> <copyright holders>",
+        "Copyright (c) 2019 Jane Doe\nCopyright (c) 2021-2024 The Example Project Developers",
+    ));
+    let diff = text::diff(mit(), &candidate);
+    assert!(diff.is_trivial());
+    assert!(!diff.is_identical());
+    let changed: Vec<_> = diff
+        .changes()
+        .filter(|change| !matches!(change, Change::Equal(_)))
+        .collect();
+    assert_eq!(
+        changed,
+        [
+            Change::Removed("Copyright (c) <year> <copyright holders>"),
+            Change::Added(
+                "Copyright (c) 2019 Jane Doe\nCopyright (c) 2021-2024 The Example Project Developers"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn extra_clause_is_not_trivial() {
+    let clause = "The Software shall not be used for military purposes.";
+    let candidate = mit()
+        .replace("<year> <copyright holders>", "2024 Example Corp")
+        .replace(
+            "portions of the Software.\n",
+            &format!("portions of the Software.\n\n{}\n", clause),
+        );
+    let diff = text::diff(mit(), &candidate);
+    assert!(!diff.is_trivial());
+
+    let rendered = diff.to_string();
+    let hunks: Vec<_> = rendered.lines().filter(|l| l.starts_with("@@")).collect();
+    assert_eq!(hunks, ["@@ -1,5 +1,6 @@"]);
+    assert!(rendered.contains(&format!("\n+{}\n", clause)));
+    assert!(rendered.contains("\n-Copyright (c) <year> <copyright holders>\n"));
+}
+
+#[test]
+fn wrapping_and_case_are_normalized() {
+    let candidate = wrap(mit()).to_uppercase();
+    assert!(text::diff(mit(), &candidate).is_identical());
+    assert_eq!(
+        text::diff(mit(), &candidate).to_string(),
+        "--- canonical\n+++ candidate\n"
+    );
+}
+
+#[test]
+fn unrelated_texts_differ_everywhere() {
+    let apache = license::from_id("Apache-2.0").unwrap().text();
+    let diff = text::diff(mit(), apache);
+    assert!(!diff.is_trivial());
+    let removed = diff
+        .changes()
+        .filter(|change| matches!(change, Change::Removed(_)))
+        .count();
+    assert_eq!(removed, 5);
+}
@@ -0,0 +1,238 @@
+use license::{Conditions, Limitations, ListStyle, Permissions, SummaryDisplay};
+use std::fmt::Write;
+
+// The `Display` impls as they were before they delegated to the list wrappers, apart from
+// the explicit sentence of empty flags.
+
+fn legacy_permissions(p: Permissions) -> String {
+    let mut s = String::new();
+    if p.commercial_use() {
+        s.push_str("- May be used for commercial purposes.\n");
+    }
+    if p.distribution() {
+        s.push_str("- May be distributed.\n");
+    }
+    if p.modification() {
+        s.push_str("- May be modified.\n");
+    }
+    if p.patent_rights() {
+        s.push_str("- Provides an express grant of patent rights from contributors.\n");
+    }
+    if p.private_use() {
+        s.push_str("- May be used for private purposes.\n");
+    }
+    if s.is_empty() {
+        s.push_str("- Grants no permissions.\n");
+    }
+    s
+}
+
+fn legacy_conditions(c: Conditions) -> String {
+    let mut s = String::new();
+    if c.disclose_sources() {
+        s.push_str("- Source code must be made available when the software is distributed.\n");
+    }
+    if c.document_changes() {
+        s.push_str("- Changes made to the code must be documented.\n");
+    }
+    if c.license_and_copyright_notice() {
+        s.push_str("- The license and copyright notice must be included with the software.\n");
+    }
+    if c.network_use_is_distribution() {
+        s.push_str("- Users who interact with the software via network are given the right to receive a copy of the source code.\n");
+    }
+    if c.same_license() {
+        s.push_str("- Modifications must be released under the same license.\n");
+    }
+    if c.notice_file() {
+        s.push_str(
+            "- The attribution notices in a NOTICE file must be included with the software.\n",
+        );
+    }
+    if s.is_empty() {
+        s.push_str("- Has no conditions.\n");
+    }
+    s
+}
+
+fn legacy_limitations(l: Limitations) -> String {
+    let mut s = String::new();
+    if l.no_liability() {
+        s.push_str("- Includes a limitation of liability.\n");
+    }
+    if l.no_trademark_rights() {
+        s.push_str("- Does not grant trademark rights.\n");
+    }
+    if l.no_warranty() {
+        s.push_str("- Does not provide any warranty.\n");
+    }
+    if l.no_patent_rights() {
+        s.push_str("- Does not provide any rights in the patents of contributors.\n");
+    }
+    if s.is_empty() {
+        s.push_str("- Has no limitations.\n");
+    }
+    s
+}
+
+#[test]
+fn default_style_is_byte_identical() {
+    for bits in 0..64 {
+        if let Some(p) = Permissions::from_bits(bits) {
+            assert_eq!(p.to_string(), legacy_permissions(p));
+            assert_eq!(
+                p.list(ListStyle::default()).to_string(),
+                legacy_permissions(p)
+            );
+        }
+        if let Some(c) = Conditions::from_bits(bits) {
+            assert_eq!(c.to_string(), legacy_conditions(c));
+            assert_eq!(
+                c.list(ListStyle::default()).to_string(),
+                legacy_conditions(c)
+            );
+        }
+        if let Some(l) = Limitations::from_bits(bits) {
+            assert_eq!(l.to_string(), legacy_limitations(l));
+            assert_eq!(
+                l.list(ListStyle::default()).to_string(),
+                legacy_limitations(l)
+            );
+        }
+    }
+}
+
+#[test]
+fn lists_embed_in_format_args() {
+    let apache = license::from_id_ext("Apache-2.0").unwrap();
+    let style = ListStyle {
+        bullet: '•',
+        trailing_newline: false,
+        capitalize: true,
+    };
+    let mut s = String::new();
+    write!(s, "[{}]", apache.limitations().list(style)).unwrap();
+    assert_eq!(
+        s,
+        "[• Includes a limitation of liability.\n\
+         • Does not grant trademark rights.\n\
+         • Does not provide any warranty.]"
+    );
+    assert_eq!(
+        Permissions::default().list(style).to_string(),
+        "• Grants no permissions."
+    );
+}
+
+#[test]
+fn modification_wording_per_license() {
+    let cases: &[(&str, &str)] = &[
+        (
+            "Zlib",
+            "- The license and copyright notice must be included with the software.\n\
+             - Altered source versions must be plainly marked as such.\n",
+        ),
+        (
+            "GPL-3.0-only",
+            "- Source code must be made available when the software is distributed.\n\
+             - Changes made to the code must be documented.\n\
+             - The license and copyright notice must be included with the software.\n\
+             - Modifications must be released under the same license.\n",
+        ),
+        (
+            "Apache-2.0",
+            "- Changes made to the code must be documented.\n\
+             - The license and copyright notice must be included with the software.\n\
+             - The attribution notices in a NOTICE file must be included with the software.\n",
+        ),
+    ];
+    for &(id, expected) in cases {
+        let license = license::from_id_ext(id).unwrap();
+        assert_eq!(license.conditions().to_string(), expected, "{}", id);
+    }
+}
+
+#[test]
+fn alternate_form_is_one_line() {
+    let mit = license::from_id_ext("MIT").unwrap();
+    assert_eq!(
+        format!("{:#}", mit.permissions()),
+        "May be used for commercial purposes. May be distributed. May be modified. \
+         May be used for private purposes."
+    );
+    assert_eq!(
+        format!("| {:#} |", mit.conditions()),
+        "| The license and copyright notice must be included with the software. |"
+    );
+    assert_eq!(
+        format!("{:#}", mit.limitations()),
+        "Includes a limitation of liability. Does not provide any warranty."
+    );
+
+    let wtfpl = license::from_id_ext("WTFPL").unwrap();
+    assert_eq!(format!("{:#}", wtfpl.conditions()), "(none)");
+
+    let style = ListStyle {
+        bullet: '*',
+        trailing_newline: true,
+        capitalize: false,
+    };
+    assert_eq!(
+        format!("{:#}", mit.conditions().list(style)),
+        "the license and copyright notice must be included with the software."
+    );
+}
+
+#[test]
+fn public_domain_equivalents_render_their_empty_lists() {
+    for id in ["WTFPL", "0BSD", "Unlicense", "CC0-1.0"] {
+        let license = license::from_id_ext(id).unwrap();
+        let conditions = license.conditions();
+        assert!(conditions.is_empty(), "{}", id);
+        assert_eq!(conditions.to_string(), "- Has no conditions.\n", "{}", id);
+        assert_eq!(format!("{:#}", conditions), "(none)", "{}", id);
+
+        let summary = SummaryDisplay::new(license, ListStyle::default()).to_string();
+        assert!(summary.starts_with("Permissions:\n"), "{}", id);
+        assert!(!summary.contains("Conditions:"), "{}", id);
+        let note = summary.lines().last().unwrap();
+        assert!(note.starts_with("No conditions."), "{}: {}", id, note);
+        assert_eq!(
+            note.contains("No limitations."),
+            license.limitations().is_empty(),
+            "{}",
+            id
+        );
+    }
+}
+
+#[test]
+fn compact_legends() {
+    for &(id, symbols, ascii) in &[
+        ("MIT", "✔✔✔–✔ | ––©–––– | ⚠–⚠–", "CDM-U | --C---- | L-W-"),
+        (
+            "GPL-3.0-only",
+            "✔✔✔✔✔ | ⓢⓓ©–ⓛ–– | ⚠–⚠–",
+            "CDMPU | SDC-L-- | L-W-",
+        ),
+        ("WTFPL", "✔✔✔–✔ | ––––––– | ––––", "CDM-U | ------- | ----"),
+    ] {
+        let legend = license::from_id_ext(id).unwrap().compact_legend();
+        assert_eq!(legend.to_string(), symbols, "{}", id);
+        assert_eq!(legend.ascii().to_string(), ascii, "{}", id);
+    }
+}
+
+#[test]
+fn compact_legends_have_a_fixed_width() {
+    use license::{CompactLegend, NONE, UNKNOWN};
+
+    let sentinels = [UNKNOWN, NONE];
+    for license in license::LICENSES_EXT.iter().chain(&sentinels) {
+        let legend = license.compact_legend();
+        assert_eq!(legend.to_string().chars().count(), CompactLegend::WIDTH);
+        assert_eq!(legend.ascii().to_string().len(), CompactLegend::WIDTH);
+        assert!(legend.ascii().to_string().is_ascii());
+    }
+    assert_eq!(CompactLegend::WIDTH, 22);
+}
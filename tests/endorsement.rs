@@ -0,0 +1,117 @@
+use license::{LicenseExt, LICENSES_EXT};
+
+/// The licenses with a clause, and its first and last words.
+const CLAUSES: &[(&str, &str, &str)] = &[
+    (
+        "Apache-2.0",
+        "This License does not grant permission",
+        "the NOTICE file.",
+    ),
+    (
+        "BSD-3-Clause",
+        "Neither the name of the copyright holder",
+        "prior written permission.",
+    ),
+    (
+        "BSD-3-Clause-Clear",
+        "Neither the name of [Owner Organization]",
+        "prior written permission.",
+    ),
+    (
+        "BSD-4-Clause",
+        "Neither the name of the copyright holder",
+        "prior written permission.",
+    ),
+    (
+        "MS-PL",
+        "This license does not grant you rights",
+        "logo, or trademarks.",
+    ),
+];
+
+/// The byte offset of a clause in the text that it borrows from.
+fn offset(text: &str, clause: &str) -> usize {
+    let offset = clause.as_ptr() as usize - text.as_ptr() as usize;
+    assert!(offset + clause.len() <= text.len());
+    offset
+}
+
+#[test]
+fn clauses_are_verbatim() {
+    for &(id, start, end) in CLAUSES {
+        let license = license::from_id_ext(id).unwrap();
+        let clause = license.endorsement_restriction().unwrap();
+        assert!(clause.starts_with(start), "{}: {:?}", id, clause);
+        assert!(clause.ends_with(end), "{}: {:?}", id, clause);
+
+        let text = license.text();
+        let offset = offset(text, clause);
+        assert_eq!(&text[offset..offset + clause.len()], clause);
+        assert_eq!(text.matches(clause).count(), 1, "{}", id);
+    }
+}
+
+#[test]
+fn clauses_are_whole_sentences() {
+    for license in LICENSES_EXT {
+        let clause = match license.endorsement_restriction() {
+            Some(clause) => clause,
+            None => continue,
+        };
+        let text = license.text();
+        let offset = offset(text, clause);
+        let before = text[..offset].trim_end();
+        assert!(
+            before.ends_with(|c: char| c == '.' || c == '-' || c == '*'),
+            "{}: {:?}",
+            license.id(),
+            &before[before.len().saturating_sub(20)..]
+        );
+        assert!(
+            text[offset + clause.len()..].starts_with(char::is_whitespace),
+            "{}",
+            license.id()
+        );
+        assert_eq!(clause.trim(), clause, "{}", license.id());
+    }
+}
+
+#[test]
+fn only_curated_licenses_have_clauses() {
+    let ids: Vec<_> = LICENSES_EXT
+        .iter()
+        .filter(|l| l.endorsement_restriction().is_some())
+        .map(|l| l.id())
+        .collect();
+    let curated: Vec<_> = CLAUSES.iter().map(|&(id, _, _)| id).collect();
+    assert_eq!(ids, curated);
+    assert_eq!(license::UNKNOWN.endorsement_restriction(), None);
+}
+
+#[test]
+fn trademark_flag_is_separate() {
+    let apache = license::from_id_ext("Apache-2.0").unwrap();
+    assert!(apache.limitations().no_trademark_rights());
+    let span = apache
+        .flag_provenance(license::Limitation::NoTrademarkRights.into())
+        .unwrap();
+    let clause = apache.endorsement_restriction().unwrap();
+    let offset = offset(apache.text(), clause);
+    assert!(span.start < offset && offset + clause.len() == span.end);
+
+    for id in ["BSD-3-Clause", "BSD-3-Clause-Clear", "BSD-4-Clause"] {
+        let bsd = license::from_id_ext(id).unwrap();
+        assert!(!bsd.limitations().no_trademark_rights(), "{}", id);
+        assert!(bsd.endorsement_restriction().is_some(), "{}", id);
+    }
+}
+
+#[test]
+fn advertising_clause_is_not_the_endorsement_clause() {
+    let clause = license::BSD_4_Clause.endorsement_restriction().unwrap();
+    assert_eq!(
+        Some(clause),
+        license::BSD_3_Clause.endorsement_restriction()
+    );
+    assert!(!clause.contains("advertising"));
+}
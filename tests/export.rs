@@ -0,0 +1,74 @@
+use license::export::{self, DumpFormat, SCHEMA_VERSION};
+use license::LICENSES;
+use serde_json::Value;
+
+const CSV_HEADER: &str = "schema_version,id,name,osi_approved,fsf_libre,deprecated,family,copyleft,permissions,conditions,limitations";
+
+const JSON_MIT: &str = r#"{"schema_version":1,"id":"MIT","name":"MIT License","osi_approved":true,"fsf_libre":true,"deprecated":false,"family":"other","copyleft":"none","permissions":["commercial_use","distribution","modification","private_use"],"conditions":["license_and_copyright_notice"],"limitations":["no_liability","no_warranty"]},"#;
+
+#[test]
+fn csv_header() {
+    let csv = export::dump_all(DumpFormat::Csv).to_string();
+    assert_eq!(csv.lines().next().unwrap(), CSV_HEADER);
+    let with_texts = export::dump_all(DumpFormat::Csv).with_texts().to_string();
+    assert_eq!(
+        with_texts.lines().next().unwrap(),
+        format!("{},text", CSV_HEADER)
+    );
+}
+
+#[test]
+fn json_record() {
+    let json = export::dump_all(DumpFormat::Json).to_string();
+    assert!(json.lines().any(|line| line == JSON_MIT));
+}
+
+#[test]
+fn json_keeps_empty_flags() {
+    let json = export::dump_all(DumpFormat::Json).to_string();
+    let dump: Value = serde_json::from_str(&json).unwrap();
+    for id in ["WTFPL", "0BSD", "Unlicense", "CC0-1.0"] {
+        let record = dump["licenses"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|record| record["id"] == id)
+            .unwrap();
+        assert_eq!(record["conditions"], Value::Array(Vec::new()), "{}", id);
+        assert!(record["limitations"].is_array(), "{}", id);
+        assert!(
+            !record["permissions"].as_array().unwrap().is_empty(),
+            "{}",
+            id
+        );
+    }
+}
+
+#[test]
+fn json_is_valid_with_texts() {
+    let json = export::dump_all(DumpFormat::Json).with_texts().to_string();
+    let dump: Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(dump["schema_version"], SCHEMA_VERSION);
+    let licenses = dump["licenses"].as_array().unwrap();
+    assert!(licenses.len() > LICENSES.len());
+    for (record, license) in licenses.iter().zip(LICENSES) {
+        assert_eq!(record["id"], license.id());
+        assert_eq!(record["text"], license.text());
+        assert_eq!(record["copyleft"].is_null(), license.ext().is_none());
+    }
+}
+
+#[test]
+fn csv_has_a_row_per_license() {
+    let csv = export::dump_all(DumpFormat::Csv).to_string();
+    let json = export::dump_all(DumpFormat::Json).to_string();
+    let dump: Value = serde_json::from_str(&json).unwrap();
+    // Without the texts, no field spans lines.
+    assert_eq!(
+        csv.lines().count() - 1,
+        dump["licenses"].as_array().unwrap().len()
+    );
+    assert!(csv.contains(
+        "\r\n1,GPL-2.0-only WITH Classpath-exception-2.0,GNU General Public License v2.0 only WITH Classpath exception 2.0,true,true,false,gnu,weak,"
+    ));
+}
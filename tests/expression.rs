@@ -0,0 +1,300 @@
+#![cfg(feature = "alloc")]
+
+use license::expression::{ExprStyle, Expression, Leaf};
+use license::{ExpressionError, PackageLicense};
+
+fn license(id: &'static str) -> Expression {
+    Expression::Leaf(Leaf::License {
+        id,
+        or_later: false,
+    })
+}
+
+#[test]
+fn parse() {
+    let expression = Expression::parse("(MIT OR Apache-2.0) AND (ISC AND GPL-2.0+)").unwrap();
+    assert_eq!(
+        expression,
+        Expression::And(vec![
+            Expression::Or(vec![license("MIT"), license("Apache-2.0")]),
+            license("ISC"),
+            Expression::Leaf(Leaf::License {
+                id: "GPL-2.0",
+                or_later: true,
+            }),
+        ])
+    );
+    assert_eq!(Expression::parse(" ( MIT ) ").unwrap(), license("MIT"));
+    assert_eq!(
+        Expression::parse("MIT/Apache-2.0").unwrap(),
+        Expression::parse("MIT OR Apache-2.0").unwrap()
+    );
+    assert_eq!(
+        Expression::parse("DocumentRef-spdx-tool-1.2:LicenseRef-MIT-Style-2").unwrap(),
+        Expression::Leaf(Leaf::LicenseRef {
+            document: Some("DocumentRef-spdx-tool-1.2".into()),
+            license: "LicenseRef-MIT-Style-2".into(),
+            exception: None,
+        })
+    );
+}
+
+#[test]
+fn license_refs_keep_their_exception() {
+    let expression = Expression::parse("LicenseRef-Foo WITH Classpath-exception-2.0").unwrap();
+    assert_eq!(
+        expression,
+        Expression::Leaf(Leaf::LicenseRef {
+            document: None,
+            license: "LicenseRef-Foo".into(),
+            exception: Some("Classpath-exception-2.0"),
+        })
+    );
+    assert_eq!(
+        expression.to_string(),
+        "LicenseRef-Foo WITH Classpath-exception-2.0"
+    );
+    let leaf = expression.leaves().next().unwrap();
+    assert!(leaf.is_license_ref() && leaf.license().is_none());
+    assert_eq!(leaf.exception().unwrap().id(), "Classpath-exception-2.0");
+
+    let expression =
+        Expression::parse("MIT OR DocumentRef-a:LicenseRef-B WITH LLVM-exception").unwrap();
+    assert_eq!(
+        expression.to_string(),
+        "MIT OR DocumentRef-a:LicenseRef-B WITH LLVM-exception"
+    );
+    assert!(Expression::parse("LicenseRef-Foo WITH Foo").is_err());
+}
+
+#[test]
+fn errors_match_the_package_license_errors() {
+    let cases = [
+        "",
+        "MIT OR",
+        "(MIT",
+        "MIT)",
+        "MIT Apache-2.0",
+        "mit",
+        "MIT WITH",
+        "MIT WITH Foo",
+        "GPL-2.0+ WITH Classpath-exception-2.0+",
+    ];
+    for &expression in &cases {
+        let expected = ExpressionError::from(PackageLicense::parse(expression).unwrap_err());
+        let err = Expression::parse(expression).unwrap_err();
+        assert_eq!(err, expected, "{:?}", expression);
+        assert_eq!(err.to_string(), expected.to_string());
+        assert_eq!(err.offending(), expected.offending());
+    }
+
+    for expression in ["LicenseRef-", "LicenseRef-Vendor+", "DocumentRef-a:MIT"] {
+        assert!(
+            matches!(
+                Expression::parse(expression),
+                Err(ExpressionError::UnknownLicense { position: 0, .. })
+            ),
+            "{:?}",
+            expression
+        );
+    }
+}
+
+#[test]
+fn display_is_canonical() {
+    let cases = [
+        ("MIT", "MIT"),
+        ("  MIT   OR Apache-2.0 ", "MIT OR Apache-2.0"),
+        ("MIT/Apache-2.0", "MIT OR Apache-2.0"),
+        ("(MIT AND ISC) OR Zlib", "MIT AND ISC OR Zlib"),
+        ("MIT AND (ISC OR Zlib)", "MIT AND (ISC OR Zlib)"),
+        ("((MIT))", "MIT"),
+        (
+            "GPL-2.0+ WITH Classpath-exception-2.0",
+            "GPL-2.0+ WITH Classpath-exception-2.0",
+        ),
+    ];
+    for &(expression, canonical) in &cases {
+        let parsed = Expression::parse(expression).unwrap();
+        assert_eq!(parsed.to_string(), canonical);
+        assert_eq!(Expression::parse(canonical).unwrap(), parsed);
+    }
+}
+
+#[test]
+fn leaves() {
+    let expression =
+        Expression::parse("MIT OR (GPL-2.0-only WITH Classpath-exception-2.0 AND LicenseRef-A)")
+            .unwrap();
+    let leaves: Vec<_> = expression.leaves().map(|leaf| leaf.to_string()).collect();
+    assert_eq!(
+        leaves,
+        [
+            "MIT",
+            "GPL-2.0-only WITH Classpath-exception-2.0",
+            "LicenseRef-A"
+        ]
+    );
+    let licenses: Vec<_> = expression
+        .leaves()
+        .filter_map(|leaf| leaf.license())
+        .map(|license| license.id())
+        .collect();
+    assert_eq!(licenses, ["MIT", "GPL-2.0-only"]);
+    let exceptions: Vec<_> = expression
+        .leaves()
+        .filter_map(|leaf| leaf.exception())
+        .map(|exception| exception.id())
+        .collect();
+    assert_eq!(exceptions, ["Classpath-exception-2.0"]);
+}
+
+#[test]
+fn any_and_all() {
+    let expression = Expression::parse("MIT OR (GPL-3.0-only AND LicenseRef-A)").unwrap();
+    let copyleft = |leaf: &Leaf| {
+        leaf.license()
+            .and_then(|license| license.ext())
+            .map_or(false, |ext| ext.conditions().same_license())
+    };
+    assert!(expression.any(copyleft));
+    assert!(!expression.all(copyleft));
+    assert!(expression.any(Leaf::is_license_ref));
+    assert!(!Expression::parse("MIT AND ISC").unwrap().any(copyleft));
+}
+
+#[test]
+fn map_leaves_upgrades_deprecated_ids() {
+    let expression = Expression::parse(
+        "(GPL-2.0 OR MIT) AND GPL-2.0 WITH Classpath-exception-2.0 AND (ISC OR GPL-2.0+)",
+    )
+    .unwrap();
+    let upgraded = expression.map_leaves(|leaf| match leaf {
+        Leaf::License {
+            id: "GPL-2.0",
+            or_later,
+        } => Leaf::License {
+            id: if or_later {
+                "GPL-2.0-or-later"
+            } else {
+                "GPL-2.0-only"
+            },
+            or_later: false,
+        },
+        Leaf::WithException {
+            id: "GPL-2.0",
+            or_later: false,
+            exception,
+        } => Leaf::WithException {
+            id: "GPL-2.0-only",
+            or_later: false,
+            exception,
+        },
+        leaf => leaf,
+    });
+    assert_eq!(
+        upgraded.to_string(),
+        "(GPL-2.0-only OR MIT) AND GPL-2.0-only WITH Classpath-exception-2.0 AND (ISC OR GPL-2.0-or-later)"
+    );
+    assert!(upgraded.all(|leaf| leaf.license().map_or(false, |l| !l.is_deprecated())));
+}
+
+#[test]
+fn from_package_license() {
+    let declared = *license::declared!("MIT OR Apache-2.0 WITH LLVM-exception");
+    assert_eq!(
+        Expression::from(declared),
+        Expression::parse(declared.expression()).unwrap()
+    );
+}
+
+/// License fields of crates on crates.io.
+const CRATES_IO: &[&str] = &[
+    "MIT OR Apache-2.0",
+    "Apache-2.0 OR MIT",
+    "MIT/Apache-2.0",
+    "Unlicense OR MIT",
+    "Unlicense/MIT",
+    "MIT",
+    "Apache-2.0",
+    "BSD-3-Clause",
+    "ISC",
+    "Zlib OR Apache-2.0 OR MIT",
+    "Apache-2.0 WITH LLVM-exception OR Apache-2.0 OR MIT",
+    "(MIT OR Apache-2.0) AND Unicode-DFS-2016",
+    "MIT AND (MIT OR Apache-2.0)",
+    "BSD-2-Clause OR Apache-2.0 OR MIT",
+    "0BSD OR MIT OR Apache-2.0",
+    "Apache-2.0 AND ISC",
+    "MIT OR Apache-2.0 OR Zlib",
+    "MPL-2.0",
+    "CC0-1.0 OR Apache-2.0",
+    "LGPL-2.1-or-later",
+    "GPL-3.0-or-later",
+    "BSL-1.0",
+    "Apache-2.0/ISC/MIT",
+    "(Apache-2.0 OR MIT) AND BSD-3-Clause",
+    "MIT OR Apache-2.0 AND BSD-2-Clause",
+    "(MIT OR Apache-2.0) AND (BSD-3-Clause OR ISC) AND Zlib",
+    "GPL-2.0-only WITH Classpath-exception-2.0 OR MIT",
+    "LicenseRef-Proprietary",
+];
+
+#[test]
+fn styles_round_trip() {
+    let styles = [
+        ExprStyle::new(),
+        ExprStyle::new().full_parentheses(),
+        ExprStyle::new().lowercase_operators(),
+        ExprStyle::new().full_parentheses().lowercase_operators(),
+    ];
+    for &field in CRATES_IO {
+        let expression = Expression::parse(field).unwrap();
+        assert_eq!(
+            Expression::parse(&expression.to_string()).unwrap(),
+            expression,
+            "{}",
+            field
+        );
+        for &style in &styles {
+            let styled = expression.to_string_styled(style);
+            let normalized = license::normalize_legacy(&styled).unwrap().to_string();
+            assert_eq!(
+                Expression::parse(&normalized).unwrap(),
+                expression,
+                "{} {:?}",
+                field,
+                style
+            );
+        }
+    }
+}
+
+#[test]
+fn styles() {
+    let expression = Expression::parse(
+        "(MIT OR Apache-2.0) AND (ISC AND Zlib OR BSD-3-Clause WITH LLVM-exception)",
+    )
+    .unwrap();
+    assert_eq!(
+        expression.to_string_styled(ExprStyle::default()),
+        expression.to_string()
+    );
+    assert_eq!(
+        expression.to_string(),
+        "(MIT OR Apache-2.0) AND (ISC AND Zlib OR BSD-3-Clause WITH LLVM-exception)"
+    );
+    assert_eq!(
+        expression.to_string_styled(ExprStyle::new().full_parentheses()),
+        "(MIT OR Apache-2.0) AND ((ISC AND Zlib) OR BSD-3-Clause WITH LLVM-exception)"
+    );
+    assert_eq!(
+        expression.to_string_styled(ExprStyle::new().lowercase_operators()),
+        "(MIT or Apache-2.0) and (ISC and Zlib or BSD-3-Clause with LLVM-exception)"
+    );
+    let leaf = Expression::parse("GPL-2.0-or-later").unwrap();
+    assert_eq!(
+        leaf.to_string_styled(ExprStyle::new().full_parentheses()),
+        "GPL-2.0-or-later"
+    );
+}
@@ -0,0 +1,169 @@
+use license::{Family, LICENSES_EXT};
+
+const FAMILIES: &[(&str, Family)] = &[
+    ("0BSD", Family::Bsd),
+    ("AFL-3.0", Family::Other("AFL-3.0")),
+    ("AGPL-3.0-only", Family::Gnu),
+    ("APSL-2.0", Family::Other("APSL-2.0")),
+    ("Apache-2.0", Family::Apache),
+    ("Apache-2.0 WITH SHL-2.1", Family::Apache),
+    ("BSD-2-Clause", Family::Bsd),
+    ("BSD-3-Clause", Family::Bsd),
+    ("BSD-3-Clause-Clear", Family::Bsd),
+    ("BSD-4-Clause", Family::Bsd),
+    ("BSL-1.0", Family::Other("BSL-1.0")),
+    ("BUSL-1.1", Family::Other("BUSL-1.1")),
+    ("CC-BY-4.0", Family::CreativeCommons),
+    ("CC-BY-NC-4.0", Family::CreativeCommons),
+    ("CC-BY-NC-ND-4.0", Family::CreativeCommons),
+    ("CC-BY-NC-SA-4.0", Family::CreativeCommons),
+    ("CC-BY-ND-4.0", Family::CreativeCommons),
+    ("CC-BY-SA-3.0", Family::CreativeCommons),
+    ("CC-BY-SA-4.0", Family::CreativeCommons),
+    ("CC0-1.0", Family::PublicDomain),
+    ("CECILL-2.0", Family::Other("CECILL-2.0")),
+    ("CECILL-2.1", Family::Other("CECILL-2.1")),
+    ("CERN-OHL-P-2.0", Family::Other("CERN-OHL-P-2.0")),
+    ("CERN-OHL-S-2.0", Family::Other("CERN-OHL-S-2.0")),
+    ("CERN-OHL-W-2.0", Family::Other("CERN-OHL-W-2.0")),
+    ("CPL-1.0", Family::Other("CPL-1.0")),
+    ("DL-DE-BY-2.0", Family::Other("DL-DE-BY-2.0")),
+    ("ECL-2.0", Family::Other("ECL-2.0")),
+    ("EPL-1.0", Family::Other("EPL-1.0")),
+    ("EPL-2.0", Family::Other("EPL-2.0")),
+    ("EUPL-1.1", Family::Other("EUPL-1.1")),
+    ("EUPL-1.2", Family::Other("EUPL-1.2")),
+    ("Elastic-2.0", Family::Other("Elastic-2.0")),
+    ("GFDL-1.3-only", Family::Gnu),
+    ("GFDL-1.3-or-later", Family::Gnu),
+    ("GPL-2.0-only", Family::Gnu),
+    ("GPL-2.0-only WITH Classpath-exception-2.0", Family::Gnu),
+    ("GPL-3.0-only", Family::Gnu),
+    ("LGPL-2.1-only", Family::Gnu),
+    ("LGPL-3.0-only", Family::Gnu),
+    ("LiLiQ-R-1.1", Family::Other("LiLiQ-R-1.1")),
+    ("LiLiQ-Rplus-1.1", Family::Other("LiLiQ-Rplus-1.1")),
+    ("MIT", Family::Other("MIT")),
+    ("MIT-CMU", Family::Other("MIT-CMU")),
+    ("MIT-advertising", Family::Other("MIT-advertising")),
+    ("MITNFA", Family::Other("MITNFA")),
+    ("MPL-2.0", Family::Mozilla),
+    ("MPL-2.0-no-copyleft-exception", Family::Mozilla),
+    ("MS-PL", Family::Other("MS-PL")),
+    ("MirOS", Family::Other("MirOS")),
+    ("NTP", Family::Other("NTP")),
+    ("OGL-UK-3.0", Family::Other("OGL-UK-3.0")),
+    ("OSL-2.1", Family::Other("OSL-2.1")),
+    ("OSL-3.0", Family::Other("OSL-3.0")),
+    ("TAPR-OHL-1.0", Family::Other("TAPR-OHL-1.0")),
+    ("Unlicense", Family::PublicDomain),
+    ("WTFPL", Family::Other("WTFPL")),
+    ("X11", Family::Other("X11")),
+    ("Zlib", Family::Other("Zlib")),
+];
+
+#[test]
+fn every_license_has_its_family() {
+    let ids: Vec<_> = LICENSES_EXT.iter().map(|l| l.id()).collect();
+    let pinned: Vec<_> = FAMILIES.iter().map(|&(id, _)| id).collect();
+    assert_eq!(ids, pinned, "FAMILIES is out of date");
+    for (license, &(_, family)) in LICENSES_EXT.iter().zip(FAMILIES) {
+        assert_eq!(license.family(), family, "{}", license.id());
+    }
+}
+
+#[test]
+fn by_family_covers_every_license_once() {
+    let mut families: Vec<_> = FAMILIES.iter().map(|&(_, family)| family).collect();
+    families.sort();
+    families.dedup();
+    let count: usize = families
+        .into_iter()
+        .map(|family| license::by_family(family).count())
+        .sum();
+    assert_eq!(count, LICENSES_EXT.len());
+}
+
+#[test]
+fn variants_share_a_family() {
+    for &(id, family) in &[
+        ("GPL-2.0+", Family::Gnu),
+        ("LGPL-2.1", Family::Gnu),
+        ("GFDL-1.2-no-invariants-or-later", Family::Gnu),
+        ("BSD-4-Clause-UC", Family::Bsd),
+        ("CC-BY-SA-4.0", Family::CreativeCommons),
+        ("MPL-2.0-no-copyleft-exception", Family::Mozilla),
+        ("Apache-1.1", Family::Apache),
+        ("AGPL-1.0", Family::Other("AGPL-1.0")),
+    ] {
+        assert_eq!(license::from_id(id).unwrap().family(), family, "{}", id);
+    }
+}
+
+#[test]
+fn family_modules_reexport_the_family() {
+    use license::licenses::{bsd, gnu};
+    use license::License;
+
+    let licenses: [(&dyn License, Family); 6] = [
+        (&gnu::GPL_3_0_only, Family::Gnu),
+        (&gnu::LGPL_2_1_plus, Family::Gnu),
+        (&gnu::GFDL_1_3_no_invariants_or_later, Family::Gnu),
+        (&bsd::ZERO_BSD, Family::Bsd),
+        (&bsd::BSD_4_Clause_UC, Family::Bsd),
+        (&bsd::FreeBSD_DOC, Family::Bsd),
+    ];
+    for (license, family) in licenses {
+        assert_eq!(license.family(), family, "{}", license.id());
+    }
+    assert!(bsd::BSD_3_Clause.as_any().is::<license::BSD_3_Clause>());
+}
+
+#[test]
+fn cern_ohl_reciprocity_grows() {
+    use license::report::Copyleft;
+
+    let family: Vec<_> = ["CERN-OHL-P-2.0", "CERN-OHL-W-2.0", "CERN-OHL-S-2.0"]
+        .iter()
+        .map(|id| license::from_id_ext(id).unwrap())
+        .collect();
+    for pair in family.windows(2) {
+        let (weaker, stronger) = (pair[0], pair[1]);
+        assert!(
+            weaker.conditions().is_subset(stronger.conditions()),
+            "{} {}",
+            weaker.id(),
+            stronger.id()
+        );
+        assert!(Copyleft::of(weaker) < Copyleft::of(stronger));
+    }
+    let classes: Vec<_> = family.iter().map(|&l| Copyleft::of(l)).collect();
+    assert_eq!(classes, [Copyleft::None, Copyleft::Weak, Copyleft::Strong]);
+    assert!(family.iter().all(|l| l.permissions().patent_rights()));
+}
+
+#[test]
+fn hardware_licenses() {
+    use license::report::Copyleft;
+
+    let apache = license::from_id_ext("Apache-2.0").unwrap();
+    let shl = license::from_id_ext("Apache-2.0 WITH SHL-2.1").unwrap();
+    assert_eq!(shl.permissions(), apache.permissions());
+    assert_eq!(shl.conditions(), apache.conditions());
+    assert_eq!(shl.limitations(), apache.limitations());
+    assert_eq!(shl.text(), apache.text());
+    assert!(shl
+        .exception()
+        .unwrap()
+        .text()
+        .starts_with("SOLDERPAD HARDWARE LICENSE VERSION 2.1"));
+    assert!(shl
+        .header()
+        .unwrap()
+        .contains("Solderpad Hardware License v 2.1"));
+
+    let tapr = license::from_id_ext("TAPR-OHL-1.0").unwrap();
+    assert!(tapr.conditions().same_license() && tapr.conditions().disclose_sources());
+    assert!(tapr.permissions().patent_rights());
+    assert_eq!(Copyleft::of(tapr), Copyleft::Strong);
+}
@@ -0,0 +1,52 @@
+use license::BuildFeatures;
+
+fn enabled(features: BuildFeatures) -> [(&'static str, bool); 7] {
+    [
+        ("alloc", features.alloc()),
+        ("arbitrary", features.arbitrary()),
+        ("cli", features.cli()),
+        ("fs", features.fs()),
+        ("rayon", features.rayon()),
+        ("serde", features.serde()),
+        ("std", features.std()),
+    ]
+}
+
+#[test]
+fn features_reflect_the_build() {
+    let features = license::features();
+    assert_eq!(features.alloc(), cfg!(feature = "alloc"));
+    assert_eq!(features.arbitrary(), cfg!(feature = "arbitrary"));
+    assert_eq!(features.cli(), cfg!(feature = "cli"));
+    assert_eq!(features.fs(), cfg!(feature = "fs"));
+    assert_eq!(features.rayon(), cfg!(feature = "rayon"));
+    assert_eq!(features.serde(), cfg!(feature = "serde"));
+    assert_eq!(features.std(), cfg!(feature = "std"));
+}
+
+#[test]
+fn features_string_lists_every_feature() {
+    let expected: Vec<_> = enabled(license::features())
+        .iter()
+        .map(|&(name, on)| format!("{}{}", if on { '+' } else { '-' }, name))
+        .collect();
+    assert_eq!(license::FEATURES_STRING, expected.join(" "));
+}
+
+#[cfg(not(feature = "alloc"))]
+#[test]
+fn default_build_has_no_features() {
+    assert_eq!(
+        license::FEATURES_STRING,
+        "-alloc -arbitrary -cli -fs -rayon -serde -std"
+    );
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn fs_implies_std_and_alloc() {
+    let features = license::features();
+    assert!(features.std() && features.alloc());
+    assert!(license::FEATURES_STRING.starts_with("+alloc "));
+    assert!(license::FEATURES_STRING.ends_with(" +std"));
+}
@@ -0,0 +1,90 @@
+use license::{Condition, Flag, Limitation, Permission, LICENSES_EXT};
+
+/// The licenses whose flags are all curated.
+const CURATED: &[&str] = &["Apache-2.0", "GPL-3.0-only", "MIT", "MPL-2.0"];
+
+/// Words of which a span of a flag must contain at least one, in lowercase.
+fn keywords(flag: Flag) -> &'static [&'static str] {
+    match flag {
+        Flag::Permission(Permission::CommercialUse) => &["sell", "charge", "royalty-free"],
+        Flag::Permission(Permission::Distribution) => &["distribut", "convey"],
+        Flag::Permission(Permission::Modification) => &["modif", "derivative works"],
+        Flag::Permission(Permission::PatentRights) => &["patent"],
+        Flag::Permission(Permission::PrivateUse) => &["use", "run", "reproduce"],
+        Flag::Condition(Condition::DiscloseSources) => &["source"],
+        Flag::Condition(Condition::DocumentChanges) => &["modified", "changed"],
+        Flag::Condition(Condition::LicenseAndCopyrightNotice) => &["copyright"],
+        Flag::Condition(Condition::NetworkUseIsDistribution) => &["network"],
+        Flag::Condition(Condition::SameLicense) => &["under this license", "terms of this license"],
+        Flag::Condition(Condition::NoticeFile) => &["notice"],
+        Flag::Condition(Condition::MarkModifications) => &["modif", "changed"],
+        Flag::Limitation(Limitation::NoLiability) => &["liable", "liability"],
+        Flag::Limitation(Limitation::NoTrademarkRights) => &["trademark"],
+        Flag::Limitation(Limitation::NoWarranty) => &["warrant"],
+        Flag::Limitation(Limitation::NoPatentRights) => &["patent"],
+    }
+}
+
+fn flags() -> impl Iterator<Item = Flag> {
+    Permission::ALL
+        .iter()
+        .map(|&flag| Flag::from(flag))
+        .chain(Condition::ALL.iter().map(|&flag| Flag::from(flag)))
+        .chain(Limitation::ALL.iter().map(|&flag| Flag::from(flag)))
+}
+
+#[test]
+fn spans_contain_a_keyword() {
+    for license in LICENSES_EXT {
+        for flag in flags() {
+            let span = match license.flag_provenance(flag) {
+                Some(span) => span,
+                None => continue,
+            };
+            let clause = license
+                .text()
+                .get(span.clone())
+                .unwrap_or_else(|| panic!("{} {:?} {:?}", license.id(), flag, span))
+                .to_lowercase();
+            assert!(
+                keywords(flag).iter().any(|word| clause.contains(word)),
+                "{} {}: {:?}",
+                license.id(),
+                flag.name(),
+                clause
+            );
+        }
+    }
+}
+
+#[test]
+fn only_set_flags_have_spans() {
+    for license in LICENSES_EXT {
+        for flag in flags() {
+            if license.flag_provenance(flag).is_some() {
+                assert!(
+                    license.metadata().contains(flag),
+                    "{} {:?}",
+                    license.id(),
+                    flag
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn curated_licenses_cover_every_flag() {
+    for license in LICENSES_EXT {
+        let curated = CURATED.contains(&license.id());
+        for flag in flags().filter(|&flag| license.metadata().contains(flag)) {
+            assert_eq!(
+                license.flag_provenance(flag).is_some(),
+                curated,
+                "{} {}",
+                license.id(),
+                flag.name()
+            );
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! The table of the flags, which the renderers are built on.
+
+use license::{Condition, Conditions, Limitation, Limitations, Permission, Permissions};
+
+macro_rules! table {
+    ($($module:ident: $flag:ident in $flags:ident,)*) => {$(
+        mod $module {
+            use super::*;
+
+            #[test]
+            fn descriptions_are_the_display_output() {
+                for flag in $flag::ALL.iter().copied() {
+                    let flags = $flags::from_bits(flag.bit()).unwrap();
+                    assert_eq!(flags.to_string(), format!("- {}\n", flag.description()));
+                    assert_eq!(format!("{:#}", flags), flag.description());
+                }
+                let all: String = $flag::ALL
+                    .iter()
+                    .map(|flag| format!("- {}\n", flag.description()))
+                    .collect();
+                assert_eq!($flags::from_bits_truncate(!0).to_string(), all);
+            }
+
+            #[test]
+            fn flags_are_in_bit_order() {
+                for (i, flag) in $flag::ALL.iter().enumerate() {
+                    assert_eq!(flag.bit(), 1 << i);
+                }
+                let all = $flags::from_bits_truncate(!0);
+                assert!(all.iter().eq($flag::ALL.iter().copied()));
+                assert_eq!($flags::default().iter().count(), 0);
+            }
+
+            #[test]
+            fn flags_match_the_getters() {
+                let all = $flags::from_bits_truncate(!0);
+                assert_eq!($flags::FLAGS.len(), all.iter().count());
+                for (info, flag) in $flags::FLAGS.iter().zip(all.iter()) {
+                    assert_eq!(info.name, flag.name());
+                    assert_eq!(info.label, flag.label());
+                    assert_eq!(info.description, flag.description());
+                }
+            }
+
+            #[test]
+            fn names_and_labels_are_unique() {
+                for (i, a) in $flag::ALL.iter().enumerate() {
+                    for b in &$flag::ALL[i + 1..] {
+                        assert_ne!(a.name(), b.name());
+                        assert_ne!(a.label(), b.label());
+                    }
+                }
+            }
+        }
+    )*};
+}
+
+table! {
+    permission: Permission in Permissions,
+    condition: Condition in Conditions,
+    limitation: Limitation in Limitations,
+}
+
+/// The names of the flags when they were first listed, which must stay a prefix of
+/// `FLAGS`.
+#[test]
+fn flags_are_only_appended() {
+    let names = |flags: &[license::FlagInfo]| flags.iter().map(|f| f.name).collect::<Vec<_>>();
+    let permissions = [
+        "commercial_use",
+        "distribution",
+        "modification",
+        "patent_rights",
+        "private_use",
+    ];
+    assert!(names(Permissions::FLAGS).starts_with(&permissions));
+    let conditions = [
+        "disclose_sources",
+        "document_changes",
+        "license_and_copyright_notice",
+        "network_use_is_distribution",
+        "same_license",
+        "notice_file",
+        "mark_modifications",
+    ];
+    assert!(names(Conditions::FLAGS).starts_with(&conditions));
+    let limitations = [
+        "no_liability",
+        "no_trademark_rights",
+        "no_warranty",
+        "no_patent_rights",
+    ];
+    assert!(names(Limitations::FLAGS).starts_with(&limitations));
+}
@@ -0,0 +1,66 @@
+//! Algebraic laws of the set operations on the flag structs.
+
+use license::{Conditions, Limitations, Permissions};
+use proptest::prelude::*;
+
+macro_rules! laws {
+    ($($module:ident: $flags:ident,)*) => {$(
+        mod $module {
+            use super::*;
+
+            fn flags() -> impl Strategy<Value = $flags> {
+                any::<u32>().prop_map($flags::from_bits_truncate)
+            }
+
+            proptest! {
+                #[test]
+                fn bits_round_trip(a in flags()) {
+                    prop_assert_eq!($flags::from_bits(a.bits()), Some(a));
+                }
+
+                #[test]
+                fn union_is_commutative(a in flags(), b in flags()) {
+                    prop_assert_eq!(a.union(b), b.union(a));
+                }
+
+                #[test]
+                fn intersection_is_commutative(a in flags(), b in flags()) {
+                    prop_assert_eq!(a.intersection(b), b.intersection(a));
+                }
+
+                #[test]
+                fn intersection_with_default_is_default(a in flags()) {
+                    prop_assert_eq!(a.intersection($flags::default()), $flags::default());
+                }
+
+                #[test]
+                fn union_with_default_is_identity(a in flags()) {
+                    prop_assert_eq!(a.union($flags::default()), a);
+                }
+
+                #[test]
+                fn difference_then_union_round_trips(a in flags(), b in flags()) {
+                    prop_assert_eq!(a.difference(b).union(b), a.union(b));
+                    prop_assert_eq!(a.difference(b).union(a.intersection(b)), a);
+                }
+
+                #[test]
+                fn operands_are_subsets_of_union(a in flags(), b in flags()) {
+                    prop_assert!(a.is_subset(a.union(b)));
+                    prop_assert!(a.intersection(b).is_subset(a));
+                }
+
+                #[test]
+                fn subsets_are_ordered_before_supersets(a in flags(), b in flags()) {
+                    prop_assert!(a.intersection(b) <= a.union(b));
+                }
+            }
+        }
+    )*};
+}
+
+laws! {
+    permissions: Permissions,
+    conditions: Conditions,
+    limitations: Limitations,
+}
@@ -0,0 +1,98 @@
+use license::{License, LICENSES, LICENSES_EXT};
+use std::collections::BTreeMap;
+
+/// Every license, including the ones combined with an exception.
+fn all_licenses() -> impl Iterator<Item = &'static dyn License> {
+    LICENSES.iter().copied().chain(
+        LICENSES_EXT
+            .iter()
+            .filter(|l| license::from_id(l.id()).is_none())
+            .map(|l| *l as &'static dyn License),
+    )
+}
+
+/// A deprecated form such as `GPL-2.0` is also the id of the deprecated license, which
+/// [`license::from_id`] returns, so it is only checked against the other forms.
+#[test]
+fn no_string_resolves_to_two_licenses() {
+    let mut owners = BTreeMap::new();
+    let mut deprecated_owners = BTreeMap::new();
+    for license in all_licenses() {
+        let identifiers = license.identifiers();
+        for identifier in std::iter::once(identifiers.id()).chain(identifiers.aliases()) {
+            if let Some(other) = owners.insert(identifier, license.id()) {
+                panic!("{} is both {} and {}", identifier, other, license.id());
+            }
+        }
+        for form in identifiers.deprecated_forms() {
+            if let Some(other) = deprecated_owners.insert(form, license.id()) {
+                panic!("{} is both {} and {}", form, other, license.id());
+            }
+        }
+    }
+    for (form, owner) in &deprecated_owners {
+        if let Some(other) = owners.get(form) {
+            assert_eq!(other, form, "{} is both {} and {}", form, other, owner);
+        }
+    }
+}
+
+#[test]
+fn aliases_are_rewritten_to_the_id() {
+    for license in all_licenses() {
+        let identifiers = license.identifiers();
+        for alias in identifiers.aliases() {
+            assert!(license::from_id(alias).is_none(), "{}", alias);
+            for form in [
+                alias.to_string(),
+                alias.to_lowercase(),
+                alias.to_uppercase(),
+            ] {
+                let normalized = license::normalize_legacy(&form).unwrap().to_string();
+                assert_eq!(normalized, license.id(), "{}", form);
+            }
+        }
+    }
+}
+
+#[test]
+fn deprecated_forms_resolve_to_the_license() {
+    let mut count = 0;
+    for license in all_licenses() {
+        for form in license.identifiers().deprecated_forms() {
+            count += 1;
+            match license::from_id(form) {
+                Some(deprecated) => {
+                    assert!(deprecated.is_deprecated(), "{}", form);
+                    let normalized = license::normalize_legacy(form).unwrap().to_string();
+                    assert_eq!(normalized, license.id(), "{}", form);
+                }
+                None => assert_eq!(license::from_id_ext(form).unwrap().id(), license.id()),
+            }
+        }
+    }
+    assert!(count > 0);
+}
+
+#[test]
+fn with_exception() {
+    let openjdk = license::from_id_ext("GPL-2.0-only WITH Classpath-exception-2.0")
+        .unwrap()
+        .identifiers();
+    assert_eq!(openjdk.aliases().count(), 0);
+    assert_eq!(
+        openjdk.all().collect::<Vec<_>>(),
+        [
+            "GPL-2.0-only WITH Classpath-exception-2.0",
+            "GPL-2.0 WITH Classpath-exception-2.0"
+        ]
+    );
+}
+
+#[test]
+fn or_later() {
+    let gpl = license::from_id("GPL-3.0-or-later").unwrap().identifiers();
+    assert_eq!(gpl.id(), "GPL-3.0-or-later");
+    assert_eq!(gpl.aliases().collect::<Vec<_>>(), ["GPLv3+"]);
+    assert_eq!(gpl.deprecated_forms().collect::<Vec<_>>(), ["GPL-3.0+"]);
+}
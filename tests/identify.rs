@@ -0,0 +1,146 @@
+#![cfg(feature = "alloc")]
+
+use license::{Identification, Method};
+
+fn identify(input: &str) -> Identification {
+    license::identify_str(input).unwrap()
+}
+
+#[track_caller]
+fn assert_identified(found: &Identification, id: &str, method: Method, confidence: f32) {
+    assert_eq!(found.license.license.id(), id);
+    assert_eq!(found.method, method);
+    assert_eq!(found.confidence, confidence);
+}
+
+#[test]
+fn exact_ids() {
+    let mit = identify(" MIT\n");
+    assert_identified(&mit, "MIT", Method::ExactId, 1.0);
+    assert!(mit.input_excerpt.is_none());
+
+    let apache = identify("Apache-2.0+");
+    assert_identified(&apache, "Apache-2.0", Method::ExactId, 1.0);
+    assert!(apache.license.or_later);
+
+    let classpath = identify("GPL-2.0-only WITH Classpath-exception-2.0");
+    assert_identified(
+        &classpath,
+        "GPL-2.0-only WITH Classpath-exception-2.0",
+        Method::ExactId,
+        1.0,
+    );
+}
+
+#[test]
+fn deprecated_ids() {
+    let gpl = identify("GPL-2.0");
+    assert_identified(&gpl, "GPL-2.0-only", Method::DeprecatedId, 0.95);
+    assert!(gpl.license.deprecated_form && !gpl.license.or_later);
+
+    let later = identify("GPL-2.0+");
+    assert_identified(&later, "GPL-2.0-or-later", Method::DeprecatedId, 0.95);
+    assert!(later.license.or_later);
+
+    let classpath = identify("GPL-2.0 WITH Classpath-exception-2.0");
+    assert_identified(
+        &classpath,
+        "GPL-2.0-only WITH Classpath-exception-2.0",
+        Method::DeprecatedId,
+        0.95,
+    );
+}
+
+#[test]
+fn aliases() {
+    assert_identified(&identify("GPLv3+"), "GPL-3.0-or-later", Method::Alias, 0.9);
+    assert_identified(&identify("MIT/X11"), "MIT", Method::Alias, 0.9);
+    assert_identified(&identify("apache2"), "Apache-2.0", Method::Alias, 0.9);
+    assert_identified(&identify("mit"), "MIT", Method::Alias, 0.9);
+    // Names that don't say if later versions are allowed.
+    assert_identified(&identify("GPLv3"), "GPL-3.0-only", Method::Alias, 0.8);
+    // A deprecated id in another case is still deprecated.
+    assert_identified(
+        &identify("gpl-2.0"),
+        "GPL-2.0-only",
+        Method::DeprecatedId,
+        0.95,
+    );
+}
+
+#[test]
+fn texts() {
+    let bsd = license::from_id("BSD-3-Clause").unwrap().text();
+    let exact = identify(&bsd.replace("<year> <owner>", "2024 Example Corp"));
+    assert_identified(&exact, "BSD-3-Clause", Method::TextExact, 1.0);
+    assert_eq!(
+        exact.input_excerpt.as_deref(),
+        Some("Copyright (c) 2024 Example Corp. All rights reserved.")
+    );
+
+    let mit = license::from_id("MIT").unwrap().text();
+    let variant = mit.replace(
+        "The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.",
+        "The above copyright notice shall be included in all copies of the Software,\nand the Software shall not be used for evil.",
+    );
+    let fuzzy = identify(&variant);
+    assert_eq!(fuzzy.license.license.id(), "MIT");
+    assert_eq!(fuzzy.method, Method::TextFuzzy);
+    let similarity = license::text::best_match(&variant).unwrap().similarity();
+    assert_eq!(fuzzy.confidence, 0.9 * similarity);
+    assert!(fuzzy.confidence < 0.8);
+    assert_eq!(fuzzy.input_excerpt.as_deref(), Some("MIT License"));
+}
+
+#[test]
+fn excerpts_are_short() {
+    let gpl = license::from_id("GPL-3.0-only").unwrap().text();
+    let found = identify(&format!("{}\n\n{}", "x".repeat(200), gpl));
+    assert_eq!(found.license.license.id(), "GPL-3.0-only");
+    assert_eq!(found.input_excerpt.unwrap().chars().count(), 80);
+}
+
+#[test]
+fn unknown() {
+    for input in ["", "Custom License v1", "MIT-2.0", "All rights reserved."] {
+        assert!(license::identify_str(input).is_none(), "{:?}", input);
+    }
+}
+
+#[test]
+fn expression_leaves() {
+    let expression =
+        license::declared!("(MIT OR Apache-2.0 WITH LLVM-exception) AND GPL-2.0 WITH Classpath-exception-2.0 AND BSD-3-Clause+");
+    let identified = expression.identify();
+    let ids: Vec<_> = identified.iter().map(|i| i.license.license.id()).collect();
+    assert_eq!(
+        ids,
+        [
+            "MIT",
+            "Apache-2.0",
+            "GPL-2.0-only WITH Classpath-exception-2.0",
+            "BSD-3-Clause"
+        ]
+    );
+    let written: Vec<_> = identified
+        .iter()
+        .map(|i| i.input_excerpt.as_deref().unwrap())
+        .collect();
+    assert_eq!(
+        written,
+        [
+            "MIT",
+            "Apache-2.0 WITH LLVM-exception",
+            "GPL-2.0 WITH Classpath-exception-2.0",
+            "BSD-3-Clause+"
+        ]
+    );
+    for found in &identified {
+        assert_eq!(
+            (found.method, found.confidence),
+            (Method::ExpressionLeaf, 1.0)
+        );
+    }
+    assert!(identified[2].license.deprecated_form);
+    assert!(identified[3].license.or_later);
+}
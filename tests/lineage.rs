@@ -0,0 +1,47 @@
+#[test]
+fn lineage_is_symmetric() {
+    for license in license::LICENSES {
+        if let Some(next) = license.successor() {
+            let back = next.predecessor().unwrap();
+            assert_eq!(back.id(), license.id());
+        }
+        if let Some(previous) = license.predecessor() {
+            let forward = previous.successor().unwrap();
+            assert_eq!(forward.id(), license.id());
+        }
+    }
+}
+
+#[test]
+fn versions_are_published_in_order() {
+    for license in license::LICENSES {
+        if let Some(next) = license.successor() {
+            let (year, next_year) = (license.published_year(), next.published_year());
+            assert!(year.is_some() && year <= next_year, "{}", license.id());
+        }
+    }
+}
+
+#[test]
+fn lineages() {
+    let chain = |id: &str| {
+        let mut license = license::from_id(id).unwrap();
+        let mut ids = vec![license.id()];
+        while let Some(next) = license.successor() {
+            ids.push(next.id());
+            license = next;
+        }
+        ids
+    };
+    assert_eq!(chain("GPL-2.0-only"), ["GPL-2.0-only", "GPL-3.0-only"]);
+    assert_eq!(chain("MPL-1.1"), ["MPL-1.1", "MPL-2.0"]);
+    assert_eq!(chain("EUPL-1.1"), ["EUPL-1.1", "EUPL-1.2"]);
+    assert_eq!(chain("AFL-2.1"), ["AFL-2.1", "AFL-3.0"]);
+    assert_eq!(chain("OSL-2.1"), ["OSL-2.1", "OSL-3.0"]);
+    assert_eq!(chain("CPL-1.0"), ["CPL-1.0", "EPL-1.0", "EPL-2.0"]);
+    assert!(license::from_id("GPL-1.0-only")
+        .unwrap()
+        .predecessor()
+        .is_none());
+    assert!(license::from_id("GPL-2.0").unwrap().successor().is_none());
+}
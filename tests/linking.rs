@@ -0,0 +1,102 @@
+use license::{Confidence, Coverage, LinkObligations, Linkage, LICENSES_EXT};
+
+const LINKAGES: [Linkage; 4] = [
+    Linkage::Static,
+    Linkage::Dynamic,
+    Linkage::SeparateProcess,
+    Linkage::NetworkApi,
+];
+
+fn obligations(id: &str, link: Linkage) -> LinkObligations {
+    license::from_id_ext(id)
+        .unwrap()
+        .obligations_for_linking(link)
+}
+
+#[test]
+fn lgpl_dynamic_linking() {
+    for id in ["LGPL-2.1-only", "LGPL-3.0-only"] {
+        let dynamic = obligations(id, Linkage::Dynamic);
+        assert_eq!(dynamic.coverage(), Coverage::LibraryOnly);
+        assert!(dynamic.relinking() && dynamic.notice() && dynamic.library_source());
+        assert_eq!(dynamic.confidence(), Confidence::High);
+
+        let fixed = obligations(id, Linkage::Static);
+        assert_eq!(fixed.coverage(), Coverage::LibraryOnly);
+        assert!(fixed.relinking());
+        assert_eq!(fixed.confidence(), Confidence::Low);
+    }
+}
+
+#[test]
+fn gpl_covers_linked_programs() {
+    for id in ["GPL-2.0-only", "GPL-3.0-only", "AGPL-3.0-only"] {
+        assert_eq!(
+            obligations(id, Linkage::Static).coverage(),
+            Coverage::CombinedWork
+        );
+        assert_eq!(
+            obligations(id, Linkage::Dynamic).coverage(),
+            Coverage::CombinedWork
+        );
+        assert_eq!(
+            obligations(id, Linkage::SeparateProcess).coverage(),
+            Coverage::LibraryOnly
+        );
+    }
+    assert!(!obligations("GPL-3.0-only", Linkage::NetworkApi).library_source());
+    assert!(obligations("AGPL-3.0-only", Linkage::NetworkApi).library_source());
+
+    let classpath = "GPL-2.0-only WITH Classpath-exception-2.0";
+    assert_eq!(
+        obligations(classpath, Linkage::Static).coverage(),
+        Coverage::LibraryOnly
+    );
+}
+
+#[test]
+fn mpl_is_file_level() {
+    for link in LINKAGES.iter() {
+        let mpl = obligations("MPL-2.0", *link);
+        assert_eq!(mpl.coverage(), Coverage::LibraryOnly);
+        assert!(!mpl.relinking());
+        assert_eq!(mpl.library_source(), *link != Linkage::NetworkApi);
+    }
+}
+
+#[test]
+fn permissive_licenses_are_uniform() {
+    for link in LINKAGES.iter() {
+        let mit = obligations("MIT", *link);
+        for id in ["Apache-2.0", "BSD-3-Clause", "Zlib", "ISC"] {
+            if let Some(license) = license::from_id_ext(id) {
+                assert_eq!(license.obligations_for_linking(*link), mit, "{}", id);
+            }
+        }
+        assert_eq!(mit.coverage(), Coverage::LibraryOnly);
+        assert_eq!(mit.notice(), *link != Linkage::NetworkApi);
+        assert!(!mit.library_source() && !mit.relinking());
+    }
+    let cc0 = obligations("CC0-1.0", Linkage::Static);
+    assert!(!cc0.notice());
+}
+
+#[test]
+fn uncurated_copyleft_is_unclear() {
+    for license in LICENSES_EXT {
+        let obligations = license.obligations_for_linking(Linkage::Static);
+        if obligations.coverage() == Coverage::Unclear {
+            assert_eq!(
+                obligations.confidence(),
+                Confidence::Low,
+                "{}",
+                license.id()
+            );
+            assert!(obligations.notice(), "{}", license.id());
+        }
+    }
+    assert_eq!(
+        obligations("EUPL-1.2", Linkage::Dynamic).coverage(),
+        Coverage::Unclear
+    );
+}
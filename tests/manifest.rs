@@ -0,0 +1,192 @@
+#![cfg(feature = "alloc")]
+
+use license::manifest::{self, Declared};
+
+/// The normalized expression of a declaration, if it is one.
+fn expression(declared: Declared) -> Option<String> {
+    match declared {
+        Declared::Expression(e) => Some(e.to_string()),
+        _ => None,
+    }
+}
+
+/// Classifiers of packages on PyPI and the ids they map to.
+const CLASSIFIERS: &[(&str, &str)] = &[
+    ("License :: Aladdin Free Public License (AFPL)", "Aladdin"),
+    ("License :: CC0 1.0 Universal (CC0 1.0) Public Domain Dedication", "CC0-1.0"),
+    ("License :: OSI Approved :: Apache Software License", "Apache-2.0"),
+    ("License :: OSI Approved :: Attribution Assurance License", "AAL"),
+    ("License :: OSI Approved :: Boost Software License 1.0 (BSL-1.0)", "BSL-1.0"),
+    ("License :: OSI Approved :: CEA CNRS Inria Logiciel Libre License, version 2.1 (CeCILL-2.1)", "CECILL-2.1"),
+    ("License :: OSI Approved :: Common Development and Distribution License 1.0 (CDDL-1.0)", "CDDL-1.0"),
+    ("License :: OSI Approved :: Common Public License", "CPL-1.0"),
+    ("License :: OSI Approved :: Eclipse Public License 1.0 (EPL-1.0)", "EPL-1.0"),
+    ("License :: OSI Approved :: Eclipse Public License 2.0 (EPL-2.0)", "EPL-2.0"),
+    ("License :: OSI Approved :: European Union Public Licence 1.0 (EUPL 1.0)", "EUPL-1.0"),
+    ("License :: OSI Approved :: European Union Public Licence 1.1 (EUPL 1.1)", "EUPL-1.1"),
+    ("License :: OSI Approved :: European Union Public Licence 1.2 (EUPL 1.2)", "EUPL-1.2"),
+    ("License :: OSI Approved :: GNU Affero General Public License v3", "AGPL-3.0-only"),
+    ("License :: OSI Approved :: GNU Affero General Public License v3 or later (AGPLv3+)", "AGPL-3.0-or-later"),
+    ("License :: OSI Approved :: GNU General Public License v2 (GPLv2)", "GPL-2.0-only"),
+    ("License :: OSI Approved :: GNU General Public License v2 or later (GPLv2+)", "GPL-2.0-or-later"),
+    ("License :: OSI Approved :: GNU General Public License v3 (GPLv3)", "GPL-3.0-only"),
+    ("License :: OSI Approved :: GNU General Public License v3 or later (GPLv3+)", "GPL-3.0-or-later"),
+    ("License :: OSI Approved :: GNU Lesser General Public License v2 (LGPLv2)", "LGPL-2.0-only"),
+    ("License :: OSI Approved :: GNU Lesser General Public License v2 or later (LGPLv2+)", "LGPL-2.0-or-later"),
+    ("License :: OSI Approved :: GNU Lesser General Public License v3 (LGPLv3)", "LGPL-3.0-only"),
+    ("License :: OSI Approved :: GNU Lesser General Public License v3 or later (LGPLv3+)", "LGPL-3.0-or-later"),
+    ("License :: OSI Approved :: Historical Permission Notice and Disclaimer (HPND)", "HPND"),
+    ("License :: OSI Approved :: IBM Public License", "IPL-1.0"),
+    ("License :: OSI Approved :: ISC License (ISCL)", "ISC"),
+    ("License :: OSI Approved :: Intel Open Source License", "Intel"),
+    ("License :: OSI Approved :: MIT License", "MIT"),
+    ("License :: OSI Approved :: Motosoto License", "Motosoto"),
+    ("License :: OSI Approved :: Mozilla Public License 1.0 (MPL)", "MPL-1.0"),
+    ("License :: OSI Approved :: Mozilla Public License 1.1 (MPL 1.1)", "MPL-1.1"),
+    ("License :: OSI Approved :: Mozilla Public License 2.0 (MPL 2.0)", "MPL-2.0"),
+    ("License :: OSI Approved :: Nokia Open Source License", "Nokia"),
+    ("License :: OSI Approved :: Open Group Test Suite License", "OGTSL"),
+    ("License :: OSI Approved :: Open Software License 3.0 (OSL-3.0)", "OSL-3.0"),
+    ("License :: OSI Approved :: PostgreSQL License", "PostgreSQL"),
+    ("License :: OSI Approved :: Python License (CNRI Python License)", "CNRI-Python"),
+    ("License :: OSI Approved :: Python Software Foundation License", "PSF-2.0"),
+    ("License :: OSI Approved :: Qt Public License (QPL)", "QPL-1.0"),
+    ("License :: OSI Approved :: Ricoh Source Code Public License", "RSCPL"),
+    ("License :: OSI Approved :: SIL Open Font License 1.1 (OFL-1.1)", "OFL-1.1"),
+    ("License :: OSI Approved :: Sleepycat License", "Sleepycat"),
+    ("License :: OSI Approved :: Sun Industry Standards Source License (SISSL)", "SISSL"),
+    ("License :: OSI Approved :: Sun Public License", "SPL-1.0"),
+    ("License :: OSI Approved :: The Unlicense (Unlicense)", "Unlicense"),
+    ("License :: OSI Approved :: Universal Permissive License (UPL)", "UPL-1.0"),
+    ("License :: OSI Approved :: University of Illinois/NCSA Open Source License", "NCSA"),
+    ("License :: OSI Approved :: Vovida Software License 1.0", "VSL-1.0"),
+    ("License :: OSI Approved :: W3C License", "W3C"),
+    ("License :: OSI Approved :: X.Net License", "Xnet"),
+    ("License :: OSI Approved :: Zero-Clause BSD (0BSD)", "0BSD"),
+    ("License :: OSI Approved :: zlib/libpng License", "Zlib"),
+];
+
+#[test]
+fn classifiers() {
+    for &(classifier, id) in CLASSIFIERS {
+        assert_eq!(
+            expression(manifest::from_python_classifier(classifier)).as_deref(),
+            Some(id),
+            "{}",
+            classifier
+        );
+        assert!(
+            license::from_id(id).is_some(),
+            "{}: unknown id {}",
+            classifier,
+            id
+        );
+    }
+}
+
+#[test]
+fn classifier_spacing() {
+    assert_eq!(
+        expression(manifest::from_python_classifier(
+            "License::OSI Approved  ::  MIT License "
+        ))
+        .as_deref(),
+        Some("MIT")
+    );
+}
+
+#[test]
+fn classifiers_without_a_single_license() {
+    for classifier in &[
+        "License :: OSI Approved :: BSD License",
+        "License :: OSI Approved :: GNU General Public License (GPL)",
+        "License :: OSI Approved",
+        "License :: Public Domain",
+        "License :: Freely Distributable",
+        "License :: OSI Approved :: MIT License :: Extra",
+        "Programming Language :: Rust",
+    ] {
+        assert_eq!(
+            manifest::from_python_classifier(classifier),
+            Declared::Unknown(classifier)
+        );
+    }
+    assert_eq!(
+        manifest::from_python_classifier("License :: Other/Proprietary License"),
+        Declared::Proprietary
+    );
+}
+
+#[test]
+fn npm_fields() {
+    let cases = [
+        ("MIT", "MIT"),
+        ("(MIT OR Apache-2.0)", "(MIT OR Apache-2.0)"),
+        ("BSD-3-Clause", "BSD-3-Clause"),
+        ("mit", "MIT"),
+    ];
+    for &(field, normalized) in &cases {
+        assert_eq!(
+            expression(manifest::from_npm_field(field)).as_deref(),
+            Some(normalized),
+            "{}",
+            field
+        );
+    }
+    assert_eq!(
+        manifest::from_npm_field("SEE LICENSE IN LICENSE.txt"),
+        Declared::File("LICENSE.txt")
+    );
+    assert_eq!(
+        manifest::from_npm_field(" UNLICENSED "),
+        Declared::Proprietary
+    );
+    assert_eq!(
+        manifest::from_npm_field("SEE LICENSE IN "),
+        Declared::Unknown("SEE LICENSE IN ")
+    );
+    assert_eq!(manifest::from_npm_field(""), Declared::Unknown(""));
+}
+
+#[test]
+fn names_that_are_not_expressions_are_unknown() {
+    for field in [
+        "some random text",
+        "GPL",
+        "Apache 2.0",
+        "SEE LICENSE INLICENSE",
+        "MIT OR",
+        "MIT AND (Apache-2.0",
+        "BSD-3-Clause WITH Foo",
+    ] {
+        assert_eq!(manifest::from_npm_field(field), Declared::Unknown(field));
+        assert_eq!(manifest::from_cargo_field(field), Declared::Unknown(field));
+    }
+    assert_eq!(
+        expression(manifest::from_cargo_field("LicenseRef-Vendor OR mit")).as_deref(),
+        Some("LicenseRef-Vendor OR MIT")
+    );
+}
+
+#[test]
+fn cargo_fields() {
+    let cases = [
+        ("MIT OR Apache-2.0", "MIT OR Apache-2.0"),
+        ("MIT/Apache-2.0", "MIT OR Apache-2.0"),
+        ("Apache-2.0 / MIT", "Apache-2.0 OR MIT"),
+        ("BSD-3-Clause AND Zlib", "BSD-3-Clause AND Zlib"),
+        ("GPL-2.0+", "GPL-2.0-or-later"),
+    ];
+    for &(field, normalized) in &cases {
+        assert_eq!(
+            expression(manifest::from_cargo_field(field)).as_deref(),
+            Some(normalized),
+            "{}",
+            field
+        );
+    }
+    assert_eq!(
+        manifest::from_cargo_field("MIT/"),
+        Declared::Unknown("MIT/")
+    );
+}
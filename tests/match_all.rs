@@ -0,0 +1,68 @@
+#![cfg(feature = "alloc")]
+
+use license::text;
+
+/// A project's own MIT license, followed by the Apache-2.0 and the Zlib of vendored code,
+/// with notes about the vendored code between them.
+const MULTI_LICENSE: &str = include_str!("fixtures/multi-license.txt");
+
+fn ids(text: &str) -> Vec<&'static str> {
+    text::match_all(text)
+        .iter()
+        .map(|m| m.license().id())
+        .collect()
+}
+
+#[test]
+fn concatenated_licenses_are_found_in_order() {
+    let matches = text::match_all(MULTI_LICENSE);
+    assert_eq!(
+        matches.iter().map(|m| m.license().id()).collect::<Vec<_>>(),
+        ["MIT", "Apache-2.0", "Zlib"]
+    );
+    for m in &matches {
+        assert!(m.confidence() > 0.9, "{:?}", m);
+    }
+
+    let regions: Vec<&str> = matches.iter().map(|m| &MULTI_LICENSE[m.range()]).collect();
+    assert!(regions[0].starts_with("MIT License\n\nCopyright (c) 2021 Example Project"));
+    assert!(regions[0].ends_with("OTHER DEALINGS IN THE\nSOFTWARE."));
+    assert!(regions[1].starts_with("Apache License\nVersion 2.0, January 2004"));
+    assert!(regions[1].ends_with("limitations under the License."));
+    assert!(regions[2].starts_with("Copyright (C) 1995-2017 Jean-loup Gailly"));
+    assert!(regions[2].ends_with("altered from any source distribution."));
+
+    // The notes between the licenses are left out.
+    for note in ["vendor/parser", "vendor/zlib"] {
+        assert!(regions.iter().all(|region| !region.contains(note)));
+    }
+}
+
+#[test]
+fn overlapping_matches_keep_the_longest() {
+    // The X11 text contains the MIT text, which matches MIT but is shorter.
+    let x11 = license::from_id("X11").unwrap().text();
+    assert_eq!(ids(x11), ["X11"]);
+
+    let mit = license::from_id("MIT").unwrap().text();
+    assert_eq!(ids(mit), ["MIT"]);
+    assert_eq!(ids(&format!("{}\n{}", mit, mit)), ["MIT", "MIT"]);
+}
+
+#[test]
+fn single_licenses_match_themselves() {
+    for id in ["Apache-2.0", "BSD-3-Clause", "GPL-3.0-only", "MPL-2.0"] {
+        let license = license::from_id(id).unwrap();
+        let matches = text::match_all(license.text());
+        assert_eq!(matches.len(), 1, "{}", id);
+        assert_eq!(matches[0].license().id(), id);
+        assert_eq!(matches[0].range(), 0..license.text().trim_end().len());
+        assert_eq!(matches[0].confidence(), 1.0);
+    }
+}
+
+#[test]
+fn unrelated_text_has_no_match() {
+    assert!(text::match_all("").is_empty());
+    assert!(text::match_all("See the README for the license of each crate.").is_empty());
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "alloc")]
+
+use license::text;
+
+/// The GPL-3.0 up to its section 7.
+fn truncated_gpl() -> &'static str {
+    let gpl = license::from_id("GPL-3.0-only").unwrap().text();
+    &gpl[..gpl.find("7. Additional Terms.").unwrap()]
+}
+
+#[test]
+fn truncated_text_misses_the_later_sections() {
+    let report = text::match_report(truncated_gpl(), 3);
+    let candidates = report.candidates();
+    assert_eq!(candidates.len(), 3);
+    let best = &candidates[0];
+    assert_eq!(best.license().id(), "GPL-3.0-only");
+    assert!(best.coverage() > 0.4 && best.coverage() < 0.6, "{:?}", best);
+
+    let numbers: Vec<_> = best.missing().iter().map(|s| s.number()).collect();
+    let mut expected: Vec<_> = [
+        "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17",
+    ]
+    .iter()
+    .map(|&n| Some(n))
+    .collect();
+    expected.push(None);
+    assert_eq!(numbers, expected);
+    assert_eq!(best.missing()[11].title(), "END OF TERMS AND CONDITIONS");
+}
+
+#[test]
+fn report_names_the_missing_sections() {
+    let report = text::match_report(truncated_gpl(), 1).to_string();
+    assert!(report
+        .starts_with("GPL-3.0-only: 51% found\n  missing: 7. Additional Terms; 8. Termination; "));
+    assert!(report
+        .ends_with("; 17. Interpretation of Sections 15 and 16; END OF TERMS AND CONDITIONS\n"));
+    assert_eq!(report.lines().count(), 2);
+}
+
+#[test]
+fn complete_text_misses_nothing() {
+    let apache = license::from_id("Apache-2.0").unwrap().text();
+    let report = text::match_report(apache, 1);
+    let best = &report.candidates()[0];
+    assert_eq!(best.license().id(), "Apache-2.0");
+    assert_eq!(best.coverage(), 1.0);
+    assert!(best.missing().is_empty());
+    assert_eq!(report.to_string(), "Apache-2.0: 100% found\n");
+}
+
+#[test]
+fn deprecated_ids_come_after_their_replacements() {
+    let report = text::match_report(truncated_gpl(), 3);
+    let ids: Vec<_> = report
+        .candidates()
+        .iter()
+        .map(|c| c.license().id())
+        .collect();
+    assert_eq!(ids, ["GPL-3.0-only", "GPL-3.0-or-later", "GPL-3.0"]);
+}
+
+#[test]
+fn unrelated_text_has_no_candidates() {
+    let report = text::match_report("hello world", 5);
+    assert!(report.candidates().is_empty());
+    assert_eq!(report.to_string(), "No similar license.\n");
+    assert!(text::match_report(truncated_gpl(), 0)
+        .candidates()
+        .is_empty());
+}
@@ -0,0 +1,144 @@
+#![cfg(feature = "alloc")]
+
+use license::text::{self, Matcher};
+use license::License;
+
+fn id(m: Option<text::Match>) -> Option<&'static str> {
+    m.map(|m| m.license().id())
+}
+
+#[test]
+fn matcher_agrees_with_best_match() {
+    let matcher = Matcher::new();
+    assert_eq!(matcher.licenses().count(), license::LICENSES.len());
+    let mit = license::from_id("MIT").unwrap().text();
+    let x11 = format!(
+        "{}\n\nExcept as contained in this notice, the name of Example Corp shall not be \
+         used in advertising or otherwise to promote the sale, use or other dealings in \
+         this Software without prior written authorization from Example Corp.",
+        mit
+    );
+    let mpl = license::from_id("MPL-2.0").unwrap().text();
+    let exhibit_b = format!(
+        "{}\n\nThis Source Code Form is \"Incompatible With Secondary Licenses\", as \
+         defined by the Mozilla Public License, v. 2.0.",
+        mpl
+    );
+    let texts = [
+        mit,
+        &x11,
+        mpl,
+        &exhibit_b,
+        "Redistribution and use",
+        "",
+        "hello",
+    ];
+    for text in texts.iter() {
+        let (m, best) = (matcher.match_one(text), text::best_match(text));
+        assert_eq!(id(m), id(best), "{}", text);
+        assert_eq!(m.map(|m| m.similarity()), best.map(|m| m.similarity()));
+    }
+    assert_eq!(id(matcher.match_one(&x11)), Some("X11"));
+    assert_eq!(
+        id(matcher.match_one(&exhibit_b)),
+        Some("MPL-2.0-no-copyleft-exception")
+    );
+    for license in license::LICENSES.iter().step_by(150) {
+        let text = license.text();
+        assert_eq!(id(matcher.match_one(text)), id(text::best_match(text)));
+    }
+}
+
+#[test]
+fn matcher_only_knows_its_licenses() {
+    let licenses: [&'static dyn License; 2] = [
+        license::from_id("Apache-2.0").unwrap(),
+        license::from_id("MIT").unwrap(),
+    ];
+    let matcher = Matcher::with_licenses(&licenses);
+    let ids: Vec<_> = matcher.licenses().map(|l| l.id()).collect();
+    assert_eq!(ids, ["Apache-2.0", "MIT"]);
+    let isc = license::from_id("ISC").unwrap().text();
+    assert_eq!(id(matcher.match_one(isc)), Some("MIT"));
+    // X11 isn't one of the licenses, so MIT stays the match.
+    let x11 = license::from_id("X11").unwrap().text();
+    assert_eq!(id(matcher.match_one(x11)), Some("MIT"));
+    assert_eq!(id(Matcher::with_licenses(&[]).match_one(isc)), None);
+}
+
+#[test]
+fn matcher_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+    let matcher = Matcher::default();
+    assert_send_sync(&matcher);
+    assert!(format!("{:?}", matcher).starts_with("Matcher { len: "));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn match_many_keeps_the_order() {
+    let matcher = Matcher::new();
+    let texts: Vec<&str> = ["Apache-2.0", "MIT", "Zlib", "GPL-3.0-only", "ISC"]
+        .iter()
+        .map(|id| license::from_id(id).unwrap().text())
+        .chain(Some("hello"))
+        .collect();
+    let many: Vec<_> = matcher.match_many(&texts).into_iter().map(id).collect();
+    let one: Vec<_> = texts
+        .iter()
+        .map(|text| id(matcher.match_one(text)))
+        .collect();
+    assert_eq!(many, one);
+    assert_eq!(many[..3], [Some("Apache-2.0"), Some("MIT"), Some("Zlib")]);
+    assert_eq!(many[5], None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn cached_matches_agree_with_uncached() {
+    let licenses: Vec<&'static dyn License> = ["Apache-2.0", "BSD-2-Clause", "ISC", "MIT", "X11"]
+        .iter()
+        .map(|id| license::from_id(id).unwrap())
+        .collect();
+    let uncached = Matcher::with_licenses(&licenses);
+    let cached = Matcher::with_licenses(&licenses).with_cache(3);
+    assert_eq!(uncached.cache_stats(), None);
+    let texts: Vec<&str> = licenses
+        .iter()
+        .map(|license| license.text())
+        .chain(Some("Nothing in common"))
+        .cycle()
+        .take(18)
+        .collect();
+    for round in 0..2 {
+        for text in &texts {
+            let (a, b) = (uncached.match_one(text), cached.match_one(text));
+            assert_eq!(id(a), id(b), "{}", round);
+            assert_eq!(a.map(|m| m.similarity()), b.map(|m| m.similarity()));
+        }
+    }
+    // Six distinct texts in a cache of three, cycled, always miss.
+    let stats = cached.cache_stats().unwrap();
+    assert_eq!((stats.hits(), stats.misses()), (0, 36));
+    assert_eq!((stats.len(), stats.capacity()), (3, 3));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn cache_keeps_the_recently_used_texts() {
+    let licenses = [
+        license::from_id("ISC").unwrap(),
+        license::from_id("MIT").unwrap(),
+    ];
+    let matcher = Matcher::with_licenses(&licenses).with_cache(2);
+    let (isc, mit) = (licenses[0].text(), licenses[1].text());
+    for text in &[isc, mit, isc, "ISC", isc, mit] {
+        matcher.match_one(text);
+    }
+    // "ISC" evicted MIT, the least recently used, which then evicted "ISC".
+    let stats = matcher.cache_stats().unwrap();
+    assert_eq!((stats.hits(), stats.misses()), (2, 4));
+    assert_eq!(id(matcher.clone().match_one(isc)), Some("ISC"));
+    assert_eq!(matcher.cache_stats().unwrap().hits(), 2);
+    assert_eq!(Matcher::new().with_cache(0).cache_stats(), None);
+}
@@ -0,0 +1,102 @@
+use license::compat::{Compatibility, Reason};
+use license::migration::{self, MigrationReport};
+use license::report::Copyleft;
+
+fn analyze(from: &str, to: &str) -> MigrationReport<'static> {
+    migration::analyze(
+        license::from_id_ext(from).unwrap(),
+        license::from_id_ext(to).unwrap(),
+    )
+}
+
+#[test]
+fn gpl_to_mit_loses_copyleft() {
+    let report = analyze("GPL-3.0-only", "MIT");
+    assert!(report.requires_consent_of_all_holders());
+    assert_eq!(
+        (report.from_copyleft(), report.to_copyleft()),
+        (Copyleft::Strong, Copyleft::None)
+    );
+    let removed = report.removed_conditions();
+    assert!(removed.same_license() && removed.disclose_sources());
+    assert!(report.added_conditions().is_empty());
+    assert!(report.lost_permissions().patent_rights());
+    match report.to_incorporates_from() {
+        Compatibility::Incompatible(i) => assert_eq!(
+            i.reason(),
+            Reason::CopyleftConflict {
+                required: "GPL-3.0-only",
+                actual: "MIT"
+            }
+        ),
+        other => panic!("{:?}", other),
+    }
+    assert_eq!(report.from_incorporates_to(), Compatibility::Compatible);
+    assert_eq!(
+        report.to_string(),
+        "# Migration from GPL-3.0-only to MIT\n\
+         \n\
+         - Copyleft: strong to none\n\
+         - Consent of all copyright holders: required\n\
+         - MIT can incorporate GPL-3.0-only code: no, because the combined work must be \
+         distributed under GPL-3.0-only, not MIT\n\
+         - GPL-3.0-only can incorporate MIT code: yes\n\
+         \n\
+         ## Permissions\n\
+         \n\
+         - Gained: none\n\
+         - Lost: Patent rights\n\
+         \n\
+         ## Conditions\n\
+         \n\
+         - Added: none\n\
+         - Removed: Disclose sources, Document changes, Same license\n\
+         \n\
+         ## Limitations\n\
+         \n\
+         - Added: none\n\
+         - Removed: none\n"
+    );
+}
+
+#[test]
+fn mit_to_apache_gains_a_patent_grant() {
+    let report = analyze("MIT", "Apache-2.0");
+    assert!(!report.requires_consent_of_all_holders());
+    assert!(report.gained_permissions().patent_rights());
+    assert!(report.lost_permissions().is_empty());
+    assert!(report.added_conditions().notice_file());
+    assert!(report.added_limitations().no_trademark_rights());
+    assert_eq!(report.to_incorporates_from(), Compatibility::Compatible);
+    assert_eq!(report.from_incorporates_to(), Compatibility::Compatible);
+    assert_eq!(report.from().id(), "MIT");
+    assert_eq!(report.to().id(), "Apache-2.0");
+}
+
+#[test]
+fn stronger_copyleft_needs_no_consent_if_it_incorporates() {
+    assert!(!analyze("MIT", "GPL-3.0-only").requires_consent_of_all_holders());
+    assert!(!analyze("GPL-3.0-only", "AGPL-3.0-only").requires_consent_of_all_holders());
+    // GPL-2.0-only can't incorporate Apache-2.0 code, whatever the copyleft.
+    assert!(analyze("Apache-2.0", "GPL-2.0-only").requires_consent_of_all_holders());
+}
+
+#[test]
+fn migrating_to_the_same_license_changes_nothing() {
+    let report = analyze("MPL-2.0", "MPL-2.0");
+    assert!(!report.requires_consent_of_all_holders());
+    let unchanged = report.gained_permissions().is_empty()
+        && report.lost_permissions().is_empty()
+        && report.added_conditions().is_empty()
+        && report.removed_conditions().is_empty()
+        && report.added_limitations().is_empty()
+        && report.removed_limitations().is_empty();
+    assert!(unchanged);
+}
+
+#[test]
+fn unknown_license_needs_consent() {
+    let mit = license::from_id_ext("MIT").unwrap();
+    let report = migration::analyze(license::UNKNOWN, mit);
+    assert!(report.requires_consent_of_all_holders());
+}
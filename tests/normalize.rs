@@ -0,0 +1,170 @@
+use license::NormalizeError;
+
+/// Fields in the style of crates.io metadata, their normalized form, and if it is lossy.
+const CASES: &[(&str, &str, bool)] = &[
+    // Alternatives written with a slash.
+    ("MIT/Apache-2.0", "MIT OR Apache-2.0", false),
+    ("Apache-2.0/MIT", "Apache-2.0 OR MIT", false),
+    ("MIT / Apache-2.0", "MIT OR Apache-2.0", false),
+    ("Unlicense/MIT", "Unlicense OR MIT", false),
+    (
+        "MIT/Apache-2.0/BSD-3-Clause",
+        "MIT OR Apache-2.0 OR BSD-3-Clause",
+        false,
+    ),
+    // Alternatives next to an `AND` or a `WITH` may not be what was meant.
+    (
+        "MIT/Apache-2.0 AND BSD-3-Clause",
+        "MIT OR Apache-2.0 AND BSD-3-Clause",
+        true,
+    ),
+    (
+        "Zlib and MIT/Apache-2.0",
+        "Zlib AND MIT OR Apache-2.0",
+        true,
+    ),
+    (
+        "MIT/GPL-2.0-only WITH Classpath-exception-2.0",
+        "MIT OR GPL-2.0-only WITH Classpath-exception-2.0",
+        true,
+    ),
+    (
+        "(MIT/Apache-2.0) AND BSD-3-Clause",
+        "(MIT OR Apache-2.0) AND BSD-3-Clause",
+        false,
+    ),
+    (
+        "MIT/(Apache-2.0 AND BSD-3-Clause)",
+        "MIT OR (Apache-2.0 AND BSD-3-Clause)",
+        false,
+    ),
+    ("MIT/Apache-2.0 OR Zlib", "MIT OR Apache-2.0 OR Zlib", false),
+    // Casing of operators and ids.
+    ("BSD-3-Clause and MIT", "BSD-3-Clause AND MIT", false),
+    ("mit or apache-2.0", "MIT OR Apache-2.0", false),
+    ("MIT Or Zlib", "MIT OR Zlib", false),
+    (
+        "(mit OR Apache-2.0) and unlicense",
+        "(MIT OR Apache-2.0) AND Unlicense",
+        false,
+    ),
+    (
+        "GPL-2.0-only with Classpath-exception-2.0",
+        "GPL-2.0-only WITH Classpath-exception-2.0",
+        false,
+    ),
+    // Legacy names, one per rule.
+    ("MIT/X11", "MIT", false),
+    ("mit/x11", "MIT", false),
+    ("AGPL-3.0", "AGPL-3.0-only", false),
+    ("AGPL-3.0+", "AGPL-3.0-or-later", false),
+    ("AGPLv3", "AGPL-3.0-only", true),
+    ("AGPLv3+", "AGPL-3.0-or-later", false),
+    ("Apache2", "Apache-2.0", false),
+    ("Apache-2", "Apache-2.0", false),
+    ("ASL-2.0", "Apache-2.0", false),
+    ("BSD-2", "BSD-2-Clause", false),
+    ("BSD-3", "BSD-3-Clause", false),
+    ("Boost", "BSL-1.0", false),
+    ("GPL-2.0", "GPL-2.0-only", false),
+    ("GPL-2.0+", "GPL-2.0-or-later", false),
+    ("GPL-3.0", "GPL-3.0-only", false),
+    ("GPL-3.0+", "GPL-3.0-or-later", false),
+    ("GPLv2", "GPL-2.0-only", true),
+    ("GPLv2+", "GPL-2.0-or-later", false),
+    ("GPLv3", "GPL-3.0-only", true),
+    ("GPLv3+", "GPL-3.0-or-later", false),
+    ("LGPL-2.1", "LGPL-2.1-only", false),
+    ("LGPL-2.1+", "LGPL-2.1-or-later", false),
+    ("LGPL-3.0", "LGPL-3.0-only", false),
+    ("LGPL-3.0+", "LGPL-3.0-or-later", false),
+    ("LGPLv2.1", "LGPL-2.1-only", true),
+    ("LGPLv2.1+", "LGPL-2.1-or-later", false),
+    ("LGPLv3", "LGPL-3.0-only", true),
+    ("LGPLv3+", "LGPL-3.0-or-later", false),
+    ("MPL2", "MPL-2.0", false),
+    ("MPLv2", "MPL-2.0", false),
+    ("MPL-2", "MPL-2.0", false),
+    // Legacy names within expressions.
+    ("MIT/X11 OR Apache-2.0", "MIT OR Apache-2.0", false),
+    ("GPLv3/MIT", "GPL-3.0-only OR MIT", true),
+    ("(gplv2+)", "(GPL-2.0-or-later)", false),
+    // Valid and unknown fields are unchanged.
+    ("MIT OR Apache-2.0", "MIT OR Apache-2.0", false),
+    ("GPL-2.0-or-later", "GPL-2.0-or-later", false),
+    ("LicenseRef-Proprietary", "LicenseRef-Proprietary", false),
+    ("Custom License v1", "Custom License v1", false),
+    ("GPLv2-ish", "GPLv2-ish", false),
+    ("MIT,Apache-2.0", "MIT,Apache-2.0", false),
+];
+
+#[test]
+fn rules() {
+    for &(field, expected, lossy) in CASES {
+        let normalized = license::normalize_legacy(field).unwrap();
+        assert_eq!(normalized.to_string(), expected, "{}", field);
+        assert_eq!(normalized.is_lossy(), lossy, "{}", field);
+    }
+}
+
+#[test]
+fn unchanged_fields_have_no_rewrites() {
+    for &(field, expected, _) in CASES {
+        let rewrites = license::normalize_legacy(field).unwrap().rewrites().count();
+        assert_eq!(rewrites == 0, field == expected, "{}", field);
+    }
+}
+
+#[test]
+fn rewrites_point_into_the_field() {
+    let rewrites: Vec<_> = license::normalize_legacy("mit / GPLv2 and LGPLv3+")
+        .unwrap()
+        .rewrites()
+        .map(|r| (r.from, r.to, r.lossy))
+        .collect();
+    assert_eq!(
+        rewrites,
+        [
+            ("mit", "MIT", false),
+            (" / ", " OR ", true),
+            ("GPLv2", "GPL-2.0-only", true),
+            ("and", "AND", false),
+            ("LGPLv3+", "LGPL-3.0-or-later", false),
+        ]
+    );
+}
+
+#[test]
+fn errors() {
+    for &(field, message) in &[
+        ("", "the license field is empty"),
+        ("   ", "the license field is empty"),
+        (
+            "MIT/",
+            "the `/` at position 3 of `MIT/` is not between two licenses",
+        ),
+        (
+            "/MIT",
+            "the `/` at position 0 of `/MIT` is not between two licenses",
+        ),
+        (
+            "MIT//Apache-2.0",
+            "the `/` at position 4 of `MIT//Apache-2.0` is not between two licenses",
+        ),
+        (
+            "MIT / / Apache-2.0",
+            "the `/` at position 6 of `MIT / / Apache-2.0` is not between two licenses",
+        ),
+    ] {
+        let err = license::normalize_legacy(field).unwrap_err();
+        assert_eq!(err.to_string(), message, "{}", field);
+    }
+    assert!(matches!(
+        license::normalize_legacy("MIT/"),
+        Err(NormalizeError::EmptyOperand {
+            field: "MIT/",
+            position: 3,
+            ..
+        })
+    ));
+}
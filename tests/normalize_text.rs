@@ -0,0 +1,82 @@
+use license::text;
+
+const MIT_CURLY_QUOTES: &str = include_str!("fixtures/MIT-curly-quotes.txt");
+const APACHE_2_0_BOM: &[u8] = include_bytes!("fixtures/Apache-2.0-bom.txt");
+
+#[test]
+fn curly_quoted_mit_matches_mit() {
+    let mit = license::from_id("MIT").unwrap();
+    let diff = text::diff(mit.text(), MIT_CURLY_QUOTES);
+    assert!(diff.is_trivial(), "{}", diff);
+
+    let m = text::best_match(MIT_CURLY_QUOTES).unwrap();
+    assert_eq!(m.license().id(), "MIT");
+}
+
+#[test]
+fn bom_prefixed_apache_matches_apache() {
+    let candidate = text::from_utf8(APACHE_2_0_BOM).unwrap();
+    assert!(candidate.starts_with("Apache License"));
+
+    let apache = license::from_id("Apache-2.0").unwrap();
+    let diff = text::diff(apache.text(), candidate);
+    assert!(diff.is_identical(), "{}", diff);
+    assert_eq!(
+        text::best_match(candidate).unwrap().license().id(),
+        "Apache-2.0"
+    );
+
+    // The byte order mark is also ignored when it is kept in the text.
+    let with_bom = core::str::from_utf8(APACHE_2_0_BOM).unwrap();
+    assert_eq!(text::fingerprint(with_bom), apache.fingerprint());
+}
+
+#[test]
+fn invisible_characters_do_not_split_words() {
+    assert_eq!(
+        text::fingerprint("soft\u{200b}ware docu\u{ad}mentation \u{fb01}les"),
+        text::fingerprint("software documentation files")
+    );
+}
+
+#[test]
+fn compatibility_forms_match_their_letters() {
+    let mit = license::from_id("MIT").unwrap();
+    let spaces = [
+        '\u{a0}', '\u{2002}', '\u{2009}', '\u{202f}', '\u{205f}', '\u{3000}',
+    ];
+    let mut spaced = String::new();
+    for (i, word) in mit.text().split(' ').enumerate() {
+        if i > 0 {
+            spaced.push(spaces[i % spaces.len()]);
+        }
+        spaced.push_str(word);
+    }
+    let copied = spaced
+        .replace("fi", "\u{fb01}")
+        .replace("MIT", "\u{ff2d}\u{ff29}\u{ff34}")
+        .replace("(c)", "\u{ff08}\u{ff43}\u{ff09}");
+    assert!(copied.contains('\u{fb01}') && copied.contains('\u{ff43}'));
+
+    let diff = text::diff(mit.text(), &copied);
+    assert!(diff.is_identical(), "{}", diff);
+    assert_eq!(text::fingerprint(&copied), mit.fingerprint());
+    assert_eq!(text::best_match(&copied).unwrap().license().id(), "MIT");
+
+    let ligatures = "\u{fb00} \u{fb01} \u{fb02} \u{fb03} \u{fb04} \u{fb05} \u{fb06}";
+    assert_eq!(
+        text::normalize(ligatures).collect::<String>(),
+        "ff fi fl ffi ffl st st"
+    );
+    let fullwidth = "\u{ff01}\u{ff10}\u{ff21}\u{ff41}\u{ff5e}";
+    assert_eq!(text::normalize(fullwidth).collect::<String>(), "!0Aa~");
+}
+
+#[test]
+fn non_utf8_input_is_an_error() {
+    // ISO 8859-1, as old LICENSE files sometimes are.
+    let latin1 = b"Copyright (c) 2024 J\xf6rg M\xfcller\n\nPermission is hereby granted";
+    let err = text::from_utf8(latin1).unwrap_err();
+    assert_eq!(err.valid_up_to(), 20);
+    assert_eq!(text::from_utf8(b"\xef\xbb\xbf"), Ok(""));
+}
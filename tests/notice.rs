@@ -0,0 +1,83 @@
+use license::{Notice, LICENSES_EXT};
+
+/// Says if a notice still has a placeholder such as `<year>` or `[yyyy]`.
+fn has_placeholder(notice: &str) -> bool {
+    [('<', '>'), ('[', ']')].iter().any(|&(open, close)| {
+        matches!(notice.find(open), Some(start) if notice[start..].contains(close))
+    })
+}
+
+fn notice(id: &str, holder: &str, year: u16) -> String {
+    license::from_id_ext(id)
+        .unwrap()
+        .notice(holder, year)
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn curated() {
+    let curated: Vec<_> = LICENSES_EXT
+        .iter()
+        .filter(|l| l.notice("", 0).is_some())
+        .map(|l| l.id())
+        .collect();
+    assert_eq!(
+        curated,
+        [
+            "Apache-2.0",
+            "Apache-2.0 WITH SHL-2.1",
+            "BSD-3-Clause",
+            "MIT"
+        ]
+    );
+}
+
+#[test]
+fn examples_are_the_substitution() {
+    for license in LICENSES_EXT.iter().filter(|l| l.notice("", 0).is_some()) {
+        let example = license.notice_example().unwrap().to_string();
+        assert_eq!(
+            example,
+            notice(license.id(), Notice::EXAMPLE_HOLDER, Notice::EXAMPLE_YEAR),
+            "{}",
+            license.id()
+        );
+        assert!(example.contains("2024 Example Corp"), "{}", license.id());
+        assert!(!has_placeholder(&example), "{}", license.id());
+        assert!(example.ends_with(".\n"), "{}", license.id());
+    }
+}
+
+#[test]
+fn mit() {
+    let notice = notice("MIT", "Jane Doe", 1999);
+    assert!(notice
+        .starts_with("MIT License\n\nCopyright (c) 1999 Jane Doe\n\nPermission is hereby granted"));
+    let text = license::from_id("MIT").unwrap().text();
+    assert_eq!(
+        notice.replace("1999 Jane Doe", "<year> <copyright holders>"),
+        text
+    );
+}
+
+#[test]
+fn bsd_3_clause() {
+    let notice = notice("BSD-3-Clause", "Jane Doe", 1999);
+    assert!(notice.starts_with("Copyright (c) 1999 Jane Doe. All rights reserved.\n\n"));
+    assert!(notice.contains("\n3. Neither the name of the copyright holder"));
+}
+
+#[test]
+fn apache_2_0() {
+    let notice = notice("Apache-2.0", "Jane Doe", 1999);
+    assert_eq!(
+        notice.lines().take(3).collect::<Vec<_>>(),
+        [
+            "Copyright 1999 Jane Doe",
+            "",
+            "Licensed under the Apache License, Version 2.0 (the \"License\");"
+        ]
+    );
+    assert!(notice.ends_with("\nlimitations under the License.\n"));
+}
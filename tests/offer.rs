@@ -0,0 +1,123 @@
+#![cfg(feature = "alloc")]
+
+use license::{OfferParams, LICENSES_EXT};
+
+const PARAMS: OfferParams = OfferParams {
+    company: "Example Corp",
+    contact: "Example Corp, 1 Main Street, Springfield",
+    years: None,
+    medium: None,
+};
+
+fn offer(id: &str, params: &OfferParams) -> String {
+    let license = license::from_id_ext(id).unwrap();
+    license.render_source_offer(params).unwrap()
+}
+
+#[test]
+fn gpl_2_offer() {
+    let offer = offer("GPL-2.0-only", &PARAMS);
+    assert!(offer.contains("GNU General Public License, version 2."));
+    assert!(offer.contains("For at least 3 years from the date of distribution"));
+    assert!(offer.contains(
+        "for a charge no more than its cost of physically performing source distribution"
+    ));
+    assert!(offer.contains("on a medium customarily used for software interchange."));
+    assert!(
+        offer.ends_with("To request a copy, contact Example Corp, 1 Main Street, Springfield.\n")
+    );
+    assert!(!offer.contains("network server"));
+}
+
+#[test]
+fn gpl_3_offer() {
+    let offer = offer("GPL-3.0-only", &PARAMS);
+    assert!(offer.contains("GNU General Public License, version 3."));
+    assert!(offer.contains("For at least 3 years, and for as long as Example Corp offers spare parts or customer support"));
+    assert!(offer.contains("to give anyone who possesses the object code"));
+    assert!(
+        offer.contains("on a durable physical medium customarily used for software interchange")
+    );
+    assert!(offer.contains(
+        "(2) access to copy the Corresponding Source from a network server at no charge."
+    ));
+    assert!(!offer.contains("physically performing source distribution"));
+}
+
+#[test]
+fn every_gnu_version_has_its_offer() {
+    let offers: Vec<_> = LICENSES_EXT
+        .iter()
+        .filter(|l| l.source_offer_template().is_some())
+        .map(|l| l.id())
+        .collect();
+    assert_eq!(
+        offers,
+        [
+            "AGPL-3.0-only",
+            "GPL-2.0-only",
+            "GPL-2.0-only WITH Classpath-exception-2.0",
+            "GPL-3.0-only",
+            "LGPL-2.1-only",
+            "LGPL-3.0-only",
+        ]
+    );
+    assert!(
+        offer("AGPL-3.0-only", &PARAMS).contains("GNU Affero General Public License, version 3.")
+    );
+    assert!(offer("LGPL-2.1-only", &PARAMS).contains("the materials specified in Subsection 6a"));
+    assert!(offer("LGPL-3.0-only", &PARAMS)
+        .contains("the Minimal Corresponding Source and the Corresponding Application Code"));
+    assert_eq!(
+        offer("GPL-2.0-only WITH Classpath-exception-2.0", &PARAMS),
+        offer("GPL-2.0-only", &PARAMS)
+    );
+}
+
+#[test]
+fn placeholders_are_filled() {
+    for license in LICENSES_EXT {
+        let template = match license.source_offer_template() {
+            Some(template) => template,
+            None => continue,
+        };
+        for placeholder in ["<company>", "<contact>", "<years>", "<medium>"] {
+            assert!(
+                template.contains(placeholder),
+                "{}: {}",
+                license.id(),
+                placeholder
+            );
+        }
+        let offer = license.render_source_offer(&PARAMS).unwrap();
+        assert!(!offer.contains('<'), "{}", license.id());
+    }
+}
+
+#[test]
+fn params_override_the_defaults() {
+    let params = OfferParams {
+        years: Some(5),
+        medium: Some("a USB flash drive"),
+        ..PARAMS
+    };
+    let offer = offer("GPL-3.0-only", &params);
+    assert!(offer.contains("For at least 5 years"));
+    assert!(offer.contains("on a USB flash drive,"));
+
+    let short = OfferParams {
+        years: Some(1),
+        ..PARAMS
+    };
+    assert!(self::offer("GPL-2.0-only", &short).contains("For at least 3 years"));
+}
+
+#[test]
+fn other_licenses_have_no_offer() {
+    for id in ["MIT", "MPL-2.0", "EPL-2.0", "CC-BY-SA-4.0"] {
+        let license = license::from_id_ext(id).unwrap();
+        assert_eq!(license.source_offer_template(), None, "{}", id);
+        assert_eq!(license.render_source_offer(&PARAMS), None, "{}", id);
+    }
+    assert_eq!(license::UNKNOWN.source_offer_template(), None);
+}
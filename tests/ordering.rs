@@ -0,0 +1,175 @@
+use license::report;
+use license::{
+    CompositeLicense, Conditions, LicenseExt, Limitations, ListStyle, Permissions, SummaryDisplay,
+    LICENSES, LICENSES_EXT,
+};
+use std::collections::{BTreeSet, HashSet};
+
+fn licenses(ids: &[&str]) -> Vec<&'static dyn LicenseExt> {
+    ids.iter()
+        .map(|id| license::from_id_ext(id).unwrap())
+        .collect()
+}
+
+/// Every reordering of `ids`, by Heap's algorithm.
+fn permutations(ids: &[&'static str]) -> Vec<Vec<&'static str>> {
+    fn permute(k: usize, ids: &mut Vec<&'static str>, out: &mut Vec<Vec<&'static str>>) {
+        if k <= 1 {
+            out.push(ids.clone());
+            return;
+        }
+        for i in 0..k {
+            permute(k - 1, ids, out);
+            let j = if k % 2 == 0 { i } else { 0 };
+            ids.swap(j, k - 1);
+        }
+    }
+    let mut out = Vec::new();
+    permute(ids.len(), &mut ids.to_vec(), &mut out);
+    out
+}
+
+const MIX: &[&str] = &["MIT", "AGPL-3.0-only", "Apache-2.0", "GPL-3.0-only", "MIT"];
+
+#[test]
+fn tables_are_sorted_by_id() {
+    let ids: Vec<_> = LICENSES.iter().map(|l| l.id()).collect();
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    let ids: Vec<_> = LICENSES_EXT.iter().map(|l| l.id()).collect();
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn tables_are_sorted_by_the_order_of_licenses() {
+    assert!(LICENSES.windows(2).all(|w| w[0] < w[1]));
+    assert!(LICENSES_EXT.windows(2).all(|w| w[0] < w[1]));
+    let set: BTreeSet<_> = LICENSES.iter().copied().collect();
+    assert_eq!(set.len(), LICENSES.len(), "an id is listed twice");
+    assert!(set.iter().copied().eq(LICENSES.iter().copied()));
+}
+
+#[test]
+fn licenses_compare_by_id() {
+    let mit = license::from_id("MIT").unwrap();
+    assert!(mit == license::from_id_ext("MIT").unwrap() as &dyn license::License);
+    assert!(mit != license::from_id("MIT-CMU").unwrap());
+    assert!(license::from_id("MIT-CMU").unwrap() < license::from_id("MITNFA").unwrap());
+    // Uppercase sorts before lowercase.
+    assert!(license::from_id("MIT-CMU").unwrap() < license::from_id("MIT-advertising").unwrap());
+
+    let set: HashSet<&dyn LicenseExt> = licenses(MIX).into_iter().collect();
+    assert_eq!(set.len(), 4);
+    let sorted: BTreeSet<&dyn LicenseExt> = licenses(MIX).into_iter().collect();
+    let ids: Vec<_> = sorted.iter().map(|l| l.id()).collect();
+    assert_eq!(ids, ["AGPL-3.0-only", "Apache-2.0", "GPL-3.0-only", "MIT"]);
+}
+
+#[test]
+fn report_does_not_depend_on_the_input_order() {
+    let expected = report::combine(&licenses(MIX)).to_string();
+    assert!(expected.contains("- Licenses: AGPL-3.0-only, Apache-2.0, GPL-3.0-only, MIT\n"));
+    for ids in permutations(MIX) {
+        let licenses = licenses(&ids);
+        let report = report::combine(&licenses);
+        assert_eq!(report.to_string(), expected, "{:?}", ids);
+        let floor: Vec<_> = report.floor().map(|l| l.id()).collect();
+        assert_eq!(floor, ["AGPL-3.0-only"]);
+    }
+}
+
+#[test]
+fn network_triggering_is_sorted() {
+    let licenses = licenses(&["GPL-3.0-only", "AGPL-3.0-only", "AGPL-3.0-only"]);
+    let triggering: Vec<_> = report::combine(&licenses)
+        .network_triggering()
+        .map(|l| l.id())
+        .collect();
+    assert_eq!(triggering, ["AGPL-3.0-only"]);
+}
+
+#[test]
+fn composite_does_not_depend_on_the_input_order() {
+    for ids in permutations(MIX) {
+        let licenses = licenses(&ids);
+        let composite = CompositeLicense::all_of(&licenses);
+        assert_eq!(
+            composite.id().to_string(),
+            "AGPL-3.0-only AND Apache-2.0 AND GPL-3.0-only AND MIT"
+        );
+        assert_eq!(
+            composite.text().to_string(),
+            CompositeLicense::all_of(&self::licenses(MIX))
+                .text()
+                .to_string()
+        );
+    }
+}
+
+#[test]
+fn rendering_is_repeatable() {
+    let licenses = licenses(MIX);
+    let report = report::combine(&licenses);
+    assert_eq!(report.to_string(), report.to_string());
+    for license in LICENSES_EXT {
+        let summary = SummaryDisplay::new(*license, ListStyle::default());
+        assert_eq!(summary.to_string(), summary.to_string());
+    }
+}
+
+#[test]
+fn flags_compare_in_field_order() {
+    // The order of the derived impls before the flags got bits, first field first.
+    for a in 0..32 {
+        for b in 0..32 {
+            let fields = |bits| {
+                let p = Permissions::from_bits(bits).unwrap();
+                (
+                    p.commercial_use(),
+                    p.distribution(),
+                    p.modification(),
+                    p.patent_rights(),
+                    p.private_use(),
+                )
+            };
+            let (x, y) = (Permissions::from_bits(a), Permissions::from_bits(b));
+            assert_eq!(x.cmp(&y), fields(a).cmp(&fields(b)), "{:#b} {:#b}", a, b);
+        }
+    }
+    for a in 0..128 {
+        for b in 0..128 {
+            let fields = |bits| {
+                let c = Conditions::from_bits(bits).unwrap();
+                (
+                    c.disclose_sources(),
+                    c.document_changes(),
+                    c.license_and_copyright_notice(),
+                    c.network_use_is_distribution(),
+                    c.same_license(),
+                    c.notice_file(),
+                    c.mark_modifications(),
+                )
+            };
+            let (x, y) = (Conditions::from_bits(a), Conditions::from_bits(b));
+            assert_eq!(x.cmp(&y), fields(a).cmp(&fields(b)), "{:#b} {:#b}", a, b);
+        }
+    }
+    for a in 0..16 {
+        for b in 0..16 {
+            let fields = |bits| {
+                let l = Limitations::from_bits(bits).unwrap();
+                (
+                    l.no_liability(),
+                    l.no_trademark_rights(),
+                    l.no_warranty(),
+                    l.no_patent_rights(),
+                )
+            };
+            let (x, y) = (Limitations::from_bits(a), Limitations::from_bits(b));
+            assert_eq!(x.cmp(&y), fields(a).cmp(&fields(b)), "{:#b} {:#b}", a, b);
+        }
+    }
+
+    let commercial_use = Permissions::from_bits(0b00001).unwrap();
+    let the_rest = Permissions::from_bits(0b11110).unwrap();
+    assert!(the_rest < commercial_use);
+}
@@ -0,0 +1,204 @@
+#![cfg(feature = "alloc")]
+
+use license::overrides::Overrides;
+use license::{
+    Condition, Flag, License, LicenseExt, Limitation, Metadata, OverrideError, PatentPosture,
+    Permission, LICENSES_EXT,
+};
+
+#[test]
+fn partial_overrides_merge_flag_by_flag() {
+    let mut overrides = Overrides::new();
+    overrides
+        .set("CC0-1.0", Permission::PatentRights, true)
+        .unwrap()
+        .set("CC0-1.0", Limitation::NoPatentRights, false)
+        .unwrap();
+    let cc0 = overrides.get("CC0-1.0").unwrap();
+    let builtin = license::from_id_ext("CC0-1.0").unwrap();
+
+    assert!(cc0.permissions().patent_rights());
+    assert!(!builtin.permissions().patent_rights());
+    assert!(!license::CC0_1_0.permissions().patent_rights());
+    assert_eq!(
+        cc0.permissions().difference(builtin.permissions()),
+        license::Permissions::from_bits(Permission::PatentRights.bit()).unwrap()
+    );
+    assert_eq!(cc0.conditions(), builtin.conditions());
+    assert!(cc0.limitations().no_trademark_rights() && cc0.limitations().no_warranty());
+    assert!(!cc0.limitations().no_patent_rights());
+    assert_eq!(cc0.patent_posture(), PatentPosture::ExpressGrant);
+    assert_eq!(builtin.patent_posture(), PatentPosture::ExplicitNonGrant);
+    assert!(cc0.is_overridden());
+}
+
+#[test]
+fn views_keep_the_canonical_data() {
+    let mut overrides = Overrides::new();
+    overrides
+        .set("Apache-2.0", Limitation::NoTrademarkRights, false)
+        .unwrap();
+    let apache = overrides.get("Apache-2.0").unwrap();
+    let builtin = license::from_id_ext("Apache-2.0").unwrap();
+    assert_eq!(apache.id(), "Apache-2.0");
+    assert_eq!(apache.text(), builtin.text());
+    assert_eq!(apache.name(), builtin.name());
+    assert_eq!(apache.sections(), builtin.sections());
+    assert_eq!(apache.code(), builtin.code());
+    assert_eq!(apache.patent_posture(), PatentPosture::GrantWithTermination);
+    assert!(apache.notice("Jane Doe", 2024).is_some());
+    assert!(apache.as_any().is::<license::Apache_2_0>());
+    assert_eq!(apache.license().id(), "Apache-2.0");
+
+    let trademarks = Flag::from(Limitation::NoTrademarkRights);
+    assert!(builtin.flag_provenance(trademarks).is_some());
+    assert_eq!(apache.flag_provenance(trademarks), None);
+    let warranty = Flag::from(Limitation::NoWarranty);
+    assert_eq!(
+        apache.flag_provenance(warranty),
+        builtin.flag_provenance(warranty)
+    );
+}
+
+#[test]
+fn overrides_flow_into_the_predicates() {
+    let mut overrides = Overrides::new();
+    overrides
+        .set("MIT", Condition::SameLicense, true)
+        .unwrap()
+        .set("GPL-3.0-only", Condition::NetworkUseIsDistribution, true)
+        .unwrap();
+    assert!(!overrides.get("MIT").unwrap().is_permissive());
+    assert!(license::from_id_ext("MIT").unwrap().is_permissive());
+    let gpl = overrides.get("GPL-3.0-only").unwrap();
+    assert!(gpl.requires_source_disclosure(false, true));
+    assert!(!gpl.private_modifications_allowed(true));
+}
+
+#[test]
+fn later_overrides_win() {
+    let mut overrides = Overrides::new();
+    overrides
+        .set("MIT", Condition::NoticeFile, true)
+        .unwrap()
+        .set("MIT", Condition::NoticeFile, false)
+        .unwrap();
+    let mit = overrides.get("MIT").unwrap();
+    assert!(!mit.conditions().notice_file());
+    assert!(!mit.is_overridden());
+    assert_eq!(overrides.ids().collect::<Vec<_>>(), ["MIT"]);
+
+    overrides.replace("MIT", Metadata::default()).unwrap();
+    let mit = overrides.get("MIT").unwrap();
+    assert_eq!(mit.metadata(), Metadata::default());
+    assert_eq!(mit.patent_posture(), PatentPosture::Silent);
+}
+
+#[test]
+fn untouched_licenses_are_unchanged() {
+    let mut overrides = Overrides::new();
+    overrides.set("MIT", Condition::NoticeFile, true).unwrap();
+    for license in LICENSES_EXT.iter().filter(|l| l.id() != "MIT") {
+        let view = overrides.view(*license);
+        assert_eq!(view.metadata(), license.metadata(), "{}", license.id());
+        assert_eq!(
+            view.patent_posture(),
+            license.patent_posture(),
+            "{}",
+            license.id()
+        );
+        assert!(!view.is_overridden(), "{}", license.id());
+    }
+    assert!(overrides.get("AAL").is_none());
+}
+
+#[test]
+fn unknown_ids_are_errors() {
+    let mut overrides = Overrides::new();
+    let err = overrides
+        .set("CC0-1", Permission::PatentRights, false)
+        .unwrap_err();
+    assert!(matches!(
+        &err,
+        OverrideError::UnknownLicense { id, suggestion: Some("CC0-1.0"), .. } if id == "CC0-1"
+    ));
+    assert_eq!(
+        err.to_string(),
+        "unknown license id `CC0-1`, did you mean `CC0-1.0`?"
+    );
+    let err = overrides.replace("AAL", Metadata::default()).unwrap_err();
+    assert_eq!(err.to_string(), "unknown license id `AAL`");
+    assert_eq!(overrides, Overrides::new());
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+
+    #[test]
+    fn partial_override_from_toml() {
+        let overrides: Overrides = toml::from_str(
+            "[\"CC0-1.0\".permissions]\n\
+             patent_rights = true\n",
+        )
+        .unwrap();
+        let cc0 = overrides.get("CC0-1.0").unwrap();
+        let builtin = license::from_id_ext("CC0-1.0").unwrap();
+        assert!(cc0.permissions().patent_rights());
+        assert!(!builtin.permissions().patent_rights());
+        assert!(cc0.permissions().commercial_use() && cc0.permissions().private_use());
+        assert_eq!(cc0.limitations(), builtin.limitations());
+        assert_eq!(cc0.id(), "CC0-1.0");
+        assert_eq!(cc0.text(), builtin.text());
+    }
+
+    #[test]
+    fn full_override_from_json() {
+        let overrides: Overrides = serde_json::from_str(
+            r#"{"MIT": {"conditions": ["license_and_copyright_notice", "notice_file"], "limitations": []}}"#,
+        )
+        .unwrap();
+        let mit = overrides.get("MIT").unwrap();
+        let builtin = license::from_id_ext("MIT").unwrap();
+        assert!(mit.conditions().notice_file());
+        assert!(mit.conditions().license_and_copyright_notice());
+        assert_eq!(mit.conditions().iter().count(), 2);
+        assert!(mit.limitations().is_empty());
+        assert_eq!(mit.permissions(), builtin.permissions());
+    }
+
+    #[test]
+    fn invalid_documents_are_errors() {
+        let err = toml::from_str::<Overrides>("[\"CC0-10\".permissions]\npatent_rights = true\n")
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("unknown license id `CC0-10`, did you mean `CC0-1.0`?"),
+            "{}",
+            err
+        );
+        let err =
+            serde_json::from_str::<Overrides>(r#"{"MIT": {"permissions": {"patents": true}}}"#)
+                .unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("unknown flag `patents` of the permissions"),
+            "{}",
+            err
+        );
+        let err = serde_json::from_str::<Overrides>(r#"{"MIT": {"flags": []}}"#).unwrap_err();
+        assert!(
+            err.to_string().starts_with("unknown field `flags`"),
+            "{}",
+            err
+        );
+        let err = serde_json::from_str::<Overrides>(r#"{"MIT": {"conditions": ["no_warranty"]}}"#)
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("unknown flag `no_warranty` of the conditions"),
+            "{}",
+            err
+        );
+    }
+}
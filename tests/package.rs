@@ -0,0 +1,104 @@
+use license::{PackageLicense, PackageLicenseError};
+
+#[test]
+fn from_env() {
+    let license = license::from_env!();
+    assert_eq!(license.expression(), env!("CARGO_PKG_LICENSE"));
+    assert!(license.ext().is_none());
+}
+
+#[test]
+fn parse() {
+    let cases = [
+        ("MIT", Ok(())),
+        (" MIT ", Ok(())),
+        ("MIT/Apache-2.0", Ok(())),
+        ("GPL-2.0+", Ok(())),
+        ("(MIT OR (Apache-2.0 AND Zlib))", Ok(())),
+        ("GPL-3.0-or-later WITH GCC-exception-3.1", Ok(())),
+        ("", Err(None)),
+        ("MIT OR", Err(Some(6))),
+        ("(MIT", Err(Some(4))),
+        ("MIT)", Err(Some(3))),
+        ("MIT Apache-2.0", Err(Some(4))),
+        ("mit", Err(Some(0))),
+        ("LicenseRef-Proprietary", Err(Some(0))),
+        ("MIT WITH", Err(Some(8))),
+        ("MIT WITH Foo", Err(Some(9))),
+    ];
+    for &(expression, expected) in &cases {
+        assert_eq!(
+            PackageLicense::parse(expression)
+                .map(|_| ())
+                .map_err(|err| err.position()),
+            expected,
+            "{:?}",
+            expression
+        );
+    }
+}
+
+#[test]
+fn errors() {
+    let cases = [
+        ("", "the license expression is empty"),
+        (
+            "MIT OR",
+            "invalid license expression `MIT OR`: unexpected end at position 6",
+        ),
+        (
+            "MIT)",
+            "invalid license expression `MIT)`: unexpected `)` at position 3",
+        ),
+        (
+            "MIT Apache-2.0",
+            "invalid license expression `MIT Apache-2.0`: unexpected `Apache-2.0` at position 4",
+        ),
+        (
+            "MIT OR mit",
+            "unknown license id `mit` at position 7 of `MIT OR mit`",
+        ),
+        (
+            "MIT WITH Foo",
+            "unknown exception id `Foo` at position 9 of `MIT WITH Foo`",
+        ),
+    ];
+    for &(expression, message) in &cases {
+        let err = PackageLicense::parse(expression).unwrap_err();
+        assert_eq!(err.to_string(), message);
+    }
+
+    let err = PackageLicense::parse("MIT WITH Foo").unwrap_err();
+    assert!(matches!(
+        err,
+        PackageLicenseError::UnknownException {
+            expression: "MIT WITH Foo",
+            position: 9,
+            ..
+        }
+    ));
+    assert_eq!(err.offending(), Some("Foo"));
+    assert_eq!(
+        PackageLicense::parse("MIT OR").unwrap_err().offending(),
+        None
+    );
+}
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/valid.rs");
+    t.compile_fail("tests/ui/empty-manifest.rs");
+    t.compile_fail("tests/ui/invalid-syntax.rs");
+    t.compile_fail("tests/ui/unknown-exception.rs");
+    t.compile_fail("tests/ui/unknown-license.rs");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn errors_are_std_errors() {
+    let err: Box<dyn std::error::Error> = Box::new(PackageLicense::parse("MIT OR").unwrap_err());
+    assert!(err.to_string().contains("`MIT OR`"));
+    let err: Box<dyn std::error::Error> = Box::new(license::normalize_legacy("").unwrap_err());
+    assert_eq!(err.to_string(), "the license field is empty");
+}
@@ -0,0 +1,106 @@
+use license::error::CopyrightYearsError;
+use license::params::CopyrightYears;
+
+#[test]
+fn formatting() {
+    assert_eq!(CopyrightYears::single(2021).to_string(), "2021");
+    assert_eq!(CopyrightYears::from(2021).to_string(), "2021");
+    assert_eq!(
+        CopyrightYears::range(2019, 2025).unwrap().to_string(),
+        "2019-2025"
+    );
+
+    let same = CopyrightYears::range(2021, 2021).unwrap();
+    assert!(same.is_single());
+    assert_eq!(same, CopyrightYears::single(2021));
+    assert_eq!(same.to_string(), "2021");
+}
+
+#[test]
+fn reversed_range() {
+    let err = CopyrightYears::range(2025, 2019).unwrap_err();
+    assert!(matches!(
+        err,
+        CopyrightYearsError::Reversed {
+            start: 2025,
+            end: 2019,
+            ..
+        }
+    ));
+    assert_eq!(
+        err.to_string(),
+        "the copyright years `2025-2019` end before they start"
+    );
+}
+
+#[test]
+fn notice_with_a_range() {
+    let years = CopyrightYears::range(2019, 2025).unwrap();
+    let mit = license::from_id_ext("MIT").unwrap();
+    let notice = mit.notice("Jane Doe", 2019).unwrap().with_years(years);
+    assert!(notice
+        .to_string()
+        .starts_with("MIT License\n\nCopyright (c) 2019-2025 Jane Doe\n"));
+
+    let apache = license::from_id_ext("Apache-2.0").unwrap();
+    let notice = apache.notice("Jane Doe", 2019).unwrap().with_years(years);
+    assert!(notice
+        .to_string()
+        .contains("Copyright 2019-2025 Jane Doe\n"));
+
+    let gpl = license::from_id_ext("GPL-3.0-only").unwrap();
+    let instructions = gpl
+        .apply_instructions("frobnicate", "Jane Doe", 2019)
+        .unwrap()
+        .with_years(years);
+    assert!(instructions
+        .header()
+        .to_string()
+        .starts_with("frobnicate\nCopyright (C) 2019-2025 Jane Doe\n"));
+    assert!(instructions
+        .interactive_notice()
+        .unwrap()
+        .to_string()
+        .starts_with("frobnicate  Copyright (C) 2019-2025  Jane Doe\n"));
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn license_files_with_a_range() {
+    use license::write::{self, LicenseChoice, LicenseParams};
+
+    let dir = tempfile::tempdir().unwrap();
+    let params = LicenseParams {
+        holder: "Jane Doe",
+        years: CopyrightYears::range(2019, 2025).unwrap(),
+        overwrite: false,
+    };
+    write::write_license_files(dir.path(), &LicenseChoice::MitOrApache, &params).unwrap();
+    let mit = std::fs::read_to_string(dir.path().join("LICENSE-MIT")).unwrap();
+    assert!(mit.starts_with("MIT License\n\nCopyright (c) 2019-2025 Jane Doe\n"));
+}
+
+#[cfg(feature = "clock")]
+#[test]
+fn through_current() {
+    let years = CopyrightYears::through_current(2000).unwrap();
+    assert_eq!(years.start(), 2000);
+    assert!(years.end() >= 2024, "{:?}", years);
+    assert_eq!(years.to_string(), format!("2000-{}", years.end()));
+
+    let current = CopyrightYears::through_current(years.end()).unwrap();
+    assert!(current.is_single());
+
+    let err = CopyrightYears::through_current(u16::MAX).unwrap_err();
+    assert!(matches!(
+        err,
+        CopyrightYearsError::FutureStart { start: u16::MAX, current, .. } if current == years.end()
+    ));
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "the copyright years start in 65535, after the current year {}",
+            years.end()
+        )
+    );
+}
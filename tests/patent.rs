@@ -0,0 +1,120 @@
+use license::{PatentPosture, LICENSES_EXT, NONE, UNKNOWN};
+
+const POSTURES: &[(&str, PatentPosture)] = &[
+    ("0BSD", PatentPosture::Silent),
+    ("AFL-3.0", PatentPosture::GrantWithTermination),
+    ("AGPL-3.0-only", PatentPosture::ExpressGrant),
+    ("APSL-2.0", PatentPosture::GrantWithTermination),
+    ("Apache-2.0", PatentPosture::GrantWithTermination),
+    (
+        "Apache-2.0 WITH SHL-2.1",
+        PatentPosture::GrantWithTermination,
+    ),
+    ("BSD-2-Clause", PatentPosture::Silent),
+    ("BSD-3-Clause", PatentPosture::Silent),
+    ("BSD-3-Clause-Clear", PatentPosture::ExplicitNonGrant),
+    ("BSD-4-Clause", PatentPosture::Silent),
+    ("BSL-1.0", PatentPosture::Silent),
+    ("BUSL-1.1", PatentPosture::Silent),
+    ("CC-BY-4.0", PatentPosture::ExplicitNonGrant),
+    ("CC-BY-NC-4.0", PatentPosture::ExplicitNonGrant),
+    ("CC-BY-NC-ND-4.0", PatentPosture::ExplicitNonGrant),
+    ("CC-BY-NC-SA-4.0", PatentPosture::ExplicitNonGrant),
+    ("CC-BY-ND-4.0", PatentPosture::ExplicitNonGrant),
+    ("CC-BY-SA-3.0", PatentPosture::ExplicitNonGrant),
+    ("CC-BY-SA-4.0", PatentPosture::ExplicitNonGrant),
+    ("CC0-1.0", PatentPosture::ExplicitNonGrant),
+    ("CECILL-2.0", PatentPosture::ExpressGrant),
+    ("CECILL-2.1", PatentPosture::ExpressGrant),
+    ("CERN-OHL-P-2.0", PatentPosture::GrantWithTermination),
+    ("CERN-OHL-S-2.0", PatentPosture::GrantWithTermination),
+    ("CERN-OHL-W-2.0", PatentPosture::GrantWithTermination),
+    ("CPL-1.0", PatentPosture::GrantWithTermination),
+    ("DL-DE-BY-2.0", PatentPosture::Silent),
+    ("ECL-2.0", PatentPosture::GrantWithTermination),
+    ("EPL-1.0", PatentPosture::GrantWithTermination),
+    ("EPL-2.0", PatentPosture::GrantWithTermination),
+    ("EUPL-1.1", PatentPosture::ExpressGrant),
+    ("EUPL-1.2", PatentPosture::ExpressGrant),
+    ("Elastic-2.0", PatentPosture::GrantWithTermination),
+    ("GFDL-1.3-only", PatentPosture::Silent),
+    ("GFDL-1.3-or-later", PatentPosture::Silent),
+    ("GPL-2.0-only", PatentPosture::Silent),
+    (
+        "GPL-2.0-only WITH Classpath-exception-2.0",
+        PatentPosture::Silent,
+    ),
+    ("GPL-3.0-only", PatentPosture::ExpressGrant),
+    ("LGPL-2.1-only", PatentPosture::Silent),
+    ("LGPL-3.0-only", PatentPosture::ExpressGrant),
+    ("LiLiQ-R-1.1", PatentPosture::ExpressGrant),
+    ("LiLiQ-Rplus-1.1", PatentPosture::ExpressGrant),
+    ("MIT", PatentPosture::Silent),
+    ("MIT-CMU", PatentPosture::Silent),
+    ("MIT-advertising", PatentPosture::Silent),
+    ("MITNFA", PatentPosture::Silent),
+    ("MPL-2.0", PatentPosture::GrantWithTermination),
+    (
+        "MPL-2.0-no-copyleft-exception",
+        PatentPosture::GrantWithTermination,
+    ),
+    ("MS-PL", PatentPosture::GrantWithTermination),
+    ("MirOS", PatentPosture::Silent),
+    ("NTP", PatentPosture::Silent),
+    ("OGL-UK-3.0", PatentPosture::ExplicitNonGrant),
+    ("OSL-2.1", PatentPosture::GrantWithTermination),
+    ("OSL-3.0", PatentPosture::GrantWithTermination),
+    ("TAPR-OHL-1.0", PatentPosture::ExpressGrant),
+    ("Unlicense", PatentPosture::Silent),
+    ("WTFPL", PatentPosture::Silent),
+    ("X11", PatentPosture::Silent),
+    ("Zlib", PatentPosture::Silent),
+];
+
+#[test]
+fn every_license_has_its_posture() {
+    let ids: Vec<_> = LICENSES_EXT.iter().map(|l| l.id()).collect();
+    let pinned: Vec<_> = POSTURES.iter().map(|&(id, _)| id).collect();
+    assert_eq!(ids, pinned, "POSTURES is out of date");
+    for (license, &(_, posture)) in LICENSES_EXT.iter().zip(POSTURES) {
+        assert_eq!(license.patent_posture(), posture, "{}", license.id());
+    }
+}
+
+#[test]
+fn flags_follow_the_posture() {
+    for license in LICENSES_EXT {
+        let posture = license.patent_posture();
+        assert_eq!(
+            license.permissions().patent_rights(),
+            posture.grants_patent_rights(),
+            "{}",
+            license.id()
+        );
+        assert_eq!(
+            license.limitations().no_patent_rights(),
+            posture.excludes_patent_rights(),
+            "{}",
+            license.id()
+        );
+    }
+}
+
+#[test]
+fn terminating_grants_are_grants() {
+    // The booleans flatten these into a plain grant.
+    for id in ["MS-PL", "MPL-2.0", "EPL-2.0", "Elastic-2.0"] {
+        let license = license::from_id_ext(id).unwrap();
+        assert_eq!(
+            license.patent_posture(),
+            PatentPosture::GrantWithTermination
+        );
+        assert!(license.permissions().patent_rights(), "{}", id);
+    }
+}
+
+#[test]
+fn sentinels_are_silent() {
+    assert_eq!(UNKNOWN.patent_posture(), PatentPosture::Silent);
+    assert_eq!(NONE.patent_posture(), PatentPosture::Silent);
+}
@@ -0,0 +1,464 @@
+#![cfg(feature = "alloc")]
+
+use license::expression::{Expression, Leaf};
+use license::policy::{Decision, DenyReason, Policy};
+use license::report::Copyleft;
+use license::{declared, Conditions, PackageLicense, PolicyError};
+
+/// Allows MIT and Apache-2.0 and denies the GPL, as a typical project would.
+fn policy() -> Policy {
+    let mut policy = Policy::new();
+    policy
+        .allow("MIT")
+        .unwrap()
+        .allow("Apache-2.0")
+        .unwrap()
+        .deny("GPL-2.0-or-later")
+        .unwrap()
+        .deny_copyleft(Copyleft::Strong)
+        .deny_copyleft(Copyleft::Network);
+    policy
+}
+
+/// A decision with the licenses as written, to compare with literals.
+#[derive(Debug, PartialEq)]
+enum Judged {
+    Allow(Vec<String>),
+    Review(Vec<String>),
+    Deny(Vec<(String, DenyReason)>),
+}
+
+fn written(leaves: &[Leaf]) -> Vec<String> {
+    leaves.iter().map(|leaf| leaf.to_string()).collect()
+}
+
+fn judged(decision: Decision) -> Judged {
+    match decision {
+        Decision::Allow { chosen } => Judged::Allow(written(&chosen)),
+        Decision::NeedsReview { unknowns } => Judged::Review(written(&unknowns)),
+        Decision::Deny { reasons } => Judged::Deny(
+            reasons
+                .iter()
+                .map(|d| (d.license().to_string(), d.reason()))
+                .collect(),
+        ),
+    }
+}
+
+fn evaluate(policy: &Policy, expression: &str) -> Judged {
+    judged(policy.evaluate(&Expression::parse(expression).unwrap()))
+}
+
+fn allow(chosen: &[&str]) -> Judged {
+    Judged::Allow(chosen.iter().map(|id| id.to_string()).collect())
+}
+
+fn review(unknowns: &[&str]) -> Judged {
+    Judged::Review(unknowns.iter().map(|id| id.to_string()).collect())
+}
+
+fn denied(judged: Judged) -> Vec<(String, DenyReason)> {
+    match judged {
+        Judged::Deny(reasons) => reasons,
+        other => panic!("{:?}", other),
+    }
+}
+
+fn reasons(reasons: &[(&str, DenyReason)]) -> Vec<(String, DenyReason)> {
+    reasons
+        .iter()
+        .map(|&(license, reason)| (license.to_string(), reason))
+        .collect()
+}
+
+#[test]
+fn single_licenses() {
+    let policy = policy();
+    assert_eq!(evaluate(&policy, "MIT"), allow(&["MIT"]));
+    assert_eq!(evaluate(&policy, "MPL-2.0"), review(&["MPL-2.0"]));
+    assert_eq!(evaluate(&policy, "Unlicense"), review(&["Unlicense"]));
+    assert_eq!(
+        denied(evaluate(&policy, "GPL-3.0-only")),
+        reasons(&[("GPL-3.0-only", DenyReason::Copyleft(Copyleft::Strong))])
+    );
+    assert_eq!(
+        denied(evaluate(&policy, "AGPL-3.0-only")),
+        reasons(&[("AGPL-3.0-only", DenyReason::Copyleft(Copyleft::Network))])
+    );
+}
+
+#[test]
+fn ids_match_as_written_and_as_resolved() {
+    let policy = policy();
+    assert_eq!(
+        denied(evaluate(&policy, "GPL-2.0+")),
+        reasons(&[("GPL-2.0+", DenyReason::Id)])
+    );
+    assert_eq!(
+        denied(evaluate(&policy, "GPL-2.0-or-later")),
+        reasons(&[("GPL-2.0-or-later", DenyReason::Id)])
+    );
+    assert_eq!(evaluate(&policy, "Apache-2.0+"), allow(&["Apache-2.0+"]));
+}
+
+#[test]
+fn deny_beats_allow() {
+    let mut policy = policy();
+    policy.deny("MIT").unwrap();
+    assert_eq!(
+        denied(evaluate(&policy, "MIT")),
+        reasons(&[("MIT", DenyReason::Id)])
+    );
+}
+
+#[test]
+fn exceptions() {
+    let mut policy = policy();
+    // The exception weakens the copyleft of the GPL.
+    assert_eq!(
+        evaluate(&policy, "GPL-2.0-only WITH Classpath-exception-2.0"),
+        review(&["GPL-2.0-only WITH Classpath-exception-2.0"])
+    );
+    policy
+        .allow("GPL-2.0-only WITH Classpath-exception-2.0")
+        .unwrap();
+    assert_eq!(
+        evaluate(&policy, "GPL-2.0-only WITH Classpath-exception-2.0"),
+        allow(&["GPL-2.0-only WITH Classpath-exception-2.0"])
+    );
+    assert_eq!(
+        evaluate(&policy, "Apache-2.0 WITH LLVM-exception"),
+        allow(&["Apache-2.0 WITH LLVM-exception"])
+    );
+}
+
+#[test]
+fn or_picks_an_allowed_branch() {
+    let policy = policy();
+    assert_eq!(evaluate(&policy, "GPL-3.0-only OR MIT"), allow(&["MIT"]));
+    assert_eq!(evaluate(&policy, "MIT/GPL-3.0-only"), allow(&["MIT"]));
+    assert_eq!(
+        evaluate(&policy, "GPL-3.0-only OR MPL-2.0"),
+        review(&["MPL-2.0"])
+    );
+    assert_eq!(
+        denied(evaluate(&policy, "GPL-3.0-only OR GPL-2.0+")),
+        reasons(&[
+            ("GPL-3.0-only", DenyReason::Copyleft(Copyleft::Strong)),
+            ("GPL-2.0+", DenyReason::Id)
+        ])
+    );
+}
+
+#[test]
+fn or_prefers_the_more_permissive_branch() {
+    let mut policy = policy();
+    policy.allow("MPL-2.0").unwrap();
+    assert_eq!(evaluate(&policy, "MPL-2.0 OR MIT"), allow(&["MIT"]));
+    assert_eq!(evaluate(&policy, "MIT OR MPL-2.0"), allow(&["MIT"]));
+    assert_eq!(
+        evaluate(&policy, "(MPL-2.0 AND MIT) OR Apache-2.0"),
+        allow(&["Apache-2.0"])
+    );
+}
+
+#[test]
+fn or_picks_the_leftmost_of_equal_branches() {
+    let policy = policy();
+    assert_eq!(evaluate(&policy, "MIT OR Apache-2.0"), allow(&["MIT"]));
+    assert_eq!(
+        evaluate(&policy, "Apache-2.0 OR MIT"),
+        allow(&["Apache-2.0"])
+    );
+    for _ in 0..10 {
+        assert_eq!(
+            evaluate(&policy, "Apache-2.0 OR MIT"),
+            allow(&["Apache-2.0"])
+        );
+    }
+}
+
+#[test]
+fn and_needs_every_part() {
+    let policy = policy();
+    assert_eq!(
+        evaluate(&policy, "MIT AND Apache-2.0"),
+        allow(&["MIT", "Apache-2.0"])
+    );
+    assert_eq!(
+        evaluate(&policy, "MIT AND MPL-2.0 AND Unlicense"),
+        review(&["MPL-2.0", "Unlicense"])
+    );
+    assert_eq!(
+        denied(evaluate(&policy, "MPL-2.0 AND GPL-3.0-only")),
+        reasons(&[("GPL-3.0-only", DenyReason::Copyleft(Copyleft::Strong))])
+    );
+}
+
+#[test]
+fn and_binds_tighter_than_or() {
+    let policy = policy();
+    assert_eq!(
+        evaluate(&policy, "MIT AND GPL-3.0-only OR Apache-2.0"),
+        allow(&["Apache-2.0"])
+    );
+    assert_eq!(
+        evaluate(&policy, "(MIT OR GPL-3.0-only) AND (Apache-2.0 OR MPL-2.0)"),
+        allow(&["MIT", "Apache-2.0"])
+    );
+}
+
+#[test]
+fn denied_conditions() {
+    let mut policy = Policy::new();
+    policy
+        .allow_copyleft(Copyleft::None)
+        .allow_copyleft(Copyleft::Weak)
+        // notice_file and mark_modifications.
+        .deny_conditions(Conditions::from_bits_truncate(0b110_0000));
+    assert_eq!(
+        denied(evaluate(&policy, "Apache-2.0")),
+        reasons(&[(
+            "Apache-2.0",
+            DenyReason::Conditions(Conditions::from_bits_truncate(0b010_0000))
+        )])
+    );
+    assert_eq!(evaluate(&policy, "MIT OR Apache-2.0"), allow(&["MIT"]));
+}
+
+#[test]
+fn denials_display() {
+    let policy = policy();
+    let expression = Expression::parse("GPL-3.0-only OR GPL-2.0+").unwrap();
+    let reasons: Vec<_> = match policy.evaluate(&expression) {
+        Decision::Deny { reasons } => reasons.iter().map(|d| d.to_string()).collect(),
+        other => panic!("{:?}", other),
+    };
+    assert_eq!(
+        reasons,
+        [
+            "GPL-3.0-only has a denied strong copyleft",
+            "GPL-2.0+ is denied"
+        ]
+    );
+}
+
+#[test]
+fn declared_expressions() {
+    assert_eq!(
+        judged(policy().evaluate(&Expression::from(*declared!("Apache-2.0 OR MIT")))),
+        allow(&["Apache-2.0"])
+    );
+}
+
+/// Allows only the GPL-3.0, as written.
+fn gpl3_only() -> Policy {
+    let mut policy = Policy::new();
+    policy.allow("GPL-3.0-only").unwrap().allow("MIT").unwrap();
+    policy
+}
+
+fn effective(policy: &Policy, expression: &'static str) -> Option<Vec<String>> {
+    let license = PackageLicense::parse(expression).unwrap();
+    let effective = license.effective_licenses(policy)?;
+    Some(effective.iter().map(|l| l.to_string()).collect())
+}
+
+#[test]
+fn or_later_is_exercised_as_an_allowed_later_version() {
+    let policy = gpl3_only();
+    for expression in ["GPL-2.0-or-later", "GPL-2.0+", "GPL-3.0-or-later"] {
+        assert_eq!(evaluate(&policy, expression), allow(&[expression]));
+    }
+    assert_eq!(
+        effective(&policy, "GPL-2.0-or-later").unwrap(),
+        ["GPL-2.0-or-later exercised as GPL-3.0-only"]
+    );
+    assert_eq!(
+        effective(&policy, "GPL-2.0+ AND MIT").unwrap(),
+        ["GPL-2.0+ exercised as GPL-3.0-only", "MIT"]
+    );
+
+    let license = PackageLicense::parse("GPL-2.0-or-later").unwrap();
+    let effective = license.effective_licenses(&policy).unwrap();
+    assert_eq!(effective[0].written().to_string(), "GPL-2.0-or-later");
+    assert_eq!(effective[0].exercised(), Some("GPL-3.0-only"));
+}
+
+#[test]
+fn only_never_upgrades() {
+    let policy = gpl3_only();
+    for expression in ["GPL-2.0-only", "GPL-2.0", "LGPL-2.1-only", "GPL-1.0-only"] {
+        assert_eq!(evaluate(&policy, expression), review(&[expression]));
+        assert_eq!(effective(&policy, expression), None);
+    }
+    // The GPL family doesn't reach the LGPL or the AGPL.
+    assert_eq!(
+        evaluate(&policy, "LGPL-2.1-or-later"),
+        review(&["LGPL-2.1-or-later"])
+    );
+}
+
+#[test]
+fn or_later_keeps_earlier_versions_and_the_deny_list() {
+    let mut policy = Policy::new();
+    policy
+        .allow("GPL-2.0-only")
+        .unwrap()
+        .allow("GPL-3.0-only")
+        .unwrap();
+    assert_eq!(
+        effective(&policy, "GPL-2.0-or-later").unwrap(),
+        ["GPL-2.0-or-later exercised as GPL-2.0-only"]
+    );
+
+    policy.deny("GPL-2.0-or-later").unwrap();
+    assert_eq!(
+        denied(evaluate(&policy, "GPL-2.0+")),
+        reasons(&[("GPL-2.0+", DenyReason::Id)])
+    );
+
+    let mut policy = Policy::new();
+    policy.allow("MPL-2.0").unwrap();
+    assert_eq!(
+        effective(&policy, "MPL-1.1+").unwrap(),
+        ["MPL-1.1+ exercised as MPL-2.0"]
+    );
+    assert_eq!(evaluate(&policy, "MPL-1.1"), review(&["MPL-1.1"]));
+}
+
+#[test]
+fn effective_licenses_without_upgrades() {
+    assert_eq!(
+        effective(&policy(), "MIT OR GPL-3.0-only").unwrap(),
+        ["MIT"]
+    );
+    assert_eq!(effective(&policy(), "GPL-3.0-only"), None);
+}
+
+#[test]
+fn runtime_expressions() {
+    let policy = policy();
+    let field = String::from("MIT OR LicenseRef-Vendor");
+    assert_eq!(
+        judged(policy.evaluate_str(&field).unwrap()),
+        allow(&["MIT"])
+    );
+    assert_eq!(
+        judged(policy.evaluate_str("LicenseRef-Vendor AND ISC").unwrap()),
+        review(&["LicenseRef-Vendor", "ISC"])
+    );
+    for expression in ["MIT", "Apache-2.0 AND GPL-3.0-only", "GPL-2.0+ OR ISC"] {
+        assert_eq!(
+            judged(policy.evaluate_str(expression).unwrap()),
+            evaluate(&policy, expression)
+        );
+    }
+    let err = policy.evaluate_str("MIT OR").unwrap_err();
+    assert_eq!(
+        err,
+        license::ExpressionError::from(PackageLicense::parse("MIT OR").unwrap_err())
+    );
+}
+
+#[test]
+fn ids_are_checked_when_the_policy_is_built() {
+    let mut policy = Policy::new();
+    for id in [
+        "MIT",
+        "GPL-2.0+",
+        "GPL-2.0-only WITH Classpath-exception-2.0",
+        "LicenseRef-Vendor",
+    ] {
+        assert!(policy.allow(id).is_ok(), "{}", id);
+        assert!(policy.deny(id).is_ok(), "{}", id);
+    }
+    assert!(matches!(
+        policy.allow("Apache2.0").unwrap_err(),
+        PolicyError::UnknownLicense { id, suggestion: Some("Apache-2.0"), .. } if id == "Apache2.0"
+    ));
+    for id in ["mit", "MIT OR ISC", "", "GPL-2.0-only WITH Apache-2.0"] {
+        assert!(policy.deny(id).is_err(), "{}", id);
+    }
+    assert_eq!(
+        policy.deny("MIT OR ISC").unwrap_err().to_string(),
+        "unknown license id `MIT OR ISC`"
+    );
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+
+    #[test]
+    fn policy_from_toml() {
+        let loaded: Policy = toml::from_str(
+            "allow = [\"MIT\", \"Apache-2.0\"]\n\
+             deny = [\"GPL-2.0-or-later\"]\n\
+             deny_copyleft = [\"strong\", \"network\"]\n",
+        )
+        .unwrap();
+        assert_eq!(loaded, policy());
+        assert_eq!(toml::from_str::<Policy>("").unwrap(), Policy::new());
+
+        let loaded: Policy = serde_json::from_str(
+            r#"{"allow_copyleft": ["none"], "deny_conditions": ["network_use_is_distribution"]}"#,
+        )
+        .unwrap();
+        assert_eq!(evaluate(&loaded, "MIT"), allow(&["MIT"]));
+        let reasons = denied(evaluate(&loaded, "AGPL-3.0-only"));
+        assert!(matches!(
+            &reasons[..],
+            [(license, DenyReason::Conditions(_))] if license == "AGPL-3.0-only"
+        ));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut policy = policy();
+        policy
+            .allow_copyleft(Copyleft::Weak)
+            .deny_conditions(Conditions::from_bits_truncate(
+                license::Condition::DiscloseSources.bit(),
+            ));
+        let json = serde_json::to_string(&policy).unwrap();
+        assert_eq!(
+            json,
+            r#"{"allow":["MIT","Apache-2.0"],"deny":["GPL-2.0-or-later"],"allow_copyleft":["weak"],"deny_copyleft":["strong","network"],"deny_conditions":["disclose_sources"]}"#
+        );
+        assert_eq!(serde_json::from_str::<Policy>(&json).unwrap(), policy);
+        let toml = toml::to_string(&policy).unwrap();
+        assert_eq!(toml::from_str::<Policy>(&toml).unwrap(), policy);
+    }
+
+    #[test]
+    fn invalid_documents_are_errors() {
+        let err = serde_json::from_str::<Policy>(r#"{"allowed": []}"#).unwrap_err();
+        assert!(
+            err.to_string().starts_with("unknown field `allowed`"),
+            "{}",
+            err
+        );
+        let err = serde_json::from_str::<Policy>(r#"{"deny_copyleft": ["viral"]}"#).unwrap_err();
+        assert!(
+            err.to_string().starts_with("unknown variant `viral`"),
+            "{}",
+            err
+        );
+        let err = serde_json::from_str::<Policy>(r#"{"allow": ["Apache2.0"]}"#).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("unknown license id `Apache2.0`, did you mean `Apache-2.0`?"),
+            "{}",
+            err
+        );
+        let err =
+            serde_json::from_str::<Policy>(r#"{"deny_conditions": ["no_warranty"]}"#).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("unknown flag `no_warranty` of the conditions"),
+            "{}",
+            err
+        );
+    }
+}
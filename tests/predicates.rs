@@ -0,0 +1,248 @@
+use license::{LICENSES_EXT, NONE, UNKNOWN};
+
+/// Each license with `is_permissive`, and `requires_source_disclosure` when it is
+/// distributed and when it is offered as a network service.
+const PREDICATES: &[(&str, bool, bool, bool)] = &[
+    ("0BSD", true, false, false),
+    ("AFL-3.0", true, false, false),
+    ("AGPL-3.0-only", false, true, true),
+    // Deploying modified code externally, as a service, counts as distribution.
+    ("APSL-2.0", false, true, true),
+    ("Apache-2.0", true, false, false),
+    ("Apache-2.0 WITH SHL-2.1", true, false, false),
+    ("BSD-2-Clause", true, false, false),
+    ("BSD-3-Clause", true, false, false),
+    ("BSD-3-Clause-Clear", true, false, false),
+    ("BSD-4-Clause", true, false, false),
+    ("BSL-1.0", true, false, false),
+    // Source available: the source comes with it, but production use is restricted.
+    ("BUSL-1.1", false, false, false),
+    ("CC-BY-4.0", true, false, false),
+    // No copyleft, but no commercial use or no modification either.
+    ("CC-BY-NC-4.0", false, false, false),
+    ("CC-BY-NC-ND-4.0", false, false, false),
+    ("CC-BY-NC-SA-4.0", false, false, false),
+    ("CC-BY-ND-4.0", false, false, false),
+    // ShareAlike requires the same license, but not the disclosure of a source form.
+    ("CC-BY-SA-3.0", false, false, false),
+    ("CC-BY-SA-4.0", false, false, false),
+    ("CC0-1.0", true, false, false),
+    ("CECILL-2.0", false, true, false),
+    ("CECILL-2.1", false, true, false),
+    ("CERN-OHL-P-2.0", true, false, false),
+    ("CERN-OHL-S-2.0", false, true, false),
+    ("CERN-OHL-W-2.0", false, true, false),
+    ("CPL-1.0", false, true, false),
+    ("DL-DE-BY-2.0", true, false, false),
+    ("ECL-2.0", true, false, false),
+    ("EPL-1.0", false, true, false),
+    ("EPL-2.0", false, true, false),
+    ("EUPL-1.1", false, true, true),
+    ("EUPL-1.2", false, true, true),
+    // Source available, like BUSL-1.1.
+    ("Elastic-2.0", false, false, false),
+    // Requires a transparent copy for large numbers of copies, which isn't tracked.
+    ("GFDL-1.3-only", false, false, false),
+    ("GFDL-1.3-or-later", false, false, false),
+    ("GPL-2.0-only", false, true, false),
+    (
+        "GPL-2.0-only WITH Classpath-exception-2.0",
+        false,
+        true,
+        false,
+    ),
+    ("GPL-3.0-only", false, true, false),
+    ("LGPL-2.1-only", false, true, false),
+    ("LGPL-3.0-only", false, true, false),
+    ("LiLiQ-R-1.1", false, true, false),
+    ("LiLiQ-Rplus-1.1", false, true, false),
+    ("MIT", true, false, false),
+    ("MIT-CMU", true, false, false),
+    ("MIT-advertising", true, false, false),
+    ("MITNFA", true, false, false),
+    ("MPL-2.0", false, true, false),
+    ("MPL-2.0-no-copyleft-exception", false, true, false),
+    ("MS-PL", true, false, false),
+    ("MirOS", true, false, false),
+    ("NTP", true, false, false),
+    ("OGL-UK-3.0", true, false, false),
+    ("OSL-2.1", false, true, true),
+    ("OSL-3.0", false, true, true),
+    ("TAPR-OHL-1.0", false, true, false),
+    ("Unlicense", true, false, false),
+    ("WTFPL", true, false, false),
+    ("X11", true, false, false),
+    ("Zlib", true, false, false),
+];
+
+/// Each license with `private_modifications_allowed` when it is only used internally,
+/// and when it is also offered as a network service.
+const PRIVATE_MODIFICATIONS: &[(&str, bool, bool)] = &[
+    ("0BSD", true, true),
+    ("AFL-3.0", true, true),
+    // The service requires the source of the changes.
+    ("AGPL-3.0-only", true, false),
+    ("APSL-2.0", true, false),
+    ("Apache-2.0", true, true),
+    ("Apache-2.0 WITH SHL-2.1", true, true),
+    ("BSD-2-Clause", true, true),
+    ("BSD-3-Clause", true, true),
+    ("BSD-3-Clause-Clear", true, true),
+    ("BSD-4-Clause", true, true),
+    ("BSL-1.0", true, true),
+    ("BUSL-1.1", true, true),
+    ("CC-BY-4.0", true, true),
+    ("CC-BY-NC-4.0", true, true),
+    // Modification isn't granted, though the ND licenses allow adapted material that
+    // is never shared.
+    ("CC-BY-NC-ND-4.0", false, false),
+    ("CC-BY-NC-SA-4.0", true, true),
+    ("CC-BY-ND-4.0", false, false),
+    ("CC-BY-SA-3.0", true, true),
+    ("CC-BY-SA-4.0", true, true),
+    ("CC0-1.0", true, true),
+    ("CECILL-2.0", true, true),
+    ("CECILL-2.1", true, true),
+    ("CERN-OHL-P-2.0", true, true),
+    ("CERN-OHL-S-2.0", true, true),
+    ("CERN-OHL-W-2.0", true, true),
+    ("CPL-1.0", true, true),
+    ("DL-DE-BY-2.0", true, true),
+    ("ECL-2.0", true, true),
+    ("EPL-1.0", true, true),
+    ("EPL-2.0", true, true),
+    ("EUPL-1.1", true, false),
+    ("EUPL-1.2", true, false),
+    ("Elastic-2.0", true, true),
+    ("GFDL-1.3-only", true, true),
+    ("GFDL-1.3-or-later", true, true),
+    // Copyleft that only distribution triggers.
+    ("GPL-2.0-only", true, true),
+    ("GPL-2.0-only WITH Classpath-exception-2.0", true, true),
+    ("GPL-3.0-only", true, true),
+    ("LGPL-2.1-only", true, true),
+    ("LGPL-3.0-only", true, true),
+    ("LiLiQ-R-1.1", true, true),
+    ("LiLiQ-Rplus-1.1", true, true),
+    ("MIT", true, true),
+    ("MIT-CMU", true, true),
+    ("MIT-advertising", true, true),
+    ("MITNFA", true, true),
+    ("MPL-2.0", true, true),
+    ("MPL-2.0-no-copyleft-exception", true, true),
+    ("MS-PL", true, true),
+    ("MirOS", true, true),
+    ("NTP", true, true),
+    ("OGL-UK-3.0", true, true),
+    ("OSL-2.1", true, false),
+    ("OSL-3.0", true, false),
+    ("TAPR-OHL-1.0", true, true),
+    ("Unlicense", true, true),
+    ("WTFPL", true, true),
+    ("X11", true, true),
+    ("Zlib", true, true),
+];
+
+#[test]
+fn every_license_has_its_predicates() {
+    let ids: Vec<_> = LICENSES_EXT.iter().map(|l| l.id()).collect();
+    let expected: Vec<_> = PREDICATES.iter().map(|&(id, _, _, _)| id).collect();
+    assert_eq!(ids, expected, "PREDICATES is out of date");
+    for (license, &(id, permissive, distributed, network)) in LICENSES_EXT.iter().zip(PREDICATES) {
+        assert_eq!(license.is_permissive(), permissive, "{}", id);
+        assert_eq!(
+            license.requires_source_disclosure(true, false),
+            distributed,
+            "{}",
+            id
+        );
+        assert_eq!(
+            license.requires_source_disclosure(false, true),
+            network,
+            "{}",
+            id
+        );
+        assert!(!license.requires_source_disclosure(false, false), "{}", id);
+        assert_eq!(
+            license.requires_source_disclosure(true, true),
+            distributed || network,
+            "{}",
+            id
+        );
+    }
+}
+
+#[test]
+fn permissive_agrees_with_the_category() {
+    let category: Vec<_> = license::permissive().map(|l| l.id()).collect();
+    let predicate: Vec<_> = PREDICATES
+        .iter()
+        .filter(|&&(_, permissive, _, _)| permissive)
+        .map(|&(id, _, _, _)| id)
+        .collect();
+    assert_eq!(category, predicate);
+}
+
+#[test]
+fn permissive_licenses_never_require_disclosure() {
+    for license in LICENSES_EXT.iter().filter(|l| l.is_permissive()) {
+        assert!(
+            !license.requires_source_disclosure(true, true),
+            "{}",
+            license.id()
+        );
+    }
+}
+
+#[test]
+fn sentinels_are_conservative() {
+    for sentinel in [UNKNOWN, NONE] {
+        assert!(!sentinel.is_permissive());
+        assert!(sentinel.requires_source_disclosure(true, false));
+        assert!(sentinel.requires_source_disclosure(false, true));
+        assert!(!sentinel.requires_source_disclosure(false, false));
+        assert!(!sentinel.private_modifications_allowed(false));
+        assert!(!sentinel.private_modifications_allowed(true));
+    }
+}
+
+#[test]
+fn every_license_has_its_private_modifications() {
+    let ids: Vec<_> = LICENSES_EXT.iter().map(|l| l.id()).collect();
+    let expected: Vec<_> = PRIVATE_MODIFICATIONS.iter().map(|&(id, _, _)| id).collect();
+    assert_eq!(ids, expected, "PRIVATE_MODIFICATIONS is out of date");
+    for (license, &(id, internal, deployed)) in LICENSES_EXT.iter().zip(PRIVATE_MODIFICATIONS) {
+        assert_eq!(
+            license.private_modifications_allowed(false),
+            internal,
+            "{}",
+            id
+        );
+        assert_eq!(
+            license.private_modifications_allowed(true),
+            deployed,
+            "{}",
+            id
+        );
+    }
+}
+
+#[test]
+fn network_service_only_restricts() {
+    for license in LICENSES_EXT {
+        if license.private_modifications_allowed(true) {
+            assert!(
+                license.private_modifications_allowed(false),
+                "{}",
+                license.id()
+            );
+        }
+        if license.requires_source_disclosure(false, true) {
+            assert!(
+                !license.private_modifications_allowed(true),
+                "{}",
+                license.id()
+            );
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use license::prelude::*;
+
+#[test]
+fn prelude_calls_the_methods() {
+    let gpl = from_id_ext("GPL-3.0-only").unwrap();
+    assert!(gpl.conditions().same_license());
+    assert_eq!(from_id("MIT").unwrap().id(), "MIT");
+    let exception = from_id_exception("LLVM-exception").unwrap();
+    assert_eq!(exception.id(), "LLVM-exception");
+    assert_eq!(
+        from_id_flexible("GPL-2.0+").unwrap().license.id(),
+        "GPL-2.0-or-later"
+    );
+    assert!(UNKNOWN.conditions() == NONE.conditions());
+}
+
+#[test]
+fn zero_bsd() {
+    use license::licenses::ZERO_BSD;
+
+    assert_eq!(ZERO_BSD.id(), "0BSD");
+    assert_eq!(license::ZERO_BSD.id(), "0BSD");
+    let bsd = from_id_ext("0BSD").unwrap();
+    assert!(bsd.as_any().is::<ZERO_BSD>());
+    assert_eq!(license::LICENSES_EXT[0].id(), "0BSD");
+}
+
+#[test]
+#[allow(deprecated)]
+fn old_names_still_resolve() {
+    use license::licenses::{BSD_0, ZERO_BSD};
+
+    assert_eq!(BSD_0.id(), "0BSD");
+    assert_eq!(license::BSD_0, ZERO_BSD);
+    let old: BSD_0 = ZERO_BSD;
+    assert_eq!(old.name(), "BSD Zero Clause License");
+    assert!(from_id("0BSD").unwrap().as_any().is::<license::BSD_0>());
+}
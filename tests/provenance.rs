@@ -0,0 +1,82 @@
+use license::LICENSES;
+
+const MANIFEST: &str = include_str!("../src/provenance.tsv");
+
+/// The 64-bit FNV-1a hash of the UTF-8 bytes of a text, as the generator computes it.
+fn checksum(text: &str) -> u64 {
+    text.bytes().fold(0xCBF2_9CE4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01B3)
+    })
+}
+
+/// The entries of the manifest, as `(id, kind, source, checksum)`.
+fn entries() -> impl Iterator<Item = (&'static str, &'static str, &'static str, u64)> {
+    MANIFEST
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .skip(1)
+        .map(|line| {
+            let columns: Vec<_> = line.split('\t').collect();
+            assert_eq!(columns.len(), 4, "{}", line);
+            let checksum = u64::from_str_radix(columns[3], 16).unwrap();
+            (columns[0], columns[1], columns[2], checksum)
+        })
+}
+
+#[test]
+fn checksums_match_the_texts() {
+    for (id, kind, _, expected) in entries() {
+        let text = match kind {
+            "license" => license::from_id(id).unwrap().text(),
+            "exception" => license::from_id_exception(id).unwrap().text(),
+            _ => panic!("unknown kind `{}`", kind),
+        };
+        assert_eq!(checksum(text), expected, "the text of {} was edited", id);
+    }
+}
+
+#[test]
+fn every_license_has_an_entry() {
+    let licenses: Vec<_> = entries()
+        .filter(|(_, kind, _, _)| *kind == "license")
+        .map(|(id, _, _, _)| id)
+        .collect();
+    let ids: Vec<_> = LICENSES.iter().map(|l| l.id()).collect();
+    assert_eq!(licenses, ids);
+}
+
+/// The deviations from the policy, one line for each license that is newer than the
+/// retrieval.
+fn deviations() -> impl Iterator<Item = &'static str> {
+    MANIFEST
+        .lines()
+        .filter_map(|line| line.strip_prefix("# deviations: "))
+}
+
+#[test]
+fn sources_are_spdx() {
+    for (id, _, source, _) in entries() {
+        // A license that is newer than the retrieval is taken from the page its
+        // deviation names.
+        match deviations().find(|deviation| deviation.split(' ').next() == Some(id)) {
+            Some(deviation) => assert!(deviation.contains(source), "{}", deviation),
+            None => assert_eq!(source, format!("https://spdx.org/licenses/{}.json", id)),
+        }
+    }
+}
+
+#[test]
+fn placeholders_follow_the_policy() {
+    assert!(MANIFEST.contains("\n# policy: "));
+    // Licenses that are newer than the retrieval can be added by hand, and are named.
+    assert!(deviations().next().is_some());
+    for deviation in deviations().filter(|deviation| *deviation != "none") {
+        let id = deviation.split(' ').next().unwrap();
+        assert!(license::from_id(id).is_some(), "{}", deviation);
+    }
+    for license in LICENSES {
+        assert!(!license.text().contains("[fullname]"), "{}", license.id());
+    }
+    let mit = license::from_id("MIT").unwrap().text();
+    assert!(mit.contains("Copyright (c) <year> <copyright holders>\n"));
+}
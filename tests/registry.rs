@@ -0,0 +1,196 @@
+#![cfg(feature = "alloc")]
+
+use license::{License, LicenseExt, Metadata, RegisterError, Registry};
+use std::sync::Arc;
+use std::thread;
+
+/// A license with any id, as an organization would define.
+struct Internal(&'static str);
+
+impl License for Internal {
+    fn name(&self) -> &'static str {
+        "Internal License"
+    }
+
+    fn id(&self) -> &'static str {
+        self.0
+    }
+
+    fn text(&self) -> &'static str {
+        "For internal use only."
+    }
+
+    fn header(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn is_osi_approved(&self) -> bool {
+        false
+    }
+
+    fn is_fsf_libre(&self) -> bool {
+        false
+    }
+
+    fn is_deprecated(&self) -> bool {
+        false
+    }
+
+    fn see_also(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+impl LicenseExt for Internal {
+    fn metadata(&self) -> Metadata {
+        Metadata::default()
+    }
+}
+
+/// A license with the metadata of WTFPL.
+struct Permissive;
+
+impl License for Permissive {
+    fn name(&self) -> &'static str {
+        "Permissive License"
+    }
+
+    fn id(&self) -> &'static str {
+        "LicenseRef-Permissive"
+    }
+
+    fn text(&self) -> &'static str {
+        "Do as you please."
+    }
+
+    fn header(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn is_osi_approved(&self) -> bool {
+        false
+    }
+
+    fn is_fsf_libre(&self) -> bool {
+        false
+    }
+
+    fn is_deprecated(&self) -> bool {
+        false
+    }
+
+    fn see_also(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+impl LicenseExt for Permissive {
+    fn metadata(&self) -> Metadata {
+        license::from_id_ext("WTFPL").unwrap().metadata()
+    }
+}
+
+fn registry() -> Registry {
+    let mut registry = Registry::with_builtin();
+    registry
+        .register(Box::new(Internal("LicenseRef-Internal-1.0")))
+        .unwrap();
+    registry
+        .register(Box::new(Internal("LicenseRef-Internal-2.0")))
+        .unwrap();
+    registry
+}
+
+#[test]
+fn collisions_are_rejected() {
+    let mut registry = registry();
+    let err = registry.register(Box::new(Internal("MIT"))).unwrap_err();
+    assert!(matches!(err, RegisterError::Builtin { id: "MIT", .. }));
+    assert_eq!(err.to_string(), "`MIT` is the id of a builtin license");
+    assert!(matches!(
+        registry.register(Box::new(Internal(
+            "GPL-2.0-only WITH Classpath-exception-2.0"
+        ))),
+        Err(RegisterError::Builtin { .. })
+    ));
+    let err = registry
+        .register(Box::new(Internal("LicenseRef-Internal-1.0")))
+        .unwrap_err();
+    assert!(matches!(err, RegisterError::Registered { .. }));
+    assert_eq!(
+        err.to_string(),
+        "a license with the id `LicenseRef-Internal-1.0` is already registered"
+    );
+    assert_eq!(registry.iter().count(), license::LICENSES_EXT.len() + 2);
+}
+
+#[test]
+fn builtins_are_not_shadowed() {
+    let registry = registry();
+    for builtin in license::LICENSES_EXT {
+        let found = registry.get(builtin.id()).unwrap();
+        assert_eq!(found.as_any().type_id(), builtin.as_any().type_id());
+    }
+    assert_eq!(
+        registry.get("LicenseRef-Internal-2.0").unwrap().text(),
+        "For internal use only."
+    );
+    assert!(registry.get("LicenseRef-Internal-3.0").is_none());
+}
+
+#[test]
+fn registry_is_shared_between_threads() {
+    let registry = Arc::new(registry());
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let registry = Arc::clone(&registry);
+            thread::spawn(move || {
+                registry.get("LicenseRef-Internal-1.0").unwrap().id() == "LicenseRef-Internal-1.0"
+                    && registry.get("Apache-2.0").is_some()
+            })
+        })
+        .collect();
+    for handle in handles {
+        assert!(handle.join().unwrap());
+    }
+}
+
+#[test]
+fn registered_licenses_are_ordered_by_id() {
+    let mut registry = Registry::with_builtin();
+    for id in &["LicenseRef-C", "LicenseRef-A", "LicenseRef-B"] {
+        registry.register(Box::new(Internal(id))).unwrap();
+    }
+    let registered: Vec<_> = registry
+        .iter()
+        .skip(license::LICENSES_EXT.len())
+        .map(|l| l.id())
+        .collect();
+    assert_eq!(registered, ["LicenseRef-A", "LicenseRef-B", "LicenseRef-C"]);
+    assert_eq!(registry.get("LicenseRef-B").unwrap().id(), "LicenseRef-B");
+}
+
+#[test]
+fn accessors_follow_the_metadata() {
+    let wtfpl = license::from_id_ext("WTFPL").unwrap();
+    assert!(Permissive.is_permissive());
+    assert_eq!(
+        Permissive.compact_legend().to_string(),
+        wtfpl.compact_legend().to_string()
+    );
+
+    let mut registry = Registry::with_builtin();
+    registry.register(Box::new(Permissive)).unwrap();
+    let permissive = registry.get("LicenseRef-Permissive").unwrap();
+    assert_eq!(permissive.permissions(), wtfpl.permissions());
+    assert_eq!(permissive.conditions(), wtfpl.conditions());
+    assert_eq!(permissive.limitations(), wtfpl.limitations());
+}
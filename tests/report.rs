@@ -0,0 +1,72 @@
+use license::report::{self, Copyleft};
+use license::LicenseExt;
+
+fn licenses(ids: &[&str]) -> Vec<&'static dyn LicenseExt> {
+    ids.iter()
+        .map(|id| license::from_id_ext(id).unwrap())
+        .collect()
+}
+
+#[test]
+fn workspace_mix() {
+    let licenses = licenses(&["MIT", "Apache-2.0", "MPL-2.0", "GPL-3.0-only"]);
+    let report = report::combine(&licenses);
+    assert_eq!(report.copyleft(), Copyleft::Strong);
+    assert_eq!(
+        report.to_string(),
+        "# License report\n\
+         \n\
+         - Licenses: Apache-2.0, GPL-3.0-only, MIT, MPL-2.0\n\
+         - Strongest copyleft: strong\n\
+         - Outbound license floor: GPL-3.0-only\n\
+         - Triggered by network use: none\n\
+         \n\
+         ## Combined conditions\n\
+         \n\
+         - Source code must be made available when the software is distributed.\n\
+         - Changes made to the code must be documented.\n\
+         - The license and copyright notice must be included with the software.\n\
+         - Modifications must be released under the same license.\n\
+         - The attribution notices in a NOTICE file must be included with the software.\n"
+    );
+}
+
+#[test]
+fn network_copyleft_sets_the_floor() {
+    let licenses = licenses(&["GPL-3.0-only", "AGPL-3.0-only", "APSL-2.0", "Zlib"]);
+    let report = report::combine(&licenses);
+    assert_eq!(report.copyleft(), Copyleft::Network);
+    let floor: Vec<_> = report.floor().map(|l| l.id()).collect();
+    assert_eq!(floor, ["AGPL-3.0-only"]);
+    let network: Vec<_> = report.network_triggering().map(|l| l.id()).collect();
+    assert_eq!(network, ["AGPL-3.0-only", "APSL-2.0"]);
+}
+
+#[test]
+fn permissive_and_weak_have_no_floor() {
+    let licenses = licenses(&["MIT", "LGPL-3.0-only"]);
+    let report = report::combine(&licenses);
+    assert_eq!(report.copyleft(), Copyleft::Weak);
+    assert_eq!(report.floor().count(), 0);
+
+    let empty = report::combine(&[]);
+    assert_eq!(empty.copyleft(), Copyleft::None);
+    assert_eq!(
+        empty.to_string(),
+        "# License report\n\
+         \n\
+         - Licenses: none\n\
+         - Strongest copyleft: none\n\
+         - Outbound license floor: none\n\
+         - Triggered by network use: none\n\
+         - No conditions.\n"
+    );
+}
+
+#[test]
+fn public_domain_equivalents_have_no_conditions_section() {
+    let licenses = licenses(&["WTFPL", "0BSD", "Unlicense", "CC0-1.0"]);
+    let report = report::combine(&licenses).to_string();
+    assert!(report.ends_with("- Triggered by network use: none\n- No conditions.\n"));
+    assert!(!report.contains("## Combined conditions"));
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "fs")]
+
+use license::scan::{self, ScanHit, ScanOutcome};
+use std::fs;
+use std::path::Path;
+
+fn text(id: &str) -> &'static str {
+    license::from_id(id).unwrap().text()
+}
+
+fn relative(hits: &[ScanHit], root: &Path) -> Vec<String> {
+    hits.iter()
+        .map(|hit| {
+            let path = hit.path.strip_prefix(root).unwrap();
+            path.to_string_lossy().replace('\\', "/")
+        })
+        .collect()
+}
+
+#[test]
+fn license_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    let mit = text("MIT").replace("<year> <copyright holders>", "2024 Jane Doe");
+    fs::write(root.join("LICENSE-MIT"), &mit).unwrap();
+    fs::write(root.join("README.md"), &mit).unwrap();
+    fs::create_dir_all(root.join("vendor/a")).unwrap();
+    fs::create_dir_all(root.join("vendor/b")).unwrap();
+    let changed = format!("{}\nThis file was changed by Example Corp.\n", text("ISC"));
+    fs::write(root.join("vendor/a/license.txt"), changed).unwrap();
+    fs::write(root.join("vendor/a/COPYING"), " \n\n").unwrap();
+    fs::write(root.join("vendor/b/NOTICE"), b"Copyright (c) 2024 J\xf6rg").unwrap();
+    let both = format!("{}\n\nvendor/zlib:\n\n{}", mit, text("Zlib"));
+    fs::write(root.join("vendor/b/LICENSE"), both).unwrap();
+    fs::write(
+        root.join("vendor/b/NOTICES"),
+        "This product includes software.",
+    )
+    .unwrap();
+
+    let hits: Vec<_> = scan::scan_dir(root).collect();
+    assert_eq!(
+        relative(&hits, root),
+        [
+            "LICENSE-MIT",
+            "vendor/a/COPYING",
+            "vendor/a/license.txt",
+            "vendor/b/LICENSE",
+            "vendor/b/NOTICE",
+            "vendor/b/NOTICES",
+        ]
+    );
+    assert!(matches!(&hits[0].outcome, ScanOutcome::Exact(found) if found.license().id() == "MIT"));
+    assert_eq!(hits[0].license().unwrap().id(), "MIT");
+    assert!(matches!(hits[1].outcome, ScanOutcome::Empty));
+    assert!(matches!(&hits[2].outcome, ScanOutcome::Fuzzy(found) if found.license().id() == "ISC"));
+    match &hits[3].outcome {
+        ScanOutcome::Several(regions) => {
+            let ids: Vec<_> = regions.iter().map(|m| m.license().id()).collect();
+            assert_eq!(ids, ["MIT", "Zlib"]);
+        }
+        outcome => panic!("{:?}", outcome),
+    }
+    assert!(hits[3].license().is_none());
+    assert!(matches!(hits[4].outcome, ScanOutcome::NotUtf8));
+    match hits[5].outcome {
+        ScanOutcome::Unmatched { similarity } => {
+            assert!(similarity < license::MIN_TEXT_SIMILARITY)
+        }
+        ref outcome => panic!("{:?}", outcome),
+    }
+}
+
+#[test]
+fn missing_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir.path().join("missing");
+    let hits: Vec<_> = scan::scan_dir(&missing).collect();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].path, missing);
+    assert!(matches!(
+        hits[0].outcome,
+        ScanOutcome::Error(std::io::ErrorKind::NotFound)
+    ));
+}
+
+#[cfg(unix)]
+#[test]
+fn symlink_cycles() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::create_dir(root.join("vendor")).unwrap();
+    fs::write(root.join("vendor/LICENSE"), text("Apache-2.0")).unwrap();
+    std::os::unix::fs::symlink(root, root.join("vendor/root")).unwrap();
+    std::os::unix::fs::symlink("vendor", root.join("again")).unwrap();
+    std::os::unix::fs::symlink("missing", root.join("LICENSE")).unwrap();
+
+    let hits: Vec<_> = scan::scan_dir(root).collect();
+    assert_eq!(relative(&hits, root), ["LICENSE", "again/LICENSE"]);
+    assert!(matches!(
+        hits[0].outcome,
+        ScanOutcome::Error(std::io::ErrorKind::NotFound)
+    ));
+    assert_eq!(hits[1].license().unwrap().id(), "Apache-2.0");
+}
@@ -0,0 +1,57 @@
+use license::License;
+
+/// Asserts that the spans of the sections cover the text with no gaps or overlaps.
+fn assert_partitions(license: &dyn License) {
+    let text = license.text();
+    let mut end = 0;
+    let mut rebuilt = String::new();
+    for section in license.sections() {
+        assert_eq!(section.span().start, end, "{}: {:?}", license.id(), section);
+        end = section.span().end;
+        rebuilt.push_str(&text[section.span()]);
+    }
+    assert_eq!(end, text.len(), "{}", license.id());
+    assert_eq!(rebuilt, text, "{}", license.id());
+}
+
+#[test]
+fn gpl_3_0_sections_reconstruct_the_text() {
+    let gpl = license::from_id("GPL-3.0-only").unwrap();
+    assert_partitions(gpl);
+
+    let numbers: Vec<_> = gpl.sections().iter().filter_map(|s| s.number()).collect();
+    let expected: Vec<_> = (0..=17).map(|n| n.to_string()).collect();
+    assert_eq!(numbers, expected);
+    assert_eq!(gpl.sections()[0].title(), "GNU GENERAL PUBLIC LICENSE");
+    assert_eq!(
+        gpl.sections().last().unwrap().title(),
+        "END OF TERMS AND CONDITIONS"
+    );
+    assert!(gpl.section("11").unwrap().starts_with("11. Patents."));
+}
+
+#[test]
+fn sections_partition_every_text() {
+    for license in license::LICENSES {
+        if !license.sections().is_empty() {
+            assert_partitions(*license);
+        }
+    }
+}
+
+#[test]
+fn free_form_texts_have_no_sections() {
+    for id in ["MIT", "ISC", "0BSD"] {
+        let license = license::from_id(id).unwrap();
+        assert!(license.sections().is_empty(), "{}", id);
+        assert_eq!(license.section("1"), None, "{}", id);
+    }
+}
+
+#[test]
+fn with_exception_has_the_sections_of_the_license() {
+    let gpl = license::from_id("GPL-2.0-only").unwrap();
+    let classpath = license::from_id_ext("GPL-2.0-only WITH Classpath-exception-2.0").unwrap();
+    assert_eq!(classpath.sections(), gpl.sections());
+    assert!(!gpl.sections().is_empty());
+}
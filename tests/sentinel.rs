@@ -0,0 +1,77 @@
+use license::report::{self, Copyleft};
+use license::{Confidence, Coverage, Linkage, ListStyle, SummaryDisplay, LICENSES};
+
+#[test]
+fn lookups_resolve_the_sentinels() {
+    for (id, sentinel) in [("NOASSERTION", license::UNKNOWN), ("NONE", license::NONE)] {
+        assert_eq!(sentinel.id(), id);
+        assert_eq!(license::from_id(id).unwrap().id(), id);
+        assert_eq!(license::from_id_ext(id).unwrap().id(), id);
+        assert_eq!(license::from_id_flexible(id).unwrap().license.id(), id);
+        assert!(license::from_id(id).unwrap().ext().is_some());
+        assert!(LICENSES.iter().all(|l| l.id() != id));
+    }
+    assert!(license::from_id("noassertion").is_none());
+    assert!(license::from_id_ext("UNKNOWN").is_none());
+}
+
+#[test]
+fn sentinels_are_not_permissive() {
+    for sentinel in [license::UNKNOWN, license::NONE] {
+        assert!(sentinel.permissions().is_empty());
+        assert!(sentinel.conditions().is_empty());
+        assert!(sentinel.limitations().is_empty());
+        assert!(!sentinel.is_osi_approved() && !sentinel.is_fsf_libre());
+        assert_eq!(Copyleft::of(sentinel), Copyleft::Network);
+        assert!(sentinel
+            .binary_distribution_requirements()
+            .needs_legal_review());
+        let linking = sentinel.obligations_for_linking(Linkage::Dynamic);
+        assert_eq!(linking.coverage(), Coverage::Unclear);
+        assert_eq!(linking.confidence(), Confidence::Low);
+        assert!(license::permissive().all(|l| l.id() != sentinel.id()));
+    }
+}
+
+#[test]
+fn summaries_say_what_the_sentinels_are() {
+    let summary = |license| SummaryDisplay::new(license, ListStyle::default()).to_string();
+    assert_eq!(
+        summary(license::UNKNOWN),
+        "License could not be determined.\n"
+    );
+    assert_eq!(summary(license::NONE), "No license, all rights reserved.\n");
+    let style = ListStyle {
+        trailing_newline: false,
+        ..ListStyle::default()
+    };
+    assert_eq!(
+        SummaryDisplay::new(license::UNKNOWN, style).to_string(),
+        "License could not be determined."
+    );
+}
+
+#[test]
+fn reports_note_the_sentinels() {
+    let licenses = [
+        license::from_id_ext("MIT").unwrap(),
+        license::UNKNOWN,
+        license::NONE,
+        license::UNKNOWN,
+    ];
+    assert_eq!(
+        report::combine(&licenses).to_string(),
+        "# License report\n\
+         \n\
+         - Licenses: MIT, NOASSERTION, NONE\n\
+         - NOASSERTION: License could not be determined.\n\
+         - NONE: No license, all rights reserved.\n\
+         - Strongest copyleft: network\n\
+         - Outbound license floor: NOASSERTION, NONE\n\
+         - Triggered by network use: none\n\
+         \n\
+         ## Combined conditions\n\
+         \n\
+         - The license and copyright notice must be included with the software.\n"
+    );
+}
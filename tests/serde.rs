@@ -0,0 +1,92 @@
+#![cfg(feature = "serde")]
+
+use license::LicenseExt;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct Strict {
+    #[serde(with = "license::serde::license_ref")]
+    license: &'static dyn LicenseExt,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Lenient {
+    #[serde(with = "license::serde::license_ref_lenient")]
+    license: &'static dyn LicenseExt,
+}
+
+#[test]
+fn json_round_trip() {
+    for license in license::LICENSES_EXT {
+        let json = serde_json::to_string(&Strict { license: *license }).unwrap();
+        assert_eq!(json, format!(r#"{{"license":"{}"}}"#, license.id()));
+        let strict: Strict = serde_json::from_str(&json).unwrap();
+        assert_eq!(strict.license.id(), license.id());
+    }
+}
+
+#[test]
+fn toml_round_trip() {
+    let apache = Strict {
+        license: license::from_id_ext("Apache-2.0").unwrap(),
+    };
+    let toml = toml::to_string(&apache).unwrap();
+    assert_eq!(toml, "license = \"Apache-2.0\"\n");
+    let strict: Strict = toml::from_str(&toml).unwrap();
+    assert_eq!(strict.license.id(), "Apache-2.0");
+}
+
+#[test]
+fn unknown_ids_are_errors() {
+    let err = serde_json::from_str::<Strict>(r#"{"license":"Apache-2"}"#)
+        .err()
+        .unwrap();
+    assert!(
+        err.to_string()
+            .starts_with("unknown license id `Apache-2`, did you mean `Apache-2.0`?"),
+        "{}",
+        err
+    );
+
+    let err = toml::from_str::<Strict>("license = \"Proprietary\"")
+        .err()
+        .unwrap();
+    assert!(
+        err.message() == "unknown license id `Proprietary`",
+        "{}",
+        err.message()
+    );
+
+    let err = serde_json::from_str::<Strict>(r#"{"license":"mit"}"#)
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("did you mean `MIT`?"), "{}", err);
+}
+
+#[test]
+fn lenient() {
+    for (input, id) in [
+        ("mit", "MIT"),
+        ("GPLv3", "GPL-3.0-only"),
+        ("GPL-2.0", "GPL-2.0-only"),
+        ("ASL-2.0", "Apache-2.0"),
+        (
+            "GPL-2.0 WITH Classpath-exception-2.0",
+            "GPL-2.0-only WITH Classpath-exception-2.0",
+        ),
+    ] {
+        let json = format!(r#"{{"license":"{}"}}"#, input);
+        let lenient: Lenient = serde_json::from_str(&json).unwrap();
+        assert_eq!(lenient.license.id(), id);
+        assert_eq!(
+            serde_json::to_string(&lenient).unwrap(),
+            format!(r#"{{"license":"{}"}}"#, id)
+        );
+
+        let toml = format!("license = \"{}\"", input);
+        let lenient: Lenient = toml::from_str(&toml).unwrap();
+        assert_eq!(lenient.license.id(), id);
+    }
+    assert!(serde_json::from_str::<Strict>(r#"{"license":"GPLv3"}"#).is_err());
+    assert!(serde_json::from_str::<Lenient>(r#"{"license":"Unknown"}"#).is_err());
+}
@@ -0,0 +1,69 @@
+use license::{LicenseExt, LICENSES, LICENSES_EXT};
+use std::collections::HashMap;
+
+/// The transformation documented on [`LicenseExt::slug`].
+fn slug_of(id: &str) -> String {
+    id.to_ascii_lowercase()
+        .replace(' ', "-")
+        .replace('+', "-plus")
+}
+
+fn all() -> impl Iterator<Item = &'static dyn LicenseExt> {
+    LICENSES_EXT
+        .iter()
+        .copied()
+        .chain([license::UNKNOWN, license::NONE])
+}
+
+#[test]
+fn slugs_follow_the_documented_transformation() {
+    for l in all() {
+        assert_eq!(l.slug(), slug_of(l.id()), "{}", l.id());
+    }
+}
+
+#[test]
+fn slugs_are_safe_in_file_names_and_urls() {
+    for l in all() {
+        let slug = l.slug();
+        assert!(!slug.is_empty() && !slug.starts_with('.'), "{}", slug);
+        assert!(
+            slug.bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'.'),
+            "{}",
+            slug
+        );
+    }
+}
+
+#[test]
+fn slugs_never_collide() {
+    let mut seen = HashMap::new();
+    for l in all() {
+        if let Some(other) = seen.insert(l.slug(), l.id()) {
+            panic!("{} and {} share the slug {}", other, l.id(), l.slug());
+        }
+    }
+}
+
+#[test]
+fn the_transformation_would_not_collide_for_any_license() {
+    let mut seen = HashMap::new();
+    for l in LICENSES {
+        if let Some(other) = seen.insert(slug_of(l.id()), l.id()) {
+            panic!("{} and {} would share a slug", other, l.id());
+        }
+    }
+    assert_eq!(slug_of("GPL-2.0+"), "gpl-2.0-plus");
+    assert_eq!(slug_of("OFL-1.1"), "ofl-1.1");
+    assert!(seen.contains_key("gpl-2.0-or-later"));
+}
+
+#[test]
+fn from_slug_round_trips() {
+    for l in all() {
+        assert_eq!(license::from_slug(l.slug()).map(|l| l.id()), Some(l.id()));
+    }
+    assert!(license::from_slug("WTFPL").is_none());
+    assert!(license::from_slug("gpl-2.0-only-").is_none());
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "spdx-doc")]
+
+use license::expression::Expression;
+use license::spdx_doc::SpdxDocument;
+use serde_json::Value;
+
+/// The JSON schema of SPDX 2.3, from https://github.com/spdx/spdx-spec.
+const SCHEMA: &str = include_str!("fixtures/spdx-schema-2.3.json");
+
+fn validate(document: &SpdxDocument) -> Value {
+    let json = document.to_string();
+    let instance: Value = serde_json::from_str(&json).unwrap();
+    let mut schemas = boon::Schemas::new();
+    let mut compiler = boon::Compiler::new();
+    compiler
+        .add_resource(
+            "http://spdx.org/rdf/terms/2.3",
+            serde_json::from_str(SCHEMA).unwrap(),
+        )
+        .unwrap();
+    let schema = compiler
+        .compile("http://spdx.org/rdf/terms/2.3", &mut schemas)
+        .unwrap();
+    if let Err(err) = schemas.validate(&instance, schema) {
+        panic!("{}\n{}", err, json);
+    }
+    instance
+}
+
+fn parse(expression: &str) -> Expression {
+    Expression::parse(expression).unwrap()
+}
+
+#[test]
+fn packages() {
+    let declared = parse("MIT OR Apache-2.0");
+    let vendored = parse("(LicenseRef-Vendor WITH LLVM-exception OR MIT) AND LicenseRef-Fonts");
+    let foreign = parse("DocumentRef-Upstream:LicenseRef-Upstream OR GPL-2.0+");
+    let document = SpdxDocument::new("example-1.0.0", "https://example.com/spdx/example-1.0.0")
+        .with_created(1_700_000_000)
+        .with_creator("Organization: Example Corp")
+        .with_extracted_text("LicenseRef-Fonts", "The fonts may be \"embedded\".\n")
+        .add_package("example", "1.0.0", &declared, Some(&declared))
+        .add_package(
+            "vendored",
+            "0.1.0",
+            &vendored,
+            Some(&parse("LicenseRef-Vendor")),
+        )
+        .add_package("foreign", "2.0.0", &foreign, None);
+    let json = validate(&document);
+
+    assert_eq!(json["spdxVersion"], "SPDX-2.3");
+    assert_eq!(json["dataLicense"], "CC0-1.0");
+    assert_eq!(json["creationInfo"]["created"], "2023-11-14T22:13:20Z");
+    let creators = &json["creationInfo"]["creators"];
+    assert!(creators[0].as_str().unwrap().starts_with("Tool: license-"));
+    assert_eq!(creators[1], "Organization: Example Corp");
+    if let Some(version) = json["creationInfo"].get("licenseListVersion") {
+        let version = version.as_str().unwrap();
+        assert!(
+            version.split('.').all(|n| n.parse::<u32>().is_ok()),
+            "{}",
+            version
+        );
+    }
+
+    let packages = json["packages"].as_array().unwrap();
+    let ids: Vec<_> = packages
+        .iter()
+        .map(|p| p["SPDXID"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        ids,
+        [
+            "SPDXRef-Package-1",
+            "SPDXRef-Package-2",
+            "SPDXRef-Package-3"
+        ]
+    );
+    assert_eq!(packages[0]["licenseDeclared"], "MIT OR Apache-2.0");
+    assert_eq!(packages[0]["licenseConcluded"], "MIT OR Apache-2.0");
+    assert_eq!(packages[2]["licenseConcluded"], "NOASSERTION");
+    for (package, expression) in packages.iter().zip(&[&declared, &vendored, &foreign]) {
+        let written = package["licenseDeclared"].as_str().unwrap();
+        assert_eq!(&parse(written), *expression);
+    }
+
+    let extracted = json["hasExtractedLicensingInfos"].as_array().unwrap();
+    let refs: Vec<_> = extracted
+        .iter()
+        .map(|info| {
+            (
+                info["licenseId"].as_str().unwrap(),
+                info["extractedText"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        refs,
+        [
+            ("LicenseRef-Fonts", "The fonts may be \"embedded\".\n"),
+            ("LicenseRef-Vendor", "NOASSERTION"),
+        ]
+    );
+
+    let described: Vec<_> = json["relationships"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| {
+            assert_eq!(r["spdxElementId"], "SPDXRef-DOCUMENT");
+            assert_eq!(r["relationshipType"], "DESCRIBES");
+            r["relatedSpdxElement"].as_str().unwrap()
+        })
+        .collect();
+    assert_eq!(described, ids);
+}
+
+#[test]
+fn empty() {
+    let document = SpdxDocument::new("empty", "https://example.com/spdx/empty").with_created(0);
+    let json = validate(&document);
+    assert_eq!(json["creationInfo"]["created"], "1970-01-01T00:00:00Z");
+    assert_eq!(json["packages"], Value::Array(Vec::new()));
+    assert!(json.get("hasExtractedLicensingInfos").is_none());
+}
+
+#[test]
+fn created() {
+    let created = |secs| {
+        let document = SpdxDocument::new("a", "https://example.com/a").with_created(secs);
+        validate(&document)["creationInfo"]["created"].clone()
+    };
+    assert_eq!(created(951_782_400), "2000-02-29T00:00:00Z");
+    assert_eq!(created(951_955_199), "2000-03-01T23:59:59Z");
+    assert_eq!(created(4_107_542_399), "2100-02-28T23:59:59Z");
+}
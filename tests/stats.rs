@@ -0,0 +1,118 @@
+#![cfg(feature = "alloc")]
+
+use license::report::Copyleft;
+use license::stats::Tally;
+
+/// A synthetic dependency tree of 14 packages under 5 licenses.
+fn tally() -> Tally {
+    let packages = [
+        "MIT",
+        "Apache-2.0",
+        "MIT",
+        "BSD-3-Clause",
+        "MPL-2.0",
+        "MIT",
+        "Apache-2.0",
+        "GPL-3.0-only",
+        "BSD-3-Clause",
+        "MIT",
+        "MPL-2.0",
+        "Apache-2.0",
+        "BSD-3-Clause",
+        "MIT",
+    ];
+    packages
+        .iter()
+        .map(|id| license::from_id_ext(id).unwrap())
+        .collect()
+}
+
+#[test]
+fn counts() {
+    let tally = tally();
+    assert_eq!(tally.total(), 14);
+    assert_eq!(tally.count("MIT"), 5);
+    assert_eq!(tally.count("GPL-3.0-only"), 1);
+    assert_eq!(tally.count("Zlib"), 0);
+    assert_eq!(
+        tally.by_category(),
+        [
+            (Copyleft::None, 11),
+            (Copyleft::Weak, 2),
+            (Copyleft::Strong, 1),
+            (Copyleft::Network, 0),
+        ]
+    );
+    assert_eq!(tally.condition_counts()[0], ("disclose_sources", 3));
+    assert!(tally.patent_mismatch());
+}
+
+#[test]
+fn table() {
+    assert_eq!(
+        tally().to_string(),
+        "# License statistics
+
+- Total: 14
+- Patent mismatch: yes
+
+| License | Count |
+| --- | ---: |
+| MIT | 5 |
+| Apache-2.0 | 3 |
+| BSD-3-Clause | 3 |
+| MPL-2.0 | 2 |
+| GPL-3.0-only | 1 |
+
+| Copyleft | Count |
+| --- | ---: |
+| none | 11 |
+| weak | 2 |
+| strong | 1 |
+| network | 0 |
+
+| Condition | Count |
+| --- | ---: |
+| disclose_sources | 3 |
+| document_changes | 4 |
+| license_and_copyright_notice | 14 |
+| network_use_is_distribution | 0 |
+| same_license | 3 |
+| notice_file | 3 |
+| mark_modifications | 0 |
+"
+    );
+}
+
+#[test]
+fn many_insertions() {
+    let mut tally = Tally::new();
+    for license in license::LICENSES_EXT.iter().cycle().take(50_000) {
+        tally.insert(*license);
+    }
+    tally.insert_many(license::from_id_ext("MIT").unwrap(), 1_000_000);
+    assert_eq!(tally.total(), 1_050_000);
+    assert_eq!(tally.licenses().count(), license::LICENSES_EXT.len());
+    assert_eq!(tally.licenses().next().unwrap().0.id(), "MIT");
+}
+
+#[test]
+fn no_patent_mismatch_without_termination() {
+    let tally: Tally = ["MIT", "BSD-2-Clause", "EUPL-1.2"]
+        .iter()
+        .map(|id| license::from_id_ext(id).unwrap())
+        .collect();
+    assert!(!tally.patent_mismatch());
+}
+
+#[test]
+fn rendering_does_not_depend_on_the_insertion_order() {
+    let mut reversed: Vec<_> = tally()
+        .licenses()
+        .flat_map(|(l, count)| std::iter::repeat(l).take(count as usize))
+        .collect();
+    reversed.reverse();
+    let reversed: Tally = reversed.into_iter().collect();
+    assert_eq!(reversed.to_string(), tally().to_string());
+    assert_eq!(format!("{:?}", reversed), format!("{:?}", tally()));
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "alloc")]
+
+use license::expression::{Expression, Leaf};
+use license::policy::{Decision, Policy};
+use license::tags::TagSet;
+use license::TagError;
+
+fn tags_for(tags: &TagSet, id: &str) -> Vec<String> {
+    tags.tags_for(license::from_id(id).unwrap()).to_vec()
+}
+
+#[test]
+fn tags_are_looked_up_by_license() {
+    let mut tags = TagSet::new();
+    tags.insert("MIT", "approved")
+        .unwrap()
+        .insert("MIT", "attribution")
+        .unwrap()
+        .insert("MIT", "approved")
+        .unwrap()
+        .insert("GPL-2.0-only WITH Classpath-exception-2.0", "approved")
+        .unwrap();
+    assert_eq!(tags_for(&tags, "MIT"), ["approved", "attribution"]);
+    assert!(tags_for(&tags, "GPL-2.0-only").is_empty());
+    let classpath = license::from_id_ext("GPL-2.0-only WITH Classpath-exception-2.0").unwrap();
+    assert_eq!(tags.tags_for(classpath), ["approved"]);
+    assert_eq!(
+        tags.iter()
+            .map(|(id, tags)| (id, tags.len()))
+            .collect::<Vec<_>>(),
+        [("GPL-2.0-only WITH Classpath-exception-2.0", 1), ("MIT", 2)]
+    );
+    assert_eq!(
+        tags.licenses_with("attribution").collect::<Vec<_>>(),
+        ["MIT"]
+    );
+    assert_eq!(tags.licenses_with("banned").count(), 0);
+}
+
+#[test]
+fn unknown_ids_are_errors() {
+    let mut tags = TagSet::new();
+    let err = tags.insert("GPL-3.0-onyl", "approved").unwrap_err();
+    match &err {
+        TagError::UnknownLicense { id, suggestion, .. } => {
+            assert_eq!(id, "GPL-3.0-onyl");
+            assert_eq!(*suggestion, Some("GPL-3.0-only"));
+        }
+        other => panic!("{:?}", other),
+    }
+    assert_eq!(
+        err.to_string(),
+        "unknown license id `GPL-3.0-onyl`, did you mean `GPL-3.0-only`?"
+    );
+    let err = tags.insert("Internal-Proprietary", "approved").unwrap_err();
+    assert_eq!(err.to_string(), "unknown license id `Internal-Proprietary`");
+    assert_eq!(tags, TagSet::new());
+}
+
+#[test]
+fn policies_consult_tags() {
+    let mut tags = TagSet::new();
+    tags.insert("MIT", "approved")
+        .unwrap()
+        .insert("Apache-2.0", "approved")
+        .unwrap()
+        .insert("AGPL-3.0-only", "banned-for-saas")
+        .unwrap();
+    let mut policy = Policy::new();
+    policy
+        .allow_tagged(&tags, "approved")
+        .deny_tagged(&tags, "banned-for-saas");
+    let evaluate = |expression| policy.evaluate(&Expression::parse(expression).unwrap());
+    let leaf = |id| Leaf::License {
+        id,
+        or_later: false,
+    };
+    assert_eq!(
+        evaluate("MIT AND Apache-2.0"),
+        Decision::Allow {
+            chosen: vec![leaf("MIT"), leaf("Apache-2.0")]
+        }
+    );
+    assert!(matches!(evaluate("AGPL-3.0-only"), Decision::Deny { .. }));
+    assert_eq!(
+        evaluate("ISC"),
+        Decision::NeedsReview {
+            unknowns: vec![leaf("ISC")]
+        }
+    );
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+
+    #[test]
+    fn tags_load_from_toml() {
+        let tags: TagSet = toml::from_str(
+            "MIT = [\"approved\"]\n\
+             \"AGPL-3.0-only\" = [\"banned-for-saas\", \"needs-legal-review\"]\n\
+             ISC = []\n",
+        )
+        .unwrap();
+        assert_eq!(tags_for(&tags, "MIT"), ["approved"]);
+        assert_eq!(
+            tags_for(&tags, "AGPL-3.0-only"),
+            ["banned-for-saas", "needs-legal-review"]
+        );
+        assert!(tags_for(&tags, "ISC").is_empty());
+        assert_eq!(tags.iter().count(), 2);
+    }
+
+    #[test]
+    fn tags_load_from_json() {
+        let tags: TagSet =
+            serde_json::from_str(r#"{"Apache-2.0": ["approved"], "MIT": ["approved"]}"#).unwrap();
+        assert_eq!(
+            tags.licenses_with("approved").collect::<Vec<_>>(),
+            ["Apache-2.0", "MIT"]
+        );
+    }
+
+    #[test]
+    fn unknown_ids_in_documents_are_errors() {
+        let err = serde_json::from_str::<TagSet>(r#"{"MIT": ["approved"], "Apache2.0": []}"#)
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("unknown license id `Apache2.0`, did you mean `Apache-2.0`?"),
+            "{}",
+            err
+        );
+        let err = toml::from_str::<TagSet>("MIT = \"approved\"\n").unwrap_err();
+        assert!(err.to_string().contains("a list of tags"), "{}", err);
+    }
+}
@@ -0,0 +1,120 @@
+use license::text::{self, EQUIVALENT_WORDS};
+
+fn tokens(text: &str) -> Vec<String> {
+    text::tokenize(text)
+        .map(|token| token.to_string())
+        .collect()
+}
+
+#[test]
+fn equivalent_words_are_folded() {
+    assert_eq!(tokens("This Licence"), tokens("this license"));
+    assert_eq!(tokens("the licence"), ["the", "license"]);
+    assert_eq!(tokens("sub-license"), ["sublicense"]);
+    assert_eq!(tokens("Sub License"), ["sublicense"]);
+    assert_eq!(tokens("non-commercial"), tokens("noncommercial"));
+    assert_eq!(tokens("ten per cent"), ["ten", "percent"]);
+    // Only the whole word is folded.
+    assert_eq!(tokens("licences licensed"), ["licences", "licensed"]);
+    assert_eq!(tokens("per capita"), ["per", "capita"]);
+
+    for (variant, word) in EQUIVALENT_WORDS {
+        assert!(
+            text::tokenize(variant).eq(text::tokenize(word)),
+            "{}",
+            variant
+        );
+        assert!(!word.contains(' '), "{}", word);
+    }
+}
+
+#[test]
+fn hyphenated_words_are_joined() {
+    assert_eq!(tokens("non-exclusive"), ["nonexclusive"]);
+    assert_eq!(tokens("non-\nexclusive"), ["nonexclusive"]);
+    assert_eq!(tokens("non-\r\n    exclusive"), ["nonexclusive"]);
+    assert_eq!(tokens("non\u{2010}exclusive"), ["nonexclusive"]);
+    // A dash between spaced words, or a hyphen before a blank line, only separates.
+    assert_eq!(tokens("terms - conditions"), ["terms", "conditions"]);
+    assert_eq!(tokens("terms -conditions"), ["terms", "conditions"]);
+    assert_eq!(tokens("terms- conditions"), ["terms", "conditions"]);
+    assert_eq!(tokens("terms-\n\nconditions"), ["terms", "conditions"]);
+}
+
+#[test]
+fn punctuation_and_case_are_ignored() {
+    assert_eq!(
+        tokens("\u{201c}THE SOFTWARE\u{201d} IS PROVIDED \"AS IS\"..."),
+        ["the", "software", "is", "provided", "as", "is"]
+    );
+    assert_eq!(tokens("\u{fb01}les"), ["files"]);
+}
+
+#[test]
+fn copyright_lines_are_removed() {
+    let text = "Copyright (c) 2024 Example Corp\n\
+                Copyright 2023 Other Corp\n\
+                \n\
+                (C) 2022 Third Corp\n\
+                \u{a9} Fourth Corp\n\
+                \n\
+                Permission is granted\n\
+                copyright holders may not\n\
+                \n\
+                (c) You must retain the notices";
+    assert_eq!(
+        tokens(text),
+        [
+            "permission",
+            "is",
+            "granted",
+            "copyright",
+            "holders",
+            "may",
+            "not",
+            "c",
+            "you",
+            "must",
+            "retain",
+            "the",
+            "notices"
+        ]
+    );
+}
+
+#[test]
+fn raw_spans_point_into_the_text() {
+    let text = "A Licence, sub-\nlicensed.";
+    let raw: Vec<_> = text::tokenize(text).map(|token| token.raw()).collect();
+    assert_eq!(raw, ["A", "Licence", "sub-\nlicensed"]);
+
+    let licence = text::tokenize(text).nth(1).unwrap();
+    let license = text::tokenize("license").next().unwrap();
+    assert_eq!(licence, license);
+    assert_eq!(format!("{:?}", licence), "Token(license)");
+}
+
+#[test]
+fn matching_uses_the_tokenizer() {
+    let mit = license::from_id("MIT").unwrap().text();
+    let british = mit
+        .replace("MIT License", "MIT Licence")
+        .replace("sublicense", "sub-license")
+        .replace("<year> <copyright holders>", "2024 Example Corp");
+    assert_eq!(text::fingerprint(&british), text::fingerprint(mit));
+    assert_eq!(text::best_match(&british).unwrap().license().id(), "MIT");
+}
+
+#[test]
+fn generated_fingerprints_are_current() {
+    for license in license::LICENSES {
+        assert_eq!(
+            license.fingerprint(),
+            text::fingerprint(license.text()),
+            "{}: run `run` again",
+            license.id()
+        );
+    }
+    let classpath = license::from_id_ext("GPL-2.0-only WITH Classpath-exception-2.0").unwrap();
+    assert_eq!(classpath.fingerprint(), text::fingerprint(classpath.text()));
+}
@@ -0,0 +1,4 @@
+fn main() {
+    // The test crate generated by trybuild doesn't set `license`.
+    let _ = license::from_env!();
+}
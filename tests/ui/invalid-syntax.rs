@@ -0,0 +1,3 @@
+fn main() {
+    let _ = license::declared!("MIT, Apache-2.0");
+}
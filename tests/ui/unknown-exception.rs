@@ -0,0 +1,3 @@
+fn main() {
+    let _ = license::declared!("GPL-2.0-only WITH Classpath-exception");
+}
@@ -0,0 +1,3 @@
+fn main() {
+    let _ = license::declared!("MIT OR Apache");
+}
@@ -0,0 +1,8 @@
+const LICENSE: &license::PackageLicense = license::declared!("(MIT OR Apache-2.0) AND Zlib");
+
+fn main() {
+    let gpl = license::declared!("GPL-2.0-or-later WITH Classpath-exception-2.0");
+    assert!(gpl.ext().is_none());
+    assert_eq!(LICENSE.expression(), "(MIT OR Apache-2.0) AND Zlib");
+    assert_eq!(license::declared!("Zlib").ext().unwrap().id(), "Zlib");
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "fs")]
+
+use license::params::CopyrightYears;
+use license::write::{self, LicenseChoice, LicenseParams};
+use std::fs;
+use std::io;
+
+const PARAMS: LicenseParams = LicenseParams {
+    holder: "Jane Doe",
+    years: CopyrightYears::single(2024),
+    overwrite: false,
+};
+
+#[test]
+fn mit_or_apache() {
+    let dir = tempfile::tempdir().unwrap();
+    let paths =
+        write::write_license_files(dir.path(), &LicenseChoice::MitOrApache, &PARAMS).unwrap();
+    assert_eq!(
+        paths,
+        [
+            dir.path().join("LICENSE-APACHE"),
+            dir.path().join("LICENSE-MIT")
+        ]
+    );
+
+    let mit = fs::read_to_string(dir.path().join("LICENSE-MIT")).unwrap();
+    assert!(mit.starts_with("MIT License\n\nCopyright (c) 2024 Jane Doe\n"));
+    assert!(mit.ends_with("SOFTWARE.\n"));
+
+    let apache = fs::read_to_string(dir.path().join("LICENSE-APACHE")).unwrap();
+    let text = license::from_id("Apache-2.0").unwrap().text();
+    assert_eq!(apache.trim_end(), text.trim_end());
+    assert!(apache.ends_with(".\n") && !apache.ends_with("\n\n"));
+}
+
+#[test]
+fn existing_files_are_not_overwritten() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("LICENSE-MIT"), "custom").unwrap();
+
+    let err =
+        write::write_license_files(dir.path(), &LicenseChoice::MitOrApache, &PARAMS).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+    assert_eq!(
+        fs::read_to_string(dir.path().join("LICENSE-MIT")).unwrap(),
+        "custom"
+    );
+    assert!(!dir.path().join("LICENSE-APACHE").exists());
+
+    let overwrite = LicenseParams {
+        overwrite: true,
+        ..PARAMS
+    };
+    write::write_license_files(dir.path(), &LicenseChoice::MitOrApache, &overwrite).unwrap();
+    let mit = fs::read_to_string(dir.path().join("LICENSE-MIT")).unwrap();
+    assert!(mit.starts_with("MIT License"));
+}
+
+#[test]
+fn single_license() {
+    let dir = tempfile::tempdir().unwrap();
+    let bsd = license::from_id("BSD-3-Clause").unwrap();
+    let paths =
+        write::write_license_files(dir.path(), &LicenseChoice::Single(bsd), &PARAMS).unwrap();
+    assert_eq!(paths, [dir.path().join("LICENSE")]);
+    let text = fs::read_to_string(&paths[0]).unwrap();
+    assert!(text.starts_with("Copyright (c) 2024 Jane Doe"), "{}", text);
+}
+
+#[test]
+fn expression_leaves() {
+    let dir = tempfile::tempdir().unwrap();
+    let choice = LicenseChoice::Expression(*license::declared!(
+        "(MIT OR BSD-3-Clause) AND GPL-2.0-only WITH Classpath-exception-2.0 AND MIT"
+    ));
+    let paths = write::write_license_files(dir.path(), &choice, &PARAMS).unwrap();
+    assert_eq!(
+        paths,
+        [
+            dir.path().join("LICENSE-BSD-3-Clause"),
+            dir.path().join("LICENSE-GPL-2.0-only"),
+            dir.path().join("LICENSE-MIT"),
+        ]
+    );
+
+    let single = LicenseChoice::Expression(*license::declared!("GPL-3.0+"));
+    let dir = tempfile::tempdir().unwrap();
+    let paths = write::write_license_files(dir.path(), &single, &PARAMS).unwrap();
+    assert_eq!(paths, [dir.path().join("LICENSE")]);
+}